@@ -0,0 +1,237 @@
+//! Estimated tax due dates for IRS Form 1040-ES.
+//!
+//! The four installments for a calendar tax year are nominally due on
+//! April 15, June 15 and September 15 of the tax year and January 15 of the
+//! following year. When a nominal date falls on a Saturday, Sunday or legal
+//! holiday, the payment is on time if made on the next business day
+//! (IRC § 7503).
+//!
+//! Legal holidays are the federal holidays together with District of Columbia
+//! Emancipation Day (April 16), which the IRS observes and which can move the
+//! April deadline. Holidays falling on a Saturday are observed on the
+//! preceding Friday and those falling on a Sunday on the following Monday.
+//!
+//! All rules are computed from the year, so no per-year data is required.
+//!
+//! # Example
+//!
+//! ```
+//! use chrono::NaiveDate;
+//! use tax_core::calculations::due_dates::installment_due_dates;
+//!
+//! let due_dates = installment_due_dates(2025).unwrap();
+//!
+//! // June 15, 2025 is a Sunday, so the second payment is due Monday, June 16.
+//! assert_eq!(due_dates[1], NaiveDate::from_ymd_opt(2025, 6, 16).unwrap());
+//! ```
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+/// Nominal (year offset, month, day) of each installment, relative to the
+/// tax year. The fourth installment falls in the following calendar year.
+const NOMINAL_DUE_DATES: [(i32, u32, u32); 4] = [(0, 4, 15), (0, 6, 15), (0, 9, 15), (1, 1, 15)];
+
+/// Returns the four installment due dates for a calendar tax year, rolled
+/// forward past weekends and legal holidays.
+///
+/// Returns `None` if the year is outside the range chrono can represent.
+pub fn installment_due_dates(tax_year: i32) -> Option<[NaiveDate; 4]> {
+    let mut due_dates = [NaiveDate::MIN; 4];
+    for (due_date, (year_offset, month, day)) in due_dates.iter_mut().zip(NOMINAL_DUE_DATES) {
+        let nominal = NaiveDate::from_ymd_opt(tax_year.checked_add(year_offset)?, month, day)?;
+        *due_date = next_business_day(nominal);
+    }
+    Some(due_dates)
+}
+
+/// Returns `date` if it is a business day, otherwise the next business day.
+pub fn next_business_day(date: NaiveDate) -> NaiveDate {
+    let mut date = date;
+    while !is_business_day(date) {
+        date = date
+            .succ_opt()
+            .expect("date overflow while skipping holidays");
+    }
+    date
+}
+
+/// Returns `true` if `date` is neither a weekend nor a legal holiday.
+pub fn is_business_day(date: NaiveDate) -> bool {
+    !is_weekend(date) && !is_legal_holiday(date)
+}
+
+/// Returns `true` if `date` is the observed date of a legal holiday.
+///
+/// New Year's Day falling on a Saturday is observed on December 31 of the
+/// previous year, so the following year's holidays are checked as well.
+pub fn is_legal_holiday(date: NaiveDate) -> bool {
+    legal_holidays(date.year()).contains(&date)
+        || legal_holidays(date.year().saturating_add(1)).contains(&date)
+}
+
+/// Returns the observed dates of the legal holidays for `year`.
+///
+/// The observed New Year's Day may fall in the previous calendar year.
+pub fn legal_holidays(year: i32) -> Vec<NaiveDate> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day).map(observed);
+    let nth = |month, weekday, n| NaiveDate::from_weekday_of_month_opt(year, month, weekday, n);
+    let last = |month, weekday| nth(month, weekday, 5).or_else(|| nth(month, weekday, 4));
+
+    [
+        // New Year's Day
+        fixed(1, 1),
+        // Birthday of Martin Luther King, Jr.: third Monday in January
+        nth(1, Weekday::Mon, 3),
+        // Washington's Birthday: third Monday in February
+        nth(2, Weekday::Mon, 3),
+        // District of Columbia Emancipation Day
+        fixed(4, 16),
+        // Memorial Day: last Monday in May
+        last(5, Weekday::Mon),
+        // Juneteenth National Independence Day
+        fixed(6, 19),
+        // Independence Day
+        fixed(7, 4),
+        // Labor Day: first Monday in September
+        nth(9, Weekday::Mon, 1),
+        // Columbus Day: second Monday in October
+        nth(10, Weekday::Mon, 2),
+        // Veterans Day
+        fixed(11, 11),
+        // Thanksgiving Day: fourth Thursday in November
+        nth(11, Weekday::Thu, 4),
+        // Christmas Day
+        fixed(12, 25),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Moves a holiday falling on a weekend to its observed weekday.
+fn observed(date: NaiveDate) -> NaiveDate {
+    match date.weekday() {
+        Weekday::Sat => date.pred_opt().unwrap_or(date),
+        Weekday::Sun => date.succ_opt().unwrap_or(date),
+        _ => date,
+    }
+}
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn date(
+        year: i32,
+        month: u32,
+        day: u32,
+    ) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    // =========================================================================
+    // installment_due_dates tests
+    // =========================================================================
+
+    #[test]
+    fn due_dates_2025_roll_june_past_sunday() {
+        assert_eq!(
+            installment_due_dates(2025),
+            Some([
+                date(2025, 4, 15),
+                date(2025, 6, 16),
+                date(2025, 9, 15),
+                date(2026, 1, 15),
+            ])
+        );
+    }
+
+    #[test]
+    fn due_dates_2026_fall_on_nominal_dates() {
+        assert_eq!(
+            installment_due_dates(2026),
+            Some([
+                date(2026, 4, 15),
+                date(2026, 6, 15),
+                date(2026, 9, 15),
+                date(2027, 1, 15),
+            ])
+        );
+    }
+
+    #[test]
+    fn due_dates_roll_april_past_emancipation_day() {
+        // April 15, 2022 was a Friday on which Emancipation Day was observed.
+        let due_dates = installment_due_dates(2022).unwrap();
+
+        assert_eq!(due_dates[0], date(2022, 4, 18));
+    }
+
+    #[test]
+    fn due_dates_roll_january_past_martin_luther_king_day() {
+        // January 15, 2024 was the third Monday in January.
+        let due_dates = installment_due_dates(2023).unwrap();
+
+        assert_eq!(due_dates[3], date(2024, 1, 16));
+    }
+
+    #[test]
+    fn due_dates_none_for_unrepresentable_year() {
+        assert_eq!(installment_due_dates(i32::MAX), None);
+    }
+
+    // =========================================================================
+    // holiday tests
+    // =========================================================================
+
+    #[test]
+    fn observed_moves_saturday_to_friday_and_sunday_to_monday() {
+        assert_eq!(observed(date(2026, 7, 4)), date(2026, 7, 3));
+        assert_eq!(observed(date(2023, 4, 16)), date(2023, 4, 17));
+        assert_eq!(observed(date(2025, 12, 25)), date(2025, 12, 25));
+    }
+
+    #[test]
+    fn legal_holidays_2025() {
+        assert_eq!(
+            legal_holidays(2025),
+            vec![
+                date(2025, 1, 1),
+                date(2025, 1, 20),
+                date(2025, 2, 17),
+                date(2025, 4, 16),
+                date(2025, 5, 26),
+                date(2025, 6, 19),
+                date(2025, 7, 4),
+                date(2025, 9, 1),
+                date(2025, 10, 13),
+                date(2025, 11, 11),
+                date(2025, 11, 27),
+                date(2025, 12, 25),
+            ]
+        );
+    }
+
+    #[test]
+    fn new_years_day_on_saturday_observed_in_previous_year() {
+        // January 1, 2028 is a Saturday.
+        assert!(is_legal_holiday(date(2027, 12, 31)));
+    }
+
+    #[test]
+    fn next_business_day_skips_weekend_and_holiday() {
+        // Saturday, May 23, 2026 -> Memorial Day Monday -> Tuesday.
+        assert_eq!(next_business_day(date(2026, 5, 23)), date(2026, 5, 26));
+    }
+
+    #[test]
+    fn next_business_day_keeps_business_day() {
+        assert_eq!(next_business_day(date(2025, 4, 15)), date(2025, 4, 15));
+    }
+}
@@ -0,0 +1,400 @@
+//! Quarterly installment schedule for IRS Form 1040-ES.
+//!
+//! Splits the amount to pay through estimated tax (Estimated Tax Worksheet
+//! line 14a) into four equal installments and pairs each with its due date
+//! from [`installment_due_dates`].
+//!
+//! Each installment is rounded to the cent; the fourth installment absorbs
+//! any rounding remainder so the installments always sum to the annual amount.
+//!
+//! # Example
+//!
+//! ```
+//! use chrono::NaiveDate;
+//! use rust_decimal_macros::dec;
+//! use tax_core::calculations::InstallmentSchedule;
+//!
+//! let schedule = InstallmentSchedule::calculate(2025, dec!(10000.01)).unwrap();
+//!
+//! assert_eq!(schedule.installments[0].amount, dec!(2500.00));
+//! assert_eq!(schedule.installments[3].amount, dec!(2500.01));
+//! assert_eq!(
+//!     schedule.installments[1].due_date,
+//!     NaiveDate::from_ymd_opt(2025, 6, 16).unwrap()
+//! );
+//! assert_eq!(schedule.total(), dec!(10000.01));
+//! ```
+
+use std::fmt;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::calculations::EstimatedTaxWorksheetResult;
+use crate::calculations::common::round_half_up;
+use crate::calculations::due_dates::installment_due_dates;
+
+/// Number of estimated tax installments in a calendar tax year.
+const INSTALLMENT_COUNT: usize = 4;
+
+/// Errors that can occur while building an installment schedule.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum InstallmentScheduleError {
+    /// Due dates cannot be computed for the tax year.
+    #[error("installment due dates cannot be computed for tax year {0}")]
+    InvalidTaxYear(i32),
+
+    /// The annual amount to schedule must be non-negative.
+    #[error("annual installment amount must be non-negative, got {0}")]
+    NegativeAmount(Decimal),
+}
+
+/// How the required installments are figured.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InstallmentMethod {
+    /// Four equal installments of 25% of the required annual payment.
+    #[default]
+    Regular,
+
+    /// Installments based on income received before each due date
+    /// (Form 2210, Schedule AI).
+    AnnualizedIncome,
+}
+
+/// A single estimated tax installment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Installment {
+    /// Installment number, 1 through 4.
+    pub number: u8,
+
+    /// Date the payment is due, after rolling past weekends and holidays.
+    pub due_date: NaiveDate,
+
+    /// Amount to pay by the due date.
+    pub amount: Decimal,
+}
+
+/// The four estimated tax installments for a tax year.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstallmentSchedule {
+    /// Tax year the installments are paid for.
+    pub tax_year: i32,
+
+    /// Installments in due-date order.
+    pub installments: Vec<Installment>,
+}
+
+impl InstallmentSchedule {
+    /// Splits `annual_amount` into four installments due during `tax_year`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InstallmentScheduleError`] if:
+    /// - `annual_amount` is negative
+    /// - due dates cannot be computed for `tax_year`
+    pub fn calculate(
+        tax_year: i32,
+        annual_amount: Decimal,
+    ) -> Result<Self, InstallmentScheduleError> {
+        if annual_amount < Decimal::ZERO {
+            return Err(InstallmentScheduleError::NegativeAmount(annual_amount));
+        }
+        let due_dates = installment_due_dates(tax_year)
+            .ok_or(InstallmentScheduleError::InvalidTaxYear(tax_year))?;
+
+        let annual_amount = round_half_up(annual_amount);
+        Ok(Self::with_due_dates(
+            tax_year,
+            due_dates,
+            Self::split(annual_amount),
+        ))
+    }
+
+    /// Pairs explicit per-installment amounts with the due dates for
+    /// `tax_year`, e.g. installments from the annualized income method.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InstallmentScheduleError`] if:
+    /// - any amount is negative
+    /// - due dates cannot be computed for `tax_year`
+    pub fn from_amounts(
+        tax_year: i32,
+        amounts: [Decimal; INSTALLMENT_COUNT],
+    ) -> Result<Self, InstallmentScheduleError> {
+        if let Some(&amount) = amounts.iter().find(|a| **a < Decimal::ZERO) {
+            return Err(InstallmentScheduleError::NegativeAmount(amount));
+        }
+        let due_dates = installment_due_dates(tax_year)
+            .ok_or(InstallmentScheduleError::InvalidTaxYear(tax_year))?;
+
+        Ok(Self::with_due_dates(
+            tax_year,
+            due_dates,
+            amounts.map(round_half_up),
+        ))
+    }
+
+    fn with_due_dates(
+        tax_year: i32,
+        due_dates: [NaiveDate; INSTALLMENT_COUNT],
+        amounts: [Decimal; INSTALLMENT_COUNT],
+    ) -> Self {
+        let installments = due_dates
+            .into_iter()
+            .zip(amounts)
+            .zip(1..)
+            .map(|((due_date, amount), number)| Installment {
+                number,
+                due_date,
+                amount,
+            })
+            .collect();
+
+        Self {
+            tax_year,
+            installments,
+        }
+    }
+
+    /// Builds the schedule for an Estimated Tax Worksheet result.
+    ///
+    /// Uses the line 14a underpayment when estimated payments are required
+    /// and zero otherwise, so the due dates are still reported.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InstallmentScheduleError`] if due dates cannot be computed
+    /// for `tax_year`.
+    pub fn from_worksheet_result(
+        tax_year: i32,
+        result: &EstimatedTaxWorksheetResult,
+    ) -> Result<Self, InstallmentScheduleError> {
+        let annual_amount = if result.estimated_payments_required {
+            result.underpayment
+        } else {
+            Decimal::ZERO
+        };
+        Self::calculate(tax_year, annual_amount)
+    }
+
+    /// Sum of all installment amounts.
+    pub fn total(&self) -> Decimal {
+        self.installments.iter().map(|i| i.amount).sum()
+    }
+
+    /// Splits a rounded annual amount into equal installments, with the
+    /// rounding remainder added to the last one.
+    fn split(annual_amount: Decimal) -> [Decimal; INSTALLMENT_COUNT] {
+        let each = round_half_up(annual_amount / Decimal::from(INSTALLMENT_COUNT));
+        let last = annual_amount - each * Decimal::from(INSTALLMENT_COUNT - 1);
+        [each, each, each, last]
+    }
+}
+
+impl fmt::Display for InstallmentSchedule {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        writeln!(f, "InstallmentSchedule {} {{", self.tax_year)?;
+        for installment in &self.installments {
+            writeln!(
+                f,
+                "    {} : {} : ${}",
+                installment.number,
+                installment.due_date,
+                installment.amount.round_dp(2)
+            )?;
+        }
+        write!(f, "}}")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn date(
+        year: i32,
+        month: u32,
+        day: u32,
+    ) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn worksheet_result(
+        underpayment: Decimal,
+        estimated_payments_required: bool,
+    ) -> EstimatedTaxWorksheetResult {
+        EstimatedTaxWorksheetResult {
+            taxable_income: dec!(85000.00),
+            calculated_tax: dec!(13614.00),
+            total_estimated_tax: dec!(13614.00),
+            prior_year_tax_multiplier: dec!(1.00),
+            prior_year_safe_harbor: dec!(12000.00),
+            required_annual_payment: dec!(12000.00),
+            underpayment,
+            estimated_payments_required,
+        }
+    }
+
+    // =========================================================================
+    // split tests
+    // =========================================================================
+
+    #[test]
+    fn split_divides_evenly() {
+        assert_eq!(
+            InstallmentSchedule::split(dec!(12000.00)),
+            [dec!(3000.00), dec!(3000.00), dec!(3000.00), dec!(3000.00)]
+        );
+    }
+
+    #[test]
+    fn split_puts_remainder_in_last_installment() {
+        assert_eq!(
+            InstallmentSchedule::split(dec!(100.02)),
+            [dec!(25.01), dec!(25.01), dec!(25.01), dec!(24.99)]
+        );
+    }
+
+    // =========================================================================
+    // calculate tests
+    // =========================================================================
+
+    #[test]
+    fn calculate_pairs_amounts_with_2026_due_dates() {
+        let schedule = InstallmentSchedule::calculate(2026, dec!(8000.00)).unwrap();
+
+        assert_eq!(
+            schedule,
+            InstallmentSchedule {
+                tax_year: 2026,
+                installments: vec![
+                    Installment {
+                        number: 1,
+                        due_date: date(2026, 4, 15),
+                        amount: dec!(2000.00),
+                    },
+                    Installment {
+                        number: 2,
+                        due_date: date(2026, 6, 15),
+                        amount: dec!(2000.00),
+                    },
+                    Installment {
+                        number: 3,
+                        due_date: date(2026, 9, 15),
+                        amount: dec!(2000.00),
+                    },
+                    Installment {
+                        number: 4,
+                        due_date: date(2027, 1, 15),
+                        amount: dec!(2000.00),
+                    },
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn calculate_total_matches_rounded_annual_amount() {
+        let schedule = InstallmentSchedule::calculate(2025, dec!(1234.567)).unwrap();
+
+        assert_eq!(schedule.total(), dec!(1234.57));
+    }
+
+    #[test]
+    fn calculate_rejects_negative_amount() {
+        assert_eq!(
+            InstallmentSchedule::calculate(2025, dec!(-1.00)),
+            Err(InstallmentScheduleError::NegativeAmount(dec!(-1.00)))
+        );
+    }
+
+    #[test]
+    fn calculate_rejects_unrepresentable_year() {
+        assert_eq!(
+            InstallmentSchedule::calculate(i32::MAX, dec!(1.00)),
+            Err(InstallmentScheduleError::InvalidTaxYear(i32::MAX))
+        );
+    }
+
+    // =========================================================================
+    // from_amounts tests
+    // =========================================================================
+
+    #[test]
+    fn from_amounts_keeps_uneven_installments() {
+        let schedule = InstallmentSchedule::from_amounts(
+            2025,
+            [dec!(0.00), dec!(0.00), dec!(1012.50), dec!(4837.50)],
+        )
+        .unwrap();
+
+        assert_eq!(schedule.installments[2].amount, dec!(1012.50));
+        assert_eq!(schedule.installments[3].due_date, date(2026, 1, 15));
+        assert_eq!(schedule.total(), dec!(5850.00));
+    }
+
+    #[test]
+    fn from_amounts_rejects_negative_amount() {
+        assert_eq!(
+            InstallmentSchedule::from_amounts(
+                2025,
+                [dec!(1.00), dec!(-2.00), dec!(0.00), dec!(0.00)]
+            ),
+            Err(InstallmentScheduleError::NegativeAmount(dec!(-2.00)))
+        );
+    }
+
+    // =========================================================================
+    // from_worksheet_result tests
+    // =========================================================================
+
+    #[test]
+    fn from_worksheet_result_schedules_underpayment() {
+        let schedule = InstallmentSchedule::from_worksheet_result(
+            2025,
+            &worksheet_result(dec!(6000.00), true),
+        )
+        .unwrap();
+
+        assert_eq!(schedule.total(), dec!(6000.00));
+        assert_eq!(schedule.installments[1].due_date, date(2025, 6, 16));
+    }
+
+    #[test]
+    fn from_worksheet_result_zero_when_payments_not_required() {
+        let schedule = InstallmentSchedule::from_worksheet_result(
+            2025,
+            &worksheet_result(dec!(600.00), false),
+        )
+        .unwrap();
+
+        assert_eq!(schedule.installments.len(), 4);
+        assert_eq!(schedule.total(), dec!(0.00));
+    }
+
+    // =========================================================================
+    // Display tests
+    // =========================================================================
+
+    #[test]
+    fn installment_schedule_display() {
+        let schedule = InstallmentSchedule::calculate(2025, dec!(4000.00)).unwrap();
+        let expected = "InstallmentSchedule 2025 {
+    1 : 2025-04-15 : $1000.00
+    2 : 2025-06-16 : $1000.00
+    3 : 2025-09-15 : $1000.00
+    4 : 2026-01-15 : $1000.00
+}";
+        assert_eq!(format!("{schedule}"), expected);
+    }
+}
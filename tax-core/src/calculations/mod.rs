@@ -4,10 +4,25 @@
 //! organized by the various worksheets that comprise Form 1040-ES.
 
 pub mod common;
+pub mod due_dates;
+pub mod installments;
+pub mod penalty;
 pub mod worksheets;
 
+pub use installments::{
+    Installment, InstallmentMethod, InstallmentSchedule, InstallmentScheduleError,
+};
+pub use penalty::{
+    Payment, UnderpaymentPenaltyCalculator, UnderpaymentPenaltyError, UnderpaymentPenaltyInput,
+    UnderpaymentPenaltyResult, UnderpaymentPeriod, WithholdingTiming,
+};
 pub use worksheets::{
-    EstimatedTaxWorksheet, EstimatedTaxWorksheetContext, EstimatedTaxWorksheetError,
-    EstimatedTaxWorksheetInput, EstimatedTaxWorksheetResult, SeWorksheet, SeWorksheetConfig,
-    SeWorksheetError, SeWorksheetResult,
+    ANNUALIZATION_MONTHS, AdditionalMedicareTaxConfig, AdditionalMedicareTaxError,
+    AdditionalMedicareTaxResult, AdditionalMedicareTaxWorksheet, AnnualizedIncomeError,
+    AnnualizedIncomeInput, AnnualizedIncomePeriod, AnnualizedIncomePeriodResult,
+    AnnualizedIncomeResult, AnnualizedIncomeWorksheet, EstimatedTaxWorksheet,
+    EstimatedTaxWorksheetContext, EstimatedTaxWorksheetError, EstimatedTaxWorksheetInput,
+    EstimatedTaxWorksheetLine, EstimatedTaxWorksheetResult, EstimatedTaxWorksheetTrace, NiitConfig,
+    NiitError, NiitResult, NiitWorksheet, QbiConfig, QbiError, QbiResult, QbiWorksheet, QdcgConfig,
+    QdcgResult, QdcgWorksheet, SeWorksheet, SeWorksheetConfig, SeWorksheetError, SeWorksheetResult,
 };
@@ -0,0 +1,806 @@
+//! Underpayment of estimated tax penalty for IRS Form 2210.
+//!
+//! Each required installment is compared with the estimated payments and
+//! withholding credited by its due date. A shortfall is an underpayment, and
+//! the penalty is interest on it at the IRS underpayment rate from the due
+//! date until it is paid, or until April 15 of the following year if that is
+//! earlier (Form 2210, Part IV and the Penalty Worksheet).
+//!
+//! Payments are applied to the earliest unpaid installment first. Amounts in
+//! excess of an installment are carried forward to the next one.
+//!
+//! Withholding is treated as paid in equal parts on each due date unless the
+//! taxpayer elects to use the dates it was actually withheld.
+//!
+//! Interest is figured separately for each calendar quarter using that
+//! quarter's [`UnderpaymentInterestRate`], divided over the days in the year
+//! (365 or 366). Each quarter's amount is rounded to the cent.
+//!
+//! The IRS announces rates a quarter at a time. Quarters beyond the latest
+//! known rate use that rate, and the result is flagged with
+//! [`UnderpaymentPenaltyResult::uses_projected_rate`].
+//!
+//! # Example
+//!
+//! ```
+//! use chrono::NaiveDate;
+//! use rust_decimal_macros::dec;
+//! use tax_core::UnderpaymentInterestRate;
+//! use tax_core::calculations::{
+//!     InstallmentSchedule, Payment, UnderpaymentPenaltyCalculator, UnderpaymentPenaltyInput,
+//!     WithholdingTiming,
+//! };
+//!
+//! let rates = vec![UnderpaymentInterestRate {
+//!     year: 2025,
+//!     quarter: 2,
+//!     rate: dec!(0.07),
+//! }];
+//! let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+//! let payment = |date, amount| Payment { date, amount };
+//! let input = UnderpaymentPenaltyInput {
+//!     required: InstallmentSchedule::calculate(2025, dec!(4000.00)).unwrap(),
+//!     payments: vec![
+//!         // The first installment is paid 30 days late.
+//!         payment(date(2025, 5, 15), dec!(1000.00)),
+//!         payment(date(2025, 6, 16), dec!(1000.00)),
+//!         payment(date(2025, 9, 15), dec!(1000.00)),
+//!         payment(date(2026, 1, 15), dec!(1000.00)),
+//!     ],
+//!     withholding: WithholdingTiming::Evenly(dec!(0.00)),
+//! };
+//!
+//! let result = UnderpaymentPenaltyCalculator::new(&rates)
+//!     .calculate(&input)
+//!     .unwrap();
+//!
+//! // 1000 × 7% × 30 / 365
+//! assert_eq!(result.total_penalty(), dec!(5.75));
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use chrono::{Datelike, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::calculations::common::round_half_up;
+use crate::calculations::installments::{InstallmentSchedule, InstallmentScheduleError};
+use crate::models::UnderpaymentInterestRate;
+
+/// Month and day of the end of the penalty period, in the year after the
+/// tax year.
+const PENALTY_END: (u32, u32) = (4, 15);
+
+/// Errors that can occur while figuring the underpayment penalty.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum UnderpaymentPenaltyError {
+    /// No rate is known for a quarter before the latest known rate.
+    #[error("no underpayment interest rate for {year} Q{quarter}")]
+    MissingRate { year: i32, quarter: u32 },
+
+    /// Payments and withholding must be non-negative.
+    #[error("payment amount must be non-negative, got {0}")]
+    NegativeAmount(Decimal),
+
+    /// The end of the penalty period cannot be computed for the tax year.
+    #[error("penalty period cannot be computed for tax year {0}")]
+    InvalidTaxYear(i32),
+
+    /// The evenly spread withholding could not be scheduled.
+    #[error(transparent)]
+    Withholding(#[from] InstallmentScheduleError),
+}
+
+/// An amount paid toward the tax year on a given date.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Payment {
+    pub date: NaiveDate,
+    pub amount: Decimal,
+}
+
+/// When withheld income tax is treated as paid.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WithholdingTiming {
+    /// Total withholding for the year, treated as paid in equal parts on
+    /// each installment due date.
+    Evenly(Decimal),
+
+    /// Withholding on the dates it was actually withheld.
+    ActualDates(Vec<Payment>),
+}
+
+/// Inputs for the Form 2210 penalty calculation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnderpaymentPenaltyInput {
+    /// Required installments (Form 2210 line 18), before withholding.
+    pub required: InstallmentSchedule,
+
+    /// Estimated tax payments actually made, in any order.
+    pub payments: Vec<Payment>,
+
+    /// Income tax withheld during the year.
+    pub withholding: WithholdingTiming,
+}
+
+/// Underpayment and penalty for a single installment period.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnderpaymentPeriod {
+    /// Installment number, 1 through 4.
+    pub number: u8,
+
+    /// Date the installment was due.
+    pub due_date: NaiveDate,
+
+    /// Required installment.
+    pub required: Decimal,
+
+    /// Part of the required installment not paid by the due date.
+    pub underpayment: Decimal,
+
+    /// Penalty on the underpayment until it was paid.
+    pub penalty: Decimal,
+}
+
+/// Result of the Form 2210 penalty calculation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnderpaymentPenaltyResult {
+    /// Tax year the installments were required for.
+    pub tax_year: i32,
+
+    /// One entry per required installment.
+    pub periods: Vec<UnderpaymentPeriod>,
+
+    /// Date after which no further penalty accrues.
+    pub penalty_end_date: NaiveDate,
+
+    /// `true` if any quarter was figured with a rate projected from the
+    /// latest known rate rather than an announced one.
+    pub uses_projected_rate: bool,
+}
+
+impl UnderpaymentPenaltyResult {
+    /// Total penalty across all periods.
+    pub fn total_penalty(&self) -> Decimal {
+        self.periods.iter().map(|p| p.penalty).sum()
+    }
+
+    /// Total underpayment across all periods at their due dates.
+    pub fn total_underpayment(&self) -> Decimal {
+        self.periods.iter().map(|p| p.underpayment).sum()
+    }
+}
+
+impl fmt::Display for UnderpaymentPenaltyResult {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        writeln!(f, "UnderpaymentPenaltyResult {} {{", self.tax_year)?;
+        for period in &self.periods {
+            writeln!(
+                f,
+                "    {} : {} : required ${} : underpaid ${} : penalty ${}",
+                period.number,
+                period.due_date,
+                period.required.round_dp(2),
+                period.underpayment.round_dp(2),
+                period.penalty.round_dp(2)
+            )?;
+        }
+        writeln!(f, "    Penalty through         : {}", self.penalty_end_date)?;
+        writeln!(
+            f,
+            "    Uses projected rate     : {}",
+            self.uses_projected_rate
+        )?;
+        writeln!(
+            f,
+            "    Total penalty           : ${}",
+            self.total_penalty().round_dp(2)
+        )?;
+        write!(f, "}}")?;
+        Ok(())
+    }
+}
+
+/// Part of an installment that is still unpaid.
+struct Unpaid {
+    period: usize,
+    amount: Decimal,
+}
+
+/// Form 2210 underpayment penalty calculator.
+pub struct UnderpaymentPenaltyCalculator<'a> {
+    rates: &'a [UnderpaymentInterestRate],
+}
+
+impl<'a> UnderpaymentPenaltyCalculator<'a> {
+    /// Creates a calculator from the quarterly underpayment interest rates.
+    pub fn new(rates: &'a [UnderpaymentInterestRate]) -> Self {
+        Self { rates }
+    }
+
+    /// Figures the underpayment and penalty for each required installment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnderpaymentPenaltyError`] if:
+    /// - a payment or the withholding is negative
+    /// - a rate is missing for a quarter the penalty accrues in
+    /// - the penalty period cannot be computed for the tax year
+    pub fn calculate(
+        &self,
+        input: &UnderpaymentPenaltyInput,
+    ) -> Result<UnderpaymentPenaltyResult, UnderpaymentPenaltyError> {
+        let tax_year = input.required.tax_year;
+        let penalty_end_date = tax_year
+            .checked_add(1)
+            .and_then(|year| NaiveDate::from_ymd_opt(year, PENALTY_END.0, PENALTY_END.1))
+            .ok_or(UnderpaymentPenaltyError::InvalidTaxYear(tax_year))?;
+
+        let mut credits = Self::credits(input)?;
+        credits.sort_by_key(|credit| credit.date);
+        let mut credits = credits.into_iter().peekable();
+
+        let mut periods: Vec<UnderpaymentPeriod> = input
+            .required
+            .installments
+            .iter()
+            .map(|installment| UnderpaymentPeriod {
+                number: installment.number,
+                due_date: installment.due_date,
+                required: installment.amount,
+                underpayment: Decimal::ZERO,
+                penalty: Decimal::ZERO,
+            })
+            .collect();
+        let mut unpaid = VecDeque::new();
+        let mut overpayment = Decimal::ZERO;
+        let mut uses_projected_rate = false;
+
+        for index in 0..periods.len() {
+            // Payments made on or before the due date first settle earlier
+            // underpayments; anything left is credited to this installment.
+            let due_date = periods[index].due_date;
+            while let Some(credit) = credits.next_if(|credit| credit.date <= due_date) {
+                overpayment +=
+                    self.pay_down(&credit, &mut unpaid, &mut periods, &mut uses_projected_rate)?;
+            }
+
+            let period = &mut periods[index];
+            let covered = overpayment.min(period.required);
+            overpayment -= covered;
+            period.underpayment = period.required - covered;
+            if period.underpayment > Decimal::ZERO {
+                unpaid.push_back(Unpaid {
+                    period: index,
+                    amount: period.underpayment,
+                });
+            }
+        }
+
+        // Payments after the last due date settle underpayments until the
+        // end of the penalty period; later payments no longer reduce it.
+        for credit in credits.take_while(|credit| credit.date <= penalty_end_date) {
+            self.pay_down(&credit, &mut unpaid, &mut periods, &mut uses_projected_rate)?;
+        }
+
+        for remaining in unpaid {
+            let period = &mut periods[remaining.period];
+            let (penalty, projected) =
+                self.penalty(remaining.amount, period.due_date, penalty_end_date)?;
+            period.penalty += penalty;
+            uses_projected_rate |= projected;
+        }
+
+        Ok(UnderpaymentPenaltyResult {
+            tax_year,
+            periods,
+            penalty_end_date,
+            uses_projected_rate,
+        })
+    }
+
+    /// Estimated payments and withholding as dated credits.
+    fn credits(input: &UnderpaymentPenaltyInput) -> Result<Vec<Payment>, UnderpaymentPenaltyError> {
+        let withholding = match &input.withholding {
+            WithholdingTiming::Evenly(total) => {
+                InstallmentSchedule::calculate(input.required.tax_year, *total)?
+                    .installments
+                    .into_iter()
+                    .map(|installment| Payment {
+                        date: installment.due_date,
+                        amount: installment.amount,
+                    })
+                    .collect()
+            }
+            WithholdingTiming::ActualDates(withheld) => withheld.clone(),
+        };
+
+        let credits: Vec<Payment> = input.payments.iter().cloned().chain(withholding).collect();
+        if let Some(credit) = credits.iter().find(|c| c.amount < Decimal::ZERO) {
+            return Err(UnderpaymentPenaltyError::NegativeAmount(credit.amount));
+        }
+        Ok(credits)
+    }
+
+    /// Applies `payment` to the earliest unpaid installments, adding the
+    /// penalty for each from its due date to the payment date. Returns the
+    /// part of the payment left over.
+    fn pay_down(
+        &self,
+        payment: &Payment,
+        unpaid: &mut VecDeque<Unpaid>,
+        periods: &mut [UnderpaymentPeriod],
+        uses_projected_rate: &mut bool,
+    ) -> Result<Decimal, UnderpaymentPenaltyError> {
+        let mut remaining = payment.amount;
+        while remaining > Decimal::ZERO {
+            let Some(oldest) = unpaid.front_mut() else {
+                break;
+            };
+            let applied = remaining.min(oldest.amount);
+            let period = &mut periods[oldest.period];
+            let (penalty, projected) = self.penalty(applied, period.due_date, payment.date)?;
+            period.penalty += penalty;
+            *uses_projected_rate |= projected;
+
+            remaining -= applied;
+            oldest.amount -= applied;
+            if oldest.amount.is_zero() {
+                unpaid.pop_front();
+            }
+        }
+        Ok(remaining)
+    }
+
+    /// Interest on `amount` for the days after `from` through `to`, figured
+    /// separately for each calendar quarter. Also returns whether a projected
+    /// rate was used.
+    fn penalty(
+        &self,
+        amount: Decimal,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<(Decimal, bool), UnderpaymentPenaltyError> {
+        let mut penalty = Decimal::ZERO;
+        let mut projected = false;
+        let mut cursor = from;
+        while cursor < to {
+            let Some(day) = cursor.succ_opt() else {
+                break;
+            };
+            let quarter = quarter_of(day);
+            let segment_end = quarter_end(day.year(), quarter).min(to);
+            let days = (segment_end - cursor).num_days();
+
+            let (rate, is_projected) = self.rate_for(day.year(), quarter)?;
+            penalty += round_half_up(
+                amount * rate * Decimal::from(days) / Decimal::from(days_in_year(day.year())),
+            );
+            projected |= is_projected;
+            cursor = segment_end;
+        }
+        Ok((penalty, projected))
+    }
+
+    /// The rate for a quarter, or the latest known rate (flagged as
+    /// projected) for quarters after it.
+    fn rate_for(
+        &self,
+        year: i32,
+        quarter: u32,
+    ) -> Result<(Decimal, bool), UnderpaymentPenaltyError> {
+        if let Some(rate) = self
+            .rates
+            .iter()
+            .find(|r| r.year == year && r.quarter == quarter)
+        {
+            return Ok((rate.rate, false));
+        }
+
+        match self.rates.iter().max_by_key(|r| (r.year, r.quarter)) {
+            Some(latest) if (latest.year, latest.quarter) < (year, quarter) => {
+                Ok((latest.rate, true))
+            }
+            _ => Err(UnderpaymentPenaltyError::MissingRate { year, quarter }),
+        }
+    }
+}
+
+/// Calendar quarter (1 through 4) containing `date`.
+fn quarter_of(date: NaiveDate) -> u32 {
+    date.month0() / 3 + 1
+}
+
+/// Last day of a calendar quarter.
+fn quarter_end(
+    year: i32,
+    quarter: u32,
+) -> NaiveDate {
+    let next_quarter_start = if quarter == 4 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, quarter * 3 + 1, 1)
+    };
+    next_quarter_start
+        .and_then(|date| date.pred_opt())
+        .unwrap_or(NaiveDate::MAX)
+}
+
+fn days_in_year(year: i32) -> u32 {
+    if NaiveDate::from_ymd_opt(year, 2, 29).is_some() {
+        366
+    } else {
+        365
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn date(
+        year: i32,
+        month: u32,
+        day: u32,
+    ) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn rate(
+        year: i32,
+        quarter: u32,
+        rate: Decimal,
+    ) -> UnderpaymentInterestRate {
+        UnderpaymentInterestRate {
+            year,
+            quarter,
+            rate,
+        }
+    }
+
+    /// 8% for 2024 and 7% for 2025 through the second quarter of 2026.
+    fn rates() -> Vec<UnderpaymentInterestRate> {
+        let mut rates: Vec<_> = (1..=4).map(|q| rate(2024, q, dec!(0.08))).collect();
+        rates.extend((1..=4).map(|q| rate(2025, q, dec!(0.07))));
+        rates.extend((1..=2).map(|q| rate(2026, q, dec!(0.07))));
+        rates
+    }
+
+    fn payment(
+        date: NaiveDate,
+        amount: Decimal,
+    ) -> Payment {
+        Payment { date, amount }
+    }
+
+    fn input(
+        payments: Vec<Payment>,
+        withholding: WithholdingTiming,
+    ) -> UnderpaymentPenaltyInput {
+        UnderpaymentPenaltyInput {
+            required: InstallmentSchedule::calculate(2025, dec!(4000.00)).unwrap(),
+            payments,
+            withholding,
+        }
+    }
+
+    fn penalties(result: &UnderpaymentPenaltyResult) -> Vec<Decimal> {
+        result.periods.iter().map(|p| p.penalty).collect()
+    }
+
+    fn underpayments(result: &UnderpaymentPenaltyResult) -> Vec<Decimal> {
+        result.periods.iter().map(|p| p.underpayment).collect()
+    }
+
+    // =========================================================================
+    // penalty tests
+    // =========================================================================
+
+    #[test]
+    fn penalty_within_single_quarter() {
+        let rates = rates();
+        let calculator = UnderpaymentPenaltyCalculator::new(&rates);
+
+        // 30 days at 7%: 1000 × 0.07 × 30 / 365 = 5.753...
+        assert_eq!(
+            calculator.penalty(dec!(1000.00), date(2025, 4, 15), date(2025, 5, 15)),
+            Ok((dec!(5.75), false))
+        );
+    }
+
+    #[test]
+    fn penalty_splits_across_rate_change_and_leap_year() {
+        let rates = rates();
+        let calculator = UnderpaymentPenaltyCalculator::new(&rates);
+
+        // 15 days at 8% / 366 = 3.28, then 15 days at 7% / 365 = 2.88.
+        assert_eq!(
+            calculator.penalty(dec!(1000.00), date(2024, 12, 16), date(2025, 1, 15)),
+            Ok((dec!(6.16), false))
+        );
+    }
+
+    #[test]
+    fn penalty_is_zero_when_paid_on_due_date() {
+        let rates = rates();
+        let calculator = UnderpaymentPenaltyCalculator::new(&rates);
+
+        assert_eq!(
+            calculator.penalty(dec!(1000.00), date(2025, 4, 15), date(2025, 4, 15)),
+            Ok((dec!(0), false))
+        );
+    }
+
+    // =========================================================================
+    // rate lookup tests
+    // =========================================================================
+
+    #[test]
+    fn rate_for_projects_latest_rate_forward() {
+        let rates = vec![rate(2025, 3, dec!(0.07)), rate(2025, 4, dec!(0.06))];
+        let calculator = UnderpaymentPenaltyCalculator::new(&rates);
+
+        assert_eq!(calculator.rate_for(2025, 4), Ok((dec!(0.06), false)));
+        assert_eq!(calculator.rate_for(2026, 2), Ok((dec!(0.06), true)));
+    }
+
+    #[test]
+    fn rate_for_rejects_quarter_before_latest_rate() {
+        let rates = vec![rate(2025, 3, dec!(0.07)), rate(2025, 4, dec!(0.06))];
+        let calculator = UnderpaymentPenaltyCalculator::new(&rates);
+
+        assert_eq!(
+            calculator.rate_for(2025, 2),
+            Err(UnderpaymentPenaltyError::MissingRate {
+                year: 2025,
+                quarter: 2
+            })
+        );
+    }
+
+    #[test]
+    fn rate_for_rejects_empty_rates() {
+        let calculator = UnderpaymentPenaltyCalculator::new(&[]);
+
+        assert_eq!(
+            calculator.rate_for(2025, 1),
+            Err(UnderpaymentPenaltyError::MissingRate {
+                year: 2025,
+                quarter: 1
+            })
+        );
+    }
+
+    // =========================================================================
+    // calculate tests
+    // =========================================================================
+
+    #[test]
+    fn no_penalty_when_paid_on_time() {
+        let rates = rates();
+        let payments = vec![
+            payment(date(2025, 4, 15), dec!(1000.00)),
+            payment(date(2025, 6, 16), dec!(1000.00)),
+            payment(date(2025, 9, 15), dec!(1000.00)),
+            payment(date(2026, 1, 15), dec!(1000.00)),
+        ];
+
+        let result = UnderpaymentPenaltyCalculator::new(&rates)
+            .calculate(&input(payments, WithholdingTiming::Evenly(dec!(0))))
+            .unwrap();
+
+        assert_eq!(result.total_underpayment(), dec!(0));
+        assert_eq!(result.total_penalty(), dec!(0));
+        assert!(!result.uses_projected_rate);
+    }
+
+    #[test]
+    fn overpayment_carries_forward() {
+        let rates = rates();
+        let payments = vec![payment(date(2025, 4, 1), dec!(4000.00))];
+
+        let result = UnderpaymentPenaltyCalculator::new(&rates)
+            .calculate(&input(payments, WithholdingTiming::Evenly(dec!(0))))
+            .unwrap();
+
+        assert_eq!(result.total_penalty(), dec!(0));
+    }
+
+    #[test]
+    fn late_payment_applies_to_earliest_underpayment() {
+        let rates = rates();
+        let payments = vec![
+            // Covers installment 1, 62 days late, then installment 2 on time.
+            payment(date(2025, 6, 16), dec!(2000.00)),
+            payment(date(2025, 9, 15), dec!(1000.00)),
+            payment(date(2026, 1, 15), dec!(1000.00)),
+        ];
+
+        let result = UnderpaymentPenaltyCalculator::new(&rates)
+            .calculate(&input(payments, WithholdingTiming::Evenly(dec!(0))))
+            .unwrap();
+
+        assert_eq!(
+            underpayments(&result),
+            vec![dec!(1000.00), dec!(0), dec!(0), dec!(0)]
+        );
+        // 1000 × 0.07 × 62 / 365 = 11.890...
+        assert_eq!(
+            penalties(&result),
+            vec![dec!(11.89), dec!(0), dec!(0), dec!(0)]
+        );
+    }
+
+    #[test]
+    fn skipped_payments_accrue_until_april_15() {
+        let rates = rates();
+
+        let result = UnderpaymentPenaltyCalculator::new(&rates)
+            .calculate(&input(vec![], WithholdingTiming::Evenly(dec!(0))))
+            .unwrap();
+
+        assert_eq!(result.penalty_end_date, date(2026, 4, 15));
+        assert_eq!(
+            underpayments(&result),
+            vec![dec!(1000.00), dec!(1000.00), dec!(1000.00), dec!(1000.00)]
+        );
+        // Installment 1: 76, 92 and 92 days in 2025, then 90 and 15 in 2026.
+        // Installment 4: 75 days to March 31, then 15.
+        assert_eq!(
+            penalties(&result),
+            vec![dec!(70.00), dec!(58.10), dec!(40.66), dec!(17.26)]
+        );
+        assert!(!result.uses_projected_rate);
+    }
+
+    #[test]
+    fn payment_after_april_15_does_not_reduce_penalty() {
+        let rates = rates();
+        let payments = vec![
+            payment(date(2025, 4, 15), dec!(1000.00)),
+            payment(date(2025, 6, 16), dec!(1000.00)),
+            payment(date(2025, 9, 15), dec!(1000.00)),
+            payment(date(2026, 6, 1), dec!(1000.00)),
+        ];
+
+        let result = UnderpaymentPenaltyCalculator::new(&rates)
+            .calculate(&input(payments, WithholdingTiming::Evenly(dec!(0))))
+            .unwrap();
+
+        assert_eq!(
+            penalties(&result),
+            vec![dec!(0), dec!(0), dec!(0), dec!(17.26)]
+        );
+    }
+
+    #[test]
+    fn even_withholding_is_credited_on_each_due_date() {
+        let rates = rates();
+
+        let result = UnderpaymentPenaltyCalculator::new(&rates)
+            .calculate(&input(vec![], WithholdingTiming::Evenly(dec!(4000.00))))
+            .unwrap();
+
+        assert_eq!(result.total_underpayment(), dec!(0));
+        assert_eq!(result.total_penalty(), dec!(0));
+    }
+
+    #[test]
+    fn actual_withholding_dates_credit_when_withheld() {
+        let rates = rates();
+        let withholding =
+            WithholdingTiming::ActualDates(vec![payment(date(2025, 12, 31), dec!(4000.00))]);
+
+        let result = UnderpaymentPenaltyCalculator::new(&rates)
+            .calculate(&input(vec![], withholding))
+            .unwrap();
+
+        assert_eq!(
+            underpayments(&result),
+            vec![dec!(1000.00), dec!(1000.00), dec!(1000.00), dec!(0)]
+        );
+        // Installment 1: 76 + 92 + 92 days, 2: 14 + 92 + 92, 3: 15 + 92.
+        assert_eq!(
+            penalties(&result),
+            vec![dec!(49.86), dec!(37.96), dec!(20.52), dec!(0)]
+        );
+    }
+
+    #[test]
+    fn projected_rate_is_flagged() {
+        let rates: Vec<_> = (1..=4).map(|q| rate(2025, q, dec!(0.07))).collect();
+        let payments = vec![
+            payment(date(2025, 4, 15), dec!(1000.00)),
+            payment(date(2025, 6, 16), dec!(1000.00)),
+            payment(date(2025, 9, 15), dec!(1000.00)),
+        ];
+
+        let result = UnderpaymentPenaltyCalculator::new(&rates)
+            .calculate(&input(payments, WithholdingTiming::Evenly(dec!(0))))
+            .unwrap();
+
+        assert!(result.uses_projected_rate);
+        assert_eq!(result.total_penalty(), dec!(17.26));
+    }
+
+    #[test]
+    fn missing_rate_is_an_error() {
+        let rates = vec![rate(2026, 1, dec!(0.07))];
+
+        let result = UnderpaymentPenaltyCalculator::new(&rates)
+            .calculate(&input(vec![], WithholdingTiming::Evenly(dec!(0))));
+
+        assert_eq!(
+            result,
+            Err(UnderpaymentPenaltyError::MissingRate {
+                year: 2025,
+                quarter: 2
+            })
+        );
+    }
+
+    #[test]
+    fn negative_payment_is_an_error() {
+        let rates = rates();
+        let payments = vec![payment(date(2025, 4, 15), dec!(-5.00))];
+
+        let result = UnderpaymentPenaltyCalculator::new(&rates)
+            .calculate(&input(payments, WithholdingTiming::Evenly(dec!(0))));
+
+        assert_eq!(
+            result,
+            Err(UnderpaymentPenaltyError::NegativeAmount(dec!(-5.00)))
+        );
+    }
+
+    #[test]
+    fn negative_even_withholding_is_an_error() {
+        let rates = rates();
+
+        let result = UnderpaymentPenaltyCalculator::new(&rates)
+            .calculate(&input(vec![], WithholdingTiming::Evenly(dec!(-1.00))));
+
+        assert_eq!(
+            result,
+            Err(UnderpaymentPenaltyError::Withholding(
+                InstallmentScheduleError::NegativeAmount(dec!(-1.00))
+            ))
+        );
+    }
+
+    // =========================================================================
+    // Display tests
+    // =========================================================================
+
+    #[test]
+    fn underpayment_penalty_result_display() {
+        let rates = rates();
+        let payments = vec![
+            payment(date(2025, 6, 16), dec!(2000.00)),
+            payment(date(2025, 9, 15), dec!(1000.00)),
+            payment(date(2026, 1, 15), dec!(1000.00)),
+        ];
+        let result = UnderpaymentPenaltyCalculator::new(&rates)
+            .calculate(&input(payments, WithholdingTiming::Evenly(dec!(0))))
+            .unwrap();
+
+        let expected = "UnderpaymentPenaltyResult 2025 {
+    1 : 2025-04-15 : required $1000.00 : underpaid $1000.00 : penalty $11.89
+    2 : 2025-06-16 : required $1000.00 : underpaid $0.00 : penalty $0
+    3 : 2025-09-15 : required $1000.00 : underpaid $0.00 : penalty $0
+    4 : 2026-01-15 : required $1000.00 : underpaid $0.00 : penalty $0
+    Penalty through         : 2026-04-15
+    Uses projected rate     : false
+    Total penalty           : $11.89
+}";
+        assert_eq!(format!("{result}"), expected);
+    }
+}
@@ -0,0 +1,507 @@
+//! Additional Medicare Tax calculations for IRS Form 8959.
+//!
+//! This module implements Parts I, II and V of Form 8959, which apply the
+//! Additional Medicare Tax to Medicare wages and self-employment income above
+//! the filing-status threshold. The total is reported on Schedule 2 and is
+//! included in line 10 (other taxes) of the 1040-ES Estimated Tax Worksheet.
+//!
+//! # Worksheet Structure
+//!
+//! | Line | Description |
+//! |------|-------------|
+//! | 4    | Medicare wages and tips (Form W-2, box 5) |
+//! | 5    | Threshold for filing status |
+//! | 6    | Line 4 minus Line 5 (if zero or less, enter -0-) |
+//! | 7    | Additional Medicare Tax on wages: Line 6 × 0.9% |
+//! | 8    | Self-employment income (Schedule SE, line 6; if a loss, enter -0-) |
+//! | 9    | Threshold for filing status |
+//! | 10   | Amount from Line 4 |
+//! | 11   | Line 9 minus Line 10 (if zero or less, enter -0-) |
+//! | 12   | Line 8 minus Line 11 (if zero or less, enter -0-) |
+//! | 13   | Additional Medicare Tax on SE income: Line 12 × 0.9% |
+//! | 18   | Total Additional Medicare Tax: Line 7 + Line 13 |
+//!
+//! Wages reduce the threshold available to self-employment income (Line 11),
+//! so a taxpayer with wages above the threshold pays the tax on every dollar
+//! of self-employment income.
+//!
+//! # Example
+//!
+//! ```
+//! use rust_decimal_macros::dec;
+//! use tax_core::calculations::{AdditionalMedicareTaxConfig, AdditionalMedicareTaxWorksheet};
+//!
+//! let config = AdditionalMedicareTaxConfig {
+//!     rate: dec!(0.009),
+//!     threshold: dec!(200000.00),
+//! };
+//!
+//! let worksheet = AdditionalMedicareTaxWorksheet::new(config);
+//! let result = worksheet.calculate(
+//!     dec!(150000.00), // wages
+//!     dec!(92350.00),  // SE income (Schedule SE, line 6)
+//! ).unwrap();
+//!
+//! // Wages are under the threshold, which leaves $50,000 for SE income.
+//! assert_eq!(result.se_threshold, dec!(50000.00));
+//! assert_eq!(result.se_income_over_threshold, dec!(42350.00));
+//! assert_eq!(result.total_tax, dec!(381.15));
+//! ```
+
+use std::fmt;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::calculations::common::{max, round_half_up};
+use crate::{AdditionalMedicareThreshold, TaxYearConfig};
+
+/// Errors that can occur during Additional Medicare Tax calculations.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum AdditionalMedicareTaxError {
+    /// The Additional Medicare Tax rate must be between 0 and 1.
+    #[error("additional medicare tax rate must be between 0 and 1, got {0}")]
+    InvalidRate(Decimal),
+
+    /// The filing-status threshold must be non-negative.
+    #[error("additional medicare tax threshold must be non-negative, got {0}")]
+    InvalidThreshold(Decimal),
+}
+
+/// Configuration parameters for Form 8959 calculations.
+///
+/// The rate comes from [`TaxYearConfig`] and the threshold from the
+/// [`AdditionalMedicareThreshold`] for the taxpayer's filing status.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AdditionalMedicareTaxConfig {
+    /// Additional Medicare Tax rate (Lines 7 and 13 multiplier).
+    ///
+    /// For 2025, this is 0.9%.
+    pub rate: Decimal,
+
+    /// Threshold for the filing status (Lines 5 and 9).
+    ///
+    /// $250,000 for married filing jointly, $125,000 for married filing
+    /// separately, and $200,000 for all other filing statuses.
+    pub threshold: Decimal,
+}
+
+impl AdditionalMedicareTaxConfig {
+    /// Creates a new configuration from the tax year's reference data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal_macros::dec;
+    /// use tax_core::calculations::AdditionalMedicareTaxConfig;
+    /// use tax_core::{AdditionalMedicareThreshold, TaxYearConfig};
+    ///
+    /// let tax_year_config = TaxYearConfig {
+    ///     tax_year: 2025,
+    ///     ss_wage_max: dec!(176100.00),
+    ///     ss_tax_rate: dec!(0.124),
+    ///     medicare_tax_rate: dec!(0.029),
+    ///     se_tax_deduct_pcnt: dec!(0.9235),
+    ///     se_deduction_factor: dec!(0.50),
+    ///     req_pmnt_threshold: dec!(1000.00),
+    ///     min_se_threshold: dec!(400.00),
+    ///     addl_medicare_tax_rate: dec!(0.009),
+    ///     niit_rate: dec!(0.038),
+    ///     prior_agi_threshold: dec!(150000.00),
+    ///     prior_agi_threshold_mfs: dec!(75000.00),
+    ///     prior_tax_multiplier: dec!(1.10),
+    /// };
+    /// let threshold = AdditionalMedicareThreshold {
+    ///     tax_year: 2025,
+    ///     filing_status_id: 2,
+    ///     amount: dec!(250000.00),
+    /// };
+    ///
+    /// let config = AdditionalMedicareTaxConfig::from_reference(&tax_year_config, &threshold);
+    ///
+    /// assert_eq!(config.rate, dec!(0.009));
+    /// assert_eq!(config.threshold, dec!(250000.00));
+    /// ```
+    pub fn from_reference(
+        config: &TaxYearConfig,
+        threshold: &AdditionalMedicareThreshold,
+    ) -> Self {
+        Self {
+            rate: config.addl_medicare_tax_rate,
+            threshold: threshold.amount,
+        }
+    }
+
+    /// Validates the configuration values.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AdditionalMedicareTaxError`] if:
+    /// - `rate` is not in [0, 1]
+    /// - `threshold` is negative
+    pub fn validate(&self) -> Result<(), AdditionalMedicareTaxError> {
+        if self.rate < Decimal::ZERO || self.rate > Decimal::ONE {
+            return Err(AdditionalMedicareTaxError::InvalidRate(self.rate));
+        }
+        if self.threshold < Decimal::ZERO {
+            return Err(AdditionalMedicareTaxError::InvalidThreshold(self.threshold));
+        }
+        Ok(())
+    }
+}
+
+/// Result of Form 8959 calculations.
+///
+/// Carries every intermediate line so reviewers can trace how the total was
+/// reached.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AdditionalMedicareTaxResult {
+    /// Medicare wages and tips (Line 4).
+    pub medicare_wages: Decimal,
+
+    /// Wages above the filing-status threshold (Line 6).
+    pub wages_over_threshold: Decimal,
+
+    /// Additional Medicare Tax on wages (Line 7).
+    pub wage_tax: Decimal,
+
+    /// Self-employment income, with losses treated as zero (Line 8).
+    pub se_income: Decimal,
+
+    /// Threshold remaining for SE income after subtracting wages (Line 11).
+    pub se_threshold: Decimal,
+
+    /// SE income above the remaining threshold (Line 12).
+    pub se_income_over_threshold: Decimal,
+
+    /// Additional Medicare Tax on SE income (Line 13).
+    pub se_tax: Decimal,
+
+    /// Total Additional Medicare Tax (Line 18).
+    ///
+    /// This amount is included in line 10 of the Estimated Tax Worksheet.
+    pub total_tax: Decimal,
+}
+
+impl fmt::Display for AdditionalMedicareTaxResult {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        writeln!(f, "AdditionalMedicareTaxResult {{")?;
+        writeln!(
+            f,
+            "    medicare_wages           : ${}",
+            self.medicare_wages.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    wages_over_threshold     : ${}",
+            self.wages_over_threshold.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    wage_tax                 : ${}",
+            self.wage_tax.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    se_income                : ${}",
+            self.se_income.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    se_threshold             : ${}",
+            self.se_threshold.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    se_income_over_threshold : ${}",
+            self.se_income_over_threshold.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    se_tax                   : ${}",
+            self.se_tax.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    total_tax                : ${}",
+            self.total_tax.round_dp(2)
+        )?;
+        write!(f, "}}")?;
+        Ok(())
+    }
+}
+
+/// Calculator for the Additional Medicare Tax (Form 8959).
+#[derive(Debug, Clone)]
+pub struct AdditionalMedicareTaxWorksheet {
+    config: AdditionalMedicareTaxConfig,
+}
+
+impl AdditionalMedicareTaxWorksheet {
+    /// Creates a new Form 8959 calculator with the given configuration.
+    pub fn new(config: AdditionalMedicareTaxConfig) -> Self {
+        Self { config }
+    }
+
+    /// Calculates the Additional Medicare Tax on wages and SE income.
+    ///
+    /// # Arguments
+    ///
+    /// * `wages` - Medicare wages and tips (Line 4)
+    /// * `se_income` - Net earnings from self-employment, i.e. Schedule SE
+    ///   line 6 or line 3 of the SE Tax Worksheet (Line 8)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AdditionalMedicareTaxError`] if the configuration is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal_macros::dec;
+    /// use tax_core::calculations::{AdditionalMedicareTaxConfig, AdditionalMedicareTaxWorksheet};
+    ///
+    /// let config = AdditionalMedicareTaxConfig {
+    ///     rate: dec!(0.009),
+    ///     threshold: dec!(200000.00),
+    /// };
+    ///
+    /// let worksheet = AdditionalMedicareTaxWorksheet::new(config);
+    ///
+    /// // Wages alone exceed the threshold, so all SE income is taxed.
+    /// let result = worksheet.calculate(dec!(250000.00), dec!(10000.00)).unwrap();
+    ///
+    /// assert_eq!(result.wage_tax, dec!(450.00));
+    /// assert_eq!(result.se_threshold, dec!(0.00));
+    /// assert_eq!(result.se_tax, dec!(90.00));
+    /// assert_eq!(result.total_tax, dec!(540.00));
+    /// ```
+    pub fn calculate(
+        &self,
+        wages: Decimal,
+        se_income: Decimal,
+    ) -> Result<AdditionalMedicareTaxResult, AdditionalMedicareTaxError> {
+        self.config.validate()?;
+
+        // Line 4: Medicare wages
+        let medicare_wages = max(round_half_up(wages), Decimal::ZERO);
+
+        // Lines 5-6: Wages over the threshold
+        let wages_over_threshold = self.amount_over(medicare_wages, self.config.threshold);
+
+        // Line 7: Tax on wages
+        let wage_tax = self.apply_rate(wages_over_threshold);
+
+        // Line 8: SE income (a loss is entered as zero)
+        let se_income = max(round_half_up(se_income), Decimal::ZERO);
+
+        // Lines 9-11: Threshold reduced by wages
+        let se_threshold = self.amount_over(self.config.threshold, medicare_wages);
+
+        // Line 12: SE income over the reduced threshold
+        let se_income_over_threshold = self.amount_over(se_income, se_threshold);
+
+        // Line 13: Tax on SE income
+        let se_tax = self.apply_rate(se_income_over_threshold);
+
+        // Line 18: Total
+        let total_tax = round_half_up(wage_tax + se_tax);
+
+        Ok(AdditionalMedicareTaxResult {
+            medicare_wages,
+            wages_over_threshold,
+            wage_tax,
+            se_income,
+            se_threshold,
+            se_income_over_threshold,
+            se_tax,
+            total_tax,
+        })
+    }
+
+    /// Subtracts `base` from `amount`, entering zero if the result is negative.
+    ///
+    /// # Form Reference
+    ///
+    /// Lines 6, 11 and 12 all follow the "if zero or less, enter -0-" rule.
+    fn amount_over(
+        &self,
+        amount: Decimal,
+        base: Decimal,
+    ) -> Decimal {
+        max(round_half_up(amount - base), Decimal::ZERO)
+    }
+
+    /// Applies the Additional Medicare Tax rate.
+    ///
+    /// # Form Reference
+    ///
+    /// Lines 7 and 13: Multiply by 0.9% (0.009)
+    fn apply_rate(
+        &self,
+        amount: Decimal,
+    ) -> Decimal {
+        round_half_up(amount * self.config.rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn test_worksheet(threshold: Decimal) -> AdditionalMedicareTaxWorksheet {
+        AdditionalMedicareTaxWorksheet::new(AdditionalMedicareTaxConfig {
+            rate: dec!(0.009),
+            threshold,
+        })
+    }
+
+    // =========================================================================
+    // AdditionalMedicareTaxConfig tests
+    // =========================================================================
+
+    #[test]
+    fn validate_rejects_rate_above_one() {
+        let config = AdditionalMedicareTaxConfig {
+            rate: dec!(1.5),
+            threshold: dec!(200000.00),
+        };
+
+        assert_eq!(
+            config.validate(),
+            Err(AdditionalMedicareTaxError::InvalidRate(dec!(1.5)))
+        );
+    }
+
+    #[test]
+    fn validate_rejects_negative_threshold() {
+        let config = AdditionalMedicareTaxConfig {
+            rate: dec!(0.009),
+            threshold: dec!(-1.00),
+        };
+
+        assert_eq!(
+            config.validate(),
+            Err(AdditionalMedicareTaxError::InvalidThreshold(dec!(-1.00)))
+        );
+    }
+
+    // =========================================================================
+    // calculate tests
+    // =========================================================================
+
+    #[test]
+    fn calculate_below_threshold_owes_nothing() {
+        let result = test_worksheet(dec!(200000.00))
+            .calculate(dec!(120000.00), dec!(50000.00))
+            .unwrap();
+
+        assert_eq!(result.se_threshold, dec!(80000.00));
+        assert_eq!(result.total_tax, dec!(0.00));
+    }
+
+    #[test]
+    fn calculate_wages_only_over_threshold() {
+        let result = test_worksheet(dec!(200000.00))
+            .calculate(dec!(260000.00), dec!(0.00))
+            .unwrap();
+
+        assert_eq!(result.wages_over_threshold, dec!(60000.00));
+        assert_eq!(result.wage_tax, dec!(540.00));
+        assert_eq!(result.se_tax, dec!(0.00));
+        assert_eq!(result.total_tax, dec!(540.00));
+    }
+
+    #[test]
+    fn calculate_se_only_over_threshold() {
+        let result = test_worksheet(dec!(250000.00))
+            .calculate(dec!(0.00), dec!(300000.00))
+            .unwrap();
+
+        assert_eq!(result.se_threshold, dec!(250000.00));
+        assert_eq!(result.se_income_over_threshold, dec!(50000.00));
+        assert_eq!(result.se_tax, dec!(450.00));
+        assert_eq!(result.total_tax, dec!(450.00));
+    }
+
+    #[test]
+    fn calculate_mixed_wages_reduce_se_threshold() {
+        let result = test_worksheet(dec!(200000.00))
+            .calculate(dec!(150000.00), dec!(92350.00))
+            .unwrap();
+
+        assert_eq!(result.wage_tax, dec!(0.00));
+        assert_eq!(result.se_threshold, dec!(50000.00));
+        assert_eq!(result.se_income_over_threshold, dec!(42350.00));
+        assert_eq!(result.se_tax, dec!(381.15));
+        assert_eq!(result.total_tax, dec!(381.15));
+    }
+
+    #[test]
+    fn calculate_mixed_wages_over_threshold_taxes_all_se_income() {
+        let result = test_worksheet(dec!(125000.00))
+            .calculate(dec!(140000.00), dec!(20000.00))
+            .unwrap();
+
+        assert_eq!(result.wage_tax, dec!(135.00));
+        assert_eq!(result.se_threshold, dec!(0.00));
+        assert_eq!(result.se_tax, dec!(180.00));
+        assert_eq!(result.total_tax, dec!(315.00));
+    }
+
+    #[test]
+    fn calculate_se_loss_is_treated_as_zero() {
+        let result = test_worksheet(dec!(200000.00))
+            .calculate(dec!(210000.00), dec!(-5000.00))
+            .unwrap();
+
+        assert_eq!(result.se_income, dec!(0.00));
+        assert_eq!(result.se_tax, dec!(0.00));
+        assert_eq!(result.total_tax, dec!(90.00));
+    }
+
+    #[test]
+    fn calculate_rejects_invalid_config() {
+        let worksheet = AdditionalMedicareTaxWorksheet::new(AdditionalMedicareTaxConfig {
+            rate: dec!(-0.009),
+            threshold: dec!(200000.00),
+        });
+
+        assert_eq!(
+            worksheet.calculate(dec!(0.00), dec!(0.00)),
+            Err(AdditionalMedicareTaxError::InvalidRate(dec!(-0.009)))
+        );
+    }
+
+    #[test]
+    fn additional_medicare_tax_result_display() {
+        let result = AdditionalMedicareTaxResult {
+            medicare_wages: dec!(150000.00),
+            wages_over_threshold: dec!(0.00),
+            wage_tax: dec!(0.00),
+            se_income: dec!(92350.00),
+            se_threshold: dec!(50000.00),
+            se_income_over_threshold: dec!(42350.00),
+            se_tax: dec!(381.15),
+            total_tax: dec!(381.15),
+        };
+        let expected = "AdditionalMedicareTaxResult {
+    medicare_wages           : $150000.00
+    wages_over_threshold     : $0.00
+    wage_tax                 : $0.00
+    se_income                : $92350.00
+    se_threshold             : $50000.00
+    se_income_over_threshold : $42350.00
+    se_tax                   : $381.15
+    total_tax                : $381.15
+}";
+        assert_eq!(format!("{result}"), expected);
+    }
+}
@@ -0,0 +1,681 @@
+//! Annualized Income Installment Method for IRS Form 2210, Schedule AI.
+//!
+//! Taxpayers whose income is received unevenly during the year can base each
+//! required installment on the income actually received before the
+//! installment's due date, instead of paying 25% of the required annual
+//! payment every quarter. Each annualization period covers income from
+//! January 1 through the end of March, May, August and December.
+//!
+//! For every period the income is annualized, the tax is figured with the
+//! same rate schedules used by [`EstimatedTaxWorksheet`] and the
+//! self-employment tax with [`SeWorksheet`], and the applicable percentage of
+//! the annualized tax becomes the cumulative amount due. Any part of the
+//! regular installment that is not required in one period is carried to the
+//! next (lines 22–24), so both methods require the same total by year end
+//! when income turns out as expected.
+//!
+//! # Worksheet Structure
+//!
+//! | Line | Description |
+//! |------|-------------|
+//! | 1    | Income for the period, less the deductible part of SE tax |
+//! | 2    | Annualization amounts (4, 2.4, 1.5, 1) |
+//! | 3    | Annualized income (Line 1 × Line 2) |
+//! | 4-6  | Itemized deductions for the period × annualization amount |
+//! | 7    | Standard deduction |
+//! | 8    | Larger of Line 6 or Line 7 |
+//! | 9    | Qualified business income deduction (annualized) |
+//! | 10   | Total deductions (Line 8 + Line 9) |
+//! | 11   | Taxable income (Line 3 − Line 10, minimum 0) |
+//! | 12   | Tax on Line 11 |
+//! | 13   | Self-employment tax on annualized SE income |
+//! | 14   | Other taxes |
+//! | 15   | Total tax (Line 12 + Line 13 + Line 14) |
+//! | 16   | Nonrefundable credits |
+//! | 17   | Line 15 − Line 16 (minimum 0) |
+//! | 18   | Applicable percentage (22.5%, 45%, 67.5%, 90%) |
+//! | 19   | Line 17 × Line 18 |
+//! | 20   | Sum of Line 25 for all preceding periods |
+//! | 21   | Line 19 − Line 20 (minimum 0) |
+//! | 22   | 25% of the required annual payment |
+//! | 23   | Line 24 − Line 25 of the preceding period |
+//! | 24   | Line 22 + Line 23 |
+//! | 25   | Required installment: smaller of Line 21 or Line 24 |
+//!
+//! Withholding is credited against each installment in the period it is
+//! withheld, with any excess carried to the following installments.
+//!
+//! # Example
+//!
+//! ```
+//! use rust_decimal_macros::dec;
+//! use tax_core::TaxBracket;
+//! use tax_core::calculations::{
+//!     AnnualizedIncomeInput, AnnualizedIncomePeriod, AnnualizedIncomeWorksheet,
+//!     InstallmentMethod, SeWorksheetConfig,
+//! };
+//!
+//! let tax_brackets = vec![TaxBracket {
+//!     tax_year: 2025,
+//!     filing_status_id: 1,
+//!     min_income: dec!(0),
+//!     max_income: None,
+//!     tax_rate: dec!(0.10),
+//!     base_tax: dec!(0),
+//! }];
+//! let se_config = SeWorksheetConfig {
+//!     ss_wage_max: dec!(176100.00),
+//!     ss_tax_rate: dec!(0.124),
+//!     medicare_tax_rate: dec!(0.029),
+//!     net_earnings_factor: dec!(0.9235),
+//!     deduction_factor: dec!(0.50),
+//!     min_se_threshold: dec!(400.00),
+//! };
+//!
+//! // All income arrives after August.
+//! let period = |income| AnnualizedIncomePeriod {
+//!     income,
+//!     ..Default::default()
+//! };
+//! let input = AnnualizedIncomeInput {
+//!     periods: [
+//!         period(dec!(0.00)),
+//!         period(dec!(0.00)),
+//!         period(dec!(0.00)),
+//!         period(dec!(75000.00)),
+//!     ],
+//!     standard_deduction: dec!(15000.00),
+//!     credits: dec!(0.00),
+//!     other_taxes: dec!(0.00),
+//!     required_annual_payment: dec!(5400.00),
+//! };
+//!
+//! let worksheet = AnnualizedIncomeWorksheet::new(&tax_brackets, se_config);
+//! let result = worksheet.calculate(&input).unwrap();
+//!
+//! // Nothing is due until the fourth installment.
+//! assert_eq!(
+//!     result.installments(InstallmentMethod::AnnualizedIncome),
+//!     [dec!(0.00), dec!(0.00), dec!(0.00), dec!(5400.00)]
+//! );
+//! assert_eq!(
+//!     result.installments(InstallmentMethod::Regular),
+//!     [dec!(1350.00), dec!(1350.00), dec!(1350.00), dec!(1350.00)]
+//! );
+//! ```
+
+use std::fmt;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::TaxBracket;
+use crate::calculations::common::{max, round_half_up};
+use crate::calculations::installments::InstallmentMethod;
+use crate::calculations::worksheets::est_tax::{EstimatedTaxWorksheet, EstimatedTaxWorksheetError};
+use crate::calculations::worksheets::self_emp::{SeWorksheet, SeWorksheetConfig, SeWorksheetError};
+
+/// Number of months covered by each annualization period.
+pub const ANNUALIZATION_MONTHS: [u32; 4] = [3, 5, 8, 12];
+
+/// Annualization amounts for each period (Lines 2 and 5).
+const ANNUALIZATION_AMOUNTS: [Decimal; 4] = [
+    Decimal::from_parts(4, 0, 0, false, 0),
+    Decimal::from_parts(24, 0, 0, false, 1),
+    Decimal::from_parts(15, 0, 0, false, 1),
+    Decimal::from_parts(1, 0, 0, false, 0),
+];
+
+/// Applicable percentages for each period (Line 18).
+const APPLICABLE_PERCENTAGES: [Decimal; 4] = [
+    Decimal::from_parts(225, 0, 0, false, 3),
+    Decimal::from_parts(45, 0, 0, false, 2),
+    Decimal::from_parts(675, 0, 0, false, 3),
+    Decimal::from_parts(90, 0, 0, false, 2),
+];
+
+/// Share of the required annual payment due with each regular installment (Line 22).
+const REGULAR_INSTALLMENT_SHARE: Decimal = Decimal::from_parts(25, 0, 0, false, 2);
+
+/// Errors that can occur during Schedule AI calculations.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum AnnualizedIncomeError {
+    /// The regular tax could not be figured for a period.
+    #[error("period {period}: {source}")]
+    Tax {
+        period: usize,
+        source: EstimatedTaxWorksheetError,
+    },
+
+    /// The self-employment tax could not be figured for a period.
+    #[error("period {period}: {source}")]
+    SelfEmployment {
+        period: usize,
+        source: SeWorksheetError,
+    },
+}
+
+/// Amounts received from January 1 through the end of one annualization period.
+///
+/// Every amount is cumulative for the year to date, not just the months
+/// added since the previous period.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnnualizedIncomePeriod {
+    /// Income for the period, before the deductible part of self-employment
+    /// tax (the deduction is figured from the annualized SE tax).
+    pub income: Decimal,
+
+    /// Itemized deductions for the period. Zero when taking the standard deduction.
+    pub itemized_deductions: Decimal,
+
+    /// Qualified business income deduction for the period.
+    pub qbi_deduction: Decimal,
+
+    /// Net profit subject to self-employment tax for the period.
+    pub se_income: Decimal,
+
+    /// Wages subject to social security tax for the period.
+    pub wages: Decimal,
+
+    /// Income tax withheld for the period.
+    pub withholding: Decimal,
+}
+
+/// Input values for Schedule AI.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnnualizedIncomeInput {
+    /// Cumulative amounts for each period, in [`ANNUALIZATION_MONTHS`] order.
+    pub periods: [AnnualizedIncomePeriod; 4],
+
+    /// Full-year standard deduction for the filing status (Line 7).
+    pub standard_deduction: Decimal,
+
+    /// Nonrefundable credits (Line 16).
+    pub credits: Decimal,
+
+    /// Other taxes, such as AMT, Additional Medicare Tax and NIIT (Line 14).
+    pub other_taxes: Decimal,
+
+    /// Required annual payment from line 12c of the Estimated Tax Worksheet.
+    pub required_annual_payment: Decimal,
+}
+
+/// Schedule AI lines for a single annualization period.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnnualizedIncomePeriodResult {
+    /// Months covered by the period (3, 5, 8 or 12).
+    pub months: u32,
+
+    /// Annualized income less the deductible part of SE tax (Line 3).
+    pub annualized_income: Decimal,
+
+    /// Total deductions, including the QBI deduction (Line 10).
+    pub total_deductions: Decimal,
+
+    /// Annualized taxable income (Line 11).
+    pub taxable_income: Decimal,
+
+    /// Tax on annualized taxable income (Line 12).
+    pub tax: Decimal,
+
+    /// Self-employment tax on annualized SE income (Line 13).
+    pub self_employment_tax: Decimal,
+
+    /// Annualized tax after credits (Line 17).
+    pub annualized_tax: Decimal,
+
+    /// Applicable percentage of the annualized tax (Line 19).
+    pub cumulative_tax_due: Decimal,
+
+    /// Regular 25% installment (Line 22).
+    pub regular_installment: Decimal,
+
+    /// Required installment under the annualized method (Line 25).
+    pub annualized_installment: Decimal,
+
+    /// Income tax withheld during this period alone.
+    pub withholding: Decimal,
+}
+
+/// Result of Schedule AI calculations.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnnualizedIncomeResult {
+    /// One entry per annualization period.
+    pub periods: Vec<AnnualizedIncomePeriodResult>,
+}
+
+impl AnnualizedIncomeResult {
+    /// Required installment for each period under `method`, before
+    /// withholding is credited (Form 2210 line 18).
+    pub fn required_installments(
+        &self,
+        method: InstallmentMethod,
+    ) -> [Decimal; 4] {
+        let mut required = [Decimal::ZERO; 4];
+        for (amount, period) in required.iter_mut().zip(&self.periods) {
+            *amount = match method {
+                InstallmentMethod::Regular => period.regular_installment,
+                InstallmentMethod::AnnualizedIncome => period.annualized_installment,
+            };
+        }
+        required
+    }
+
+    /// Estimated payment due with each installment under `method`, after
+    /// crediting withholding for the period.
+    ///
+    /// Withholding beyond an installment is carried to later installments.
+    pub fn installments(
+        &self,
+        method: InstallmentMethod,
+    ) -> [Decimal; 4] {
+        let mut payments = [Decimal::ZERO; 4];
+        let mut credit = Decimal::ZERO;
+        let required = self.required_installments(method);
+        for ((payment, period), required) in payments.iter_mut().zip(&self.periods).zip(required) {
+            let available = credit + period.withholding;
+            *payment = max(required - available, Decimal::ZERO);
+            credit = max(available - required, Decimal::ZERO);
+        }
+        payments
+    }
+
+    /// Total estimated payments for the year under `method`.
+    pub fn total(
+        &self,
+        method: InstallmentMethod,
+    ) -> Decimal {
+        self.installments(method).iter().sum()
+    }
+}
+
+impl fmt::Display for AnnualizedIncomeResult {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        writeln!(f, "AnnualizedIncomeResult {{")?;
+        for period in &self.periods {
+            writeln!(
+                f,
+                "    {:>2} months : taxable ${} : regular ${} : annualized ${}",
+                period.months,
+                period.taxable_income.round_dp(2),
+                period.regular_installment.round_dp(2),
+                period.annualized_installment.round_dp(2)
+            )?;
+        }
+        write!(f, "}}")?;
+        Ok(())
+    }
+}
+
+/// Calculator for Form 2210, Schedule AI.
+#[derive(Debug, Clone)]
+pub struct AnnualizedIncomeWorksheet<'a> {
+    tax_worksheet: EstimatedTaxWorksheet<'a>,
+    se_worksheet: SeWorksheet,
+}
+
+impl<'a> AnnualizedIncomeWorksheet<'a> {
+    /// Creates a Schedule AI calculator from the filing status's tax brackets
+    /// and the tax year's self-employment tax configuration.
+    pub fn new(
+        tax_brackets: &'a [TaxBracket],
+        se_config: SeWorksheetConfig,
+    ) -> Self {
+        Self {
+            tax_worksheet: EstimatedTaxWorksheet::new(tax_brackets),
+            se_worksheet: SeWorksheet::new(se_config),
+        }
+    }
+
+    /// Calculates the required installment for each annualization period.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AnnualizedIncomeError`] if the tax or self-employment tax
+    /// cannot be figured for a period.
+    pub fn calculate(
+        &self,
+        input: &AnnualizedIncomeInput,
+    ) -> Result<AnnualizedIncomeResult, AnnualizedIncomeError> {
+        // Line 22: Regular installment
+        let regular_installment =
+            round_half_up(input.required_annual_payment * REGULAR_INSTALLMENT_SHARE);
+
+        let mut periods = Vec::with_capacity(input.periods.len());
+        let mut prior_installments = Decimal::ZERO;
+        let mut carryover = Decimal::ZERO;
+        let mut prior_withholding = Decimal::ZERO;
+
+        for (index, period) in input.periods.iter().enumerate() {
+            let factor = ANNUALIZATION_AMOUNTS[index];
+
+            // Line 13: SE tax on annualized SE income and wages
+            let se_result = self
+                .se_worksheet
+                .calculate(
+                    round_half_up(period.se_income * factor),
+                    Decimal::ZERO,
+                    round_half_up(period.wages * factor),
+                )
+                .map_err(|source| AnnualizedIncomeError::SelfEmployment {
+                    period: index + 1,
+                    source,
+                })?;
+            let self_employment_tax = se_result.self_employment_tax;
+
+            // Lines 1-3: Annualized income less the deductible part of SE tax
+            let annualized_income =
+                round_half_up(period.income * factor) - se_result.se_tax_deduction;
+
+            // Lines 4-10: Deductions
+            let total_deductions = self.total_deductions(period, factor, input.standard_deduction);
+
+            // Line 11: Taxable income
+            let taxable_income = max(annualized_income - total_deductions, Decimal::ZERO);
+
+            // Line 12: Tax
+            let tax = self
+                .tax_worksheet
+                .calculate_tax(taxable_income)
+                .map_err(|source| AnnualizedIncomeError::Tax {
+                    period: index + 1,
+                    source,
+                })?;
+
+            // Lines 15-17: Total tax after credits
+            let annualized_tax = max(
+                tax + self_employment_tax + input.other_taxes - input.credits,
+                Decimal::ZERO,
+            );
+
+            // Line 19: Applicable percentage of annualized tax
+            let cumulative_tax_due = round_half_up(annualized_tax * APPLICABLE_PERCENTAGES[index]);
+
+            // Lines 20-21: Amount not yet required by earlier installments
+            let annualized_due = max(cumulative_tax_due - prior_installments, Decimal::ZERO);
+
+            // Lines 23-24: Regular installment plus carryover
+            let regular_with_carryover = regular_installment + carryover;
+
+            // Line 25: Required installment
+            let annualized_installment = annualized_due.min(regular_with_carryover);
+
+            prior_installments += annualized_installment;
+            carryover = regular_with_carryover - annualized_installment;
+
+            let withholding = period.withholding - prior_withholding;
+            prior_withholding = period.withholding;
+
+            periods.push(AnnualizedIncomePeriodResult {
+                months: ANNUALIZATION_MONTHS[index],
+                annualized_income,
+                total_deductions,
+                taxable_income,
+                tax,
+                self_employment_tax,
+                annualized_tax,
+                cumulative_tax_due,
+                regular_installment,
+                annualized_installment,
+                withholding,
+            });
+        }
+
+        Ok(AnnualizedIncomeResult { periods })
+    }
+
+    /// Calculates total annualized deductions for a period (Lines 4-10).
+    ///
+    /// # Form Reference
+    ///
+    /// Line 8: Enter the larger of line 6 or line 7
+    /// Line 10: Add lines 8 and 9
+    fn total_deductions(
+        &self,
+        period: &AnnualizedIncomePeriod,
+        factor: Decimal,
+        standard_deduction: Decimal,
+    ) -> Decimal {
+        let itemized = round_half_up(period.itemized_deductions * factor);
+        let deduction = max(itemized, round_half_up(standard_deduction));
+        deduction + round_half_up(period.qbi_deduction * factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn flat_brackets() -> Vec<TaxBracket> {
+        vec![TaxBracket {
+            tax_year: 2025,
+            filing_status_id: 1,
+            min_income: dec!(0),
+            max_income: None,
+            tax_rate: dec!(0.10),
+            base_tax: dec!(0),
+        }]
+    }
+
+    fn se_config() -> SeWorksheetConfig {
+        SeWorksheetConfig {
+            ss_wage_max: dec!(176100.00),
+            ss_tax_rate: dec!(0.124),
+            medicare_tax_rate: dec!(0.029),
+            net_earnings_factor: dec!(0.9235),
+            deduction_factor: dec!(0.50),
+            min_se_threshold: dec!(400.00),
+        }
+    }
+
+    fn income_period(income: Decimal) -> AnnualizedIncomePeriod {
+        AnnualizedIncomePeriod {
+            income,
+            ..Default::default()
+        }
+    }
+
+    /// Income arrives late in the year: nothing through May, $20,000 through
+    /// August and $80,000 for the year.
+    fn late_income_input() -> AnnualizedIncomeInput {
+        AnnualizedIncomeInput {
+            periods: [
+                income_period(dec!(0.00)),
+                income_period(dec!(0.00)),
+                income_period(dec!(20000.00)),
+                income_period(dec!(80000.00)),
+            ],
+            standard_deduction: dec!(15000.00),
+            credits: dec!(0.00),
+            other_taxes: dec!(0.00),
+            required_annual_payment: dec!(6000.00),
+        }
+    }
+
+    fn calculate(input: &AnnualizedIncomeInput) -> AnnualizedIncomeResult {
+        let brackets = flat_brackets();
+        AnnualizedIncomeWorksheet::new(&brackets, se_config())
+            .calculate(input)
+            .unwrap()
+    }
+
+    // =========================================================================
+    // calculate tests
+    // =========================================================================
+
+    #[test]
+    fn calculate_late_income_defers_installments() {
+        let result = calculate(&late_income_input());
+
+        let installments: Vec<Decimal> = result
+            .periods
+            .iter()
+            .map(|p| p.annualized_installment)
+            .collect();
+        assert_eq!(
+            installments,
+            vec![dec!(0.00), dec!(0.00), dec!(1012.50), dec!(4837.50)]
+        );
+    }
+
+    #[test]
+    fn calculate_annualizes_income_and_applies_percentage() {
+        let result = calculate(&late_income_input());
+        let third = &result.periods[2];
+
+        assert_eq!(third.months, 8);
+        assert_eq!(third.annualized_income, dec!(30000.00));
+        assert_eq!(third.taxable_income, dec!(15000.00));
+        assert_eq!(third.tax, dec!(1500.00));
+        assert_eq!(third.cumulative_tax_due, dec!(1012.50));
+    }
+
+    #[test]
+    fn calculate_caps_installment_at_regular_plus_carryover() {
+        // Income is front-loaded, so the regular amount controls until the
+        // last period, where only the balance of the annualized tax is due.
+        let mut input = late_income_input();
+        input.periods = [
+            income_period(dec!(80000.00)),
+            income_period(dec!(80000.00)),
+            income_period(dec!(80000.00)),
+            income_period(dec!(80000.00)),
+        ];
+
+        let result = calculate(&input);
+
+        assert_eq!(
+            result.installments(InstallmentMethod::AnnualizedIncome),
+            [dec!(1500.00), dec!(1500.00), dec!(1500.00), dec!(1350.00)]
+        );
+    }
+
+    #[test]
+    fn calculate_annualizes_self_employment_tax() {
+        let mut input = late_income_input();
+        input.periods[0] = AnnualizedIncomePeriod {
+            income: dec!(10000.00),
+            se_income: dec!(10000.00),
+            ..Default::default()
+        };
+
+        let result = calculate(&input);
+        let first = &result.periods[0];
+
+        // $40,000 annualized × 92.35% × 15.3%
+        assert_eq!(first.self_employment_tax, dec!(5651.82));
+        assert_eq!(first.annualized_income, dec!(37174.09));
+    }
+
+    #[test]
+    fn calculate_subtracts_credits_and_adds_other_taxes() {
+        let mut input = late_income_input();
+        input.other_taxes = dec!(500.00);
+        input.credits = dec!(2000.00);
+
+        let result = calculate(&input);
+
+        assert_eq!(result.periods[3].annualized_tax, dec!(5000.00));
+    }
+
+    #[test]
+    fn calculate_uses_larger_of_itemized_or_standard_deduction() {
+        let mut input = late_income_input();
+        input.periods[2].itemized_deductions = dec!(12000.00);
+
+        let result = calculate(&input);
+
+        // $12,000 × 1.5 exceeds the $15,000 standard deduction.
+        assert_eq!(result.periods[2].total_deductions, dec!(18000.00));
+        assert_eq!(result.periods[3].total_deductions, dec!(15000.00));
+    }
+
+    #[test]
+    fn calculate_returns_error_without_brackets() {
+        let brackets: Vec<TaxBracket> = vec![];
+        let result =
+            AnnualizedIncomeWorksheet::new(&brackets, se_config()).calculate(&late_income_input());
+
+        assert_eq!(
+            result,
+            Err(AnnualizedIncomeError::Tax {
+                period: 3,
+                source: EstimatedTaxWorksheetError::NoMatchingBracket(dec!(15000.00)),
+            })
+        );
+    }
+
+    // =========================================================================
+    // installments tests
+    // =========================================================================
+
+    #[test]
+    fn installments_compare_methods() {
+        let result = calculate(&late_income_input());
+
+        assert_eq!(
+            result.installments(InstallmentMethod::Regular),
+            [dec!(1500.00), dec!(1500.00), dec!(1500.00), dec!(1500.00)]
+        );
+        assert_eq!(result.total(InstallmentMethod::Regular), dec!(6000.00));
+        assert_eq!(
+            result.total(InstallmentMethod::AnnualizedIncome),
+            dec!(5850.00)
+        );
+    }
+
+    #[test]
+    fn required_installments_ignore_withholding() {
+        let mut input = late_income_input();
+        input.periods[0].withholding = dec!(2000.00);
+
+        let result = calculate(&input);
+
+        assert_eq!(
+            result.required_installments(InstallmentMethod::AnnualizedIncome),
+            [dec!(0), dec!(0), dec!(1012.50), dec!(4837.50)]
+        );
+    }
+
+    #[test]
+    fn installments_credit_withholding_and_carry_excess() {
+        let mut input = late_income_input();
+        for period in input.periods.iter_mut() {
+            period.withholding = dec!(2000.00);
+        }
+
+        let result = calculate(&input);
+
+        // All $2,000 is withheld in the first period and covers the first
+        // installment, with $500 carried to the second.
+        assert_eq!(result.periods[0].withholding, dec!(2000.00));
+        assert_eq!(result.periods[1].withholding, dec!(0.00));
+        assert_eq!(
+            result.installments(InstallmentMethod::Regular),
+            [dec!(0.00), dec!(1000.00), dec!(1500.00), dec!(1500.00)]
+        );
+    }
+
+    // =========================================================================
+    // Display tests
+    // =========================================================================
+
+    #[test]
+    fn annualized_income_result_display() {
+        let result = calculate(&late_income_input());
+        let expected = "AnnualizedIncomeResult {
+     3 months : taxable $0 : regular $1500.00 : annualized $0
+     5 months : taxable $0 : regular $1500.00 : annualized $0
+     8 months : taxable $15000.00 : regular $1500.00 : annualized $1012.50
+    12 months : taxable $65000.00 : regular $1500.00 : annualized $4837.50
+}";
+        assert_eq!(format!("{result}"), expected);
+    }
+}
@@ -14,18 +14,18 @@
 //! | 2b   | Qualified business income (QBI) deduction |
 //! | 2c   | Total deductions (Line 2a + Line 2b) |
 //! | 3    | Taxable income (Line 1 - Line 2c) |
-//! | 4    | Tax (using tax rate schedules) |
+//! | 4    | Tax (tax rate schedules, or the Qualified Dividends and Capital Gain Tax Worksheet) |
 //! | 5    | Alternative minimum tax (AMT) |
 //! | 6    | Total tax before credits (Line 4 + Line 5) |
 //! | 7    | Credits (excluding withholding) |
 //! | 8    | Tax after credits (Line 6 - Line 7, minimum 0) |
 //! | 9    | Self-employment tax |
-//! | 10   | Other taxes |
+//! | 10   | Other taxes, including Additional Medicare Tax (Form 8959) and NIIT (Form 8960) |
 //! | 11a  | Total tax (Line 8 + Line 9 + Line 10) |
 //! | 11b  | Refundable credits |
 //! | 11c  | Total 2025 estimated tax (Line 11a - Line 11b, minimum 0) |
 //! | 12a  | Line 11c × 90% (or 66⅔% for farmers/fishers) |
-//! | 12b  | Prior year's tax (100% or 110% for high earners) |
+//! | 12b  | Prior year's tax × 100%, or × 110% if prior-year AGI exceeded the threshold |
 //! | 12c  | Required annual payment (smaller of 12a or 12b) |
 //! | 13   | Income tax withheld during 2025 |
 //! | 14a  | Line 12c - Line 13 (if ≤0, no estimated payments required) |
@@ -69,12 +69,19 @@
 //!     adjusted_gross_income: dec!(100000.00),
 //!     deduction: dec!(15000.00),
 //!     qbi_deduction: dec!(0.00),
+//!     qualified_dividends: dec!(0.00),
+//!     net_capital_gain: dec!(0.00),
 //!     alternative_minimum_tax: dec!(0.00),
 //!     credits: dec!(0.00),
 //!     self_employment_tax: dec!(0.00),
 //!     other_taxes: dec!(0.00),
+//!     additional_medicare_tax: dec!(0.00),
+//!     net_investment_income_tax: dec!(0.00),
 //!     refundable_credits: dec!(0.00),
 //!     prior_year_tax: dec!(12000.00),
+//!     prior_year_agi: dec!(95000.00),
+//!     prior_year_agi_threshold: dec!(150000.00),
+//!     high_income_prior_year_multiplier: dec!(1.10),
 //!     withholding: dec!(0.00),
 //!     is_farmer_or_fisher: false,
 //!     required_payment_threshold: dec!(1000.00),
@@ -84,6 +91,7 @@
 //! let result = worksheet.calculate(&input).unwrap();
 //!
 //! assert_eq!(result.total_estimated_tax, dec!(13614.00));
+//! assert_eq!(result.prior_year_tax_multiplier, dec!(1.00));
 //! assert_eq!(result.required_annual_payment, dec!(12000.00));
 //! assert!(result.estimated_payments_required);
 //! ```
@@ -96,6 +104,7 @@ use thiserror::Error;
 
 use crate::TaxBracket;
 use crate::calculations::common::{max, round_half_up};
+use crate::calculations::worksheets::qdcg::{QdcgConfig, QdcgResult, QdcgWorksheet};
 
 /// Errors that can occur during estimated tax worksheet calculations.
 #[derive(Debug, Error, PartialEq, Eq)]
@@ -107,6 +116,20 @@ pub enum EstimatedTaxWorksheetError {
     /// No tax bracket found for the given taxable income.
     #[error("no tax bracket found for taxable income {0}")]
     NoMatchingBracket(Decimal),
+
+    /// Qualified dividends or net capital gain were entered but no
+    /// capital gain rate breakpoints were configured.
+    #[error("no capital gain rate breakpoints provided")]
+    NoCapitalGainBreakpoints,
+
+    /// The capital gain rate breakpoints are negative or out of order.
+    #[error(
+        "invalid capital gain rate breakpoints: 0% up to {zero_rate_max}, 15% up to {fifteen_rate_max}"
+    )]
+    InvalidCapitalGainBreakpoints {
+        zero_rate_max: Decimal,
+        fifteen_rate_max: Decimal,
+    },
 }
 
 /// Input values for the Estimated Tax Worksheet.
@@ -116,8 +139,14 @@ pub enum EstimatedTaxWorksheetError {
 /// Additional values needed to resolve a canonical estimate into worksheet input.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct EstimatedTaxWorksheetContext {
+    /// Computed QBI deduction, used when no deduction is entered.
+    pub qbi_deduction: Decimal,
     pub self_employment_tax: Decimal,
+    pub additional_medicare_tax: Decimal,
+    pub net_investment_income_tax: Decimal,
     pub refundable_credits: Decimal,
+    pub prior_year_agi_threshold: Decimal,
+    pub high_income_prior_year_multiplier: Decimal,
     pub is_farmer_or_fisher: bool,
     pub required_payment_threshold: Decimal,
 }
@@ -133,6 +162,14 @@ pub struct EstimatedTaxWorksheetInput {
     /// Qualified business income (QBI) deduction.
     pub qbi_deduction: Decimal,
 
+    /// Qualified dividends included in AGI.
+    /// Taxed at capital gain rates on line 4.
+    pub qualified_dividends: Decimal,
+
+    /// Net capital gain included in AGI.
+    /// Taxed at capital gain rates on line 4.
+    pub net_capital_gain: Decimal,
+
     /// Alternative minimum tax from Form 6251.
     pub alternative_minimum_tax: Decimal,
 
@@ -142,16 +179,35 @@ pub struct EstimatedTaxWorksheetInput {
     /// Self-employment tax from SE worksheet.
     pub self_employment_tax: Decimal,
 
-    /// Other taxes (household employment, NIIT, etc.).
+    /// Other taxes (household employment, etc.).
     pub other_taxes: Decimal,
 
+    /// Additional Medicare Tax from Form 8959.
+    /// Added to `other_taxes` on line 10.
+    pub additional_medicare_tax: Decimal,
+
+    /// Net Investment Income Tax from Form 8960.
+    /// Added to `other_taxes` on line 10.
+    pub net_investment_income_tax: Decimal,
+
     /// Refundable credits (earned income credit, additional child tax credit, etc.).
     pub refundable_credits: Decimal,
 
-    /// Prior year's tax liability.
-    /// This should already be adjusted (100% or 110%) based on prior year AGI.
+    /// Prior year's tax liability, before any high-income adjustment.
     pub prior_year_tax: Decimal,
 
+    /// Prior year's adjusted gross income.
+    /// Selects whether line 12b uses 100% or the high-income multiplier.
+    pub prior_year_agi: Decimal,
+
+    /// Prior-year AGI above which the high-income multiplier applies.
+    /// This is $150,000 ($75,000 if married filing separately) for 2025.
+    pub prior_year_agi_threshold: Decimal,
+
+    /// Multiplier applied to the prior year's tax for high-income taxpayers.
+    /// This is typically 110% from TaxYearConfig.prior_tax_multiplier.
+    pub high_income_prior_year_multiplier: Decimal,
+
     /// Income tax withheld and estimated to be withheld during 2025.
     pub withholding: Decimal,
 
@@ -178,6 +234,13 @@ pub struct EstimatedTaxWorksheetResult {
     /// Total estimated tax for the year (line 11c).
     pub total_estimated_tax: Decimal,
 
+    /// Multiplier applied to the prior year's tax on line 12b.
+    /// 1.00 unless prior-year AGI exceeded the high-income threshold.
+    pub prior_year_tax_multiplier: Decimal,
+
+    /// Prior year's tax after applying the multiplier (line 12b).
+    pub prior_year_safe_harbor: Decimal,
+
     /// Required annual payment to avoid penalty (line 12c).
     /// This is the smaller of 90% of current year tax or prior year tax.
     pub required_annual_payment: Decimal,
@@ -212,6 +275,16 @@ impl Display for EstimatedTaxWorksheetResult {
             "    total_estimated_tax         : ${}",
             self.total_estimated_tax.round_dp(2)
         )?;
+        writeln!(
+            f,
+            "    prior_year_tax_multiplier   : {}",
+            self.prior_year_tax_multiplier
+        )?;
+        writeln!(
+            f,
+            "    prior_year_safe_harbor      : ${}",
+            self.prior_year_safe_harbor.round_dp(2)
+        )?;
         writeln!(
             f,
             "    required_annual_payment     : ${}",
@@ -232,6 +305,246 @@ impl Display for EstimatedTaxWorksheetResult {
     }
 }
 
+/// One line of the Estimated Tax Worksheet, labeled as on the paper form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EstimatedTaxWorksheetLine {
+    /// Worksheet line number, e.g. `"2b"`.
+    pub line: &'static str,
+
+    /// Description of the line, including the bracket or factor applied.
+    pub label: String,
+
+    /// Amount entered on the line.
+    pub amount: Decimal,
+}
+
+/// Every line of the Estimated Tax Worksheet (lines 1–14b).
+///
+/// Field names follow the IRS line numbers so the trace can be reconciled
+/// against the paper Form 1040-ES.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EstimatedTaxWorksheetTrace {
+    /// Line 1: Adjusted gross income.
+    pub line_1_adjusted_gross_income: Decimal,
+    /// Line 2a: Deductions.
+    pub line_2a_deduction: Decimal,
+    /// Line 2b: Qualified business income deduction.
+    pub line_2b_qbi_deduction: Decimal,
+    /// Line 2c: Add lines 2a and 2b.
+    pub line_2c_total_deductions: Decimal,
+    /// Line 3: Subtract line 2c from line 1.
+    pub line_3_taxable_income: Decimal,
+    /// Line 4: Tax.
+    pub line_4_tax: Decimal,
+    /// Tax rate schedule bracket that line 3 falls in, if line 3 is positive.
+    pub line_4_bracket: Option<TaxBracket>,
+    /// Qualified Dividends and Capital Gain Tax Worksheet, when it figured line 4.
+    pub line_4_capital_gain_worksheet: Option<QdcgResult>,
+    /// Line 5: Alternative minimum tax.
+    pub line_5_alternative_minimum_tax: Decimal,
+    /// Line 6: Add lines 4 and 5.
+    pub line_6_tax_before_credits: Decimal,
+    /// Line 7: Credits.
+    pub line_7_credits: Decimal,
+    /// Line 8: Subtract line 7 from line 6.
+    pub line_8_tax_after_credits: Decimal,
+    /// Line 9: Self-employment tax.
+    pub line_9_self_employment_tax: Decimal,
+    /// Line 10: Other taxes, including Additional Medicare Tax and NIIT.
+    pub line_10_other_taxes: Decimal,
+    /// Line 11a: Add lines 8 through 10.
+    pub line_11a_total_tax: Decimal,
+    /// Line 11b: Refundable credits.
+    pub line_11b_refundable_credits: Decimal,
+    /// Line 11c: Total estimated tax.
+    pub line_11c_total_estimated_tax: Decimal,
+    /// Factor applied to line 11c on line 12a (90%, or 66⅔% for farmers and fishers).
+    pub line_12a_factor: Decimal,
+    /// Line 12a: Multiply line 11c by the line 12a factor.
+    pub line_12a_current_year_requirement: Decimal,
+    /// Multiplier applied to the prior year's tax on line 12b.
+    pub line_12b_multiplier: Decimal,
+    /// Line 12b: Required annual payment based on prior year's tax.
+    pub line_12b_prior_year_safe_harbor: Decimal,
+    /// Line 12c: Smaller of line 12a or 12b.
+    pub line_12c_required_annual_payment: Decimal,
+    /// Line 13: Income tax withheld.
+    pub line_13_withholding: Decimal,
+    /// Line 14a: Subtract line 13 from line 12c.
+    pub line_14a_underpayment: Decimal,
+    /// Line 14b: Subtract line 13 from line 11c.
+    pub line_14b_tax_less_withholding: Decimal,
+    /// Whether estimated tax payments are required (lines 14a and 14b).
+    pub estimated_payments_required: bool,
+}
+
+impl EstimatedTaxWorksheetTrace {
+    /// Returns every worksheet line in form order with its label.
+    pub fn lines(&self) -> Vec<EstimatedTaxWorksheetLine> {
+        let line = |line: &'static str, label: String, amount: Decimal| EstimatedTaxWorksheetLine {
+            line,
+            label,
+            amount,
+        };
+
+        vec![
+            line(
+                "1",
+                "Adjusted gross income".to_string(),
+                self.line_1_adjusted_gross_income,
+            ),
+            line("2a", "Deductions".to_string(), self.line_2a_deduction),
+            line(
+                "2b",
+                "Qualified business income deduction".to_string(),
+                self.line_2b_qbi_deduction,
+            ),
+            line(
+                "2c",
+                "Add lines 2a and 2b".to_string(),
+                self.line_2c_total_deductions,
+            ),
+            line(
+                "3",
+                "Subtract line 2c from line 1".to_string(),
+                self.line_3_taxable_income,
+            ),
+            line("4", self.line_4_label(), self.line_4_tax),
+            line(
+                "5",
+                "Alternative minimum tax".to_string(),
+                self.line_5_alternative_minimum_tax,
+            ),
+            line(
+                "6",
+                "Add lines 4 and 5".to_string(),
+                self.line_6_tax_before_credits,
+            ),
+            line("7", "Credits".to_string(), self.line_7_credits),
+            line(
+                "8",
+                "Subtract line 7 from line 6".to_string(),
+                self.line_8_tax_after_credits,
+            ),
+            line(
+                "9",
+                "Self-employment tax".to_string(),
+                self.line_9_self_employment_tax,
+            ),
+            line("10", "Other taxes".to_string(), self.line_10_other_taxes),
+            line(
+                "11a",
+                "Add lines 8 through 10".to_string(),
+                self.line_11a_total_tax,
+            ),
+            line(
+                "11b",
+                "Refundable credits".to_string(),
+                self.line_11b_refundable_credits,
+            ),
+            line(
+                "11c",
+                "Total estimated tax (line 11a - line 11b)".to_string(),
+                self.line_11c_total_estimated_tax,
+            ),
+            line(
+                "12a",
+                format!("Multiply line 11c by {}", percent(self.line_12a_factor)),
+                self.line_12a_current_year_requirement,
+            ),
+            line(
+                "12b",
+                format!("Prior year's tax × {}", percent(self.line_12b_multiplier)),
+                self.line_12b_prior_year_safe_harbor,
+            ),
+            line(
+                "12c",
+                "Smaller of line 12a or 12b".to_string(),
+                self.line_12c_required_annual_payment,
+            ),
+            line(
+                "13",
+                "Income tax withheld".to_string(),
+                self.line_13_withholding,
+            ),
+            line(
+                "14a",
+                "Subtract line 13 from line 12c".to_string(),
+                self.line_14a_underpayment,
+            ),
+            line(
+                "14b",
+                "Subtract line 13 from line 11c".to_string(),
+                self.line_14b_tax_less_withholding,
+            ),
+        ]
+    }
+
+    /// Label for line 4, naming the method and the bracket applied.
+    fn line_4_label(&self) -> String {
+        if self.line_4_capital_gain_worksheet.is_some() {
+            return "Tax (Qualified Dividends and Capital Gain Tax Worksheet)".to_string();
+        }
+        match &self.line_4_bracket {
+            Some(bracket) => format!(
+                "Tax ({} bracket over ${})",
+                percent(bracket.tax_rate),
+                bracket.min_income.round_dp(2)
+            ),
+            None => "Tax".to_string(),
+        }
+    }
+}
+
+impl Display for EstimatedTaxWorksheetTrace {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        for line in self.lines() {
+            writeln!(
+                f,
+                "Line {:<4} {:<58}: ${}",
+                line.line,
+                line.label,
+                line.amount.round_dp(2)
+            )?;
+        }
+        write!(
+            f,
+            "Estimated payments required: {}",
+            self.estimated_payments_required
+        )
+    }
+}
+
+impl From<&EstimatedTaxWorksheetTrace> for EstimatedTaxWorksheetResult {
+    fn from(trace: &EstimatedTaxWorksheetTrace) -> Self {
+        Self {
+            taxable_income: trace.line_3_taxable_income,
+            calculated_tax: trace.line_4_tax,
+            total_estimated_tax: trace.line_11c_total_estimated_tax,
+            prior_year_tax_multiplier: trace.line_12b_multiplier,
+            prior_year_safe_harbor: trace.line_12b_prior_year_safe_harbor,
+            required_annual_payment: trace.line_12c_required_annual_payment,
+            underpayment: trace.line_14a_underpayment,
+            estimated_payments_required: trace.estimated_payments_required,
+        }
+    }
+}
+
+impl From<EstimatedTaxWorksheetTrace> for EstimatedTaxWorksheetResult {
+    fn from(trace: EstimatedTaxWorksheetTrace) -> Self {
+        Self::from(&trace)
+    }
+}
+
+/// Formats a rate as a percentage with at most two decimal places,
+/// e.g. `0.90` as `"90%"` and `2/3` as `"66.67%"`.
+fn percent(rate: Decimal) -> String {
+    format!("{}%", (rate * Decimal::ONE_HUNDRED).round_dp(2).normalize())
+}
+
 /// Calculator for the Estimated Tax Worksheet.
 ///
 /// This struct encapsulates the tax brackets and provides methods to calculate
@@ -239,6 +552,7 @@ impl Display for EstimatedTaxWorksheetResult {
 #[derive(Debug, Clone)]
 pub struct EstimatedTaxWorksheet<'a> {
     tax_brackets: &'a [TaxBracket],
+    capital_gain_config: Option<QdcgConfig>,
 }
 
 impl<'a> EstimatedTaxWorksheet<'a> {
@@ -248,23 +562,52 @@ impl<'a> EstimatedTaxWorksheet<'a> {
     /// must cover all income ranges (the last bracket should have `max_income`
     /// as `None`).
     pub fn new(tax_brackets: &'a [TaxBracket]) -> Self {
-        Self { tax_brackets }
+        Self {
+            tax_brackets,
+            capital_gain_config: None,
+        }
+    }
+
+    /// Taxes qualified dividends and net capital gain at the 0%/15%/20%
+    /// rates on line 4, using the filing status breakpoints in `config`.
+    pub fn with_capital_gain_breakpoints(
+        mut self,
+        config: QdcgConfig,
+    ) -> Self {
+        self.capital_gain_config = Some(config);
+        self
     }
 
     /// Calculates the complete Estimated Tax Worksheet.
     ///
     /// This is the main entry point for estimated tax calculations. It performs
     /// all line calculations and returns the key results needed for tax planning.
+    /// Use [`trace`](Self::trace) to get every worksheet line.
     ///
     /// # Errors
     ///
     /// Returns [`EstimatedTaxWorksheetError`] if:
     /// - No tax brackets were provided
     /// - No matching bracket found for the taxable income
+    /// - Qualified dividends or net capital gain were entered without valid
+    ///   capital gain rate breakpoints
     pub fn calculate(
         &self,
         input: &EstimatedTaxWorksheetInput,
     ) -> Result<EstimatedTaxWorksheetResult, EstimatedTaxWorksheetError> {
+        self.trace(input).map(EstimatedTaxWorksheetResult::from)
+    }
+
+    /// Calculates the Estimated Tax Worksheet and returns every line (1–14b)
+    /// together with the tax bracket and factors applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`calculate`](Self::calculate).
+    pub fn trace(
+        &self,
+        input: &EstimatedTaxWorksheetInput,
+    ) -> Result<EstimatedTaxWorksheetTrace, EstimatedTaxWorksheetError> {
         if self.tax_brackets.is_empty() {
             return Err(EstimatedTaxWorksheetError::NoTaxBrackets);
         }
@@ -278,8 +621,13 @@ impl<'a> EstimatedTaxWorksheet<'a> {
         // Calculate taxable income
         let taxable_income = self.taxable_income(input.adjusted_gross_income, total_deductions);
 
-        // Calculate tax from schedules
-        let calculated_tax = self.calculate_tax(taxable_income)?;
+        // Calculate tax from schedules, or the QDCG worksheet
+        let bracket = self.bracket_for(taxable_income)?.cloned();
+        let (calculated_tax, capital_gain_worksheet) = self.line_4_tax(
+            taxable_income,
+            input.qualified_dividends,
+            input.net_capital_gain,
+        )?;
 
         // Calculate total tax before credits (tax + AMT)
         let total_tax_before_credits =
@@ -288,21 +636,32 @@ impl<'a> EstimatedTaxWorksheet<'a> {
         // Apply credits
         let tax_after_credits = self.tax_after_credits(total_tax_before_credits, input.credits);
 
-        // Add SE tax and other taxes
-        let total_tax = self.total_tax(
-            tax_after_credits,
-            input.self_employment_tax,
+        // Combine other taxes with Additional Medicare Tax and NIIT
+        let other_taxes = self.other_taxes(
             input.other_taxes,
+            input.additional_medicare_tax,
+            input.net_investment_income_tax,
         );
 
+        // Add SE tax and other taxes
+        let total_tax = self.total_tax(tax_after_credits, input.self_employment_tax, other_taxes);
+
         // Subtract refundable credits to get total estimated tax
         let total_estimated_tax = self.total_estimated_tax(total_tax, input.refundable_credits);
 
         // Calculate required annual payment
+        let current_year_rate = self.current_year_rate(input.is_farmer_or_fisher);
         let current_year_factor =
             self.current_year_factor(total_estimated_tax, input.is_farmer_or_fisher);
+        let prior_year_tax_multiplier = self.prior_year_tax_multiplier(
+            input.prior_year_agi,
+            input.prior_year_agi_threshold,
+            input.high_income_prior_year_multiplier,
+        );
+        let prior_year_safe_harbor =
+            self.prior_year_safe_harbor(input.prior_year_tax, prior_year_tax_multiplier);
         let required_annual_payment =
-            self.required_annual_payment(current_year_factor, input.prior_year_tax);
+            self.required_annual_payment(current_year_factor, prior_year_safe_harbor);
 
         // Calculate underpayment (balance due via estimated payments)
         let underpayment = self.underpayment(required_annual_payment, input.withholding);
@@ -315,12 +674,32 @@ impl<'a> EstimatedTaxWorksheet<'a> {
             input.required_payment_threshold,
         );
 
-        Ok(EstimatedTaxWorksheetResult {
-            taxable_income,
-            calculated_tax,
-            total_estimated_tax,
-            required_annual_payment,
-            underpayment,
+        Ok(EstimatedTaxWorksheetTrace {
+            line_1_adjusted_gross_income: input.adjusted_gross_income,
+            line_2a_deduction: deduction,
+            line_2b_qbi_deduction: input.qbi_deduction,
+            line_2c_total_deductions: total_deductions,
+            line_3_taxable_income: taxable_income,
+            line_4_tax: calculated_tax,
+            line_4_bracket: bracket,
+            line_4_capital_gain_worksheet: capital_gain_worksheet,
+            line_5_alternative_minimum_tax: input.alternative_minimum_tax,
+            line_6_tax_before_credits: total_tax_before_credits,
+            line_7_credits: input.credits,
+            line_8_tax_after_credits: tax_after_credits,
+            line_9_self_employment_tax: input.self_employment_tax,
+            line_10_other_taxes: other_taxes,
+            line_11a_total_tax: total_tax,
+            line_11b_refundable_credits: input.refundable_credits,
+            line_11c_total_estimated_tax: total_estimated_tax,
+            line_12a_factor: current_year_rate,
+            line_12a_current_year_requirement: current_year_factor,
+            line_12b_multiplier: prior_year_tax_multiplier,
+            line_12b_prior_year_safe_harbor: prior_year_safe_harbor,
+            line_12c_required_annual_payment: required_annual_payment,
+            line_13_withholding: input.withholding,
+            line_14a_underpayment: underpayment,
+            line_14b_tax_less_withholding: threshold_amount,
             estimated_payments_required,
         })
     }
@@ -351,31 +730,82 @@ impl<'a> EstimatedTaxWorksheet<'a> {
         max(round_half_up(agi - total_deductions), Decimal::ZERO)
     }
 
-    /// Calculates tax using the tax rate schedules.
-    fn calculate_tax(
+    /// Calculates tax on taxable income using the tax rate schedules (Line 4).
+    ///
+    /// Returns zero for zero or negative taxable income.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EstimatedTaxWorksheetError::NoMatchingBracket`] if no
+    /// bracket covers `taxable_income`.
+    pub fn calculate_tax(
         &self,
         taxable_income: Decimal,
     ) -> Result<Decimal, EstimatedTaxWorksheetError> {
-        if taxable_income <= Decimal::ZERO {
+        let Some(bracket) = self.bracket_for(taxable_income)? else {
             return Ok(Decimal::ZERO);
+        };
+
+        let marginal_income = taxable_income - bracket.min_income;
+        let tax = bracket.base_tax + (marginal_income * bracket.tax_rate);
+
+        Ok(round_half_up(tax))
+    }
+
+    /// Finds the tax rate schedule bracket covering `taxable_income`.
+    ///
+    /// Returns `None` for zero or negative taxable income.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EstimatedTaxWorksheetError::NoMatchingBracket`] if no
+    /// bracket covers `taxable_income`.
+    fn bracket_for(
+        &self,
+        taxable_income: Decimal,
+    ) -> Result<Option<&TaxBracket>, EstimatedTaxWorksheetError> {
+        if taxable_income <= Decimal::ZERO {
+            return Ok(None);
         }
 
-        let bracket = self
-            .tax_brackets
+        self.tax_brackets
             .iter()
             .find(|b| {
                 taxable_income > b.min_income
                     && (b.max_income.is_none()
                         || taxable_income <= b.max_income.unwrap_or(Decimal::MAX))
             })
+            .map(Some)
             .ok_or(EstimatedTaxWorksheetError::NoMatchingBracket(
                 taxable_income,
-            ))?;
+            ))
+    }
 
-        let marginal_income = taxable_income - bracket.min_income;
-        let tax = bracket.base_tax + (marginal_income * bracket.tax_rate);
+    /// Calculates line 4 tax, using the Qualified Dividends and Capital Gain
+    /// Tax Worksheet when there are qualified dividends or net capital gain.
+    ///
+    /// Returns the worksheet result alongside the tax when it was used.
+    fn line_4_tax(
+        &self,
+        taxable_income: Decimal,
+        qualified_dividends: Decimal,
+        net_capital_gain: Decimal,
+    ) -> Result<(Decimal, Option<QdcgResult>), EstimatedTaxWorksheetError> {
+        if qualified_dividends <= Decimal::ZERO && net_capital_gain <= Decimal::ZERO {
+            return Ok((self.calculate_tax(taxable_income)?, None));
+        }
 
-        Ok(round_half_up(tax))
+        let config = self
+            .capital_gain_config
+            .clone()
+            .ok_or(EstimatedTaxWorksheetError::NoCapitalGainBreakpoints)?;
+        let result = QdcgWorksheet::new(self.tax_brackets, config).calculate(
+            taxable_income,
+            qualified_dividends,
+            net_capital_gain,
+        )?;
+
+        Ok((result.tax, Some(result)))
     }
 
     /// Calculates total tax before credits.
@@ -399,6 +829,16 @@ impl<'a> EstimatedTaxWorksheet<'a> {
         )
     }
 
+    /// Calculates other taxes (line 10), including Additional Medicare Tax and NIIT.
+    fn other_taxes(
+        &self,
+        other_taxes: Decimal,
+        additional_medicare_tax: Decimal,
+        net_investment_income_tax: Decimal,
+    ) -> Decimal {
+        round_half_up(other_taxes + additional_medicare_tax + net_investment_income_tax)
+    }
+
     /// Calculates total tax (before refundable credits).
     fn total_tax(
         &self,
@@ -418,18 +858,49 @@ impl<'a> EstimatedTaxWorksheet<'a> {
         max(round_half_up(total_tax - refundable_credits), Decimal::ZERO)
     }
 
+    /// Selects the line 12a rate (90% or 66⅔% for farmers/fishers).
+    fn current_year_rate(
+        &self,
+        is_farmer_or_fisher: bool,
+    ) -> Decimal {
+        if is_farmer_or_fisher {
+            Decimal::TWO / Decimal::from(3)
+        } else {
+            Decimal::new(90, 2)
+        }
+    }
+
     /// Calculates current year factor (90% or 66⅔% for farmers/fishers).
     fn current_year_factor(
         &self,
         total_estimated_tax: Decimal,
         is_farmer_or_fisher: bool,
     ) -> Decimal {
-        let factor = if is_farmer_or_fisher {
-            Decimal::TWO / Decimal::from(3)
+        round_half_up(total_estimated_tax * self.current_year_rate(is_farmer_or_fisher))
+    }
+
+    /// Selects the line 12b multiplier: the high-income multiplier when
+    /// prior-year AGI exceeds the threshold, otherwise 100%.
+    fn prior_year_tax_multiplier(
+        &self,
+        prior_year_agi: Decimal,
+        prior_year_agi_threshold: Decimal,
+        high_income_multiplier: Decimal,
+    ) -> Decimal {
+        if prior_year_agi > prior_year_agi_threshold {
+            high_income_multiplier
         } else {
-            Decimal::new(90, 2)
-        };
-        round_half_up(total_estimated_tax * factor)
+            Decimal::ONE
+        }
+    }
+
+    /// Calculates the prior-year safe harbor amount (line 12b).
+    fn prior_year_safe_harbor(
+        &self,
+        prior_year_tax: Decimal,
+        multiplier: Decimal,
+    ) -> Decimal {
+        round_half_up(prior_year_tax * multiplier)
     }
 
     /// Calculates required annual payment (smaller of current year factor or prior year tax).
@@ -549,12 +1020,19 @@ mod tests {
             adjusted_gross_income: dec!(100000.00),
             deduction: dec!(15000.00),
             qbi_deduction: dec!(0.00),
+            qualified_dividends: dec!(0.00),
+            net_capital_gain: dec!(0.00),
             alternative_minimum_tax: dec!(0.00),
             credits: dec!(0.00),
             self_employment_tax: dec!(0.00),
             other_taxes: dec!(0.00),
+            additional_medicare_tax: dec!(0.00),
+            net_investment_income_tax: dec!(0.00),
             refundable_credits: dec!(0.00),
             prior_year_tax: dec!(12000.00),
+            prior_year_agi: dec!(95000.00),
+            prior_year_agi_threshold: dec!(150000.00),
+            high_income_prior_year_multiplier: dec!(1.10),
             withholding: dec!(0.00),
             is_farmer_or_fisher: false,
             required_payment_threshold: dec!(1000.00),
@@ -567,6 +1045,8 @@ mod tests {
             taxable_income: dec!(85000.00),
             calculated_tax: dec!(10000.00),
             total_estimated_tax: dec!(12000.00),
+            prior_year_tax_multiplier: dec!(1.10),
+            prior_year_safe_harbor: dec!(13200.00),
             required_annual_payment: dec!(11000.00),
             underpayment: dec!(5000.00),
             estimated_payments_required: true,
@@ -575,6 +1055,8 @@ mod tests {
     taxable_income              : $85000.00
     calculated_tax              : $10000.00
     total_estimated_tax         : $12000.00
+    prior_year_tax_multiplier   : 1.10
+    prior_year_safe_harbor      : $13200.00
     required_annual_payment     : $11000.00
     underpayment                : $5000.00
     estimated_payments_required : true
@@ -784,6 +1266,42 @@ mod tests {
         assert_eq!(result, dec!(12000.00));
     }
 
+    // =========================================================================
+    // prior_year_tax_multiplier tests
+    // =========================================================================
+
+    #[test]
+    fn prior_year_tax_multiplier_is_one_at_threshold() {
+        let brackets = test_brackets_single();
+        let worksheet = EstimatedTaxWorksheet::new(&brackets);
+
+        let result =
+            worksheet.prior_year_tax_multiplier(dec!(150000.00), dec!(150000.00), dec!(1.10));
+
+        assert_eq!(result, Decimal::ONE);
+    }
+
+    #[test]
+    fn prior_year_tax_multiplier_uses_high_income_multiplier_above_threshold() {
+        let brackets = test_brackets_single();
+        let worksheet = EstimatedTaxWorksheet::new(&brackets);
+
+        let result =
+            worksheet.prior_year_tax_multiplier(dec!(150000.01), dec!(150000.00), dec!(1.10));
+
+        assert_eq!(result, dec!(1.10));
+    }
+
+    #[test]
+    fn prior_year_safe_harbor_applies_multiplier() {
+        let brackets = test_brackets_single();
+        let worksheet = EstimatedTaxWorksheet::new(&brackets);
+
+        let result = worksheet.prior_year_safe_harbor(dec!(12345.67), dec!(1.10));
+
+        assert_eq!(result, dec!(13580.24));
+    }
+
     // =========================================================================
     // underpayment tests
     // =========================================================================
@@ -895,6 +1413,38 @@ mod tests {
         assert_eq!(result.taxable_income, dec!(80000.00));
     }
 
+    #[test]
+    fn calculate_taxes_qualified_income_at_capital_gain_rates() {
+        let brackets = test_brackets_single();
+        let worksheet =
+            EstimatedTaxWorksheet::new(&brackets).with_capital_gain_breakpoints(QdcgConfig {
+                zero_rate_max: dec!(48350.00),
+                fifteen_rate_max: dec!(533400.00),
+            });
+        let mut input = test_input();
+        input.qualified_dividends = dec!(5000.00);
+        input.net_capital_gain = dec!(10000.00);
+
+        let result = worksheet.calculate(&input).unwrap();
+
+        // 15000 at 15% (2250) + ordinary tax on 70000 (10314) instead of 13614
+        assert_eq!(result.calculated_tax, dec!(12564.00));
+        assert_eq!(result.total_estimated_tax, dec!(12564.00));
+    }
+
+    #[test]
+    fn calculate_qualified_income_requires_capital_gain_breakpoints() {
+        let brackets = test_brackets_single();
+        let worksheet = EstimatedTaxWorksheet::new(&brackets);
+        let mut input = test_input();
+        input.qualified_dividends = dec!(5000.00);
+
+        assert_eq!(
+            worksheet.calculate(&input),
+            Err(EstimatedTaxWorksheetError::NoCapitalGainBreakpoints)
+        );
+    }
+
     #[test]
     fn calculate_with_withholding_covering_requirement() {
         let brackets = test_brackets_single();
@@ -956,6 +1506,52 @@ mod tests {
         assert_eq!(result.underpayment, dec!(8000.00));
     }
 
+    #[test]
+    fn calculate_high_income_prior_year_uses_110_percent() {
+        let brackets = test_brackets_single();
+        let worksheet = EstimatedTaxWorksheet::new(&brackets);
+        let mut input = test_input();
+        input.prior_year_tax = dec!(10000.00);
+        input.prior_year_agi = dec!(160000.00);
+
+        let result = worksheet.calculate(&input).unwrap();
+
+        // Prior-year AGI 160000 > 150000, so line 12b: 10000 * 1.10 = 11000
+        // Required: min(12252.60, 11000) = 11000
+        assert_eq!(result.prior_year_tax_multiplier, dec!(1.10));
+        assert_eq!(result.prior_year_safe_harbor, dec!(11000.00));
+        assert_eq!(result.required_annual_payment, dec!(11000.00));
+    }
+
+    #[test]
+    fn calculate_mfs_threshold_uses_110_percent() {
+        let brackets = test_brackets_single();
+        let worksheet = EstimatedTaxWorksheet::new(&brackets);
+        let mut input = test_input();
+        input.prior_year_tax = dec!(10000.00);
+        input.prior_year_agi = dec!(80000.00);
+        input.prior_year_agi_threshold = dec!(75000.00);
+
+        let result = worksheet.calculate(&input).unwrap();
+
+        assert_eq!(result.prior_year_tax_multiplier, dec!(1.10));
+        assert_eq!(result.prior_year_safe_harbor, dec!(11000.00));
+    }
+
+    #[test]
+    fn calculate_prior_year_agi_below_threshold_uses_100_percent() {
+        let brackets = test_brackets_single();
+        let worksheet = EstimatedTaxWorksheet::new(&brackets);
+        let mut input = test_input();
+        input.prior_year_tax = dec!(10000.00);
+
+        let result = worksheet.calculate(&input).unwrap();
+
+        assert_eq!(result.prior_year_tax_multiplier, Decimal::ONE);
+        assert_eq!(result.prior_year_safe_harbor, dec!(10000.00));
+        assert_eq!(result.required_annual_payment, dec!(10000.00));
+    }
+
     #[test]
     fn calculate_with_se_tax() {
         let brackets = test_brackets_single();
@@ -969,6 +1565,34 @@ mod tests {
         assert_eq!(result.total_estimated_tax, dec!(20679.00));
     }
 
+    #[test]
+    fn calculate_adds_additional_medicare_tax_to_other_taxes() {
+        let brackets = test_brackets_single();
+        let worksheet = EstimatedTaxWorksheet::new(&brackets);
+        let mut input = test_input();
+        input.other_taxes = dec!(500.00);
+        input.additional_medicare_tax = dec!(381.15);
+
+        let result = worksheet.calculate(&input).unwrap();
+
+        // Total estimated tax: 13614 + 500 + 381.15 = 14495.15
+        assert_eq!(result.total_estimated_tax, dec!(14495.15));
+    }
+
+    #[test]
+    fn calculate_adds_net_investment_income_tax_to_other_taxes() {
+        let brackets = test_brackets_single();
+        let worksheet = EstimatedTaxWorksheet::new(&brackets);
+        let mut input = test_input();
+        input.additional_medicare_tax = dec!(381.15);
+        input.net_investment_income_tax = dec!(570.00);
+
+        let result = worksheet.calculate(&input).unwrap();
+
+        // Total estimated tax: 13614 + 381.15 + 570 = 14565.15
+        assert_eq!(result.total_estimated_tax, dec!(14565.15));
+    }
+
     #[test]
     fn calculate_with_credits() {
         let brackets = test_brackets_single();
@@ -1010,4 +1634,121 @@ mod tests {
         assert_eq!(result.calculated_tax, dec!(0.00));
         assert_eq!(result.total_estimated_tax, dec!(0.00));
     }
+
+    // =========================================================================
+    // trace tests
+    // =========================================================================
+
+    #[test]
+    fn trace_lists_every_worksheet_line_in_order() {
+        let brackets = test_brackets_single();
+        let worksheet = EstimatedTaxWorksheet::new(&brackets);
+
+        let trace = worksheet.trace(&test_input()).unwrap();
+        let numbers: Vec<&str> = trace.lines().iter().map(|l| l.line).collect();
+
+        assert_eq!(
+            numbers,
+            vec![
+                "1", "2a", "2b", "2c", "3", "4", "5", "6", "7", "8", "9", "10", "11a", "11b",
+                "11c", "12a", "12b", "12c", "13", "14a", "14b",
+            ]
+        );
+    }
+
+    #[test]
+    fn trace_matches_calculate_result() {
+        let brackets = test_brackets_single();
+        let worksheet = EstimatedTaxWorksheet::new(&brackets);
+        let mut input = test_input();
+        input.self_employment_tax = dec!(500.00);
+        input.withholding = dec!(2000.00);
+
+        let trace = worksheet.trace(&input).unwrap();
+
+        assert_eq!(
+            EstimatedTaxWorksheetResult::from(&trace),
+            worksheet.calculate(&input).unwrap()
+        );
+        assert_eq!(trace.line_11a_total_tax, dec!(14114.00));
+        assert_eq!(trace.line_12a_current_year_requirement, dec!(12702.60));
+        assert_eq!(trace.line_13_withholding, dec!(2000.00));
+        assert_eq!(trace.line_14b_tax_less_withholding, dec!(12114.00));
+    }
+
+    #[test]
+    fn trace_reports_bracket_and_factors() {
+        let brackets = test_brackets_single();
+        let worksheet = EstimatedTaxWorksheet::new(&brackets);
+        let mut input = test_input();
+        input.is_farmer_or_fisher = true;
+        input.prior_year_agi = dec!(160000.00);
+
+        let trace = worksheet.trace(&input).unwrap();
+        let lines = trace.lines();
+
+        assert_eq!(
+            trace.line_4_bracket.as_ref().map(|b| b.tax_rate),
+            Some(dec!(0.22))
+        );
+        assert_eq!(lines[5].label, "Tax (22% bracket over $48475)");
+        assert_eq!(lines[15].label, "Multiply line 11c by 66.67%");
+        assert_eq!(lines[16].label, "Prior year's tax × 110%");
+        assert_eq!(lines[16].amount, dec!(13200.00));
+    }
+
+    #[test]
+    fn trace_includes_capital_gain_worksheet() {
+        let brackets = test_brackets_single();
+        let worksheet =
+            EstimatedTaxWorksheet::new(&brackets).with_capital_gain_breakpoints(QdcgConfig {
+                zero_rate_max: dec!(48350.00),
+                fifteen_rate_max: dec!(533400.00),
+            });
+        let mut input = test_input();
+        input.qualified_dividends = dec!(5000.00);
+
+        let trace = worksheet.trace(&input).unwrap();
+
+        assert_eq!(
+            trace
+                .line_4_capital_gain_worksheet
+                .as_ref()
+                .map(|w| w.fifteen_rate_tax),
+            Some(dec!(750.00))
+        );
+        assert_eq!(
+            trace.lines()[5].label,
+            "Tax (Qualified Dividends and Capital Gain Tax Worksheet)"
+        );
+    }
+
+    #[test]
+    fn trace_display_lists_lines_and_payment_requirement() {
+        let brackets = test_brackets_single();
+        let worksheet = EstimatedTaxWorksheet::new(&brackets);
+
+        let output = worksheet.trace(&test_input()).unwrap().to_string();
+
+        assert!(output.starts_with(
+            "Line 1    Adjusted gross income                                     : $100000.00\n"
+        ));
+        assert!(output.contains(
+            "Line 12c  Smaller of line 12a or 12b                                : $12000.00\n"
+        ));
+        assert!(output.ends_with("Estimated payments required: true"));
+    }
+
+    #[test]
+    fn trace_without_taxable_income_has_no_bracket() {
+        let brackets = test_brackets_single();
+        let worksheet = EstimatedTaxWorksheet::new(&brackets);
+        let mut input = test_input();
+        input.adjusted_gross_income = dec!(10000.00);
+
+        let trace = worksheet.trace(&input).unwrap();
+
+        assert_eq!(trace.line_4_bracket, None);
+        assert_eq!(trace.lines()[5].label, "Tax");
+    }
 }
@@ -3,11 +3,28 @@
 //! This module contains the calculation logic for the various worksheets
 //! that comprise Form 1040-ES estimated tax calculations.
 
+pub mod addl_medicare;
+pub mod annualized;
 pub mod est_tax;
+pub mod niit;
+pub mod qbi;
+pub mod qdcg;
 pub mod self_emp;
 
+pub use addl_medicare::{
+    AdditionalMedicareTaxConfig, AdditionalMedicareTaxError, AdditionalMedicareTaxResult,
+    AdditionalMedicareTaxWorksheet,
+};
+pub use annualized::{
+    ANNUALIZATION_MONTHS, AnnualizedIncomeError, AnnualizedIncomeInput, AnnualizedIncomePeriod,
+    AnnualizedIncomePeriodResult, AnnualizedIncomeResult, AnnualizedIncomeWorksheet,
+};
 pub use est_tax::{
     EstimatedTaxWorksheet, EstimatedTaxWorksheetContext, EstimatedTaxWorksheetError,
-    EstimatedTaxWorksheetInput, EstimatedTaxWorksheetResult,
+    EstimatedTaxWorksheetInput, EstimatedTaxWorksheetLine, EstimatedTaxWorksheetResult,
+    EstimatedTaxWorksheetTrace,
 };
+pub use niit::{NiitConfig, NiitError, NiitResult, NiitWorksheet};
+pub use qbi::{QbiConfig, QbiError, QbiResult, QbiWorksheet};
+pub use qdcg::{QdcgConfig, QdcgResult, QdcgWorksheet};
 pub use self_emp::{SeWorksheet, SeWorksheetConfig, SeWorksheetError, SeWorksheetResult};
@@ -0,0 +1,429 @@
+//! Net Investment Income Tax calculations for IRS Form 8960.
+//!
+//! This module implements Part III of Form 8960 for individuals. The tax is
+//! reported on Schedule 2 and is included in line 10 (other taxes) of the
+//! 1040-ES Estimated Tax Worksheet.
+//!
+//! # Worksheet Structure
+//!
+//! | Line | Description |
+//! |------|-------------|
+//! | 12   | Net investment income |
+//! | 13   | Modified adjusted gross income |
+//! | 14   | Threshold for filing status |
+//! | 15   | Line 13 minus Line 14 (if zero or less, enter -0-) |
+//! | 16   | Smaller of Line 12 or Line 15 |
+//! | 17   | Net investment income tax: Line 16 × 3.8% |
+//!
+//! # Example
+//!
+//! ```
+//! use rust_decimal_macros::dec;
+//! use tax_core::calculations::{NiitConfig, NiitWorksheet};
+//!
+//! let config = NiitConfig {
+//!     rate: dec!(0.038),
+//!     threshold: dec!(200000.00),
+//! };
+//!
+//! let worksheet = NiitWorksheet::new(config);
+//! let result = worksheet.calculate(
+//!     dec!(30000.00),  // net investment income
+//!     dec!(215000.00), // modified AGI
+//! ).unwrap();
+//!
+//! // MAGI exceeds the threshold by $15,000, which is less than NII.
+//! assert_eq!(result.taxable_amount, dec!(15000.00));
+//! assert_eq!(result.net_investment_income_tax, dec!(570.00));
+//! ```
+
+use std::fmt;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::calculations::common::{max, round_half_up};
+use crate::{NiitThreshold, TaxYearConfig};
+
+/// Errors that can occur during Net Investment Income Tax calculations.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum NiitError {
+    /// The NIIT rate must be between 0 and 1.
+    #[error("net investment income tax rate must be between 0 and 1, got {0}")]
+    InvalidRate(Decimal),
+
+    /// The filing-status threshold must be non-negative.
+    #[error("net investment income tax threshold must be non-negative, got {0}")]
+    InvalidThreshold(Decimal),
+}
+
+/// Configuration parameters for Form 8960 calculations.
+///
+/// The rate comes from [`TaxYearConfig`] and the threshold from the
+/// [`NiitThreshold`] for the taxpayer's filing status.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NiitConfig {
+    /// Net Investment Income Tax rate (Line 17 multiplier).
+    ///
+    /// For 2025, this is 3.8%.
+    pub rate: Decimal,
+
+    /// Modified AGI threshold for the filing status (Line 14).
+    ///
+    /// $250,000 for married filing jointly and qualifying surviving spouse,
+    /// $125,000 for married filing separately, and $200,000 otherwise.
+    pub threshold: Decimal,
+}
+
+impl NiitConfig {
+    /// Creates a new configuration from the tax year's reference data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal_macros::dec;
+    /// use tax_core::calculations::NiitConfig;
+    /// use tax_core::{NiitThreshold, TaxYearConfig};
+    ///
+    /// let tax_year_config = TaxYearConfig {
+    ///     tax_year: 2025,
+    ///     ss_wage_max: dec!(176100.00),
+    ///     ss_tax_rate: dec!(0.124),
+    ///     medicare_tax_rate: dec!(0.029),
+    ///     se_tax_deduct_pcnt: dec!(0.9235),
+    ///     se_deduction_factor: dec!(0.50),
+    ///     req_pmnt_threshold: dec!(1000.00),
+    ///     min_se_threshold: dec!(400.00),
+    ///     addl_medicare_tax_rate: dec!(0.009),
+    ///     niit_rate: dec!(0.038),
+    ///     prior_agi_threshold: dec!(150000.00),
+    ///     prior_agi_threshold_mfs: dec!(75000.00),
+    ///     prior_tax_multiplier: dec!(1.10),
+    /// };
+    /// let threshold = NiitThreshold {
+    ///     tax_year: 2025,
+    ///     filing_status_id: 3,
+    ///     amount: dec!(125000.00),
+    /// };
+    ///
+    /// let config = NiitConfig::from_reference(&tax_year_config, &threshold);
+    ///
+    /// assert_eq!(config.rate, dec!(0.038));
+    /// assert_eq!(config.threshold, dec!(125000.00));
+    /// ```
+    pub fn from_reference(
+        config: &TaxYearConfig,
+        threshold: &NiitThreshold,
+    ) -> Self {
+        Self {
+            rate: config.niit_rate,
+            threshold: threshold.amount,
+        }
+    }
+
+    /// Validates the configuration values.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NiitError`] if:
+    /// - `rate` is not in [0, 1]
+    /// - `threshold` is negative
+    pub fn validate(&self) -> Result<(), NiitError> {
+        if self.rate < Decimal::ZERO || self.rate > Decimal::ONE {
+            return Err(NiitError::InvalidRate(self.rate));
+        }
+        if self.threshold < Decimal::ZERO {
+            return Err(NiitError::InvalidThreshold(self.threshold));
+        }
+        Ok(())
+    }
+}
+
+/// Result of Form 8960 calculations.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NiitResult {
+    /// Net investment income, with losses treated as zero (Line 12).
+    pub net_investment_income: Decimal,
+
+    /// Modified adjusted gross income (Line 13).
+    pub modified_agi: Decimal,
+
+    /// Modified AGI above the filing-status threshold (Line 15).
+    pub magi_over_threshold: Decimal,
+
+    /// Smaller of net investment income or MAGI over threshold (Line 16).
+    pub taxable_amount: Decimal,
+
+    /// Net Investment Income Tax (Line 17).
+    ///
+    /// This amount is included in line 10 of the Estimated Tax Worksheet.
+    pub net_investment_income_tax: Decimal,
+}
+
+impl fmt::Display for NiitResult {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        writeln!(f, "NiitResult {{")?;
+        writeln!(
+            f,
+            "    net_investment_income     : ${}",
+            self.net_investment_income.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    modified_agi              : ${}",
+            self.modified_agi.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    magi_over_threshold       : ${}",
+            self.magi_over_threshold.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    taxable_amount            : ${}",
+            self.taxable_amount.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    net_investment_income_tax : ${}",
+            self.net_investment_income_tax.round_dp(2)
+        )?;
+        write!(f, "}}")?;
+        Ok(())
+    }
+}
+
+/// Calculator for the Net Investment Income Tax (Form 8960).
+#[derive(Debug, Clone)]
+pub struct NiitWorksheet {
+    config: NiitConfig,
+}
+
+impl NiitWorksheet {
+    /// Creates a new Form 8960 calculator with the given configuration.
+    pub fn new(config: NiitConfig) -> Self {
+        Self { config }
+    }
+
+    /// Calculates the Net Investment Income Tax.
+    ///
+    /// # Arguments
+    ///
+    /// * `net_investment_income` - Net investment income (Line 12)
+    /// * `modified_agi` - Modified adjusted gross income (Line 13)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NiitError`] if the configuration is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal_macros::dec;
+    /// use tax_core::calculations::{NiitConfig, NiitWorksheet};
+    ///
+    /// let config = NiitConfig {
+    ///     rate: dec!(0.038),
+    ///     threshold: dec!(250000.00),
+    /// };
+    ///
+    /// let worksheet = NiitWorksheet::new(config);
+    ///
+    /// // MAGI is under the threshold, so no NIIT is due.
+    /// let result = worksheet.calculate(dec!(40000.00), dec!(240000.00)).unwrap();
+    ///
+    /// assert_eq!(result.net_investment_income_tax, dec!(0.00));
+    /// ```
+    pub fn calculate(
+        &self,
+        net_investment_income: Decimal,
+        modified_agi: Decimal,
+    ) -> Result<NiitResult, NiitError> {
+        self.config.validate()?;
+
+        // Line 12: Net investment income (a loss is entered as zero)
+        let net_investment_income = max(round_half_up(net_investment_income), Decimal::ZERO);
+
+        // Line 13: Modified AGI
+        let modified_agi = round_half_up(modified_agi);
+
+        // Lines 14-15: MAGI over the threshold
+        let magi_over_threshold = self.magi_over_threshold(modified_agi);
+
+        // Line 16: Smaller of Line 12 or Line 15
+        let taxable_amount = net_investment_income.min(magi_over_threshold);
+
+        // Line 17: Net investment income tax
+        let net_investment_income_tax = self.net_investment_income_tax(taxable_amount);
+
+        Ok(NiitResult {
+            net_investment_income,
+            modified_agi,
+            magi_over_threshold,
+            taxable_amount,
+            net_investment_income_tax,
+        })
+    }
+
+    /// Calculates modified AGI above the threshold (Line 15).
+    ///
+    /// # Form Reference
+    ///
+    /// Line 15: Subtract line 14 from line 13. If zero or less, enter -0-
+    fn magi_over_threshold(
+        &self,
+        modified_agi: Decimal,
+    ) -> Decimal {
+        max(
+            round_half_up(modified_agi - self.config.threshold),
+            Decimal::ZERO,
+        )
+    }
+
+    /// Calculates the Net Investment Income Tax (Line 17).
+    ///
+    /// # Form Reference
+    ///
+    /// Line 17: Multiply line 16 by 3.8% (0.038)
+    fn net_investment_income_tax(
+        &self,
+        taxable_amount: Decimal,
+    ) -> Decimal {
+        round_half_up(taxable_amount * self.config.rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn test_worksheet(threshold: Decimal) -> NiitWorksheet {
+        NiitWorksheet::new(NiitConfig {
+            rate: dec!(0.038),
+            threshold,
+        })
+    }
+
+    // =========================================================================
+    // NiitConfig tests
+    // =========================================================================
+
+    #[test]
+    fn validate_rejects_negative_rate() {
+        let config = NiitConfig {
+            rate: dec!(-0.038),
+            threshold: dec!(200000.00),
+        };
+
+        assert_eq!(config.validate(), Err(NiitError::InvalidRate(dec!(-0.038))));
+    }
+
+    #[test]
+    fn validate_rejects_negative_threshold() {
+        let config = NiitConfig {
+            rate: dec!(0.038),
+            threshold: dec!(-1.00),
+        };
+
+        assert_eq!(
+            config.validate(),
+            Err(NiitError::InvalidThreshold(dec!(-1.00)))
+        );
+    }
+
+    // =========================================================================
+    // calculate tests
+    // =========================================================================
+
+    #[test]
+    fn calculate_below_threshold_owes_nothing() {
+        let result = test_worksheet(dec!(200000.00))
+            .calculate(dec!(50000.00), dec!(180000.00))
+            .unwrap();
+
+        assert_eq!(result.magi_over_threshold, dec!(0.00));
+        assert_eq!(result.taxable_amount, dec!(0.00));
+        assert_eq!(result.net_investment_income_tax, dec!(0.00));
+    }
+
+    #[test]
+    fn calculate_uses_magi_excess_when_smaller() {
+        let result = test_worksheet(dec!(200000.00))
+            .calculate(dec!(30000.00), dec!(215000.00))
+            .unwrap();
+
+        assert_eq!(result.magi_over_threshold, dec!(15000.00));
+        assert_eq!(result.taxable_amount, dec!(15000.00));
+        assert_eq!(result.net_investment_income_tax, dec!(570.00));
+    }
+
+    #[test]
+    fn calculate_uses_nii_when_smaller() {
+        let result = test_worksheet(dec!(250000.00))
+            .calculate(dec!(20000.00), dec!(400000.00))
+            .unwrap();
+
+        assert_eq!(result.magi_over_threshold, dec!(150000.00));
+        assert_eq!(result.taxable_amount, dec!(20000.00));
+        assert_eq!(result.net_investment_income_tax, dec!(760.00));
+    }
+
+    #[test]
+    fn calculate_mfs_threshold() {
+        let result = test_worksheet(dec!(125000.00))
+            .calculate(dec!(10000.00), dec!(130000.00))
+            .unwrap();
+
+        assert_eq!(result.taxable_amount, dec!(5000.00));
+        assert_eq!(result.net_investment_income_tax, dec!(190.00));
+    }
+
+    #[test]
+    fn calculate_investment_loss_is_treated_as_zero() {
+        let result = test_worksheet(dec!(200000.00))
+            .calculate(dec!(-8000.00), dec!(300000.00))
+            .unwrap();
+
+        assert_eq!(result.net_investment_income, dec!(0.00));
+        assert_eq!(result.net_investment_income_tax, dec!(0.00));
+    }
+
+    #[test]
+    fn calculate_rejects_invalid_config() {
+        let worksheet = NiitWorksheet::new(NiitConfig {
+            rate: dec!(1.5),
+            threshold: dec!(200000.00),
+        });
+
+        assert_eq!(
+            worksheet.calculate(dec!(0.00), dec!(0.00)),
+            Err(NiitError::InvalidRate(dec!(1.5)))
+        );
+    }
+
+    #[test]
+    fn niit_result_display() {
+        let result = NiitResult {
+            net_investment_income: dec!(30000.00),
+            modified_agi: dec!(215000.00),
+            magi_over_threshold: dec!(15000.00),
+            taxable_amount: dec!(15000.00),
+            net_investment_income_tax: dec!(570.00),
+        };
+        let expected = "NiitResult {
+    net_investment_income     : $30000.00
+    modified_agi              : $215000.00
+    magi_over_threshold       : $15000.00
+    taxable_amount            : $15000.00
+    net_investment_income_tax : $570.00
+}";
+        assert_eq!(format!("{result}"), expected);
+    }
+}
@@ -0,0 +1,393 @@
+//! Qualified Business Income deduction for IRS Form 8995.
+//!
+//! This module implements the simplified computation on Form 8995 for
+//! taxpayers whose taxable income before the deduction is at or below the
+//! filing-status threshold. The deduction is entered on line 2b of the
+//! 1040-ES Estimated Tax Worksheet. Taxpayers above the threshold must use
+//! Form 8995-A, which is not implemented here.
+//!
+//! Only self-employment income is considered; REIT dividends, publicly
+//! traded partnership income and loss carryforwards (Lines 3 and 6-9) are
+//! treated as zero.
+//!
+//! # Worksheet Structure
+//!
+//! | Line | Description |
+//! |------|-------------|
+//! | 4    | Total qualified business income (if zero or less, enter -0-) |
+//! | 5    | QBI component: Line 4 × 20% |
+//! | 10   | QBI deduction before the income limitation |
+//! | 11   | Taxable income before the QBI deduction |
+//! | 12   | Net capital gain |
+//! | 13   | Line 11 minus Line 12 (if zero or less, enter -0-) |
+//! | 14   | Income limitation: Line 13 × 20% |
+//! | 15   | QBI deduction: smaller of Line 10 or Line 14 |
+//!
+//! # Example
+//!
+//! ```
+//! use rust_decimal_macros::dec;
+//! use tax_core::calculations::{QbiConfig, QbiWorksheet};
+//!
+//! let config = QbiConfig {
+//!     threshold: dec!(197300.00),
+//! };
+//!
+//! let worksheet = QbiWorksheet::new(config);
+//! let result = worksheet.calculate(
+//!     dec!(60000.00), // qualified business income
+//!     dec!(45000.00), // taxable income before the QBI deduction
+//!     dec!(5000.00),  // net capital gain
+//! ).unwrap();
+//!
+//! // 20% of QBI is $12,000 but 20% of ($45,000 - $5,000) is only $8,000.
+//! assert_eq!(result.qbi_component, dec!(12000.00));
+//! assert_eq!(result.income_limitation, dec!(8000.00));
+//! assert_eq!(result.deduction, dec!(8000.00));
+//! ```
+
+use std::fmt;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::QbiThreshold;
+use crate::calculations::common::{max, round_half_up};
+
+/// Rate applied to both qualified business income and the income limitation.
+const TWENTY_PERCENT: Decimal = Decimal::from_parts(20, 0, 0, false, 2);
+
+/// Errors that can occur during Form 8995 calculations.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum QbiError {
+    /// The filing-status threshold must be non-negative.
+    #[error("qualified business income threshold must be non-negative, got {0}")]
+    InvalidThreshold(Decimal),
+
+    /// Taxable income is above the threshold, so Form 8995-A is required.
+    #[error(
+        "taxable income before the QBI deduction ({taxable_income}) exceeds the Form 8995 threshold ({threshold}); use Form 8995-A"
+    )]
+    ThresholdExceeded {
+        taxable_income: Decimal,
+        threshold: Decimal,
+    },
+}
+
+/// Configuration parameters for Form 8995 calculations.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QbiConfig {
+    /// Taxable income threshold for the filing status.
+    ///
+    /// For 2025, $197,300 ($394,600 if married filing jointly).
+    pub threshold: Decimal,
+}
+
+impl QbiConfig {
+    /// Creates a new configuration from the filing status threshold.
+    pub fn from_reference(threshold: &QbiThreshold) -> Self {
+        Self {
+            threshold: threshold.amount,
+        }
+    }
+
+    /// Validates the configuration values.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QbiError::InvalidThreshold`] if `threshold` is negative.
+    pub fn validate(&self) -> Result<(), QbiError> {
+        if self.threshold < Decimal::ZERO {
+            return Err(QbiError::InvalidThreshold(self.threshold));
+        }
+        Ok(())
+    }
+}
+
+/// Result of Form 8995 calculations.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QbiResult {
+    /// Total qualified business income, with losses treated as zero (Line 4).
+    pub qualified_business_income: Decimal,
+
+    /// Qualified business income component (Line 5).
+    pub qbi_component: Decimal,
+
+    /// Deduction before the income limitation (Line 10).
+    pub deduction_before_limitation: Decimal,
+
+    /// Taxable income before the QBI deduction (Line 11).
+    pub taxable_income: Decimal,
+
+    /// Net capital gain, including qualified dividends (Line 12).
+    pub net_capital_gain: Decimal,
+
+    /// Taxable income less net capital gain (Line 13).
+    pub taxable_income_less_gain: Decimal,
+
+    /// Income limitation (Line 14).
+    pub income_limitation: Decimal,
+
+    /// Qualified business income deduction (Line 15).
+    ///
+    /// This amount is entered on line 2b of the Estimated Tax Worksheet.
+    pub deduction: Decimal,
+}
+
+impl fmt::Display for QbiResult {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        writeln!(f, "QbiResult {{")?;
+        writeln!(
+            f,
+            "    qualified_business_income   : ${}",
+            self.qualified_business_income.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    qbi_component               : ${}",
+            self.qbi_component.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    deduction_before_limitation : ${}",
+            self.deduction_before_limitation.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    taxable_income              : ${}",
+            self.taxable_income.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    net_capital_gain            : ${}",
+            self.net_capital_gain.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    taxable_income_less_gain    : ${}",
+            self.taxable_income_less_gain.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    income_limitation           : ${}",
+            self.income_limitation.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    deduction                   : ${}",
+            self.deduction.round_dp(2)
+        )?;
+        write!(f, "}}")?;
+        Ok(())
+    }
+}
+
+/// Calculator for the Qualified Business Income deduction (Form 8995).
+#[derive(Debug, Clone)]
+pub struct QbiWorksheet {
+    config: QbiConfig,
+}
+
+impl QbiWorksheet {
+    /// Creates a new Form 8995 calculator with the given configuration.
+    pub fn new(config: QbiConfig) -> Self {
+        Self { config }
+    }
+
+    /// Derives qualified business income from self-employment income.
+    ///
+    /// QBI is net profit reduced by the deductible part of self-employment
+    /// tax attributable to the business, i.e. `se_income` less
+    /// `SeWorksheetResult::se_tax_deduction` (SE worksheet line 11).
+    pub fn qualified_business_income(
+        se_income: Decimal,
+        se_tax_deduction: Decimal,
+    ) -> Decimal {
+        round_half_up(se_income - se_tax_deduction)
+    }
+
+    /// Calculates the qualified business income deduction.
+    ///
+    /// # Arguments
+    ///
+    /// * `qualified_business_income` - Total qualified business income (Line 4)
+    /// * `taxable_income` - Taxable income before the QBI deduction (Line 11)
+    /// * `net_capital_gain` - Net capital gain plus qualified dividends (Line 12)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QbiError`] if:
+    /// - the configuration is invalid
+    /// - `taxable_income` exceeds the threshold
+    pub fn calculate(
+        &self,
+        qualified_business_income: Decimal,
+        taxable_income: Decimal,
+        net_capital_gain: Decimal,
+    ) -> Result<QbiResult, QbiError> {
+        self.config.validate()?;
+
+        // Line 11: Taxable income before the QBI deduction
+        let taxable_income = max(round_half_up(taxable_income), Decimal::ZERO);
+        if taxable_income > self.config.threshold {
+            return Err(QbiError::ThresholdExceeded {
+                taxable_income,
+                threshold: self.config.threshold,
+            });
+        }
+
+        // Line 4: Total qualified business income (a loss is entered as zero)
+        let qualified_business_income =
+            max(round_half_up(qualified_business_income), Decimal::ZERO);
+
+        // Line 5: QBI component
+        let qbi_component = round_half_up(qualified_business_income * TWENTY_PERCENT);
+
+        // Line 10: No REIT/PTP component, so this equals Line 5
+        let deduction_before_limitation = qbi_component;
+
+        // Lines 12-13: Taxable income less net capital gain
+        let net_capital_gain = max(round_half_up(net_capital_gain), Decimal::ZERO);
+        let taxable_income_less_gain = max(taxable_income - net_capital_gain, Decimal::ZERO);
+
+        // Line 14: Income limitation
+        let income_limitation = round_half_up(taxable_income_less_gain * TWENTY_PERCENT);
+
+        // Line 15: Smaller of Line 10 or Line 14
+        let deduction = deduction_before_limitation.min(income_limitation);
+
+        Ok(QbiResult {
+            qualified_business_income,
+            qbi_component,
+            deduction_before_limitation,
+            taxable_income,
+            net_capital_gain,
+            taxable_income_less_gain,
+            income_limitation,
+            deduction,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn test_worksheet() -> QbiWorksheet {
+        QbiWorksheet::new(QbiConfig {
+            threshold: dec!(197300.00),
+        })
+    }
+
+    #[test]
+    fn qbi_result_display() {
+        let result = test_worksheet()
+            .calculate(dec!(60000.00), dec!(45000.00), dec!(5000.00))
+            .unwrap();
+        let expected = "QbiResult {
+    qualified_business_income   : $60000.00
+    qbi_component               : $12000.00
+    deduction_before_limitation : $12000.00
+    taxable_income              : $45000.00
+    net_capital_gain            : $5000.00
+    taxable_income_less_gain    : $40000.00
+    income_limitation           : $8000.00
+    deduction                   : $8000.00
+}";
+        assert_eq!(format!("{result}"), expected);
+    }
+
+    #[test]
+    fn from_reference_copies_threshold() {
+        let threshold = QbiThreshold {
+            tax_year: 2025,
+            filing_status_id: 2,
+            amount: dec!(394600.00),
+        };
+
+        assert_eq!(
+            QbiConfig::from_reference(&threshold),
+            QbiConfig {
+                threshold: dec!(394600.00),
+            }
+        );
+    }
+
+    #[test]
+    fn validate_rejects_negative_threshold() {
+        let config = QbiConfig {
+            threshold: dec!(-1.00),
+        };
+
+        assert_eq!(
+            config.validate(),
+            Err(QbiError::InvalidThreshold(dec!(-1.00)))
+        );
+    }
+
+    #[test]
+    fn qualified_business_income_subtracts_se_tax_deduction() {
+        assert_eq!(
+            QbiWorksheet::qualified_business_income(dec!(80000.00), dec!(5652.00)),
+            dec!(74348.00)
+        );
+    }
+
+    #[test]
+    fn calculate_uses_qbi_component_when_smaller() {
+        let result = test_worksheet()
+            .calculate(dec!(50000.00), dec!(120000.00), dec!(0.00))
+            .unwrap();
+
+        assert_eq!(result.qbi_component, dec!(10000.00));
+        assert_eq!(result.income_limitation, dec!(24000.00));
+        assert_eq!(result.deduction, dec!(10000.00));
+    }
+
+    #[test]
+    fn calculate_business_loss_is_treated_as_zero() {
+        let result = test_worksheet()
+            .calculate(dec!(-8000.00), dec!(60000.00), dec!(0.00))
+            .unwrap();
+
+        assert_eq!(result.qualified_business_income, dec!(0.00));
+        assert_eq!(result.deduction, dec!(0.00));
+    }
+
+    #[test]
+    fn calculate_capital_gain_above_taxable_income_limits_to_zero() {
+        let result = test_worksheet()
+            .calculate(dec!(40000.00), dec!(30000.00), dec!(35000.00))
+            .unwrap();
+
+        assert_eq!(result.taxable_income_less_gain, dec!(0.00));
+        assert_eq!(result.deduction, dec!(0.00));
+    }
+
+    #[test]
+    fn calculate_at_threshold_is_allowed() {
+        let result = test_worksheet()
+            .calculate(dec!(100000.00), dec!(197300.00), dec!(0.00))
+            .unwrap();
+
+        assert_eq!(result.deduction, dec!(20000.00));
+    }
+
+    #[test]
+    fn calculate_above_threshold_requires_form_8995a() {
+        assert_eq!(
+            test_worksheet().calculate(dec!(100000.00), dec!(197300.01), dec!(0.00)),
+            Err(QbiError::ThresholdExceeded {
+                taxable_income: dec!(197300.01),
+                threshold: dec!(197300.00),
+            })
+        );
+    }
+}
@@ -0,0 +1,507 @@
+//! Qualified Dividends and Capital Gain Tax Worksheet.
+//!
+//! Qualified dividends and net capital gain are taxed at 0%, 15% or 20%
+//! instead of the ordinary rates. This worksheet (Worksheet 2-5 in Pub. 505)
+//! replaces the tax rate schedule amount on line 4 of the 1040-ES Estimated
+//! Tax Worksheet whenever either amount is positive.
+//!
+//! # Worksheet Structure
+//!
+//! | Line | Description |
+//! |------|-------------|
+//! | 1    | Taxable income |
+//! | 2    | Qualified dividends |
+//! | 3    | Net capital gain |
+//! | 4    | Line 2 + Line 3 |
+//! | 5    | Line 1 - Line 4 (if zero or less, enter -0-) |
+//! | 6    | 0% rate breakpoint for filing status |
+//! | 7    | Smaller of Line 1 or Line 6 |
+//! | 8    | Smaller of Line 5 or Line 7 |
+//! | 9    | Line 7 - Line 8 (taxed at 0%) |
+//! | 10   | Smaller of Line 1 or Line 4 |
+//! | 11   | Amount from Line 9 |
+//! | 12   | Line 10 - Line 11 |
+//! | 13   | 15% rate breakpoint for filing status |
+//! | 14   | Smaller of Line 1 or Line 13 |
+//! | 15   | Line 5 + Line 9 |
+//! | 16   | Line 14 - Line 15 (if zero or less, enter -0-) |
+//! | 17   | Smaller of Line 12 or Line 16 |
+//! | 18   | Line 17 × 15% |
+//! | 19   | Line 9 + Line 17 |
+//! | 20   | Line 10 - Line 19 |
+//! | 21   | Line 20 × 20% |
+//! | 22   | Tax on Line 5 (tax rate schedules) |
+//! | 23   | Line 18 + Line 21 + Line 22 |
+//! | 24   | Tax on Line 1 (tax rate schedules) |
+//! | 25   | Smaller of Line 23 or Line 24 |
+//!
+//! # Example
+//!
+//! ```
+//! use rust_decimal_macros::dec;
+//! use tax_core::calculations::{QdcgConfig, QdcgWorksheet};
+//! use tax_core::TaxBracket;
+//!
+//! let tax_brackets = vec![
+//!     TaxBracket {
+//!         tax_year: 2025,
+//!         filing_status_id: 1,
+//!         min_income: dec!(0),
+//!         max_income: Some(dec!(11925)),
+//!         tax_rate: dec!(0.10),
+//!         base_tax: dec!(0),
+//!     },
+//!     TaxBracket {
+//!         tax_year: 2025,
+//!         filing_status_id: 1,
+//!         min_income: dec!(11925),
+//!         max_income: Some(dec!(48475)),
+//!         tax_rate: dec!(0.12),
+//!         base_tax: dec!(1192.50),
+//!     },
+//!     TaxBracket {
+//!         tax_year: 2025,
+//!         filing_status_id: 1,
+//!         min_income: dec!(48475),
+//!         max_income: Some(dec!(103350)),
+//!         tax_rate: dec!(0.22),
+//!         base_tax: dec!(5578.50),
+//!     },
+//! ];
+//! let config = QdcgConfig {
+//!     zero_rate_max: dec!(48350.00),
+//!     fifteen_rate_max: dec!(533400.00),
+//! };
+//!
+//! let worksheet = QdcgWorksheet::new(&tax_brackets, config);
+//! let result = worksheet.calculate(
+//!     dec!(85000.00), // taxable income
+//!     dec!(5000.00),  // qualified dividends
+//!     dec!(10000.00), // net capital gain
+//! ).unwrap();
+//!
+//! // $15,000 taxed at 15% plus ordinary tax on the remaining $70,000.
+//! assert_eq!(result.fifteen_rate_tax, dec!(2250.00));
+//! assert_eq!(result.tax, dec!(12564.00));
+//! ```
+
+use std::fmt;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::calculations::common::{max, round_half_up};
+use crate::calculations::{EstimatedTaxWorksheet, EstimatedTaxWorksheetError};
+use crate::{CapitalGainBreakpoints, TaxBracket};
+
+/// Rate applied to qualified income between the 0% and 15% breakpoints.
+const FIFTEEN_PERCENT: Decimal = Decimal::from_parts(15, 0, 0, false, 2);
+
+/// Rate applied to qualified income above the 15% breakpoint.
+const TWENTY_PERCENT: Decimal = Decimal::from_parts(20, 0, 0, false, 2);
+
+/// Configuration parameters for the Qualified Dividends and Capital Gain
+/// Tax Worksheet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QdcgConfig {
+    /// Taxable income up to which qualified income is taxed at 0% (Line 6).
+    ///
+    /// For 2025, $48,350 ($96,700 if married filing jointly or qualifying
+    /// surviving spouse, $64,750 if head of household).
+    pub zero_rate_max: Decimal,
+
+    /// Taxable income up to which qualified income is taxed at 15% (Line 13).
+    ///
+    /// For 2025, $533,400 ($600,050 if married filing jointly or qualifying
+    /// surviving spouse, $300,000 if married filing separately, $566,700 if
+    /// head of household).
+    pub fifteen_rate_max: Decimal,
+}
+
+impl QdcgConfig {
+    /// Creates a new configuration from the filing status breakpoints.
+    pub fn from_reference(breakpoints: &CapitalGainBreakpoints) -> Self {
+        Self {
+            zero_rate_max: breakpoints.zero_rate_max,
+            fifteen_rate_max: breakpoints.fifteen_rate_max,
+        }
+    }
+
+    /// Validates the configuration values.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EstimatedTaxWorksheetError::InvalidCapitalGainBreakpoints`]
+    /// if either breakpoint is negative or the 15% breakpoint is below the
+    /// 0% breakpoint.
+    pub fn validate(&self) -> Result<(), EstimatedTaxWorksheetError> {
+        if self.zero_rate_max < Decimal::ZERO || self.fifteen_rate_max < self.zero_rate_max {
+            return Err(EstimatedTaxWorksheetError::InvalidCapitalGainBreakpoints {
+                zero_rate_max: self.zero_rate_max,
+                fifteen_rate_max: self.fifteen_rate_max,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Result of the Qualified Dividends and Capital Gain Tax Worksheet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QdcgResult {
+    /// Taxable income (Line 1).
+    pub taxable_income: Decimal,
+
+    /// Qualified dividends plus net capital gain (Line 4).
+    pub qualified_income: Decimal,
+
+    /// Taxable income taxed at ordinary rates (Line 5).
+    pub ordinary_income: Decimal,
+
+    /// Qualified income taxed at 0% (Line 9).
+    pub zero_rate_amount: Decimal,
+
+    /// Qualified income taxed at 15% (Line 17).
+    pub fifteen_rate_amount: Decimal,
+
+    /// Tax on the 15% portion (Line 18).
+    pub fifteen_rate_tax: Decimal,
+
+    /// Qualified income taxed at 20% (Line 20).
+    pub twenty_rate_amount: Decimal,
+
+    /// Tax on the 20% portion (Line 21).
+    pub twenty_rate_tax: Decimal,
+
+    /// Tax on ordinary income from the tax rate schedules (Line 22).
+    pub ordinary_income_tax: Decimal,
+
+    /// Tax on all taxable income at ordinary rates (Line 24).
+    pub regular_tax: Decimal,
+
+    /// Tax on all taxable income (Line 25).
+    ///
+    /// This amount is entered on line 4 of the Estimated Tax Worksheet.
+    pub tax: Decimal,
+}
+
+impl fmt::Display for QdcgResult {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        writeln!(f, "QdcgResult {{")?;
+        writeln!(
+            f,
+            "    taxable_income      : ${}",
+            self.taxable_income.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    qualified_income    : ${}",
+            self.qualified_income.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    ordinary_income     : ${}",
+            self.ordinary_income.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    zero_rate_amount    : ${}",
+            self.zero_rate_amount.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    fifteen_rate_amount : ${}",
+            self.fifteen_rate_amount.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    fifteen_rate_tax    : ${}",
+            self.fifteen_rate_tax.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    twenty_rate_amount  : ${}",
+            self.twenty_rate_amount.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    twenty_rate_tax     : ${}",
+            self.twenty_rate_tax.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    ordinary_income_tax : ${}",
+            self.ordinary_income_tax.round_dp(2)
+        )?;
+        writeln!(
+            f,
+            "    regular_tax         : ${}",
+            self.regular_tax.round_dp(2)
+        )?;
+        writeln!(f, "    tax                 : ${}", self.tax.round_dp(2))?;
+        write!(f, "}}")?;
+        Ok(())
+    }
+}
+
+/// Calculator for the Qualified Dividends and Capital Gain Tax Worksheet.
+#[derive(Debug, Clone)]
+pub struct QdcgWorksheet<'a> {
+    tax_worksheet: EstimatedTaxWorksheet<'a>,
+    config: QdcgConfig,
+}
+
+impl<'a> QdcgWorksheet<'a> {
+    /// Creates a new worksheet calculator using `tax_brackets` for the
+    /// ordinary-rate portions (Lines 22 and 24).
+    pub fn new(
+        tax_brackets: &'a [TaxBracket],
+        config: QdcgConfig,
+    ) -> Self {
+        Self {
+            tax_worksheet: EstimatedTaxWorksheet::new(tax_brackets),
+            config,
+        }
+    }
+
+    /// Calculates tax on `taxable_income` with qualified dividends and net
+    /// capital gain taxed at the 0%, 15% and 20% rates.
+    ///
+    /// Negative qualified dividends or net capital gain are treated as zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EstimatedTaxWorksheetError`] if:
+    /// - the breakpoints are invalid
+    /// - no tax bracket covers an ordinary-rate amount
+    pub fn calculate(
+        &self,
+        taxable_income: Decimal,
+        qualified_dividends: Decimal,
+        net_capital_gain: Decimal,
+    ) -> Result<QdcgResult, EstimatedTaxWorksheetError> {
+        self.config.validate()?;
+
+        let taxable_income = max(round_half_up(taxable_income), Decimal::ZERO);
+        let qualified_income = round_half_up(
+            max(qualified_dividends, Decimal::ZERO) + max(net_capital_gain, Decimal::ZERO),
+        );
+        let ordinary_income = max(taxable_income - qualified_income, Decimal::ZERO);
+
+        // Lines 6-9: qualified income that fits under the 0% breakpoint
+        let zero_rate_limit = taxable_income.min(self.config.zero_rate_max);
+        let zero_rate_amount = zero_rate_limit - ordinary_income.min(zero_rate_limit);
+
+        // Lines 10-17: qualified income that fits under the 15% breakpoint
+        let taxable_qualified_income = taxable_income.min(qualified_income);
+        let remaining_qualified_income = taxable_qualified_income - zero_rate_amount;
+        let fifteen_rate_limit = taxable_income.min(self.config.fifteen_rate_max);
+        let fifteen_rate_room = max(
+            fifteen_rate_limit - (ordinary_income + zero_rate_amount),
+            Decimal::ZERO,
+        );
+        let fifteen_rate_amount = remaining_qualified_income.min(fifteen_rate_room);
+        let fifteen_rate_tax = round_half_up(fifteen_rate_amount * FIFTEEN_PERCENT);
+
+        // Lines 19-21: everything else is taxed at 20%
+        let twenty_rate_amount =
+            taxable_qualified_income - (zero_rate_amount + fifteen_rate_amount);
+        let twenty_rate_tax = round_half_up(twenty_rate_amount * TWENTY_PERCENT);
+
+        // Lines 22-25
+        let ordinary_income_tax = self.tax_worksheet.calculate_tax(ordinary_income)?;
+        let regular_tax = self.tax_worksheet.calculate_tax(taxable_income)?;
+        let tax = (fifteen_rate_tax + twenty_rate_tax + ordinary_income_tax).min(regular_tax);
+
+        Ok(QdcgResult {
+            taxable_income,
+            qualified_income,
+            ordinary_income,
+            zero_rate_amount,
+            fifteen_rate_amount,
+            fifteen_rate_tax,
+            twenty_rate_amount,
+            twenty_rate_tax,
+            ordinary_income_tax,
+            regular_tax,
+            tax,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn test_brackets_single() -> Vec<TaxBracket> {
+        [
+            (dec!(0), Some(dec!(11925)), dec!(0.10), dec!(0)),
+            (dec!(11925), Some(dec!(48475)), dec!(0.12), dec!(1192.50)),
+            (dec!(48475), Some(dec!(103350)), dec!(0.22), dec!(5578.50)),
+            (dec!(103350), Some(dec!(197300)), dec!(0.24), dec!(17651)),
+            (dec!(197300), Some(dec!(250525)), dec!(0.32), dec!(40199)),
+            (dec!(250525), Some(dec!(626350)), dec!(0.35), dec!(57231)),
+            (dec!(626350), None, dec!(0.37), dec!(188769.75)),
+        ]
+        .into_iter()
+        .map(|(min_income, max_income, tax_rate, base_tax)| TaxBracket {
+            tax_year: 2025,
+            filing_status_id: 1,
+            min_income,
+            max_income,
+            tax_rate,
+            base_tax,
+        })
+        .collect()
+    }
+
+    fn test_config() -> QdcgConfig {
+        QdcgConfig {
+            zero_rate_max: dec!(48350.00),
+            fifteen_rate_max: dec!(533400.00),
+        }
+    }
+
+    #[test]
+    fn qdcg_result_display() {
+        let brackets = test_brackets_single();
+        let worksheet = QdcgWorksheet::new(&brackets, test_config());
+        let result = worksheet
+            .calculate(dec!(85000.00), dec!(5000.00), dec!(10000.00))
+            .unwrap();
+        let expected = "QdcgResult {
+    taxable_income      : $85000.00
+    qualified_income    : $15000.00
+    ordinary_income     : $70000.00
+    zero_rate_amount    : $0.00
+    fifteen_rate_amount : $15000.00
+    fifteen_rate_tax    : $2250.00
+    twenty_rate_amount  : $0.00
+    twenty_rate_tax     : $0
+    ordinary_income_tax : $10314.00
+    regular_tax         : $13614.00
+    tax                 : $12564.00
+}";
+        assert_eq!(format!("{result}"), expected);
+    }
+
+    #[test]
+    fn from_reference_copies_breakpoints() {
+        let breakpoints = CapitalGainBreakpoints {
+            tax_year: 2025,
+            filing_status_id: 4,
+            zero_rate_max: dec!(64750.00),
+            fifteen_rate_max: dec!(566700.00),
+        };
+
+        assert_eq!(
+            QdcgConfig::from_reference(&breakpoints),
+            QdcgConfig {
+                zero_rate_max: dec!(64750.00),
+                fifteen_rate_max: dec!(566700.00),
+            }
+        );
+    }
+
+    #[test]
+    fn validate_rejects_fifteen_rate_breakpoint_below_zero_rate_breakpoint() {
+        let config = QdcgConfig {
+            zero_rate_max: dec!(48350.00),
+            fifteen_rate_max: dec!(40000.00),
+        };
+
+        assert_eq!(
+            config.validate(),
+            Err(EstimatedTaxWorksheetError::InvalidCapitalGainBreakpoints {
+                zero_rate_max: dec!(48350.00),
+                fifteen_rate_max: dec!(40000.00),
+            })
+        );
+    }
+
+    #[test]
+    fn qualified_income_below_zero_rate_breakpoint_is_untaxed() {
+        let brackets = test_brackets_single();
+        let worksheet = QdcgWorksheet::new(&brackets, test_config());
+
+        let result = worksheet
+            .calculate(dec!(40000.00), dec!(0.00), dec!(20000.00))
+            .unwrap();
+
+        assert_eq!(result.ordinary_income, dec!(20000.00));
+        assert_eq!(result.zero_rate_amount, dec!(20000.00));
+        assert_eq!(result.fifteen_rate_amount, dec!(0.00));
+        assert_eq!(result.twenty_rate_amount, dec!(0.00));
+        // Only the $20,000 of ordinary income is taxed.
+        assert_eq!(result.tax, dec!(2161.50));
+        assert_eq!(result.regular_tax, dec!(4561.50));
+    }
+
+    #[test]
+    fn qualified_income_spans_all_three_rates() {
+        let brackets = test_brackets_single();
+        let worksheet = QdcgWorksheet::new(&brackets, test_config());
+
+        let result = worksheet
+            .calculate(dec!(600000.00), dec!(0.00), dec!(560000.00))
+            .unwrap();
+
+        assert_eq!(result.ordinary_income, dec!(40000.00));
+        assert_eq!(result.zero_rate_amount, dec!(8350.00));
+        assert_eq!(result.fifteen_rate_amount, dec!(485050.00));
+        assert_eq!(result.fifteen_rate_tax, dec!(72757.50));
+        assert_eq!(result.twenty_rate_amount, dec!(66600.00));
+        assert_eq!(result.twenty_rate_tax, dec!(13320.00));
+        assert_eq!(result.ordinary_income_tax, dec!(4561.50));
+        // 72,757.50 + 13,320 + 4,561.50
+        assert_eq!(result.tax, dec!(90639.00));
+    }
+
+    #[test]
+    fn qualified_income_is_limited_to_taxable_income() {
+        let brackets = test_brackets_single();
+        let worksheet = QdcgWorksheet::new(&brackets, test_config());
+
+        let result = worksheet
+            .calculate(dec!(10000.00), dec!(30000.00), dec!(0.00))
+            .unwrap();
+
+        assert_eq!(result.ordinary_income, dec!(0.00));
+        assert_eq!(result.zero_rate_amount, dec!(10000.00));
+        assert_eq!(result.tax, dec!(0.00));
+    }
+
+    #[test]
+    fn negative_net_capital_gain_is_treated_as_zero() {
+        let brackets = test_brackets_single();
+        let worksheet = QdcgWorksheet::new(&brackets, test_config());
+
+        let result = worksheet
+            .calculate(dec!(85000.00), dec!(5000.00), dec!(-3000.00))
+            .unwrap();
+
+        assert_eq!(result.qualified_income, dec!(5000.00));
+        // 750 at 15% plus ordinary tax on 80,000 (12,514).
+        assert_eq!(result.tax, dec!(13264.00));
+    }
+
+    #[test]
+    fn calculate_rejects_invalid_breakpoints() {
+        let brackets = test_brackets_single();
+        let config = QdcgConfig {
+            zero_rate_max: dec!(-1.00),
+            fifteen_rate_max: dec!(533400.00),
+        };
+        let worksheet = QdcgWorksheet::new(&brackets, config);
+
+        assert!(matches!(
+            worksheet.calculate(dec!(85000.00), dec!(5000.00), dec!(0.00)),
+            Err(EstimatedTaxWorksheetError::InvalidCapitalGainBreakpoints { .. })
+        ));
+    }
+}
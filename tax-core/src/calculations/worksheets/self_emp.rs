@@ -167,6 +167,11 @@ impl SeWorksheetConfig {
     ///     se_deduction_factor: dec!(0.50),
     ///     req_pmnt_threshold: dec!(1000.00),
     ///     min_se_threshold: dec!(400.00),
+    ///     addl_medicare_tax_rate: dec!(0.009),
+    ///     niit_rate: dec!(0.038),
+    ///     prior_agi_threshold: dec!(150000.00),
+    ///     prior_agi_threshold_mfs: dec!(75000.00),
+    ///     prior_tax_multiplier: dec!(1.10),
     /// };
     ///
     /// let config = SeWorksheetConfig::from_tax_year_config(&tax_year_config);
@@ -998,6 +1003,11 @@ mod tests {
             se_deduction_factor: dec!(0.50),
             req_pmnt_threshold: dec!(1000.00),
             min_se_threshold: dec!(400.00),
+            addl_medicare_tax_rate: dec!(0.009),
+            niit_rate: dec!(0.038),
+            prior_agi_threshold: dec!(150000.00),
+            prior_agi_threshold_mfs: dec!(75000.00),
+            prior_tax_multiplier: dec!(1.10),
         };
 
         let config = SeWorksheetConfig::from_tax_year_config(&tax_year_config);
@@ -1021,6 +1031,11 @@ mod tests {
             se_deduction_factor: dec!(0.50),
             req_pmnt_threshold: dec!(1000.00),
             min_se_threshold: dec!(400.00),
+            addl_medicare_tax_rate: dec!(0.009),
+            niit_rate: dec!(0.038),
+            prior_agi_threshold: dec!(150000.00),
+            prior_agi_threshold_mfs: dec!(75000.00),
+            prior_tax_multiplier: dec!(1.10),
         };
 
         let config = SeWorksheetConfig::from_tax_year_config(&tax_year_config);
@@ -1039,6 +1054,11 @@ mod tests {
             se_deduction_factor: dec!(0.50),
             req_pmnt_threshold: dec!(1000.00),
             min_se_threshold: dec!(450.00), // Different threshold for 2026
+            addl_medicare_tax_rate: dec!(0.009),
+            niit_rate: dec!(0.038),
+            prior_agi_threshold: dec!(150000.00),
+            prior_agi_threshold_mfs: dec!(75000.00),
+            prior_tax_multiplier: dec!(1.10),
         };
 
         let config = SeWorksheetConfig::from_tax_year_config(&tax_year_config);
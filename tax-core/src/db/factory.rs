@@ -13,6 +13,8 @@ use super::repository::{RepositoryError, TaxRepository};
 /// | backend    | connection_string examples          |
 /// |------------|-------------------------------------|
 /// | `sqlite`   | `taxes.db`, `:memory:`              |
+/// | `postgres` | `postgres://user@localhost/taxes`   |
+/// | `memory`   | ignored                             |
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DbConfig {
     /// Lowercase identifier matching a registered factory (e.g. `"sqlite"`).
@@ -123,10 +125,14 @@ mod tests {
     use async_trait::async_trait;
 
     use crate::models::{
-        FilingStatus, StandardDeduction, TaxBracket, TaxEstimate, TaxEstimateInput, TaxYearConfig,
+        AdditionalMedicareThreshold, CapitalGainBreakpoints, FilingStatus, FilingStatusCode,
+        NiitThreshold, QbiThreshold, StandardDeduction, TaxBracket, TaxEstimate,
+        TaxEstimateComputed, TaxEstimateInput, TaxEstimateRevision, TaxYearConfig,
+        UnderpaymentInterestRate,
     };
 
     use super::{DbConfig, RepositoryError, RepositoryFactory, RepositoryRegistry, TaxRepository};
+    use crate::db::repository::TaxTransaction;
 
     // ── test scaffolding ─────────────────────────────────────────────────
     // Every method panics unconditionally.  Nothing in these tests actually
@@ -167,6 +173,63 @@ mod tests {
         ) -> Result<StandardDeduction, RepositoryError> {
             unimplemented!()
         }
+        async fn get_additional_medicare_threshold(
+            &self,
+            _tax_year: i32,
+            _filing_status_id: i32,
+        ) -> Result<AdditionalMedicareThreshold, RepositoryError> {
+            unimplemented!()
+        }
+        async fn get_niit_threshold(
+            &self,
+            _tax_year: i32,
+            _filing_status_id: i32,
+        ) -> Result<NiitThreshold, RepositoryError> {
+            unimplemented!()
+        }
+        async fn upsert_niit_threshold(
+            &self,
+            _threshold: &NiitThreshold,
+        ) -> Result<(), RepositoryError> {
+            unimplemented!()
+        }
+        async fn get_capital_gain_breakpoints(
+            &self,
+            _tax_year: i32,
+            _filing_status_id: i32,
+        ) -> Result<CapitalGainBreakpoints, RepositoryError> {
+            unimplemented!()
+        }
+        async fn upsert_capital_gain_breakpoints(
+            &self,
+            _breakpoints: &CapitalGainBreakpoints,
+        ) -> Result<(), RepositoryError> {
+            unimplemented!()
+        }
+        async fn get_qbi_threshold(
+            &self,
+            _tax_year: i32,
+            _filing_status_id: i32,
+        ) -> Result<QbiThreshold, RepositoryError> {
+            unimplemented!()
+        }
+        async fn upsert_qbi_threshold(
+            &self,
+            _threshold: &QbiThreshold,
+        ) -> Result<(), RepositoryError> {
+            unimplemented!()
+        }
+        async fn list_underpayment_interest_rates(
+            &self
+        ) -> Result<Vec<UnderpaymentInterestRate>, RepositoryError> {
+            unimplemented!()
+        }
+        async fn upsert_underpayment_interest_rate(
+            &self,
+            _rate: &UnderpaymentInterestRate,
+        ) -> Result<(), RepositoryError> {
+            unimplemented!()
+        }
         async fn get_filing_status_data(
             &self,
             _year: i32,
@@ -200,6 +263,13 @@ mod tests {
         ) -> Result<TaxEstimate, RepositoryError> {
             unimplemented!()
         }
+        async fn create_calculated_estimate(
+            &self,
+            _estimate: TaxEstimateInput,
+            _computed: TaxEstimateComputed,
+        ) -> Result<TaxEstimate, RepositoryError> {
+            unimplemented!()
+        }
         async fn get_estimate(
             &self,
             _id: i64,
@@ -224,6 +294,51 @@ mod tests {
         ) -> Result<Vec<TaxEstimate>, RepositoryError> {
             unimplemented!()
         }
+        async fn get_estimate_by_name(
+            &self,
+            _tax_year: i32,
+            _filing_status: FilingStatusCode,
+            _scenario_name: &str,
+        ) -> Result<TaxEstimate, RepositoryError> {
+            unimplemented!()
+        }
+        async fn list_estimates_by_name(
+            &self,
+            _scenario_name: &str,
+            _tax_year: Option<i32>,
+        ) -> Result<Vec<TaxEstimate>, RepositoryError> {
+            unimplemented!()
+        }
+        async fn list_estimate_revisions(
+            &self,
+            _estimate_id: i64,
+        ) -> Result<Vec<TaxEstimateRevision>, RepositoryError> {
+            unimplemented!()
+        }
+        async fn restore_estimate_revision(
+            &self,
+            _revision_id: i64,
+        ) -> Result<TaxEstimate, RepositoryError> {
+            unimplemented!()
+        }
+        async fn list_deleted_estimates(&self) -> Result<Vec<TaxEstimate>, RepositoryError> {
+            unimplemented!()
+        }
+        async fn restore_deleted_estimate(
+            &self,
+            _id: i64,
+        ) -> Result<TaxEstimate, RepositoryError> {
+            unimplemented!()
+        }
+        async fn purge_deleted_estimates(
+            &self,
+            _retention: chrono::Duration,
+        ) -> Result<u64, RepositoryError> {
+            unimplemented!()
+        }
+        async fn begin(&self) -> Result<Box<dyn TaxTransaction>, RepositoryError> {
+            unimplemented!()
+        }
     }
 
     /// Records whether `create` was called.  The `AtomicBool` is the
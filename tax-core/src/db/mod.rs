@@ -2,4 +2,4 @@ pub mod factory;
 pub mod repository;
 
 pub use factory::{DbConfig, RepositoryFactory, RepositoryRegistry};
-pub use repository::{RepositoryError, TaxRepository};
+pub use repository::{RepositoryError, TaxRepository, TaxTransaction};
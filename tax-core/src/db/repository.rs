@@ -1,8 +1,11 @@
 use async_trait::async_trait;
+use chrono::Duration;
 use thiserror::Error;
 
 use crate::models::{
-    FilingStatus, StandardDeduction, TaxBracket, TaxEstimate, TaxEstimateInput, TaxYearConfig,
+    AdditionalMedicareThreshold, CapitalGainBreakpoints, FilingStatus, FilingStatusCode,
+    NiitThreshold, QbiThreshold, StandardDeduction, TaxBracket, TaxEstimate, TaxEstimateComputed,
+    TaxEstimateInput, TaxEstimateRevision, TaxYearConfig, UnderpaymentInterestRate,
 };
 
 #[derive(Debug, Error)]
@@ -25,6 +28,18 @@ pub enum RepositoryError {
     /// the expected domain type (e.g. an unrecognised filing status code).
     #[error("Invalid data: {0}")]
     InvalidData(String),
+
+    /// An estimate was saved under the tax year, filing status and scenario
+    /// name of estimate `id`, which is in the trash. It has to be restored
+    /// (or purged) before its scenario can be saved over.
+    #[error("Estimate {id} ('{scenario_name}') is in the trash; restore it before saving over it")]
+    EstimateInTrash { id: i64, scenario_name: String },
+
+    /// Restoring a revision would give its estimate the tax year, filing
+    /// status and scenario name of live estimate `id`. One of the two has to
+    /// be renamed first.
+    #[error("Scenario '{scenario_name}' is already used by estimate {id}")]
+    ScenarioTaken { id: i64, scenario_name: String },
 }
 
 #[async_trait]
@@ -54,6 +69,67 @@ pub trait TaxRepository: Send + Sync {
         filing_status_id: i32,
     ) -> Result<StandardDeduction, RepositoryError>;
 
+    // Additional Medicare Tax thresholds
+    async fn get_additional_medicare_threshold(
+        &self,
+        tax_year: i32,
+        filing_status_id: i32,
+    ) -> Result<AdditionalMedicareThreshold, RepositoryError>;
+
+    // Net Investment Income Tax thresholds
+    async fn get_niit_threshold(
+        &self,
+        tax_year: i32,
+        filing_status_id: i32,
+    ) -> Result<NiitThreshold, RepositoryError>;
+
+    /// Insert or replace the NIIT threshold for a tax year and filing status.
+    async fn upsert_niit_threshold(
+        &self,
+        threshold: &NiitThreshold,
+    ) -> Result<(), RepositoryError>;
+
+    // Qualified dividend and capital gain rate breakpoints
+    async fn get_capital_gain_breakpoints(
+        &self,
+        tax_year: i32,
+        filing_status_id: i32,
+    ) -> Result<CapitalGainBreakpoints, RepositoryError>;
+
+    /// Insert or replace the 0%/15% breakpoints for a tax year and filing
+    /// status.
+    async fn upsert_capital_gain_breakpoints(
+        &self,
+        breakpoints: &CapitalGainBreakpoints,
+    ) -> Result<(), RepositoryError>;
+
+    // Qualified business income deduction thresholds
+    async fn get_qbi_threshold(
+        &self,
+        tax_year: i32,
+        filing_status_id: i32,
+    ) -> Result<QbiThreshold, RepositoryError>;
+
+    /// Insert or replace the Form 8995 taxable income threshold for a tax
+    /// year and filing status.
+    async fn upsert_qbi_threshold(
+        &self,
+        threshold: &QbiThreshold,
+    ) -> Result<(), RepositoryError>;
+
+    // Underpayment interest rates
+    /// List every quarterly underpayment interest rate, ordered by year then
+    /// quarter.
+    async fn list_underpayment_interest_rates(
+        &self
+    ) -> Result<Vec<UnderpaymentInterestRate>, RepositoryError>;
+
+    /// Insert or replace the underpayment interest rate for a quarter.
+    async fn upsert_underpayment_interest_rate(
+        &self,
+        rate: &UnderpaymentInterestRate,
+    ) -> Result<(), RepositoryError>;
+
     /// Fetch every filing status together with its standard deduction and tax
     /// brackets for `year` via a single three-way JOIN, ordered by filing
     /// status id then bracket min income.
@@ -81,11 +157,28 @@ pub trait TaxRepository: Send + Sync {
     ) -> Result<(), RepositoryError>;
 
     // Tax estimates
+    /// Save an estimate under its scenario name. Saving again with the same
+    /// tax year, filing status and scenario name replaces that scenario's
+    /// inputs and clears its computed results; other scenarios are untouched.
+    /// Saving over a scenario whose estimate is in the trash fails with
+    /// [`RepositoryError::EstimateInTrash`] and leaves the trashed estimate
+    /// as it was.
     async fn create_estimate(
         &self,
         estimate: TaxEstimateInput,
     ) -> Result<TaxEstimate, RepositoryError>;
 
+    /// Save an estimate together with the results calculated for it. Behaves
+    /// like [`create_estimate`](Self::create_estimate), except the scenario
+    /// keeps `computed` instead of having its results cleared, so the save
+    /// records a single revision.
+    async fn create_calculated_estimate(
+        &self,
+        estimate: TaxEstimateInput,
+        computed: TaxEstimateComputed,
+    ) -> Result<TaxEstimate, RepositoryError>;
+
+    /// Fetch a live estimate; estimates in the trash are `NotFound`.
     async fn get_estimate(
         &self,
         id: i64,
@@ -96,13 +189,90 @@ pub trait TaxRepository: Send + Sync {
         estimate: &TaxEstimate,
     ) -> Result<(), RepositoryError>;
 
+    /// Move an estimate to the trash. It stays recoverable with
+    /// [`restore_deleted_estimate`](Self::restore_deleted_estimate) until it
+    /// is purged.
     async fn delete_estimate(
         &self,
         id: i64,
     ) -> Result<(), RepositoryError>;
 
+    /// List live estimates, optionally filtered to a single tax year, most
+    /// recently updated first.
     async fn list_estimates(
         &self,
         tax_year: Option<i32>,
     ) -> Result<Vec<TaxEstimate>, RepositoryError>;
+
+    /// Fetch the estimate saved under `scenario_name` for a tax year and
+    /// filing status.
+    async fn get_estimate_by_name(
+        &self,
+        tax_year: i32,
+        filing_status: FilingStatusCode,
+        scenario_name: &str,
+    ) -> Result<TaxEstimate, RepositoryError>;
+
+    /// List every estimate saved under `scenario_name`, optionally filtered to
+    /// a single tax year, most recently updated first.
+    async fn list_estimates_by_name(
+        &self,
+        scenario_name: &str,
+        tax_year: Option<i32>,
+    ) -> Result<Vec<TaxEstimate>, RepositoryError>;
+
+    // Tax estimate revisions
+    /// List the recorded revisions of an estimate, oldest first. A revision
+    /// is appended every time the estimate is created or updated.
+    async fn list_estimate_revisions(
+        &self,
+        estimate_id: i64,
+    ) -> Result<Vec<TaxEstimateRevision>, RepositoryError>;
+
+    /// Overwrite an estimate with the inputs and computed values captured in
+    /// revision `revision_id`. The restore itself is recorded as a new
+    /// revision, so no history is lost. If another estimate now has the
+    /// revision's scenario, the restore fails with
+    /// [`RepositoryError::ScenarioTaken`], or with
+    /// [`RepositoryError::EstimateInTrash`] when that estimate is in the trash.
+    async fn restore_estimate_revision(
+        &self,
+        revision_id: i64,
+    ) -> Result<TaxEstimate, RepositoryError>;
+
+    // Trash
+    /// List estimates in the trash, most recently deleted first.
+    async fn list_deleted_estimates(&self) -> Result<Vec<TaxEstimate>, RepositoryError>;
+
+    /// Take an estimate back out of the trash.
+    async fn restore_deleted_estimate(
+        &self,
+        id: i64,
+    ) -> Result<TaxEstimate, RepositoryError>;
+
+    /// Permanently remove estimates, and their revisions, that have been in
+    /// the trash for longer than `retention`. Returns how many were removed.
+    async fn purge_deleted_estimates(
+        &self,
+        retention: Duration,
+    ) -> Result<u64, RepositoryError>;
+
+    // Transactions
+    /// Start a unit of work. Reads and writes made through the returned
+    /// handle see each other, and are applied together by
+    /// [`TaxTransaction::commit`] or not at all if the handle is rolled back
+    /// or dropped. Calling `begin` on a transaction is a
+    /// [`RepositoryError::Configuration`] error.
+    async fn begin(&self) -> Result<Box<dyn TaxTransaction>, RepositoryError>;
+}
+
+/// An open unit of work returned by [`TaxRepository::begin`].
+#[async_trait]
+pub trait TaxTransaction: TaxRepository {
+    /// Apply every change made through this transaction.
+    async fn commit(self: Box<Self>) -> Result<(), RepositoryError>;
+
+    /// Discard every change made through this transaction. Dropping the
+    /// handle without committing does the same.
+    async fn rollback(self: Box<Self>) -> Result<(), RepositoryError>;
 }
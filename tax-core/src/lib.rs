@@ -1,9 +1,13 @@
 pub mod calculations;
 pub mod db;
 pub mod models;
+#[cfg(feature = "testkit")]
+pub mod testkit;
 
-pub use db::repository::{RepositoryError, TaxRepository};
+pub use db::repository::{RepositoryError, TaxRepository, TaxTransaction};
 pub use models::{
-    FilingStatus, FilingStatusCode, StandardDeduction, TaxBracket, TaxEstimate,
-    TaxEstimateComputed, TaxEstimateInput, TaxYearConfig,
+    AdditionalMedicareThreshold, CapitalGainBreakpoints, DEFAULT_SCENARIO_NAME, FilingStatus,
+    FilingStatusCode, NiitThreshold, QbiThreshold, StandardDeduction, TaxBracket, TaxEstimate,
+    TaxEstimateComputed, TaxEstimateFieldChange, TaxEstimateInput, TaxEstimateRevision,
+    TaxYearConfig, UnderpaymentInterestRate,
 };
@@ -0,0 +1,13 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Form 8959 threshold for one filing status in a tax year.
+///
+/// Wages and self-employment income above `amount` are subject to the
+/// Additional Medicare Tax rate from [`TaxYearConfig`](crate::TaxYearConfig).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AdditionalMedicareThreshold {
+    pub tax_year: i32,
+    pub filing_status_id: i32,
+    pub amount: Decimal,
+}
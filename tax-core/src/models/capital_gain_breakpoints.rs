@@ -0,0 +1,16 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Qualified dividend and capital gain rate breakpoints for one filing
+/// status in a tax year.
+///
+/// Taxable income up to `zero_rate_max` is taxed at 0% when it is qualified
+/// dividends or net capital gain, income up to `fifteen_rate_max` at 15%, and
+/// the rest at 20%.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CapitalGainBreakpoints {
+    pub tax_year: i32,
+    pub filing_status_id: i32,
+    pub zero_rate_max: Decimal,
+    pub fifteen_rate_max: Decimal,
+}
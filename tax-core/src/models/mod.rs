@@ -1,11 +1,23 @@
+mod additional_medicare_threshold;
+mod capital_gain_breakpoints;
 mod filing_status;
+mod niit_threshold;
+mod qbi_threshold;
 mod standard_deduction;
 mod tax_bracket;
 mod tax_estimate;
+mod tax_estimate_revision;
 mod tax_year_config;
+mod underpayment_interest_rate;
 
+pub use additional_medicare_threshold::AdditionalMedicareThreshold;
+pub use capital_gain_breakpoints::CapitalGainBreakpoints;
 pub use filing_status::{FilingStatus, FilingStatusCode};
+pub use niit_threshold::NiitThreshold;
+pub use qbi_threshold::QbiThreshold;
 pub use standard_deduction::StandardDeduction;
 pub use tax_bracket::TaxBracket;
-pub use tax_estimate::{TaxEstimate, TaxEstimateComputed, TaxEstimateInput};
+pub use tax_estimate::{DEFAULT_SCENARIO_NAME, TaxEstimate, TaxEstimateComputed, TaxEstimateInput};
+pub use tax_estimate_revision::{TaxEstimateFieldChange, TaxEstimateRevision};
 pub use tax_year_config::TaxYearConfig;
+pub use underpayment_interest_rate::UnderpaymentInterestRate;
@@ -0,0 +1,13 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Form 8960 modified AGI threshold for one filing status in a tax year.
+///
+/// The Net Investment Income Tax applies to the smaller of net investment
+/// income or modified AGI above `amount`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NiitThreshold {
+    pub tax_year: i32,
+    pub filing_status_id: i32,
+    pub amount: Decimal,
+}
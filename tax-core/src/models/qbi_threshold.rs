@@ -0,0 +1,14 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Form 8995 taxable income threshold for one filing status in a tax year.
+///
+/// Taxpayers whose taxable income before the qualified business income
+/// deduction exceeds `amount` must use Form 8995-A instead of the simplified
+/// computation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QbiThreshold {
+    pub tax_year: i32,
+    pub filing_status_id: i32,
+    pub amount: Decimal,
+}
@@ -7,15 +7,24 @@ use serde::{Deserialize, Serialize};
 use crate::calculations::{EstimatedTaxWorksheetContext, EstimatedTaxWorksheetInput};
 use crate::models::FilingStatusCode;
 
+/// Scenario name given to estimates saved without an explicit label.
+pub const DEFAULT_SCENARIO_NAME: &str = "Default";
+
 /// Canonical user-entered estimate data.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TaxEstimateInput {
     pub tax_year: i32,
     pub filing_status: FilingStatusCode,
+    /// Label distinguishing what-if versions of the same tax year and filing
+    /// status (e.g. "conservative", "expected", "optimistic").
+    pub scenario_name: String,
 
     pub se_income: Option<Decimal>,
     pub expected_crp_payments: Option<Decimal>,
     pub expected_wages: Option<Decimal>,
+    pub net_investment_income: Option<Decimal>,
+    pub qualified_dividends: Option<Decimal>,
+    pub net_capital_gain: Option<Decimal>,
 
     pub expected_agi: Decimal,
     pub expected_deduction: Decimal,
@@ -25,6 +34,7 @@ pub struct TaxEstimateInput {
     pub expected_other_taxes: Option<Decimal>,
     pub expected_withholding: Option<Decimal>,
     pub prior_year_tax: Option<Decimal>,
+    pub prior_year_agi: Option<Decimal>,
 }
 
 /// Stored calculated values for a persisted estimate.
@@ -43,6 +53,9 @@ pub struct TaxEstimate {
     pub computed: Option<TaxEstimateComputed>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// When the estimate was moved to the trash; `None` while it is live.
+    #[serde(default)]
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 /// Tax year range accepted for estimates (inclusive).
@@ -61,6 +74,10 @@ impl TaxEstimateInput {
             ));
         }
 
+        if self.scenario_name.trim().is_empty() {
+            errors.push("Scenario name is required".to_string());
+        }
+
         if self.expected_agi < Decimal::ZERO {
             errors.push("Expected AGI cannot be negative".to_string());
         }
@@ -72,12 +89,16 @@ impl TaxEstimateInput {
             ("SE income", &self.se_income),
             ("CRP payments", &self.expected_crp_payments),
             ("Wages", &self.expected_wages),
+            ("Net investment income", &self.net_investment_income),
+            ("Qualified dividends", &self.qualified_dividends),
+            ("Net capital gain", &self.net_capital_gain),
             ("QBI deduction", &self.expected_qbi_deduction),
             ("AMT", &self.expected_amt),
             ("Credits", &self.expected_credits),
             ("Other taxes", &self.expected_other_taxes),
             ("Withholding", &self.expected_withholding),
             ("Prior year tax", &self.prior_year_tax),
+            ("Prior year AGI", &self.prior_year_agi),
         ] {
             if let Some(d) = opt
                 && *d < Decimal::ZERO
@@ -101,13 +122,20 @@ impl TaxEstimateInput {
         EstimatedTaxWorksheetInput {
             adjusted_gross_income: self.expected_agi,
             deduction: self.expected_deduction,
-            qbi_deduction: self.expected_qbi_deduction.unwrap_or_default(),
+            qbi_deduction: self.expected_qbi_deduction.unwrap_or(context.qbi_deduction),
+            qualified_dividends: self.qualified_dividends.unwrap_or_default(),
+            net_capital_gain: self.net_capital_gain.unwrap_or_default(),
             alternative_minimum_tax: self.expected_amt.unwrap_or_default(),
             credits: self.expected_credits.unwrap_or_default(),
             self_employment_tax: context.self_employment_tax,
             other_taxes: self.expected_other_taxes.unwrap_or_default(),
+            additional_medicare_tax: context.additional_medicare_tax,
+            net_investment_income_tax: context.net_investment_income_tax,
             refundable_credits: context.refundable_credits,
             prior_year_tax: self.prior_year_tax.unwrap_or_default(),
+            prior_year_agi: self.prior_year_agi.unwrap_or_default(),
+            prior_year_agi_threshold: context.prior_year_agi_threshold,
+            high_income_prior_year_multiplier: context.high_income_prior_year_multiplier,
             withholding: self.expected_withholding.unwrap_or_default(),
             is_farmer_or_fisher: context.is_farmer_or_fisher,
             required_payment_threshold: context.required_payment_threshold,
@@ -122,8 +150,9 @@ impl Display for TaxEstimateInput {
     ) -> fmt::Result {
         write!(
             f,
-            "Tax estimate {}: filing status {}",
+            "Tax estimate {} '{}': filing status {}",
             self.tax_year,
+            self.scenario_name,
             self.filing_status.as_str()
         )?;
         write!(f, ", se_income: ")?;
@@ -132,6 +161,12 @@ impl Display for TaxEstimateInput {
         fmt_opt_decimal(f, self.expected_crp_payments.as_ref())?;
         write!(f, ", wages: ")?;
         fmt_opt_decimal(f, self.expected_wages.as_ref())?;
+        write!(f, ", net_investment_income: ")?;
+        fmt_opt_decimal(f, self.net_investment_income.as_ref())?;
+        write!(f, ", qualified_dividends: ")?;
+        fmt_opt_decimal(f, self.qualified_dividends.as_ref())?;
+        write!(f, ", net_capital_gain: ")?;
+        fmt_opt_decimal(f, self.net_capital_gain.as_ref())?;
         write!(
             f,
             ", AGI {}, deduction {}",
@@ -149,6 +184,8 @@ impl Display for TaxEstimateInput {
         fmt_opt_decimal(f, self.expected_withholding.as_ref())?;
         write!(f, ", prior_year_tax: ")?;
         fmt_opt_decimal(f, self.prior_year_tax.as_ref())?;
+        write!(f, ", prior_year_agi: ")?;
+        fmt_opt_decimal(f, self.prior_year_agi.as_ref())?;
         Ok(())
     }
 }
@@ -179,7 +216,15 @@ impl Display for TaxEstimate {
             f,
             ", updated_at: {}",
             self.updated_at.format("%Y-%m-%d %H:%M:%S UTC")
-        )
+        )?;
+        if let Some(deleted_at) = self.deleted_at {
+            write!(
+                f,
+                ", deleted_at: {}",
+                deleted_at.format("%Y-%m-%d %H:%M:%S UTC")
+            )?;
+        }
+        Ok(())
     }
 }
 
@@ -205,9 +250,13 @@ mod tests {
         TaxEstimateInput {
             tax_year: 2025,
             filing_status: FilingStatusCode::Single,
+            scenario_name: DEFAULT_SCENARIO_NAME.to_string(),
             se_income: None,
             expected_crp_payments: None,
             expected_wages: None,
+            net_investment_income: None,
+            qualified_dividends: None,
+            net_capital_gain: None,
             expected_agi: Decimal::ZERO,
             expected_deduction: Decimal::ZERO,
             expected_qbi_deduction: None,
@@ -216,6 +265,7 @@ mod tests {
             expected_other_taxes: None,
             expected_withholding: None,
             prior_year_tax: None,
+            prior_year_agi: None,
         }
     }
 
@@ -236,6 +286,27 @@ mod tests {
         assert!(err[0].contains("Tax year must be between"));
     }
 
+    #[test]
+    fn validate_for_submit_rejects_blank_scenario_name() {
+        let mut input = valid_input();
+        input.scenario_name = "  ".to_string();
+        let err = input
+            .validate_for_submit()
+            .expect_err("expected validation error");
+        assert_eq!(err, vec!["Scenario name is required"]);
+    }
+
+    #[test]
+    fn display_tax_estimate_input_includes_scenario_name() {
+        let mut input = valid_input();
+        input.scenario_name = "optimistic".to_string();
+        let display = format!("{input}");
+        assert!(
+            display.starts_with("Tax estimate 2025 'optimistic': filing status S"),
+            "{display}"
+        );
+    }
+
     #[test]
     fn validate_for_submit_rejects_negative_expected_deduction() {
         let mut input = valid_input();
@@ -246,13 +317,30 @@ mod tests {
         assert_eq!(err, vec!["Expected deduction cannot be negative"]);
     }
 
+    #[test]
+    fn validate_for_submit_rejects_negative_net_investment_income() {
+        let mut input = valid_input();
+        input.net_investment_income = Some(dec!(-1.00));
+        let err = input
+            .validate_for_submit()
+            .expect_err("expected validation error");
+        assert_eq!(err, vec!["Net investment income cannot be negative"]);
+    }
+
     #[test]
     fn worksheet_mapping_uses_expected_deduction_amount() {
         let mut input = valid_input();
         input.expected_deduction = dec!(15000.00);
+        input.prior_year_agi = Some(dec!(180000.00));
+        input.qualified_dividends = Some(dec!(2500.00));
         let context = EstimatedTaxWorksheetContext {
+            qbi_deduction: dec!(4000.00),
             self_employment_tax: dec!(1000.00),
+            additional_medicare_tax: dec!(90.00),
+            net_investment_income_tax: dec!(76.00),
             refundable_credits: dec!(250.00),
+            prior_year_agi_threshold: dec!(150000.00),
+            high_income_prior_year_multiplier: dec!(1.10),
             is_farmer_or_fisher: false,
             required_payment_threshold: dec!(1000.00),
         };
@@ -260,9 +348,42 @@ mod tests {
         let worksheet_input = input.to_estimated_tax_worksheet_input(&context);
 
         assert_eq!(worksheet_input.deduction, dec!(15000.00));
+        assert_eq!(worksheet_input.qualified_dividends, dec!(2500.00));
+        assert_eq!(worksheet_input.net_capital_gain, dec!(0));
         assert_eq!(worksheet_input.self_employment_tax, dec!(1000.00));
+        assert_eq!(worksheet_input.additional_medicare_tax, dec!(90.00));
+        assert_eq!(worksheet_input.net_investment_income_tax, dec!(76.00));
         assert_eq!(worksheet_input.refundable_credits, dec!(250.00));
         assert_eq!(worksheet_input.required_payment_threshold, dec!(1000.00));
+        assert_eq!(worksheet_input.prior_year_agi, dec!(180000.00));
+        assert_eq!(worksheet_input.prior_year_agi_threshold, dec!(150000.00));
+        assert_eq!(
+            worksheet_input.high_income_prior_year_multiplier,
+            dec!(1.10)
+        );
+    }
+
+    #[test]
+    fn worksheet_mapping_prefers_entered_qbi_deduction_over_computed() {
+        let mut input = valid_input();
+        let context = EstimatedTaxWorksheetContext {
+            qbi_deduction: dec!(4000.00),
+            self_employment_tax: dec!(0),
+            additional_medicare_tax: dec!(0),
+            net_investment_income_tax: dec!(0),
+            refundable_credits: dec!(0),
+            prior_year_agi_threshold: dec!(150000.00),
+            high_income_prior_year_multiplier: dec!(1.10),
+            is_farmer_or_fisher: false,
+            required_payment_threshold: dec!(1000.00),
+        };
+
+        let computed = input.to_estimated_tax_worksheet_input(&context);
+        input.expected_qbi_deduction = Some(dec!(2500.00));
+        let entered = input.to_estimated_tax_worksheet_input(&context);
+
+        assert_eq!(computed.qbi_deduction, dec!(4000.00));
+        assert_eq!(entered.qbi_deduction, dec!(2500.00));
     }
 
     #[test]
@@ -305,6 +426,7 @@ mod tests {
             computed: None,
             created_at: timestamp,
             updated_at: timestamp,
+            deleted_at: None,
         };
 
         let input_display = format!("{}", estimate.input);
@@ -327,6 +449,7 @@ mod tests {
             computed: Some(computed),
             created_at: timestamp,
             updated_at: timestamp,
+            deleted_at: None,
         };
 
         let input_display = format!("{}", estimate.input);
@@ -337,4 +460,25 @@ mod tests {
 
         assert_eq!(format!("{estimate}"), expected);
     }
+
+    #[test]
+    fn display_tax_estimate_in_trash_shows_deleted_at() {
+        let timestamp = Utc.with_ymd_and_hms(2025, 6, 15, 10, 30, 0).unwrap();
+        let estimate = TaxEstimate {
+            id: 42,
+            input: valid_input(),
+            computed: None,
+            created_at: timestamp,
+            updated_at: timestamp,
+            deleted_at: Some(Utc.with_ymd_and_hms(2025, 7, 1, 8, 0, 0).unwrap()),
+        };
+
+        let input_display = format!("{}", estimate.input);
+        let expected = format!(
+            "[id=42] {input_display}, updated_at: 2025-06-15 10:30:00 UTC, \
+             deleted_at: 2025-07-01 08:00:00 UTC"
+        );
+
+        assert_eq!(format!("{estimate}"), expected);
+    }
 }
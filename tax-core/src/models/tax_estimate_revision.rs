@@ -0,0 +1,274 @@
+use std::fmt::{self, Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::models::{TaxEstimate, TaxEstimateComputed, TaxEstimateInput};
+
+/// Immutable snapshot of a [`TaxEstimate`] taken each time it is created or
+/// updated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaxEstimateRevision {
+    pub id: i64,
+    pub estimate_id: i64,
+    /// 1-based sequence number within the estimate's history.
+    pub revision: i64,
+    pub input: TaxEstimateInput,
+    pub computed: Option<TaxEstimateComputed>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// One field whose value differs between two snapshots of an estimate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaxEstimateFieldChange {
+    pub field: &'static str,
+    pub before: String,
+    pub after: String,
+}
+
+impl TaxEstimateRevision {
+    /// Fields that differ from `earlier`, in worksheet order.
+    pub fn changes_from(
+        &self,
+        earlier: &TaxEstimateRevision,
+    ) -> Vec<TaxEstimateFieldChange> {
+        diff_fields(
+            &field_values(&earlier.input, earlier.computed.as_ref()),
+            &field_values(&self.input, self.computed.as_ref()),
+        )
+    }
+
+    /// Fields that differ between this revision and the estimate as it is
+    /// currently saved, with this revision as the "before" side.
+    pub fn changes_to(
+        &self,
+        current: &TaxEstimate,
+    ) -> Vec<TaxEstimateFieldChange> {
+        diff_fields(
+            &field_values(&self.input, self.computed.as_ref()),
+            &field_values(&current.input, current.computed.as_ref()),
+        )
+    }
+}
+
+impl Display for TaxEstimateRevision {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "Revision {} of estimate {} ({})",
+            self.revision,
+            self.estimate_id,
+            self.recorded_at.format("%Y-%m-%d %H:%M:%S UTC")
+        )?;
+        if let Some(ref computed) = self.computed {
+            write!(
+                f,
+                ": total tax {}, required payment {}",
+                computed.total_tax.round_dp(2),
+                computed.required_payment.round_dp(2)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A field's display text plus a key that ignores trailing zeros, so
+/// `90000.00` and `90000` are not reported as a change.
+struct FieldValue {
+    field: &'static str,
+    text: String,
+    key: String,
+}
+
+fn diff_fields(
+    before: &[FieldValue],
+    after: &[FieldValue],
+) -> Vec<TaxEstimateFieldChange> {
+    before
+        .iter()
+        .zip(after)
+        .filter(|(b, a)| b.key != a.key)
+        .map(|(b, a)| TaxEstimateFieldChange {
+            field: b.field,
+            before: b.text.clone(),
+            after: a.text.clone(),
+        })
+        .collect()
+}
+
+fn field_values(
+    input: &TaxEstimateInput,
+    computed: Option<&TaxEstimateComputed>,
+) -> Vec<FieldValue> {
+    let text = |field, text: String| FieldValue {
+        field,
+        key: text.clone(),
+        text,
+    };
+    let amount = |field, d: Option<Decimal>| match d {
+        Some(d) => FieldValue {
+            field,
+            text: d.to_string(),
+            key: d.normalize().to_string(),
+        },
+        None => text(field, "—".to_string()),
+    };
+    vec![
+        text("Tax year", input.tax_year.to_string()),
+        text("Filing status", input.filing_status.as_str().to_string()),
+        text("Scenario", input.scenario_name.clone()),
+        amount("SE income", input.se_income),
+        amount("CRP payments", input.expected_crp_payments),
+        amount("Wages", input.expected_wages),
+        amount("Net investment income", input.net_investment_income),
+        amount("Qualified dividends", input.qualified_dividends),
+        amount("Net capital gain", input.net_capital_gain),
+        amount("Expected AGI", Some(input.expected_agi)),
+        amount("Expected deduction", Some(input.expected_deduction)),
+        amount("QBI deduction", input.expected_qbi_deduction),
+        amount("AMT", input.expected_amt),
+        amount("Credits", input.expected_credits),
+        amount("Other taxes", input.expected_other_taxes),
+        amount("Withholding", input.expected_withholding),
+        amount("Prior year tax", input.prior_year_tax),
+        amount("Prior year AGI", input.prior_year_agi),
+        amount("SE tax", computed.map(|c| c.se_tax)),
+        amount("Total tax", computed.map(|c| c.total_tax)),
+        amount("Required payment", computed.map(|c| c.required_payment)),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::models::{DEFAULT_SCENARIO_NAME, FilingStatusCode};
+
+    fn revision(
+        revision: i64,
+        expected_agi: Decimal,
+        computed: Option<TaxEstimateComputed>,
+    ) -> TaxEstimateRevision {
+        TaxEstimateRevision {
+            id: revision,
+            estimate_id: 1,
+            revision,
+            input: TaxEstimateInput {
+                tax_year: 2025,
+                filing_status: FilingStatusCode::Single,
+                scenario_name: DEFAULT_SCENARIO_NAME.to_string(),
+                se_income: None,
+                expected_crp_payments: None,
+                expected_wages: None,
+                net_investment_income: None,
+                qualified_dividends: None,
+                net_capital_gain: None,
+                expected_agi,
+                expected_deduction: dec!(15750.00),
+                expected_qbi_deduction: None,
+                expected_amt: None,
+                expected_credits: None,
+                expected_other_taxes: None,
+                expected_withholding: None,
+                prior_year_tax: None,
+                prior_year_agi: None,
+            },
+            computed,
+            recorded_at: Utc.with_ymd_and_hms(2025, 4, 15, 9, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn changes_from_lists_only_differing_fields() {
+        let april = revision(1, dec!(90000.00), None);
+        let september = revision(
+            2,
+            dec!(120000.00),
+            Some(TaxEstimateComputed {
+                se_tax: dec!(0),
+                total_tax: dec!(14000.00),
+                required_payment: dec!(12600.00),
+            }),
+        );
+
+        let changes = september.changes_from(&april);
+
+        let fields: Vec<_> = changes.iter().map(|c| c.field).collect();
+        assert_eq!(
+            fields,
+            vec!["Expected AGI", "SE tax", "Total tax", "Required payment"]
+        );
+        assert_eq!(changes[0].before, "90000.00");
+        assert_eq!(changes[0].after, "120000.00");
+        assert_eq!(changes[2].before, "—");
+        assert_eq!(changes[2].after, "14000.00");
+    }
+
+    #[test]
+    fn changes_from_identical_revision_is_empty() {
+        let first = revision(1, dec!(90000.00), None);
+        let second = revision(2, dec!(90000.00), None);
+
+        assert!(second.changes_from(&first).is_empty());
+    }
+
+    #[test]
+    fn changes_from_ignores_trailing_zero_differences() {
+        let first = revision(1, dec!(90000.00), None);
+        let second = revision(2, dec!(90000), None);
+
+        assert!(second.changes_from(&first).is_empty());
+    }
+
+    #[test]
+    fn changes_to_current_estimate_treats_revision_as_before() {
+        let april = revision(1, dec!(90000.00), None);
+        let mut current_input = april.input.clone();
+        current_input.expected_withholding = Some(dec!(5000.00));
+        let current = TaxEstimate {
+            id: 1,
+            input: current_input,
+            computed: None,
+            created_at: april.recorded_at,
+            updated_at: april.recorded_at,
+            deleted_at: None,
+        };
+
+        let changes = april.changes_to(&current);
+
+        assert_eq!(
+            changes,
+            vec![TaxEstimateFieldChange {
+                field: "Withholding",
+                before: "—".to_string(),
+                after: "5000.00".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn display_includes_revision_and_totals() {
+        let rev = revision(
+            3,
+            dec!(90000.00),
+            Some(TaxEstimateComputed {
+                se_tax: dec!(0),
+                total_tax: dec!(14000.004),
+                required_payment: dec!(12600.00),
+            }),
+        );
+
+        assert_eq!(
+            rev.to_string(),
+            "Revision 3 of estimate 1 (2025-04-15 09:00:00 UTC): \
+             total tax 14000.00, required payment 12600.00"
+        );
+    }
+}
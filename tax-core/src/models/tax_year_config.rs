@@ -3,6 +3,8 @@ use std::fmt::Display;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::models::FilingStatusCode;
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TaxYearConfig {
     pub tax_year: i32,
@@ -13,6 +15,27 @@ pub struct TaxYearConfig {
     pub se_deduction_factor: Decimal,
     pub req_pmnt_threshold: Decimal,
     pub min_se_threshold: Decimal,
+    pub addl_medicare_tax_rate: Decimal,
+    pub niit_rate: Decimal,
+    pub prior_agi_threshold: Decimal,
+    pub prior_agi_threshold_mfs: Decimal,
+    pub prior_tax_multiplier: Decimal,
+}
+
+impl TaxYearConfig {
+    /// Prior-year AGI above which the prior-year safe harbor (1040-ES line
+    /// 12b) uses `prior_tax_multiplier` instead of 100% of last year's tax.
+    ///
+    /// Married filing separately uses its own, lower threshold.
+    pub fn prior_agi_threshold_for(
+        &self,
+        filing_status: FilingStatusCode,
+    ) -> Decimal {
+        match filing_status {
+            FilingStatusCode::MarriedFilingSeparately => self.prior_agi_threshold_mfs,
+            _ => self.prior_agi_threshold,
+        }
+    }
 }
 
 impl Display for TaxYearConfig {
@@ -21,16 +44,103 @@ impl Display for TaxYearConfig {
         f: &mut std::fmt::Formatter<'_>,
     ) -> std::fmt::Result {
         writeln!(f, "TaxYearConfig {{")?;
-        writeln!(f, "    tax_year            : {}", self.tax_year)?;
-        writeln!(f, "    ss_wage_max         : {}", self.ss_wage_max)?;
-        writeln!(f, "    ss_tax_rate         : {}", self.ss_tax_rate)?;
-        writeln!(f, "    medicare_tax_rate   : {}", self.medicare_tax_rate)?;
-        writeln!(f, "    se_tax_deduct_pcnt  : {}", self.se_tax_deduct_pcnt)?;
-        writeln!(f, "    se_deduction_factor : {}", self.se_deduction_factor)?;
-        writeln!(f, "    req_pmnt_threshold  : {}", self.req_pmnt_threshold)?;
-        writeln!(f, "    min_se_threshold    : {}", self.min_se_threshold)?;
+        writeln!(f, "    tax_year                : {}", self.tax_year)?;
+        writeln!(f, "    ss_wage_max             : {}", self.ss_wage_max)?;
+        writeln!(f, "    ss_tax_rate             : {}", self.ss_tax_rate)?;
+        writeln!(
+            f,
+            "    medicare_tax_rate       : {}",
+            self.medicare_tax_rate
+        )?;
+        writeln!(
+            f,
+            "    se_tax_deduct_pcnt      : {}",
+            self.se_tax_deduct_pcnt
+        )?;
+        writeln!(
+            f,
+            "    se_deduction_factor     : {}",
+            self.se_deduction_factor
+        )?;
+        writeln!(
+            f,
+            "    req_pmnt_threshold      : {}",
+            self.req_pmnt_threshold
+        )?;
+        writeln!(f, "    min_se_threshold        : {}", self.min_se_threshold)?;
+        writeln!(
+            f,
+            "    addl_medicare_tax_rate  : {}",
+            self.addl_medicare_tax_rate
+        )?;
+        writeln!(f, "    niit_rate               : {}", self.niit_rate)?;
+        writeln!(
+            f,
+            "    prior_agi_threshold     : {}",
+            self.prior_agi_threshold
+        )?;
+        writeln!(
+            f,
+            "    prior_agi_threshold_mfs : {}",
+            self.prior_agi_threshold_mfs
+        )?;
+        writeln!(
+            f,
+            "    prior_tax_multiplier    : {}",
+            self.prior_tax_multiplier
+        )?;
         write!(f, "}}")?;
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn config() -> TaxYearConfig {
+        TaxYearConfig {
+            tax_year: 2025,
+            ss_wage_max: dec!(176100.00),
+            ss_tax_rate: dec!(0.124),
+            medicare_tax_rate: dec!(0.029),
+            se_tax_deduct_pcnt: dec!(0.9235),
+            se_deduction_factor: dec!(0.50),
+            req_pmnt_threshold: dec!(1000.00),
+            min_se_threshold: dec!(400.00),
+            addl_medicare_tax_rate: dec!(0.009),
+            niit_rate: dec!(0.038),
+            prior_agi_threshold: dec!(150000.00),
+            prior_agi_threshold_mfs: dec!(75000.00),
+            prior_tax_multiplier: dec!(1.10),
+        }
+    }
+
+    #[test]
+    fn prior_agi_threshold_for_uses_mfs_threshold() {
+        let config = config();
+
+        assert_eq!(
+            config.prior_agi_threshold_for(FilingStatusCode::MarriedFilingSeparately),
+            dec!(75000.00)
+        );
+    }
+
+    #[test]
+    fn prior_agi_threshold_for_uses_general_threshold() {
+        let config = config();
+
+        for status in [
+            FilingStatusCode::Single,
+            FilingStatusCode::MarriedFilingJointly,
+            FilingStatusCode::HeadOfHousehold,
+            FilingStatusCode::QualifyingSurvivingSpouse,
+        ] {
+            assert_eq!(config.prior_agi_threshold_for(status), dec!(150000.00));
+        }
+    }
+}
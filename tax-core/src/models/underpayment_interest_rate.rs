@@ -0,0 +1,19 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// IRS underpayment interest rate for one calendar quarter.
+///
+/// Form 2210 figures the estimated tax penalty at the rate in effect for each
+/// day an installment remains unpaid. The IRS announces the rate quarterly,
+/// so the rates are kept as reference data rather than tax-year config.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnderpaymentInterestRate {
+    /// Calendar year the rate applies to.
+    pub year: i32,
+
+    /// Calendar quarter, 1 through 4.
+    pub quarter: u32,
+
+    /// Annual interest rate as a decimal (e.g. 0.07 for 7%).
+    pub rate: Decimal,
+}
@@ -0,0 +1,752 @@
+//! Backend-agnostic conformance checks for [`TaxRepository`] implementations.
+//!
+//! Enabled by the `testkit` feature. A backend crate pulls it in as a dev
+//! dependency and generates one test per check with
+//! [`repository_conformance_tests!`](crate::repository_conformance_tests):
+//!
+//! ```toml
+//! [dev-dependencies]
+//! tax-core = { path = "../tax-core", features = ["testkit"] }
+//! ```
+//!
+//! Every check expects a repository loaded with the default seed data (tax
+//! years 2025 and 2026, filing statuses `S`, `MFJ`, `MFS`, `HOH` and `QSS` with
+//! ids 1–5). Checks save estimates under their own scenario names and put back
+//! any reference data they change, so they can also run one after another
+//! against a single repository with [`run_all`].
+
+use chrono::Utc;
+use rust_decimal_macros::dec;
+
+use crate::db::repository::{RepositoryError, TaxRepository};
+use crate::models::{
+    FilingStatusCode, TaxEstimate, TaxEstimateComputed, TaxEstimateInput, TaxYearConfig,
+};
+
+/// Generates a `#[tokio::test]` per conformance check, each running against a
+/// fresh repository from `$setup`, an async fn returning a type that
+/// implements [`TaxRepository`].
+///
+/// ```rust,ignore
+/// async fn setup_seeded_db() -> SqliteRepository { /* migrate and seed */ }
+///
+/// tax_core::repository_conformance_tests!(setup_seeded_db);
+/// ```
+#[macro_export]
+macro_rules! repository_conformance_tests {
+    ($setup:path) => {
+        $crate::repository_conformance_tests!(
+            $setup;
+            conformance_reference_data_lookups => reference_data_lookups,
+            conformance_not_found_behavior => not_found_behavior,
+            conformance_tax_brackets_ordered_by_min_income => tax_brackets_ordered_by_min_income,
+            conformance_filing_status_data_shape => filing_status_data_shape,
+            conformance_estimate_crud => estimate_crud,
+            conformance_create_estimate_rejects_trashed_scenario => create_estimate_rejects_trashed_scenario,
+            conformance_calculated_estimate_saves_record_one_revision => calculated_estimate_saves_record_one_revision,
+            conformance_restore_revision_rejects_taken_scenario => restore_revision_rejects_taken_scenario,
+            conformance_estimate_list_ordering_and_year_filter => estimate_list_ordering_and_year_filter,
+            conformance_transactions_commit_or_roll_back => transactions_commit_or_roll_back,
+        );
+    };
+    ($setup:path; $($test:ident => $check:ident),+ $(,)?) => {
+        $(
+            #[tokio::test]
+            async fn $test() {
+                let repo = $setup().await;
+                $crate::testkit::$check(&repo).await;
+            }
+        )+
+    };
+}
+
+/// Run every check in turn against one repository.
+pub async fn run_all(repo: &dyn TaxRepository) {
+    reference_data_lookups(repo).await;
+    not_found_behavior(repo).await;
+    tax_brackets_ordered_by_min_income(repo).await;
+    filing_status_data_shape(repo).await;
+    estimate_crud(repo).await;
+    create_estimate_rejects_trashed_scenario(repo).await;
+    calculated_estimate_saves_record_one_revision(repo).await;
+    restore_revision_rejects_taken_scenario(repo).await;
+    estimate_list_ordering_and_year_filter(repo).await;
+    transactions_commit_or_roll_back(repo).await;
+}
+
+/// Each single-row lookup returns the seeded 2025 values for `S`, and the
+/// list lookups come back in their documented order.
+pub async fn reference_data_lookups(repo: &dyn TaxRepository) {
+    let years = repo.list_tax_years().await.expect("list_tax_years");
+    assert!(
+        years.windows(2).all(|w| w[0] > w[1]),
+        "tax years should be newest first: {years:?}"
+    );
+    assert!(years.contains(&2025) && years.contains(&2026));
+
+    let config: TaxYearConfig = repo
+        .get_tax_year_config(2025)
+        .await
+        .expect("get_tax_year_config");
+    assert_eq!(config.tax_year, 2025);
+    assert_eq!(config.ss_wage_max, dec!(176100.00));
+    assert_eq!(config.ss_tax_rate, dec!(0.124));
+    assert_eq!(config.medicare_tax_rate, dec!(0.029));
+
+    let statuses = repo
+        .list_filing_statuses()
+        .await
+        .expect("list_filing_statuses");
+    let codes: Vec<_> = statuses.iter().map(|s| s.status_code).collect();
+    assert_eq!(
+        codes,
+        vec![
+            FilingStatusCode::Single,
+            FilingStatusCode::MarriedFilingJointly,
+            FilingStatusCode::MarriedFilingSeparately,
+            FilingStatusCode::HeadOfHousehold,
+            FilingStatusCode::QualifyingSurvivingSpouse,
+        ],
+        "filing statuses should be ordered by id"
+    );
+    for status in &statuses {
+        assert_eq!(
+            &repo
+                .get_filing_status(status.id)
+                .await
+                .expect("get_filing_status"),
+            status
+        );
+        assert_eq!(
+            &repo
+                .get_filing_status_by_code(status.status_code.as_str())
+                .await
+                .expect("get_filing_status_by_code"),
+            status
+        );
+    }
+
+    let single = repo
+        .get_filing_status_by_code("S")
+        .await
+        .expect("get_filing_status_by_code");
+    assert_eq!(single.id, 1);
+    assert_eq!(single.status_name, "Single");
+
+    let deduction = repo
+        .get_standard_deduction(2025, single.id)
+        .await
+        .expect("get_standard_deduction");
+    assert_eq!(
+        (deduction.tax_year, deduction.filing_status_id),
+        (2025, single.id)
+    );
+    assert_eq!(deduction.amount, dec!(15750.00));
+    let medicare = repo
+        .get_additional_medicare_threshold(2025, single.id)
+        .await
+        .expect("get_additional_medicare_threshold");
+    assert_eq!(medicare.amount, dec!(200000.00));
+    let niit = repo
+        .get_niit_threshold(2025, single.id)
+        .await
+        .expect("get_niit_threshold");
+    assert_eq!(niit.amount, dec!(200000.00));
+    let qbi = repo
+        .get_qbi_threshold(2025, single.id)
+        .await
+        .expect("get_qbi_threshold");
+    assert_eq!(qbi.amount, dec!(197300.00));
+    let breakpoints = repo
+        .get_capital_gain_breakpoints(2025, single.id)
+        .await
+        .expect("get_capital_gain_breakpoints");
+    assert_eq!(breakpoints.zero_rate_max, dec!(48350.00));
+    assert_eq!(breakpoints.fifteen_rate_max, dec!(533400.00));
+
+    let rates = repo
+        .list_underpayment_interest_rates()
+        .await
+        .expect("list_underpayment_interest_rates");
+    assert!(!rates.is_empty(), "underpayment interest rates are seeded");
+    assert!(
+        rates
+            .windows(2)
+            .all(|w| (w[0].year, w[0].quarter) < (w[1].year, w[1].quarter)),
+        "underpayment interest rates should be ordered by year then quarter"
+    );
+}
+
+/// Missing rows are [`RepositoryError::NotFound`] rather than another error
+/// or an empty default, and list lookups return empty lists.
+pub async fn not_found_behavior(repo: &dyn TaxRepository) {
+    fn assert_not_found<T: std::fmt::Debug>(
+        what: &str,
+        result: Result<T, RepositoryError>,
+    ) {
+        assert!(
+            matches!(result, Err(RepositoryError::NotFound)),
+            "{what} should be NotFound, got {result:?}"
+        );
+    }
+
+    assert_not_found("get_tax_year_config", repo.get_tax_year_config(1900).await);
+    assert_not_found("get_filing_status", repo.get_filing_status(9999).await);
+    assert_not_found(
+        "get_filing_status_by_code",
+        repo.get_filing_status_by_code("XX").await,
+    );
+    assert_not_found(
+        "get_standard_deduction",
+        repo.get_standard_deduction(1900, 1).await,
+    );
+    assert_not_found(
+        "get_additional_medicare_threshold",
+        repo.get_additional_medicare_threshold(1900, 1).await,
+    );
+    assert_not_found("get_niit_threshold", repo.get_niit_threshold(1900, 1).await);
+    assert_not_found(
+        "get_capital_gain_breakpoints",
+        repo.get_capital_gain_breakpoints(1900, 1).await,
+    );
+    assert_not_found("get_qbi_threshold", repo.get_qbi_threshold(1900, 1).await);
+
+    assert!(
+        repo.get_tax_brackets(1900, 1)
+            .await
+            .expect("get_tax_brackets")
+            .is_empty()
+    );
+    assert!(
+        repo.get_filing_status_data(1900)
+            .await
+            .expect("get_filing_status_data")
+            .is_empty()
+    );
+
+    let missing = TaxEstimate {
+        id: i64::MAX,
+        input: estimate_input(2025, FilingStatusCode::Single, "conformance-missing"),
+        computed: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+        deleted_at: None,
+    };
+    assert_not_found("get_estimate", repo.get_estimate(missing.id).await);
+    assert_not_found("update_estimate", repo.update_estimate(&missing).await);
+    assert_not_found("delete_estimate", repo.delete_estimate(missing.id).await);
+    assert_not_found(
+        "get_estimate_by_name",
+        repo.get_estimate_by_name(2025, FilingStatusCode::Single, "conformance-missing")
+            .await,
+    );
+    assert_not_found(
+        "restore_deleted_estimate",
+        repo.restore_deleted_estimate(missing.id).await,
+    );
+    assert_not_found(
+        "restore_estimate_revision",
+        repo.restore_estimate_revision(i64::MAX).await,
+    );
+    assert!(
+        repo.list_estimates_by_name("conformance-missing", None)
+            .await
+            .expect("list_estimates_by_name")
+            .is_empty()
+    );
+}
+
+/// Brackets come back ordered by `min_income` however they were inserted.
+/// Reinserts the seeded 2025 `S` brackets in reverse and checks they read
+/// back unchanged, which leaves the seed data as it was.
+pub async fn tax_brackets_ordered_by_min_income(repo: &dyn TaxRepository) {
+    let seeded = repo
+        .get_tax_brackets(2025, 1)
+        .await
+        .expect("get_tax_brackets");
+    assert_eq!(seeded.len(), 7, "2025 Single has seven brackets");
+    assert!(
+        seeded.windows(2).all(|w| w[0].min_income < w[1].min_income),
+        "brackets should be ordered by min_income"
+    );
+    assert_eq!(seeded[0].min_income, dec!(0));
+    assert_eq!(seeded[6].max_income, None, "top bracket is open-ended");
+    for pair in seeded.windows(2) {
+        assert_eq!(pair[0].max_income, Some(pair[1].min_income));
+    }
+
+    repo.delete_tax_brackets(2025, 1)
+        .await
+        .expect("delete_tax_brackets");
+    assert!(
+        repo.get_tax_brackets(2025, 1)
+            .await
+            .expect("get_tax_brackets")
+            .is_empty()
+    );
+    for bracket in seeded.iter().rev() {
+        repo.insert_tax_bracket(bracket)
+            .await
+            .expect("insert_tax_bracket");
+    }
+
+    assert_eq!(
+        repo.get_tax_brackets(2025, 1)
+            .await
+            .expect("get_tax_brackets"),
+        seeded
+    );
+}
+
+/// [`TaxRepository::get_filing_status_data`] returns one entry per filing
+/// status, in id order, agreeing with the single-row lookups.
+pub async fn filing_status_data_shape(repo: &dyn TaxRepository) {
+    let statuses = repo
+        .list_filing_statuses()
+        .await
+        .expect("list_filing_statuses");
+    let data = repo
+        .get_filing_status_data(2025)
+        .await
+        .expect("get_filing_status_data");
+
+    assert_eq!(
+        data.iter()
+            .map(|(status, _, _)| status.clone())
+            .collect::<Vec<_>>(),
+        statuses
+    );
+    for (status, deduction, brackets) in &data {
+        assert_eq!(
+            deduction,
+            &repo
+                .get_standard_deduction(2025, status.id)
+                .await
+                .expect("get_standard_deduction")
+        );
+        assert_eq!(
+            brackets,
+            &repo
+                .get_tax_brackets(2025, status.id)
+                .await
+                .expect("get_tax_brackets")
+        );
+        assert!(
+            !brackets.is_empty(),
+            "{} has 2025 brackets",
+            status.status_code.as_str()
+        );
+    }
+}
+
+/// Create, read, update and delete round-trip every estimate field, and a
+/// second save under the same scenario replaces the first.
+pub async fn estimate_crud(repo: &dyn TaxRepository) {
+    let mut input = estimate_input(2025, FilingStatusCode::Single, "conformance-crud");
+    input.se_income = Some(dec!(50000.00));
+    input.expected_wages = Some(dec!(40000.00));
+    input.net_investment_income = Some(dec!(12000.00));
+    input.qualified_dividends = Some(dec!(4000.00));
+    input.net_capital_gain = Some(dec!(6000.00));
+    input.expected_qbi_deduction = Some(dec!(5000.00));
+    input.expected_credits = Some(dec!(2000.00));
+    input.expected_withholding = Some(dec!(8000.00));
+    input.prior_year_tax = Some(dec!(12000.00));
+    input.prior_year_agi = Some(dec!(160000.00));
+
+    let created = repo
+        .create_estimate(input.clone())
+        .await
+        .expect("create_estimate");
+    assert_eq!(created.input, input);
+    assert_eq!(created.computed, None);
+    assert_eq!(created.deleted_at, None);
+    assert!(created.created_at <= created.updated_at);
+
+    assert_eq!(
+        repo.get_estimate(created.id).await.expect("get_estimate"),
+        created
+    );
+    assert_eq!(
+        repo.get_estimate_by_name(2025, FilingStatusCode::Single, "conformance-crud")
+            .await
+            .expect("get_estimate_by_name"),
+        created
+    );
+
+    let mut updated = created.clone();
+    updated.input.expected_agi = dec!(150000.00);
+    updated.input.expected_amt = Some(dec!(1200.00));
+    updated.computed = Some(TaxEstimateComputed {
+        se_tax: dec!(7064.78),
+        total_tax: dec!(25000.00),
+        required_payment: dec!(22500.00),
+    });
+    repo.update_estimate(&updated)
+        .await
+        .expect("update_estimate");
+    let fetched = repo.get_estimate(created.id).await.expect("get_estimate");
+    assert_eq!(fetched.input, updated.input);
+    assert_eq!(fetched.computed, updated.computed);
+    assert_eq!(fetched.created_at, created.created_at);
+    assert!(fetched.updated_at >= created.updated_at);
+
+    let replaced = repo
+        .create_estimate(input.clone())
+        .await
+        .expect("create_estimate");
+    assert_eq!(
+        replaced.id, created.id,
+        "saving the same scenario again replaces it"
+    );
+    assert_eq!(replaced.input, input);
+    assert_eq!(
+        replaced.computed, None,
+        "replacing a scenario clears its results"
+    );
+
+    repo.delete_estimate(created.id)
+        .await
+        .expect("delete_estimate");
+    assert!(matches!(
+        repo.get_estimate(created.id).await,
+        Err(RepositoryError::NotFound)
+    ));
+    assert!(matches!(
+        repo.delete_estimate(created.id).await,
+        Err(RepositoryError::NotFound)
+    ));
+}
+
+/// Saving over a scenario whose estimate is in the trash is refused, and
+/// the trashed estimate is neither brought back nor changed. Once restored,
+/// the scenario can be saved over as usual.
+pub async fn create_estimate_rejects_trashed_scenario(repo: &dyn TaxRepository) {
+    let input = estimate_input(2025, FilingStatusCode::Single, "conformance-trashed");
+    let trashed = repo
+        .create_estimate(input.clone())
+        .await
+        .expect("create_estimate");
+    repo.delete_estimate(trashed.id)
+        .await
+        .expect("delete_estimate");
+
+    let mut changed = input.clone();
+    changed.scenario_name = " conformance-trashed ".to_string();
+    changed.expected_agi = dec!(120000.00);
+    match repo.create_estimate(changed.clone()).await {
+        Err(RepositoryError::EstimateInTrash { id, scenario_name }) => {
+            assert_eq!(id, trashed.id);
+            assert_eq!(scenario_name, "conformance-trashed");
+        }
+        other => panic!("expected EstimateInTrash, got {other:?}"),
+    }
+    assert!(matches!(
+        repo.get_estimate(trashed.id).await,
+        Err(RepositoryError::NotFound)
+    ));
+    let in_trash = repo
+        .list_deleted_estimates()
+        .await
+        .expect("list_deleted_estimates");
+    let still_trashed = in_trash
+        .iter()
+        .find(|e| e.id == trashed.id)
+        .expect("estimate should still be in the trash");
+    assert_eq!(still_trashed.input, input);
+
+    repo.restore_deleted_estimate(trashed.id)
+        .await
+        .expect("restore_deleted_estimate");
+    let replaced = repo
+        .create_estimate(changed)
+        .await
+        .expect("create_estimate");
+    assert_eq!(replaced.id, trashed.id);
+    assert_eq!(replaced.input.expected_agi, dec!(120000.00));
+}
+
+/// Saving an estimate with its results records exactly one revision per
+/// save, and that revision holds the results.
+pub async fn calculated_estimate_saves_record_one_revision(repo: &dyn TaxRepository) {
+    let input = estimate_input(2025, FilingStatusCode::Single, "conformance-calculated");
+    let computed = TaxEstimateComputed {
+        se_tax: dec!(7064.78),
+        total_tax: dec!(25000.00),
+        required_payment: dec!(22500.00),
+    };
+
+    let created = repo
+        .create_calculated_estimate(input.clone(), computed.clone())
+        .await
+        .expect("create_calculated_estimate");
+    assert_eq!(created.input, input);
+    assert_eq!(created.computed, Some(computed.clone()));
+    let revisions = repo
+        .list_estimate_revisions(created.id)
+        .await
+        .expect("list_estimate_revisions");
+    assert_eq!(revisions.len(), 1, "a new save records one revision");
+    assert_eq!(revisions[0].computed, Some(computed));
+
+    let mut changed = input.clone();
+    changed.expected_agi = dec!(150000.00);
+    let recomputed = TaxEstimateComputed {
+        se_tax: dec!(7064.78),
+        total_tax: dec!(31000.00),
+        required_payment: dec!(27900.00),
+    };
+    let replaced = repo
+        .create_calculated_estimate(changed.clone(), recomputed.clone())
+        .await
+        .expect("create_calculated_estimate");
+    assert_eq!(replaced.id, created.id);
+    assert_eq!(replaced.computed, Some(recomputed.clone()));
+    let revisions = repo
+        .list_estimate_revisions(created.id)
+        .await
+        .expect("list_estimate_revisions");
+    assert_eq!(
+        revisions.len(),
+        2,
+        "saving over a scenario records one revision"
+    );
+    assert_eq!(revisions[1].input, changed);
+    assert_eq!(revisions[1].computed, Some(recomputed));
+}
+
+/// Restoring a revision whose scenario another estimate has taken since is
+/// refused with a typed error, and leaves the estimate as it was.
+pub async fn restore_revision_rejects_taken_scenario(repo: &dyn TaxRepository) {
+    let original = repo
+        .create_estimate(estimate_input(
+            2025,
+            FilingStatusCode::Single,
+            "conformance-restore",
+        ))
+        .await
+        .expect("create_estimate");
+    let mut renamed = original.clone();
+    renamed.input.scenario_name = "conformance-restore-renamed".to_string();
+    repo.update_estimate(&renamed)
+        .await
+        .expect("update_estimate");
+    let first = repo
+        .list_estimate_revisions(original.id)
+        .await
+        .expect("list_estimate_revisions")[0]
+        .clone();
+    let taker = repo
+        .create_estimate(estimate_input(
+            2025,
+            FilingStatusCode::Single,
+            "conformance-restore",
+        ))
+        .await
+        .expect("create_estimate");
+
+    match repo.restore_estimate_revision(first.id).await {
+        Err(RepositoryError::ScenarioTaken { id, scenario_name }) => {
+            assert_eq!(id, taker.id);
+            assert_eq!(scenario_name, "conformance-restore");
+        }
+        other => panic!("expected ScenarioTaken, got {other:?}"),
+    }
+    let unchanged = repo.get_estimate(original.id).await.expect("get_estimate");
+    assert_eq!(unchanged.input, renamed.input);
+    assert_eq!(
+        repo.list_estimate_revisions(original.id)
+            .await
+            .expect("list_estimate_revisions")
+            .len(),
+        2
+    );
+
+    repo.delete_estimate(taker.id)
+        .await
+        .expect("delete_estimate");
+    assert!(matches!(
+        repo.restore_estimate_revision(first.id).await,
+        Err(RepositoryError::EstimateInTrash { id, .. }) if id == taker.id
+    ));
+
+    repo.delete_estimate(original.id)
+        .await
+        .expect("delete_estimate");
+}
+
+/// Estimate lists are most recently updated first and honour the tax year
+/// filter.
+pub async fn estimate_list_ordering_and_year_filter(repo: &dyn TaxRepository) {
+    let tick = || tokio::time::sleep(std::time::Duration::from_millis(10));
+
+    let older = repo
+        .create_estimate(estimate_input(
+            2025,
+            FilingStatusCode::Single,
+            "conformance-list",
+        ))
+        .await
+        .expect("create_estimate");
+    tick().await;
+    let other_year = repo
+        .create_estimate(estimate_input(
+            2026,
+            FilingStatusCode::Single,
+            "conformance-list",
+        ))
+        .await
+        .expect("create_estimate");
+    tick().await;
+    let newer = repo
+        .create_estimate(estimate_input(
+            2025,
+            FilingStatusCode::MarriedFilingJointly,
+            "conformance-list-other",
+        ))
+        .await
+        .expect("create_estimate");
+    tick().await;
+    repo.update_estimate(&older).await.expect("update_estimate");
+
+    let ours = [older.id, other_year.id, newer.id];
+    let ids = |estimates: Vec<TaxEstimate>| -> Vec<i64> {
+        estimates
+            .into_iter()
+            .map(|e| e.id)
+            .filter(|id| ours.contains(id))
+            .collect()
+    };
+
+    let year_2025 = repo
+        .list_estimates(Some(2025))
+        .await
+        .expect("list_estimates");
+    assert!(year_2025.iter().all(|e| e.input.tax_year == 2025));
+    assert_eq!(ids(year_2025), vec![older.id, newer.id]);
+    assert_eq!(
+        ids(repo.list_estimates(None).await.expect("list_estimates")),
+        vec![older.id, newer.id, other_year.id]
+    );
+    assert_eq!(
+        ids(repo
+            .list_estimates_by_name("conformance-list", None)
+            .await
+            .expect("list_estimates_by_name")),
+        vec![older.id, other_year.id]
+    );
+    assert_eq!(
+        ids(repo
+            .list_estimates_by_name("conformance-list", Some(2026))
+            .await
+            .expect("list_estimates_by_name")),
+        vec![other_year.id]
+    );
+
+    for id in ours {
+        repo.delete_estimate(id).await.expect("delete_estimate");
+    }
+}
+
+/// Work done through [`TaxRepository::begin`] is visible inside the
+/// transaction, applied by commit, and discarded by rollback or drop.
+pub async fn transactions_commit_or_roll_back(repo: &dyn TaxRepository) {
+    let tx = repo.begin().await.expect("begin");
+    let created = tx
+        .create_estimate(estimate_input(
+            2025,
+            FilingStatusCode::Single,
+            "conformance-rolled-back",
+        ))
+        .await
+        .expect("create_estimate in transaction");
+    assert_eq!(
+        tx.get_estimate(created.id)
+            .await
+            .expect("get_estimate in transaction"),
+        created
+    );
+    assert!(
+        matches!(tx.begin().await, Err(RepositoryError::Configuration(_))),
+        "nested begin should be a configuration error"
+    );
+    tx.rollback().await.expect("rollback");
+    assert!(matches!(
+        repo.get_estimate_by_name(2025, FilingStatusCode::Single, "conformance-rolled-back")
+            .await,
+        Err(RepositoryError::NotFound)
+    ));
+
+    {
+        let tx = repo.begin().await.expect("begin");
+        tx.create_estimate(estimate_input(
+            2025,
+            FilingStatusCode::Single,
+            "conformance-dropped",
+        ))
+        .await
+        .expect("create_estimate in transaction");
+    }
+    assert!(matches!(
+        repo.get_estimate_by_name(2025, FilingStatusCode::Single, "conformance-dropped")
+            .await,
+        Err(RepositoryError::NotFound)
+    ));
+
+    let computed = TaxEstimateComputed {
+        se_tax: dec!(0),
+        total_tax: dec!(11000.00),
+        required_payment: dec!(9900.00),
+    };
+    let tx = repo.begin().await.expect("begin");
+    let mut created = tx
+        .create_estimate(estimate_input(
+            2025,
+            FilingStatusCode::Single,
+            "conformance-committed",
+        ))
+        .await
+        .expect("create_estimate in transaction");
+    created.computed = Some(computed.clone());
+    tx.update_estimate(&created)
+        .await
+        .expect("update_estimate in transaction");
+    tx.commit().await.expect("commit");
+
+    let saved = repo
+        .get_estimate_by_name(2025, FilingStatusCode::Single, "conformance-committed")
+        .await
+        .expect("get_estimate_by_name after commit");
+    assert_eq!(saved.id, created.id);
+    assert_eq!(saved.computed, Some(computed));
+    repo.delete_estimate(saved.id)
+        .await
+        .expect("delete_estimate");
+}
+
+fn estimate_input(
+    tax_year: i32,
+    filing_status: FilingStatusCode,
+    scenario_name: &str,
+) -> TaxEstimateInput {
+    TaxEstimateInput {
+        tax_year,
+        filing_status,
+        scenario_name: scenario_name.to_string(),
+        se_income: None,
+        expected_crp_payments: None,
+        expected_wages: None,
+        net_investment_income: None,
+        qualified_dividends: None,
+        net_capital_gain: None,
+        expected_agi: dec!(90000.00),
+        expected_deduction: dec!(15750.00),
+        expected_qbi_deduction: None,
+        expected_amt: None,
+        expected_credits: None,
+        expected_other_taxes: None,
+        expected_withholding: None,
+        prior_year_tax: None,
+        prior_year_agi: None,
+    }
+}
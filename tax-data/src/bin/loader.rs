@@ -2,8 +2,8 @@ use std::fs::File;
 use std::path::PathBuf;
 
 use anyhow::{Context, Result};
-use clap::Parser;
-use tax_data::TaxBracketLoader;
+use clap::{Parser, Subcommand};
+use tax_data::{InterestRateLoader, NiitThresholdLoader, TaxBracketLoader};
 use tax_db_sqlite::SqliteRepository;
 
 /// Load tax bracket data from a CSV file into the database.
@@ -15,16 +15,41 @@ use tax_db_sqlite::SqliteRepository;
 /// - max_income: The maximum income (empty for unlimited)
 /// - base_tax: The base tax amount for this bracket
 /// - rate: The marginal tax rate as a decimal (e.g., 0.10)
+///
+/// NIIT thresholds can be loaded from a second CSV with the columns
+/// tax_year, filing_status (S, MFJ, MFS, HOH, QSS) and amount.
+///
+/// Quarterly underpayment interest rates can be loaded from a CSV with the
+/// columns year, quarter (1-4) and rate.
+///
+/// The `backup` and `restore` subcommands snapshot the database to a file and
+/// load such a snapshot back.
 #[derive(Parser, Debug)]
 #[command(name = "tax-data-loader")]
 #[command(version, about, long_about = None)]
+#[command(subcommand_negates_reqs = true)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// Path to the CSV file containing tax bracket data
-    #[arg(short, long)]
-    file: PathBuf,
+    #[arg(
+        short,
+        long,
+        required_unless_present_any = ["niit_thresholds", "interest_rates"]
+    )]
+    file: Option<PathBuf>,
+
+    /// Path to a CSV file containing Net Investment Income Tax thresholds
+    #[arg(long)]
+    niit_thresholds: Option<PathBuf>,
+
+    /// Path to a CSV file containing quarterly underpayment interest rates
+    #[arg(long)]
+    interest_rates: Option<PathBuf>,
 
     /// SQLite database URL (e.g., sqlite:tax.db?mode=rwc to create if missing)
-    #[arg(short, long, default_value = "sqlite:tax.db?mode=rwc")]
+    #[arg(short, long, global = true, default_value = "sqlite:tax.db?mode=rwc")]
     database: String,
 
     /// Run database migrations before loading data
@@ -34,6 +59,24 @@ struct Args {
     /// Run seed files from the specified directory after migrations
     #[arg(short, long)]
     seeds: Option<PathBuf>,
+
+    /// Run the seed files built into the binary after migrations
+    #[arg(long, default_value_t = false, conflicts_with = "seeds")]
+    embedded_seeds: bool,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Write a consistent snapshot of the database to a new file
+    Backup {
+        /// Path of the backup file to create; must not already exist
+        output: PathBuf,
+    },
+    /// Replace the database contents with a snapshot made by `backup`
+    Restore {
+        /// Path of the backup file to restore
+        input: PathBuf,
+    },
 }
 
 #[tokio::main]
@@ -52,32 +95,115 @@ async fn main() -> Result<()> {
         println!("Migrations complete.");
     }
 
-    if let Some(seeds_dir) = &args.seeds {
+    let applied = if let Some(seeds_dir) = &args.seeds {
         println!("Running seeds from: {}", seeds_dir.display());
-        repo.run_seeds(seeds_dir)
+        Some(
+            repo.run_seeds(seeds_dir)
+                .await
+                .with_context(|| format!("Failed to run seeds from: {}", seeds_dir.display()))?,
+        )
+    } else if args.embedded_seeds {
+        println!("Running embedded seeds...");
+        Some(
+            repo.run_embedded_seeds()
+                .await
+                .context("Failed to run embedded seeds")?,
+        )
+    } else {
+        None
+    };
+    if let Some(applied) = applied {
+        if applied.is_empty() {
+            println!("Seeds already up to date.");
+        } else {
+            for file_name in &applied {
+                println!("Applied seed: {file_name}");
+            }
+            println!("Seeds complete.");
+        }
+    }
+
+    match &args.command {
+        Some(Command::Backup { output }) => {
+            println!("Backing up database to: {}", output.display());
+            repo.backup_to(output)
+                .await
+                .with_context(|| format!("Failed to back up to: {}", output.display()))?;
+            println!("Backup complete.");
+            return Ok(());
+        }
+        Some(Command::Restore { input }) => {
+            println!("Restoring database from: {}", input.display());
+            repo.restore_from(input)
+                .await
+                .with_context(|| format!("Failed to restore from: {}", input.display()))?;
+            println!("Restore complete.");
+            return Ok(());
+        }
+        None => {}
+    }
+
+    if let Some(path) = &args.file {
+        println!("Loading tax brackets from: {}", path.display());
+
+        let file =
+            File::open(path).with_context(|| format!("Failed to open: {}", path.display()))?;
+
+        let records = TaxBracketLoader::parse(file)
+            .with_context(|| format!("Failed to parse CSV: {}", path.display()))?;
+
+        println!("Parsed {} records from CSV", records.len());
+
+        let inserted = TaxBracketLoader::load(&repo, &records)
             .await
-            .with_context(|| format!("Failed to run seeds from: {}", seeds_dir.display()))?;
-        println!("Seeds complete.");
+            .context("Failed to load tax brackets into database")?;
+
+        println!(
+            "Successfully loaded {} tax brackets into the database.",
+            inserted
+        );
     }
 
-    println!("Loading tax brackets from: {}", args.file.display());
+    if let Some(path) = &args.niit_thresholds {
+        println!("Loading NIIT thresholds from: {}", path.display());
 
-    let file = File::open(&args.file)
-        .with_context(|| format!("Failed to open: {}", args.file.display()))?;
+        let file =
+            File::open(path).with_context(|| format!("Failed to open: {}", path.display()))?;
 
-    let records = TaxBracketLoader::parse(file)
-        .with_context(|| format!("Failed to parse CSV: {}", args.file.display()))?;
+        let records = NiitThresholdLoader::parse(file)
+            .with_context(|| format!("Failed to parse CSV: {}", path.display()))?;
 
-    println!("Parsed {} records from CSV", records.len());
+        let loaded = NiitThresholdLoader::load(&repo, &records)
+            .await
+            .context("Failed to load NIIT thresholds into database")?;
 
-    let inserted = TaxBracketLoader::load(&repo, &records)
-        .await
-        .context("Failed to load tax brackets into database")?;
+        println!(
+            "Successfully loaded {} NIIT thresholds into the database.",
+            loaded
+        );
+    }
+
+    if let Some(path) = &args.interest_rates {
+        println!(
+            "Loading underpayment interest rates from: {}",
+            path.display()
+        );
+
+        let file =
+            File::open(path).with_context(|| format!("Failed to open: {}", path.display()))?;
+
+        let records = InterestRateLoader::parse(file)
+            .with_context(|| format!("Failed to parse CSV: {}", path.display()))?;
 
-    println!(
-        "Successfully loaded {} tax brackets into the database.",
-        inserted
-    );
+        let loaded = InterestRateLoader::load(&repo, &records)
+            .await
+            .context("Failed to load underpayment interest rates into database")?;
+
+        println!(
+            "Successfully loaded {} underpayment interest rates into the database.",
+            loaded
+        );
+    }
 
     Ok(())
 }
@@ -0,0 +1,126 @@
+use std::io::Read;
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use tax_core::{RepositoryError, TaxRepository, UnderpaymentInterestRate};
+use thiserror::Error;
+
+/// Errors that can occur when loading underpayment interest rate data.
+#[derive(Debug, Error)]
+pub enum InterestRateLoaderError {
+    #[error("CSV parse error: {0}")]
+    CsvParse(String),
+
+    #[error("Invalid quarter {quarter} for {year} (expected 1-4)")]
+    InvalidQuarter { year: i32, quarter: u32 },
+
+    #[error("Repository error: {0}")]
+    Repository(#[from] RepositoryError),
+}
+
+impl From<csv::Error> for InterestRateLoaderError {
+    fn from(err: csv::Error) -> Self {
+        InterestRateLoaderError::CsvParse(err.to_string())
+    }
+}
+
+/// A single record from the underpayment interest rates CSV file.
+///
+/// - `year`: The calendar year (e.g., 2025)
+/// - `quarter`: The calendar quarter, 1 through 4
+/// - `rate`: The annual underpayment rate as a decimal (e.g., 0.07)
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct InterestRateRecord {
+    pub year: i32,
+    pub quarter: u32,
+    pub rate: Decimal,
+}
+
+/// Loader for quarterly IRS underpayment interest rates from CSV files.
+///
+/// Rates are announced a quarter at a time, so each record is upserted and
+/// a file containing only the newest quarter can be loaded on its own.
+pub struct InterestRateLoader;
+
+impl InterestRateLoader {
+    /// Parse interest rate records from a CSV reader.
+    pub fn parse<R: Read>(reader: R) -> Result<Vec<InterestRateRecord>, InterestRateLoaderError> {
+        let mut csv_reader = csv::Reader::from_reader(reader);
+        let mut records = Vec::new();
+
+        for result in csv_reader.deserialize() {
+            let record: InterestRateRecord = result?;
+            if !(1..=4).contains(&record.quarter) {
+                return Err(InterestRateLoaderError::InvalidQuarter {
+                    year: record.year,
+                    quarter: record.quarter,
+                });
+            }
+            records.push(record);
+        }
+
+        Ok(records)
+    }
+
+    /// Load interest rate records into the database.
+    ///
+    /// Returns the number of rates written.
+    pub async fn load<R: TaxRepository>(
+        repo: &R,
+        records: &[InterestRateRecord],
+    ) -> Result<usize, InterestRateLoaderError> {
+        for record in records {
+            let rate = UnderpaymentInterestRate {
+                year: record.year,
+                quarter: record.quarter,
+                rate: record.rate,
+            };
+            repo.upsert_underpayment_interest_rate(&rate).await?;
+        }
+
+        Ok(records.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_rates() {
+        let csv = "year,quarter,rate\n2025,4,0.07\n2026,1,0.07";
+
+        let records = InterestRateLoader::parse(csv.as_bytes()).expect("Failed to parse CSV");
+
+        assert_eq!(
+            records,
+            vec![
+                InterestRateRecord {
+                    year: 2025,
+                    quarter: 4,
+                    rate: dec!(0.07),
+                },
+                InterestRateRecord {
+                    year: 2026,
+                    quarter: 1,
+                    rate: dec!(0.07),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_quarter() {
+        let csv = "year,quarter,rate\n2025,5,0.07";
+
+        let err = InterestRateLoader::parse(csv.as_bytes()).expect_err("Should reject quarter 5");
+
+        let InterestRateLoaderError::InvalidQuarter { year, quarter } = err else {
+            panic!("Expected InvalidQuarter error, got: {:?}", err);
+        };
+        assert_eq!((year, quarter), (2025, 5));
+    }
+}
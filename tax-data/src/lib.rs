@@ -1,3 +1,9 @@
+mod interest_rate_loader;
 mod loader;
+mod niit_threshold_loader;
 
+pub use interest_rate_loader::{InterestRateLoader, InterestRateLoaderError, InterestRateRecord};
 pub use loader::{TaxBracketLoader, TaxBracketLoaderError, TaxBracketRecord};
+pub use niit_threshold_loader::{
+    NiitThresholdLoader, NiitThresholdLoaderError, NiitThresholdRecord,
+};
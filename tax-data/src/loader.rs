@@ -112,8 +112,8 @@ impl TaxBracketLoader {
 
     /// Load tax bracket records into the database.
     ///
-    /// For each unique (tax_year, schedule) combination in the records,
-    /// this method will:
+    /// For each unique (tax_year, schedule) combination in the records, in
+    /// order of tax year and then schedule, this method will:
     /// 1. Map the schedule to one or more filing status codes
     /// 2. Look up the filing status ID from each code
     /// 3. Delete any existing brackets for that year/status combination
@@ -122,6 +122,9 @@ impl TaxBracketLoader {
     /// This ensures that loading is idempotent - running the same load
     /// multiple times will produce the same result.
     ///
+    /// Everything runs in one transaction, so if any record fails to load
+    /// the brackets already in the database are left exactly as they were.
+    ///
     /// Note: Schedule Y-1 maps to both MFJ and QSS, so those brackets
     /// will be duplicated for both filing statuses.
     pub async fn load<R: TaxRepository>(
@@ -129,10 +132,12 @@ impl TaxBracketLoader {
         records: &[TaxBracketRecord],
     ) -> Result<usize, TaxBracketLoaderError> {
         let mut inserted = 0;
+        let tx = repo.begin().await?;
 
-        // Group records by (tax_year, schedule) to delete and re-insert atomically
-        let mut groups: std::collections::HashMap<(i32, String), Vec<&TaxBracketRecord>> =
-            std::collections::HashMap::new();
+        // Group records by (tax_year, schedule) to delete and re-insert
+        // together, in year then schedule order so every load runs the same way
+        let mut groups: std::collections::BTreeMap<(i32, String), Vec<&TaxBracketRecord>> =
+            std::collections::BTreeMap::new();
 
         for record in records {
             groups
@@ -148,7 +153,7 @@ impl TaxBracketLoader {
             for status_code in filing_status_codes {
                 // Look up the filing status ID
                 let filing_status =
-                    repo.get_filing_status_by_code(status_code)
+                    tx.get_filing_status_by_code(status_code)
                         .await
                         .map_err(|e| match e {
                             RepositoryError::NotFound => {
@@ -158,7 +163,7 @@ impl TaxBracketLoader {
                         })?;
 
                 // Delete existing brackets for this year/status
-                repo.delete_tax_brackets(tax_year, filing_status.id).await?;
+                tx.delete_tax_brackets(tax_year, filing_status.id).await?;
 
                 // Insert new brackets
                 for record in &group_records {
@@ -171,7 +176,7 @@ impl TaxBracketLoader {
                         base_tax: record.base_tax,
                     };
 
-                    repo.insert_tax_bracket(&bracket).await.map_err(|e| {
+                    tx.insert_tax_bracket(&bracket).await.map_err(|e| {
                         if let RepositoryError::Database(ref inner) = e
                             && inner.to_string().contains("FOREIGN KEY constraint failed")
                         {
@@ -184,6 +189,7 @@ impl TaxBracketLoader {
             }
         }
 
+        tx.commit().await?;
         Ok(inserted)
     }
 }
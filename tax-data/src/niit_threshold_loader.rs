@@ -0,0 +1,146 @@
+use std::io::Read;
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use tax_core::{NiitThreshold, RepositoryError, TaxRepository};
+use thiserror::Error;
+
+/// Errors that can occur when loading NIIT threshold data.
+#[derive(Debug, Error)]
+pub enum NiitThresholdLoaderError {
+    #[error("CSV parse error: {0}")]
+    CsvParse(String),
+
+    #[error("Filing status '{0}' not found in database (have you run the seeds?)")]
+    FilingStatusNotFound(String),
+
+    #[error("Tax year {0} not found in database (have you run the seeds?)")]
+    TaxYearNotFound(i32),
+
+    #[error("Repository error: {0}")]
+    Repository(#[from] RepositoryError),
+}
+
+impl From<csv::Error> for NiitThresholdLoaderError {
+    fn from(err: csv::Error) -> Self {
+        NiitThresholdLoaderError::CsvParse(err.to_string())
+    }
+}
+
+/// A single record from the NIIT thresholds CSV file.
+///
+/// - `tax_year`: The tax year (e.g., 2025)
+/// - `filing_status`: The filing status code (S, MFJ, MFS, HOH, QSS)
+/// - `amount`: The modified AGI threshold for Form 8960 line 14
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct NiitThresholdRecord {
+    pub tax_year: i32,
+    pub filing_status: String,
+    pub amount: Decimal,
+}
+
+/// Loader for Net Investment Income Tax thresholds from CSV files.
+///
+/// Each record is upserted through the `TaxRepository` trait, so loading the
+/// same file repeatedly leaves the database unchanged.
+pub struct NiitThresholdLoader;
+
+impl NiitThresholdLoader {
+    /// Parse NIIT threshold records from a CSV reader.
+    pub fn parse<R: Read>(reader: R) -> Result<Vec<NiitThresholdRecord>, NiitThresholdLoaderError> {
+        let mut csv_reader = csv::Reader::from_reader(reader);
+        let mut records = Vec::new();
+
+        for result in csv_reader.deserialize() {
+            let record: NiitThresholdRecord = result?;
+            records.push(record);
+        }
+
+        Ok(records)
+    }
+
+    /// Load NIIT threshold records into the database.
+    ///
+    /// Returns the number of thresholds written.
+    pub async fn load<R: TaxRepository>(
+        repo: &R,
+        records: &[NiitThresholdRecord],
+    ) -> Result<usize, NiitThresholdLoaderError> {
+        for record in records {
+            let filing_status = repo
+                .get_filing_status_by_code(&record.filing_status)
+                .await
+                .map_err(|e| match e {
+                    RepositoryError::NotFound => {
+                        NiitThresholdLoaderError::FilingStatusNotFound(record.filing_status.clone())
+                    }
+                    other => NiitThresholdLoaderError::Repository(other),
+                })?;
+
+            let threshold = NiitThreshold {
+                tax_year: record.tax_year,
+                filing_status_id: filing_status.id,
+                amount: record.amount,
+            };
+
+            repo.upsert_niit_threshold(&threshold).await.map_err(|e| {
+                if let RepositoryError::Database(ref inner) = e
+                    && inner.to_string().contains("FOREIGN KEY constraint failed")
+                {
+                    return NiitThresholdLoaderError::TaxYearNotFound(record.tax_year);
+                }
+                NiitThresholdLoaderError::Repository(e)
+            })?;
+        }
+
+        Ok(records.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_thresholds() {
+        let csv = "tax_year,filing_status,amount\n2025,S,200000\n2025,MFS,125000.00";
+
+        let records = NiitThresholdLoader::parse(csv.as_bytes()).expect("Failed to parse CSV");
+
+        assert_eq!(
+            records,
+            vec![
+                NiitThresholdRecord {
+                    tax_year: 2025,
+                    filing_status: "S".to_string(),
+                    amount: dec!(200000),
+                },
+                NiitThresholdRecord {
+                    tax_year: 2025,
+                    filing_status: "MFS".to_string(),
+                    amount: dec!(125000.00),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_csv_missing_column() {
+        let csv = "tax_year,filing_status\n2025,S";
+
+        let err =
+            NiitThresholdLoader::parse(csv.as_bytes()).expect_err("Should fail for missing column");
+
+        let NiitThresholdLoaderError::CsvParse(msg) = err else {
+            panic!("Expected CsvParse error, got: {:?}", err);
+        };
+        assert!(
+            msg.contains("missing field"),
+            "Expected 'missing field' in error, got: {}",
+            msg
+        );
+    }
+}
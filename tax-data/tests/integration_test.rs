@@ -4,10 +4,15 @@ use pretty_assertions::assert_eq;
 use rust_decimal_macros::dec;
 use sqlx::sqlite::SqlitePoolOptions;
 use tax_core::TaxRepository;
-use tax_data::{TaxBracketLoader, TaxBracketLoaderError};
+use tax_data::{
+    InterestRateLoader, NiitThresholdLoader, NiitThresholdLoaderError, TaxBracketLoader,
+    TaxBracketLoaderError,
+};
 use tax_db_sqlite::SqliteRepository;
 
 const TEST_CSV_2025: &str = include_str!("../test-data/tax_brackets_2025.csv");
+const TEST_NIIT_CSV_2025: &str = include_str!("../test-data/niit_thresholds_2025.csv");
+const TEST_INTEREST_RATES_CSV: &str = include_str!("../test-data/underpayment_interest_rates.csv");
 
 /// Sets up a test database with migrations run but NO seed data.
 /// This simulates a user running --migrate without --seeds.
@@ -393,3 +398,121 @@ async fn test_load_preserves_other_year_brackets() {
         .expect("Failed to get 2025 brackets");
     assert_eq!(brackets_2025.len(), 7);
 }
+
+#[tokio::test]
+async fn test_failed_load_leaves_existing_brackets_untouched() {
+    let repo = setup_test_db().await;
+    let records = TaxBracketLoader::parse(TEST_CSV_2025.as_bytes()).expect("Failed to parse CSV");
+    TaxBracketLoader::load(&repo, &records)
+        .await
+        .expect("Failed to load brackets");
+    let before = repo
+        .get_tax_brackets(2025, 1)
+        .await
+        .expect("Failed to get brackets");
+
+    // Groups load in year order, so the 2025 brackets have already been
+    // deleted and replaced when the 2030 row fails for want of a tax year
+    // config. The whole load must roll back.
+    let csv = "tax_year,schedule,min_income,max_income,base_tax,rate
+2025,X,0,,0,0.50
+2030,X,0,,0,0.10
+";
+    let records = TaxBracketLoader::parse(csv.as_bytes()).expect("Failed to parse CSV");
+    let result = TaxBracketLoader::load(&repo, &records).await;
+
+    match result {
+        Err(TaxBracketLoaderError::TaxYearNotFound(year)) => assert_eq!(year, 2030),
+        other => panic!("expected TaxYearNotFound, got {other:?}"),
+    }
+    assert_eq!(
+        repo.get_tax_brackets(2025, 1)
+            .await
+            .expect("Failed to get brackets"),
+        before
+    );
+}
+
+#[tokio::test]
+async fn test_load_niit_thresholds() {
+    let repo = setup_test_db().await;
+
+    let records =
+        NiitThresholdLoader::parse(TEST_NIIT_CSV_2025.as_bytes()).expect("Failed to parse CSV");
+    let loaded = NiitThresholdLoader::load(&repo, &records)
+        .await
+        .expect("Failed to load NIIT thresholds");
+
+    assert_eq!(loaded, 5);
+
+    let single = repo
+        .get_niit_threshold(2025, 1)
+        .await
+        .expect("Failed to get Single NIIT threshold");
+    assert_eq!(single.amount, dec!(200000.00));
+
+    let mfs = repo
+        .get_niit_threshold(2025, 3)
+        .await
+        .expect("Failed to get MFS NIIT threshold");
+    assert_eq!(mfs.amount, dec!(125000.00));
+}
+
+#[tokio::test]
+async fn test_load_niit_thresholds_is_idempotent() {
+    let repo = setup_test_db().await;
+
+    let records =
+        NiitThresholdLoader::parse(TEST_NIIT_CSV_2025.as_bytes()).expect("Failed to parse CSV");
+    NiitThresholdLoader::load(&repo, &records)
+        .await
+        .expect("First load failed");
+    NiitThresholdLoader::load(&repo, &records)
+        .await
+        .expect("Second load failed");
+
+    let mfj = repo
+        .get_niit_threshold(2025, 2)
+        .await
+        .expect("Failed to get MFJ NIIT threshold");
+    assert_eq!(mfj.amount, dec!(250000.00));
+}
+
+#[tokio::test]
+async fn test_load_niit_thresholds_fails_without_filing_statuses() {
+    let repo = setup_test_db_without_seeds().await;
+
+    let records =
+        NiitThresholdLoader::parse(TEST_NIIT_CSV_2025.as_bytes()).expect("Failed to parse CSV");
+
+    let result = NiitThresholdLoader::load(&repo, &records).await;
+
+    match result {
+        Err(NiitThresholdLoaderError::FilingStatusNotFound(code)) => assert_eq!(code, "S"),
+        other => panic!("expected FilingStatusNotFound, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_load_underpayment_interest_rates() {
+    let repo = setup_test_db_without_seeds().await;
+
+    let records =
+        InterestRateLoader::parse(TEST_INTEREST_RATES_CSV.as_bytes()).expect("Failed to parse CSV");
+    let loaded = InterestRateLoader::load(&repo, &records)
+        .await
+        .expect("Failed to load interest rates");
+    InterestRateLoader::load(&repo, &records)
+        .await
+        .expect("Second load failed");
+
+    assert_eq!(loaded, 5);
+
+    let rates = repo
+        .list_underpayment_interest_rates()
+        .await
+        .expect("Failed to list interest rates");
+    assert_eq!(rates.len(), 5);
+    assert_eq!((rates[4].year, rates[4].quarter), (2026, 1));
+    assert_eq!(rates[4].rate, dec!(0.07));
+}
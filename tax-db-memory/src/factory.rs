@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+
+use tax_core::db::repository::{RepositoryError, TaxRepository};
+use tax_core::db::{DbConfig, RepositoryFactory};
+
+use crate::repository::MemoryRepository;
+
+/// [`RepositoryFactory`] for the in-memory backend.
+///
+/// Register this with a [`tax_core::db::RepositoryRegistry`] to make the
+/// `"memory"` backend available:
+///
+/// ```rust,no_run
+/// use tax_core::db::RepositoryRegistry;
+/// use tax_db_memory::MemoryRepositoryFactory;
+///
+/// let mut registry = RepositoryRegistry::new();
+/// registry.register(Box::new(MemoryRepositoryFactory));
+/// ```
+pub struct MemoryRepositoryFactory;
+
+#[async_trait]
+impl RepositoryFactory for MemoryRepositoryFactory {
+    fn backend_name(&self) -> &'static str {
+        "memory"
+    }
+
+    /// Create a fresh repository preloaded with the seeded reference data.
+    ///
+    /// `config.connection_string` is ignored: every call returns a new,
+    /// independent store that lives as long as the returned repository.
+    async fn create(
+        &self,
+        _config: &DbConfig,
+    ) -> Result<Box<dyn TaxRepository>, RepositoryError> {
+        Ok(Box::new(MemoryRepository::with_reference_data()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tax_core::db::{DbConfig, RepositoryFactory};
+
+    use super::MemoryRepositoryFactory;
+
+    #[test]
+    fn backend_name_is_memory() {
+        assert_eq!(MemoryRepositoryFactory.backend_name(), "memory");
+    }
+
+    #[tokio::test]
+    async fn creates_seeded_repository() {
+        let config = DbConfig {
+            backend: "memory".to_string(),
+            connection_string: String::new(),
+        };
+
+        let repo = MemoryRepositoryFactory
+            .create(&config)
+            .await
+            .expect("failed to create in-memory repository");
+
+        assert_eq!(repo.list_tax_years().await.unwrap(), vec![2026, 2025]);
+    }
+}
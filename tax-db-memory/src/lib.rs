@@ -0,0 +1,6 @@
+pub mod factory;
+mod reference;
+pub mod repository;
+
+pub use factory::MemoryRepositoryFactory;
+pub use repository::MemoryRepository;
@@ -0,0 +1,645 @@
+//! Reference data the in-memory backend starts with.
+//!
+//! These tables mirror the SQL seed files in `tax-db-sqlite/seeds/`. A change
+//! to a seed file needs the same change here; `tests/reference_data.rs`
+//! compares every reference lookup against a seeded SQLite database and fails
+//! until both agree.
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use tax_core::{
+    AdditionalMedicareThreshold, CapitalGainBreakpoints, FilingStatus, FilingStatusCode,
+    NiitThreshold, QbiThreshold, StandardDeduction, TaxBracket, TaxYearConfig,
+    UnderpaymentInterestRate,
+};
+
+use crate::repository::Store;
+
+const FILING_STATUSES: &[FilingStatusCode] = &[
+    FilingStatusCode::Single,
+    FilingStatusCode::MarriedFilingJointly,
+    FilingStatusCode::MarriedFilingSeparately,
+    FilingStatusCode::HeadOfHousehold,
+    FilingStatusCode::QualifyingSurvivingSpouse,
+];
+
+/// `(tax_year, ss_wage_max)`; every other rate and threshold is the same for
+/// the seeded years.
+const TAX_YEARS: &[(i32, Decimal)] = &[(2025, dec!(176100.00)), (2026, dec!(184500.00))];
+
+/// `(tax_year, filing_status_id, amount)` — standard deductions.
+const STANDARD_DEDUCTIONS: &[(i32, i32, Decimal)] = &[
+    (2025, 1, dec!(15750.00)),
+    (2025, 2, dec!(31500.00)),
+    (2025, 3, dec!(15750.00)),
+    (2025, 4, dec!(23625.00)),
+    (2025, 5, dec!(31500.00)),
+    (2026, 1, dec!(16100.00)),
+    (2026, 2, dec!(32200.00)),
+    (2026, 3, dec!(16100.00)),
+    (2026, 4, dec!(24150.00)),
+    (2026, 5, dec!(32200.00)),
+];
+
+/// `(tax_year, filing_status_id, amount)` — Form 8959 thresholds.
+const ADDITIONAL_MEDICARE_THRESHOLDS: &[(i32, i32, Decimal)] = &[
+    (2025, 1, dec!(200000.00)),
+    (2025, 2, dec!(250000.00)),
+    (2025, 3, dec!(125000.00)),
+    (2025, 4, dec!(200000.00)),
+    (2025, 5, dec!(200000.00)),
+    (2026, 1, dec!(200000.00)),
+    (2026, 2, dec!(250000.00)),
+    (2026, 3, dec!(125000.00)),
+    (2026, 4, dec!(200000.00)),
+    (2026, 5, dec!(200000.00)),
+];
+
+/// `(tax_year, filing_status_id, amount)` — Form 8960 thresholds.
+const NIIT_THRESHOLDS: &[(i32, i32, Decimal)] = &[
+    (2025, 1, dec!(200000.00)),
+    (2025, 2, dec!(250000.00)),
+    (2025, 3, dec!(125000.00)),
+    (2025, 4, dec!(200000.00)),
+    (2025, 5, dec!(250000.00)),
+    (2026, 1, dec!(200000.00)),
+    (2026, 2, dec!(250000.00)),
+    (2026, 3, dec!(125000.00)),
+    (2026, 4, dec!(200000.00)),
+    (2026, 5, dec!(250000.00)),
+];
+
+/// `(tax_year, filing_status_id, amount)` — Form 8995 thresholds.
+const QBI_THRESHOLDS: &[(i32, i32, Decimal)] = &[
+    (2025, 1, dec!(197300.00)),
+    (2025, 2, dec!(394600.00)),
+    (2025, 3, dec!(197300.00)),
+    (2025, 4, dec!(197300.00)),
+    (2025, 5, dec!(197300.00)),
+    (2026, 1, dec!(201775.00)),
+    (2026, 2, dec!(403550.00)),
+    (2026, 3, dec!(201775.00)),
+    (2026, 4, dec!(201775.00)),
+    (2026, 5, dec!(201775.00)),
+];
+
+/// `(tax_year, filing_status_id, zero_rate_max, fifteen_rate_max)`
+const CAPITAL_GAIN_BREAKPOINTS: &[(i32, i32, Decimal, Decimal)] = &[
+    (2025, 1, dec!(48350.00), dec!(533400.00)),
+    (2025, 2, dec!(96700.00), dec!(600050.00)),
+    (2025, 3, dec!(48350.00), dec!(300000.00)),
+    (2025, 4, dec!(64750.00), dec!(566700.00)),
+    (2025, 5, dec!(96700.00), dec!(600050.00)),
+    (2026, 1, dec!(49450.00), dec!(545500.00)),
+    (2026, 2, dec!(98900.00), dec!(613700.00)),
+    (2026, 3, dec!(49450.00), dec!(306850.00)),
+    (2026, 4, dec!(66200.00), dec!(579600.00)),
+    (2026, 5, dec!(98900.00), dec!(613700.00)),
+];
+
+/// `(year, quarter, rate)`
+const UNDERPAYMENT_INTEREST_RATES: &[(i32, u32, Decimal)] = &[
+    (2024, 1, dec!(0.08)),
+    (2024, 2, dec!(0.08)),
+    (2024, 3, dec!(0.08)),
+    (2024, 4, dec!(0.08)),
+    (2025, 1, dec!(0.07)),
+    (2025, 2, dec!(0.07)),
+    (2025, 3, dec!(0.07)),
+    (2025, 4, dec!(0.07)),
+    (2026, 1, dec!(0.07)),
+];
+
+/// `(tax_year, filing_status_id, min_income, max_income, tax_rate, base_tax)`
+type BracketRow = (i32, i32, Decimal, Option<Decimal>, Decimal, Decimal);
+
+const TAX_BRACKETS: &[BracketRow] = &[
+    (2025, 1, dec!(0), Some(dec!(11925)), dec!(0.10), dec!(0)),
+    (
+        2025,
+        1,
+        dec!(11925),
+        Some(dec!(48475)),
+        dec!(0.12),
+        dec!(1192.50),
+    ),
+    (
+        2025,
+        1,
+        dec!(48475),
+        Some(dec!(103350)),
+        dec!(0.22),
+        dec!(5578.50),
+    ),
+    (
+        2025,
+        1,
+        dec!(103350),
+        Some(dec!(197300)),
+        dec!(0.24),
+        dec!(17651),
+    ),
+    (
+        2025,
+        1,
+        dec!(197300),
+        Some(dec!(250525)),
+        dec!(0.32),
+        dec!(40199),
+    ),
+    (
+        2025,
+        1,
+        dec!(250525),
+        Some(dec!(626350)),
+        dec!(0.35),
+        dec!(57231),
+    ),
+    (2025, 1, dec!(626350), None, dec!(0.37), dec!(188769.75)),
+    (2025, 2, dec!(0), Some(dec!(23850)), dec!(0.10), dec!(0)),
+    (
+        2025,
+        2,
+        dec!(23850),
+        Some(dec!(96950)),
+        dec!(0.12),
+        dec!(2385),
+    ),
+    (
+        2025,
+        2,
+        dec!(96950),
+        Some(dec!(206700)),
+        dec!(0.22),
+        dec!(11157),
+    ),
+    (
+        2025,
+        2,
+        dec!(206700),
+        Some(dec!(394600)),
+        dec!(0.24),
+        dec!(35302),
+    ),
+    (
+        2025,
+        2,
+        dec!(394600),
+        Some(dec!(501050)),
+        dec!(0.32),
+        dec!(80398),
+    ),
+    (
+        2025,
+        2,
+        dec!(501050),
+        Some(dec!(751600)),
+        dec!(0.35),
+        dec!(114462),
+    ),
+    (2025, 2, dec!(751600), None, dec!(0.37), dec!(202154.50)),
+    (2025, 3, dec!(0), Some(dec!(11925)), dec!(0.10), dec!(0)),
+    (
+        2025,
+        3,
+        dec!(11925),
+        Some(dec!(48475)),
+        dec!(0.12),
+        dec!(1192.50),
+    ),
+    (
+        2025,
+        3,
+        dec!(48475),
+        Some(dec!(103350)),
+        dec!(0.22),
+        dec!(5578.50),
+    ),
+    (
+        2025,
+        3,
+        dec!(103350),
+        Some(dec!(197300)),
+        dec!(0.24),
+        dec!(17651),
+    ),
+    (
+        2025,
+        3,
+        dec!(197300),
+        Some(dec!(250525)),
+        dec!(0.32),
+        dec!(40199),
+    ),
+    (
+        2025,
+        3,
+        dec!(250525),
+        Some(dec!(375800)),
+        dec!(0.35),
+        dec!(57231),
+    ),
+    (2025, 3, dec!(375800), None, dec!(0.37), dec!(101077.25)),
+    (2025, 4, dec!(0), Some(dec!(17000)), dec!(0.10), dec!(0)),
+    (
+        2025,
+        4,
+        dec!(17000),
+        Some(dec!(64850)),
+        dec!(0.12),
+        dec!(1700),
+    ),
+    (
+        2025,
+        4,
+        dec!(64850),
+        Some(dec!(103350)),
+        dec!(0.22),
+        dec!(7442),
+    ),
+    (
+        2025,
+        4,
+        dec!(103350),
+        Some(dec!(197300)),
+        dec!(0.24),
+        dec!(15912),
+    ),
+    (
+        2025,
+        4,
+        dec!(197300),
+        Some(dec!(250500)),
+        dec!(0.32),
+        dec!(38460),
+    ),
+    (
+        2025,
+        4,
+        dec!(250500),
+        Some(dec!(626350)),
+        dec!(0.35),
+        dec!(55484),
+    ),
+    (2025, 4, dec!(626350), None, dec!(0.37), dec!(187032)),
+    (2025, 5, dec!(0), Some(dec!(23850)), dec!(0.10), dec!(0)),
+    (
+        2025,
+        5,
+        dec!(23850),
+        Some(dec!(96950)),
+        dec!(0.12),
+        dec!(2385),
+    ),
+    (
+        2025,
+        5,
+        dec!(96950),
+        Some(dec!(206700)),
+        dec!(0.22),
+        dec!(11157),
+    ),
+    (
+        2025,
+        5,
+        dec!(206700),
+        Some(dec!(394600)),
+        dec!(0.24),
+        dec!(35302),
+    ),
+    (
+        2025,
+        5,
+        dec!(394600),
+        Some(dec!(501050)),
+        dec!(0.32),
+        dec!(80398),
+    ),
+    (
+        2025,
+        5,
+        dec!(501050),
+        Some(dec!(751600)),
+        dec!(0.35),
+        dec!(114462),
+    ),
+    (2025, 5, dec!(751600), None, dec!(0.37), dec!(202154.50)),
+    (2026, 1, dec!(0), Some(dec!(12400)), dec!(0.10), dec!(0)),
+    (
+        2026,
+        1,
+        dec!(12400),
+        Some(dec!(50400)),
+        dec!(0.12),
+        dec!(1240.00),
+    ),
+    (
+        2026,
+        1,
+        dec!(50400),
+        Some(dec!(105700)),
+        dec!(0.22),
+        dec!(5800.00),
+    ),
+    (
+        2026,
+        1,
+        dec!(105700),
+        Some(dec!(201775)),
+        dec!(0.24),
+        dec!(17966.00),
+    ),
+    (
+        2026,
+        1,
+        dec!(201775),
+        Some(dec!(256225)),
+        dec!(0.32),
+        dec!(41024.00),
+    ),
+    (
+        2026,
+        1,
+        dec!(256225),
+        Some(dec!(640600)),
+        dec!(0.35),
+        dec!(58448.00),
+    ),
+    (2026, 1, dec!(640600), None, dec!(0.37), dec!(192979.25)),
+    (2026, 2, dec!(0), Some(dec!(24800)), dec!(0.10), dec!(0)),
+    (
+        2026,
+        2,
+        dec!(24800),
+        Some(dec!(100800)),
+        dec!(0.12),
+        dec!(2480.00),
+    ),
+    (
+        2026,
+        2,
+        dec!(100800),
+        Some(dec!(211400)),
+        dec!(0.22),
+        dec!(11600.00),
+    ),
+    (
+        2026,
+        2,
+        dec!(211400),
+        Some(dec!(403550)),
+        dec!(0.24),
+        dec!(35932.00),
+    ),
+    (
+        2026,
+        2,
+        dec!(403550),
+        Some(dec!(512450)),
+        dec!(0.32),
+        dec!(82048.00),
+    ),
+    (
+        2026,
+        2,
+        dec!(512450),
+        Some(dec!(768700)),
+        dec!(0.35),
+        dec!(116896.00),
+    ),
+    (2026, 2, dec!(768700), None, dec!(0.37), dec!(206583.50)),
+    (2026, 3, dec!(0), Some(dec!(12400)), dec!(0.10), dec!(0)),
+    (
+        2026,
+        3,
+        dec!(12400),
+        Some(dec!(50400)),
+        dec!(0.12),
+        dec!(1240.00),
+    ),
+    (
+        2026,
+        3,
+        dec!(50400),
+        Some(dec!(105700)),
+        dec!(0.22),
+        dec!(5800.00),
+    ),
+    (
+        2026,
+        3,
+        dec!(105700),
+        Some(dec!(201775)),
+        dec!(0.24),
+        dec!(17966.00),
+    ),
+    (
+        2026,
+        3,
+        dec!(201775),
+        Some(dec!(256225)),
+        dec!(0.32),
+        dec!(41024.00),
+    ),
+    (
+        2026,
+        3,
+        dec!(256225),
+        Some(dec!(384350)),
+        dec!(0.35),
+        dec!(58448.00),
+    ),
+    (2026, 3, dec!(384350), None, dec!(0.37), dec!(103291.75)),
+    (2026, 4, dec!(0), Some(dec!(17700)), dec!(0.10), dec!(0)),
+    (
+        2026,
+        4,
+        dec!(17700),
+        Some(dec!(67450)),
+        dec!(0.12),
+        dec!(1770.00),
+    ),
+    (
+        2026,
+        4,
+        dec!(67450),
+        Some(dec!(105700)),
+        dec!(0.22),
+        dec!(7740.00),
+    ),
+    (
+        2026,
+        4,
+        dec!(105700),
+        Some(dec!(201750)),
+        dec!(0.24),
+        dec!(16155.00),
+    ),
+    (
+        2026,
+        4,
+        dec!(201750),
+        Some(dec!(256200)),
+        dec!(0.32),
+        dec!(39207.00),
+    ),
+    (
+        2026,
+        4,
+        dec!(256200),
+        Some(dec!(640600)),
+        dec!(0.35),
+        dec!(56631.00),
+    ),
+    (2026, 4, dec!(640600), None, dec!(0.37), dec!(191171.00)),
+    (2026, 5, dec!(0), Some(dec!(24800)), dec!(0.10), dec!(0)),
+    (
+        2026,
+        5,
+        dec!(24800),
+        Some(dec!(100800)),
+        dec!(0.12),
+        dec!(2480.00),
+    ),
+    (
+        2026,
+        5,
+        dec!(100800),
+        Some(dec!(211400)),
+        dec!(0.22),
+        dec!(11600.00),
+    ),
+    (
+        2026,
+        5,
+        dec!(211400),
+        Some(dec!(403550)),
+        dec!(0.24),
+        dec!(35932.00),
+    ),
+    (
+        2026,
+        5,
+        dec!(403550),
+        Some(dec!(512450)),
+        dec!(0.32),
+        dec!(82048.00),
+    ),
+    (
+        2026,
+        5,
+        dec!(512450),
+        Some(dec!(768700)),
+        dec!(0.35),
+        dec!(116896.00),
+    ),
+    (2026, 5, dec!(768700), None, dec!(0.37), dec!(206583.50)),
+];
+
+/// Fill `store` with the seeded reference data, replacing any rows with the
+/// same keys.
+pub(crate) fn load(store: &mut Store) {
+    for code in FILING_STATUSES {
+        let status = FilingStatus {
+            id: code.filing_status_to_id(),
+            status_code: *code,
+            status_name: code.to_long_str().to_string(),
+        };
+        store.filing_statuses.insert(status.id, status);
+    }
+    for &(tax_year, ss_wage_max) in TAX_YEARS {
+        store.tax_year_configs.insert(
+            tax_year,
+            TaxYearConfig {
+                tax_year,
+                ss_wage_max,
+                ss_tax_rate: dec!(0.124),
+                medicare_tax_rate: dec!(0.029),
+                se_tax_deduct_pcnt: dec!(0.9235),
+                se_deduction_factor: dec!(0.50),
+                req_pmnt_threshold: dec!(1000.00),
+                min_se_threshold: dec!(400.00),
+                addl_medicare_tax_rate: dec!(0.009),
+                niit_rate: dec!(0.038),
+                prior_agi_threshold: dec!(150000.00),
+                prior_agi_threshold_mfs: dec!(75000.00),
+                prior_tax_multiplier: dec!(1.10),
+            },
+        );
+    }
+    for &(tax_year, filing_status_id, amount) in STANDARD_DEDUCTIONS {
+        store.standard_deductions.insert(
+            (tax_year, filing_status_id),
+            StandardDeduction {
+                tax_year,
+                filing_status_id,
+                amount,
+            },
+        );
+    }
+    for &(tax_year, filing_status_id, amount) in ADDITIONAL_MEDICARE_THRESHOLDS {
+        store.additional_medicare_thresholds.insert(
+            (tax_year, filing_status_id),
+            AdditionalMedicareThreshold {
+                tax_year,
+                filing_status_id,
+                amount,
+            },
+        );
+    }
+    for &(tax_year, filing_status_id, amount) in NIIT_THRESHOLDS {
+        store.niit_thresholds.insert(
+            (tax_year, filing_status_id),
+            NiitThreshold {
+                tax_year,
+                filing_status_id,
+                amount,
+            },
+        );
+    }
+    for &(tax_year, filing_status_id, amount) in QBI_THRESHOLDS {
+        store.qbi_thresholds.insert(
+            (tax_year, filing_status_id),
+            QbiThreshold {
+                tax_year,
+                filing_status_id,
+                amount,
+            },
+        );
+    }
+    for &(tax_year, filing_status_id, zero_rate_max, fifteen_rate_max) in CAPITAL_GAIN_BREAKPOINTS {
+        store.capital_gain_breakpoints.insert(
+            (tax_year, filing_status_id),
+            CapitalGainBreakpoints {
+                tax_year,
+                filing_status_id,
+                zero_rate_max,
+                fifteen_rate_max,
+            },
+        );
+    }
+    for &(year, quarter, rate) in UNDERPAYMENT_INTEREST_RATES {
+        store.underpayment_interest_rates.insert(
+            (year, quarter),
+            UnderpaymentInterestRate {
+                year,
+                quarter,
+                rate,
+            },
+        );
+    }
+    // Brackets are replaced wholesale for the seeded years, as in the seeds.
+    store
+        .tax_brackets
+        .retain(|b| !TAX_YEARS.iter().any(|&(year, _)| year == b.tax_year));
+    for &(tax_year, filing_status_id, min_income, max_income, tax_rate, base_tax) in TAX_BRACKETS {
+        store.tax_brackets.push(TaxBracket {
+            tax_year,
+            filing_status_id,
+            min_income,
+            max_income,
+            tax_rate,
+            base_tax,
+        });
+    }
+}
@@ -0,0 +1,1066 @@
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tax_core::{
+    AdditionalMedicareThreshold, CapitalGainBreakpoints, FilingStatus, FilingStatusCode,
+    NiitThreshold, QbiThreshold, RepositoryError, StandardDeduction, TaxBracket, TaxEstimate,
+    TaxEstimateComputed, TaxEstimateInput, TaxEstimateRevision, TaxRepository, TaxTransaction,
+    TaxYearConfig, UnderpaymentInterestRate,
+};
+
+use crate::reference;
+
+/// Every table of the in-memory backend, keyed the way the SQLite schema
+/// keys them.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct Store {
+    pub(crate) filing_statuses: BTreeMap<i32, FilingStatus>,
+    pub(crate) tax_year_configs: BTreeMap<i32, TaxYearConfig>,
+    pub(crate) standard_deductions: BTreeMap<(i32, i32), StandardDeduction>,
+    pub(crate) additional_medicare_thresholds: BTreeMap<(i32, i32), AdditionalMedicareThreshold>,
+    pub(crate) niit_thresholds: BTreeMap<(i32, i32), NiitThreshold>,
+    pub(crate) capital_gain_breakpoints: BTreeMap<(i32, i32), CapitalGainBreakpoints>,
+    pub(crate) qbi_thresholds: BTreeMap<(i32, i32), QbiThreshold>,
+    pub(crate) underpayment_interest_rates: BTreeMap<(i32, u32), UnderpaymentInterestRate>,
+    pub(crate) tax_brackets: Vec<TaxBracket>,
+    estimates: BTreeMap<i64, TaxEstimate>,
+    revisions: Vec<TaxEstimateRevision>,
+    next_estimate_id: i64,
+    next_revision_id: i64,
+    /// Bumped on every write, so a transaction can tell whether the store
+    /// changed after it took its snapshot.
+    generation: u64,
+}
+
+impl Store {
+    /// Reject rows whose tax year or filing status does not exist, as the
+    /// SQLite foreign keys do.
+    fn check_references(
+        &self,
+        tax_year: i32,
+        filing_status_id: i32,
+    ) -> Result<(), RepositoryError> {
+        if !self.tax_year_configs.contains_key(&tax_year) {
+            return Err(RepositoryError::Database(anyhow!(
+                "tax year {tax_year} has no tax_year_config"
+            )));
+        }
+        if !self.filing_statuses.contains_key(&filing_status_id) {
+            return Err(RepositoryError::Database(anyhow!(
+                "filing status {filing_status_id} does not exist"
+            )));
+        }
+        Ok(())
+    }
+
+    fn filing_status_id_for_code(
+        &self,
+        code: FilingStatusCode,
+    ) -> Result<i32, RepositoryError> {
+        self.filing_statuses
+            .values()
+            .find(|fs| fs.status_code == code)
+            .map(|fs| fs.id)
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    fn scenario_id(
+        &self,
+        input: &TaxEstimateInput,
+    ) -> Option<i64> {
+        self.estimates
+            .values()
+            .find(|e| {
+                e.input.tax_year == input.tax_year
+                    && e.input.filing_status == input.filing_status
+                    && e.input.scenario_name == input.scenario_name
+            })
+            .map(|e| e.id)
+    }
+
+    fn live_estimate(
+        &self,
+        id: i64,
+    ) -> Result<&TaxEstimate, RepositoryError> {
+        self.estimates
+            .get(&id)
+            .filter(|e| e.deleted_at.is_none())
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    /// Append a snapshot of estimate `id`, as the SQLite revision triggers do
+    /// after every insert and data update.
+    fn record_revision(
+        &mut self,
+        id: i64,
+    ) {
+        let Some(estimate) = self.estimates.get(&id) else {
+            return;
+        };
+        let revision = self
+            .revisions
+            .iter()
+            .filter(|r| r.estimate_id == id)
+            .map(|r| r.revision)
+            .max()
+            .unwrap_or(0)
+            + 1;
+        self.next_revision_id += 1;
+        self.revisions.push(TaxEstimateRevision {
+            id: self.next_revision_id,
+            estimate_id: id,
+            revision,
+            input: estimate.input.clone(),
+            computed: estimate.computed.clone(),
+            recorded_at: estimate.updated_at,
+        });
+    }
+}
+
+/// Most recently updated first; ties keep id order.
+fn by_updated_desc(mut estimates: Vec<TaxEstimate>) -> Vec<TaxEstimate> {
+    estimates.sort_by_key(|e| Reverse(e.updated_at));
+    estimates
+}
+
+/// [`TaxRepository`] that keeps every table in memory.
+///
+/// Nothing is persisted; each instance starts either empty
+/// ([`MemoryRepository::new`]) or with the same reference data the SQLite
+/// seeds provide ([`MemoryRepository::with_reference_data`]). Lookups,
+/// errors and ordering follow the SQLite backend, so it can stand in for it
+/// in tests and in builds that cannot link SQLx.
+///
+/// A transaction from [`TaxRepository::begin`] works on a copy of the store.
+/// Committing writes the copy back, unless the repository was written to in
+/// the meantime, in which case the commit fails and nothing is applied.
+#[derive(Debug, Default)]
+pub struct MemoryRepository {
+    store: Arc<Mutex<Store>>,
+    /// Set on a transaction: the store to write back to on commit, and its
+    /// generation when the transaction began.
+    commit_target: Option<(Arc<Mutex<Store>>, u64)>,
+}
+
+fn lock(store: &Mutex<Store>) -> MutexGuard<'_, Store> {
+    // A panic while holding the lock cannot leave a table half-written,
+    // so a poisoned lock is still safe to use.
+    store
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+impl MemoryRepository {
+    /// An empty repository with no reference data.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A repository preloaded with the seeded filing statuses, tax year
+    /// configs, deductions, thresholds, brackets and interest rates.
+    pub fn with_reference_data() -> Self {
+        let repo = Self::new();
+        reference::load(&mut repo.store());
+        repo
+    }
+
+    fn store(&self) -> MutexGuard<'_, Store> {
+        lock(&self.store)
+    }
+
+    /// Lock the store to change it.
+    fn store_mut(&self) -> MutexGuard<'_, Store> {
+        let mut store = self.store();
+        store.generation += 1;
+        store
+    }
+
+    /// Upsert an estimate's scenario with `computed` as its results, or with
+    /// none when `computed` is `None`.
+    fn upsert_estimate(
+        &self,
+        mut estimate: TaxEstimateInput,
+        computed: Option<TaxEstimateComputed>,
+    ) -> Result<TaxEstimate, RepositoryError> {
+        let now = Utc::now();
+        let mut store = self.store_mut();
+        let filing_status_id = store.filing_status_id_for_code(estimate.filing_status)?;
+        store.check_references(estimate.tax_year, filing_status_id)?;
+        estimate.scenario_name = estimate.scenario_name.trim().to_string();
+
+        let id = match store.scenario_id(&estimate) {
+            Some(id) => {
+                let existing = store
+                    .estimates
+                    .get_mut(&id)
+                    .ok_or(RepositoryError::NotFound)?;
+                if existing.deleted_at.is_some() {
+                    return Err(RepositoryError::EstimateInTrash {
+                        id,
+                        scenario_name: estimate.scenario_name,
+                    });
+                }
+                existing.input = estimate;
+                existing.computed = computed;
+                existing.updated_at = now;
+                id
+            }
+            None => {
+                store.next_estimate_id += 1;
+                let id = store.next_estimate_id;
+                store.estimates.insert(
+                    id,
+                    TaxEstimate {
+                        id,
+                        input: estimate,
+                        computed,
+                        created_at: now,
+                        updated_at: now,
+                        deleted_at: None,
+                    },
+                );
+                id
+            }
+        };
+        store.record_revision(id);
+        store.live_estimate(id).cloned()
+    }
+
+    fn into_commit_target(self) -> Result<(Arc<Mutex<Store>>, u64), RepositoryError> {
+        self.commit_target
+            .ok_or_else(|| RepositoryError::Configuration("No transaction in progress".to_string()))
+    }
+}
+
+#[async_trait]
+impl TaxRepository for MemoryRepository {
+    async fn get_tax_year_config(
+        &self,
+        year: i32,
+    ) -> Result<TaxYearConfig, RepositoryError> {
+        self.store()
+            .tax_year_configs
+            .get(&year)
+            .cloned()
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    async fn list_tax_years(&self) -> Result<Vec<i32>, RepositoryError> {
+        Ok(self
+            .store()
+            .tax_year_configs
+            .keys()
+            .rev()
+            .copied()
+            .collect())
+    }
+
+    async fn get_filing_status(
+        &self,
+        id: i32,
+    ) -> Result<FilingStatus, RepositoryError> {
+        self.store()
+            .filing_statuses
+            .get(&id)
+            .cloned()
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    async fn get_filing_status_by_code(
+        &self,
+        code: &str,
+    ) -> Result<FilingStatus, RepositoryError> {
+        self.store()
+            .filing_statuses
+            .values()
+            .find(|fs| fs.status_code.as_str() == code)
+            .cloned()
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    async fn list_filing_statuses(&self) -> Result<Vec<FilingStatus>, RepositoryError> {
+        Ok(self.store().filing_statuses.values().cloned().collect())
+    }
+
+    async fn get_standard_deduction(
+        &self,
+        tax_year: i32,
+        filing_status_id: i32,
+    ) -> Result<StandardDeduction, RepositoryError> {
+        self.store()
+            .standard_deductions
+            .get(&(tax_year, filing_status_id))
+            .cloned()
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    async fn get_additional_medicare_threshold(
+        &self,
+        tax_year: i32,
+        filing_status_id: i32,
+    ) -> Result<AdditionalMedicareThreshold, RepositoryError> {
+        self.store()
+            .additional_medicare_thresholds
+            .get(&(tax_year, filing_status_id))
+            .cloned()
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    async fn get_niit_threshold(
+        &self,
+        tax_year: i32,
+        filing_status_id: i32,
+    ) -> Result<NiitThreshold, RepositoryError> {
+        self.store()
+            .niit_thresholds
+            .get(&(tax_year, filing_status_id))
+            .cloned()
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    async fn upsert_niit_threshold(
+        &self,
+        threshold: &NiitThreshold,
+    ) -> Result<(), RepositoryError> {
+        let mut store = self.store_mut();
+        store.check_references(threshold.tax_year, threshold.filing_status_id)?;
+        store.niit_thresholds.insert(
+            (threshold.tax_year, threshold.filing_status_id),
+            threshold.clone(),
+        );
+        Ok(())
+    }
+
+    async fn get_capital_gain_breakpoints(
+        &self,
+        tax_year: i32,
+        filing_status_id: i32,
+    ) -> Result<CapitalGainBreakpoints, RepositoryError> {
+        self.store()
+            .capital_gain_breakpoints
+            .get(&(tax_year, filing_status_id))
+            .cloned()
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    async fn upsert_capital_gain_breakpoints(
+        &self,
+        breakpoints: &CapitalGainBreakpoints,
+    ) -> Result<(), RepositoryError> {
+        let mut store = self.store_mut();
+        store.check_references(breakpoints.tax_year, breakpoints.filing_status_id)?;
+        store.capital_gain_breakpoints.insert(
+            (breakpoints.tax_year, breakpoints.filing_status_id),
+            breakpoints.clone(),
+        );
+        Ok(())
+    }
+
+    async fn get_qbi_threshold(
+        &self,
+        tax_year: i32,
+        filing_status_id: i32,
+    ) -> Result<QbiThreshold, RepositoryError> {
+        self.store()
+            .qbi_thresholds
+            .get(&(tax_year, filing_status_id))
+            .cloned()
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    async fn upsert_qbi_threshold(
+        &self,
+        threshold: &QbiThreshold,
+    ) -> Result<(), RepositoryError> {
+        let mut store = self.store_mut();
+        store.check_references(threshold.tax_year, threshold.filing_status_id)?;
+        store.qbi_thresholds.insert(
+            (threshold.tax_year, threshold.filing_status_id),
+            threshold.clone(),
+        );
+        Ok(())
+    }
+
+    async fn list_underpayment_interest_rates(
+        &self
+    ) -> Result<Vec<UnderpaymentInterestRate>, RepositoryError> {
+        Ok(self
+            .store()
+            .underpayment_interest_rates
+            .values()
+            .cloned()
+            .collect())
+    }
+
+    async fn upsert_underpayment_interest_rate(
+        &self,
+        rate: &UnderpaymentInterestRate,
+    ) -> Result<(), RepositoryError> {
+        if !(1..=4).contains(&rate.quarter) {
+            return Err(RepositoryError::Database(anyhow!(
+                "quarter {} is not between 1 and 4",
+                rate.quarter
+            )));
+        }
+        self.store_mut()
+            .underpayment_interest_rates
+            .insert((rate.year, rate.quarter), rate.clone());
+        Ok(())
+    }
+
+    async fn get_filing_status_data(
+        &self,
+        year: i32,
+    ) -> Result<Vec<(FilingStatus, StandardDeduction, Vec<TaxBracket>)>, RepositoryError> {
+        let store = self.store();
+        let mut result = Vec::new();
+        for status in store.filing_statuses.values() {
+            let Some(deduction) = store.standard_deductions.get(&(year, status.id)) else {
+                continue;
+            };
+            let mut brackets: Vec<TaxBracket> = store
+                .tax_brackets
+                .iter()
+                .filter(|b| b.tax_year == year && b.filing_status_id == status.id)
+                .cloned()
+                .collect();
+            brackets.sort_by_key(|b| b.min_income);
+            result.push((status.clone(), deduction.clone(), brackets));
+        }
+        Ok(result)
+    }
+
+    async fn get_tax_brackets(
+        &self,
+        tax_year: i32,
+        filing_status_id: i32,
+    ) -> Result<Vec<TaxBracket>, RepositoryError> {
+        let mut brackets: Vec<TaxBracket> = self
+            .store()
+            .tax_brackets
+            .iter()
+            .filter(|b| b.tax_year == tax_year && b.filing_status_id == filing_status_id)
+            .cloned()
+            .collect();
+        brackets.sort_by_key(|b| b.min_income);
+        Ok(brackets)
+    }
+
+    async fn insert_tax_bracket(
+        &self,
+        bracket: &TaxBracket,
+    ) -> Result<(), RepositoryError> {
+        let mut store = self.store_mut();
+        store.check_references(bracket.tax_year, bracket.filing_status_id)?;
+        if store.tax_brackets.iter().any(|b| {
+            b.tax_year == bracket.tax_year
+                && b.filing_status_id == bracket.filing_status_id
+                && b.min_income == bracket.min_income
+        }) {
+            return Err(RepositoryError::Database(anyhow!(
+                "a {} bracket starting at {} already exists for filing status {}",
+                bracket.tax_year,
+                bracket.min_income,
+                bracket.filing_status_id
+            )));
+        }
+        store.tax_brackets.push(bracket.clone());
+        Ok(())
+    }
+
+    async fn delete_tax_brackets(
+        &self,
+        tax_year: i32,
+        filing_status_id: i32,
+    ) -> Result<(), RepositoryError> {
+        self.store_mut()
+            .tax_brackets
+            .retain(|b| !(b.tax_year == tax_year && b.filing_status_id == filing_status_id));
+        Ok(())
+    }
+
+    async fn create_estimate(
+        &self,
+        estimate: TaxEstimateInput,
+    ) -> Result<TaxEstimate, RepositoryError> {
+        self.upsert_estimate(estimate, None)
+    }
+
+    async fn create_calculated_estimate(
+        &self,
+        estimate: TaxEstimateInput,
+        computed: TaxEstimateComputed,
+    ) -> Result<TaxEstimate, RepositoryError> {
+        self.upsert_estimate(estimate, Some(computed))
+    }
+
+    async fn get_estimate(
+        &self,
+        id: i64,
+    ) -> Result<TaxEstimate, RepositoryError> {
+        self.store().live_estimate(id).cloned()
+    }
+
+    async fn update_estimate(
+        &self,
+        estimate: &TaxEstimate,
+    ) -> Result<(), RepositoryError> {
+        let now = Utc::now();
+        let mut store = self.store_mut();
+        let filing_status_id = store.filing_status_id_for_code(estimate.input.filing_status)?;
+        store.live_estimate(estimate.id)?;
+        store.check_references(estimate.input.tax_year, filing_status_id)?;
+
+        let mut input = estimate.input.clone();
+        input.scenario_name = input.scenario_name.trim().to_string();
+        if store
+            .scenario_id(&input)
+            .is_some_and(|other| other != estimate.id)
+        {
+            return Err(RepositoryError::Database(anyhow!(
+                "scenario '{}' already exists for {} {}",
+                input.scenario_name,
+                input.tax_year,
+                input.filing_status.as_str()
+            )));
+        }
+
+        let existing = store
+            .estimates
+            .get_mut(&estimate.id)
+            .ok_or(RepositoryError::NotFound)?;
+        existing.input = input;
+        existing.computed = estimate.computed.clone();
+        existing.updated_at = now;
+        store.record_revision(estimate.id);
+        Ok(())
+    }
+
+    async fn delete_estimate(
+        &self,
+        id: i64,
+    ) -> Result<(), RepositoryError> {
+        let mut store = self.store_mut();
+        store.live_estimate(id)?;
+        if let Some(estimate) = store.estimates.get_mut(&id) {
+            estimate.deleted_at = Some(Utc::now());
+        }
+        Ok(())
+    }
+
+    async fn list_estimates(
+        &self,
+        tax_year: Option<i32>,
+    ) -> Result<Vec<TaxEstimate>, RepositoryError> {
+        let estimates = self
+            .store()
+            .estimates
+            .values()
+            .filter(|e| e.deleted_at.is_none())
+            .filter(|e| tax_year.is_none_or(|year| e.input.tax_year == year))
+            .cloned()
+            .collect();
+        Ok(by_updated_desc(estimates))
+    }
+
+    async fn get_estimate_by_name(
+        &self,
+        tax_year: i32,
+        filing_status: FilingStatusCode,
+        scenario_name: &str,
+    ) -> Result<TaxEstimate, RepositoryError> {
+        let scenario_name = scenario_name.trim();
+        self.store()
+            .estimates
+            .values()
+            .find(|e| {
+                e.deleted_at.is_none()
+                    && e.input.tax_year == tax_year
+                    && e.input.filing_status == filing_status
+                    && e.input.scenario_name == scenario_name
+            })
+            .cloned()
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    async fn list_estimates_by_name(
+        &self,
+        scenario_name: &str,
+        tax_year: Option<i32>,
+    ) -> Result<Vec<TaxEstimate>, RepositoryError> {
+        let scenario_name = scenario_name.trim();
+        let estimates = self
+            .store()
+            .estimates
+            .values()
+            .filter(|e| e.deleted_at.is_none() && e.input.scenario_name == scenario_name)
+            .filter(|e| tax_year.is_none_or(|year| e.input.tax_year == year))
+            .cloned()
+            .collect();
+        Ok(by_updated_desc(estimates))
+    }
+
+    async fn list_estimate_revisions(
+        &self,
+        estimate_id: i64,
+    ) -> Result<Vec<TaxEstimateRevision>, RepositoryError> {
+        let mut revisions: Vec<TaxEstimateRevision> = self
+            .store()
+            .revisions
+            .iter()
+            .filter(|r| r.estimate_id == estimate_id)
+            .cloned()
+            .collect();
+        revisions.sort_by_key(|r| r.revision);
+        Ok(revisions)
+    }
+
+    async fn restore_estimate_revision(
+        &self,
+        revision_id: i64,
+    ) -> Result<TaxEstimate, RepositoryError> {
+        let revision = self
+            .store()
+            .revisions
+            .iter()
+            .find(|r| r.id == revision_id)
+            .cloned()
+            .ok_or(RepositoryError::NotFound)?;
+
+        let current = self.get_estimate(revision.estimate_id).await?;
+        let other = self
+            .store()
+            .scenario_id(&revision.input)
+            .filter(|&id| id != current.id);
+        if let Some(id) = other {
+            let scenario_name = revision.input.scenario_name;
+            return Err(if self.store().live_estimate(id).is_ok() {
+                RepositoryError::ScenarioTaken { id, scenario_name }
+            } else {
+                RepositoryError::EstimateInTrash { id, scenario_name }
+            });
+        }
+        self.update_estimate(&TaxEstimate {
+            input: revision.input,
+            computed: revision.computed,
+            ..current
+        })
+        .await?;
+        self.get_estimate(revision.estimate_id).await
+    }
+
+    async fn list_deleted_estimates(&self) -> Result<Vec<TaxEstimate>, RepositoryError> {
+        let mut estimates: Vec<TaxEstimate> = self
+            .store()
+            .estimates
+            .values()
+            .filter(|e| e.deleted_at.is_some())
+            .cloned()
+            .collect();
+        estimates.sort_by_key(|e| Reverse(e.deleted_at));
+        Ok(estimates)
+    }
+
+    async fn restore_deleted_estimate(
+        &self,
+        id: i64,
+    ) -> Result<TaxEstimate, RepositoryError> {
+        let mut store = self.store_mut();
+        let estimate = store
+            .estimates
+            .get_mut(&id)
+            .filter(|e| e.deleted_at.is_some())
+            .ok_or(RepositoryError::NotFound)?;
+        estimate.deleted_at = None;
+        Ok(estimate.clone())
+    }
+
+    async fn purge_deleted_estimates(
+        &self,
+        retention: chrono::Duration,
+    ) -> Result<u64, RepositoryError> {
+        let cutoff: DateTime<Utc> = Utc::now() - retention;
+        let mut store = self.store_mut();
+        let purged: Vec<i64> = store
+            .estimates
+            .values()
+            .filter(|e| e.deleted_at.is_some_and(|deleted_at| deleted_at < cutoff))
+            .map(|e| e.id)
+            .collect();
+        for id in &purged {
+            store.estimates.remove(id);
+        }
+        store.revisions.retain(|r| !purged.contains(&r.estimate_id));
+        Ok(purged.len() as u64)
+    }
+
+    async fn begin(&self) -> Result<Box<dyn TaxTransaction>, RepositoryError> {
+        if self.commit_target.is_some() {
+            return Err(RepositoryError::Configuration(
+                "Nested transactions are not supported".to_string(),
+            ));
+        }
+        let snapshot = self.store().clone();
+        let generation = snapshot.generation;
+        Ok(Box::new(Self {
+            store: Arc::new(Mutex::new(snapshot)),
+            commit_target: Some((Arc::clone(&self.store), generation)),
+        }))
+    }
+}
+
+#[async_trait]
+impl TaxTransaction for MemoryRepository {
+    async fn commit(self: Box<Self>) -> Result<(), RepositoryError> {
+        let staged = std::mem::take(&mut *self.store());
+        let (target, generation) = self.into_commit_target()?;
+        let mut target = lock(&target);
+        if target.generation != generation {
+            return Err(RepositoryError::Database(anyhow!(
+                "the repository was changed while the transaction was open"
+            )));
+        }
+        *target = Store {
+            generation: generation + 1,
+            ..staged
+        };
+        Ok(())
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<(), RepositoryError> {
+        self.into_commit_target().map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+    use tax_core::{DEFAULT_SCENARIO_NAME, TaxEstimateComputed};
+
+    use super::*;
+
+    fn estimate_input(
+        tax_year: i32,
+        scenario_name: &str,
+    ) -> TaxEstimateInput {
+        TaxEstimateInput {
+            tax_year,
+            filing_status: FilingStatusCode::Single,
+            scenario_name: scenario_name.to_string(),
+            se_income: None,
+            expected_crp_payments: None,
+            expected_wages: None,
+            net_investment_income: None,
+            qualified_dividends: None,
+            net_capital_gain: None,
+            expected_agi: dec!(90000.00),
+            expected_deduction: dec!(15750.00),
+            expected_qbi_deduction: None,
+            expected_amt: None,
+            expected_credits: None,
+            expected_other_taxes: None,
+            expected_withholding: None,
+            prior_year_tax: None,
+            prior_year_agi: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn new_repository_is_empty() {
+        let repo = MemoryRepository::new();
+
+        assert!(repo.list_filing_statuses().await.unwrap().is_empty());
+        assert!(repo.list_tax_years().await.unwrap().is_empty());
+        assert!(matches!(
+            repo.get_tax_year_config(2025).await,
+            Err(RepositoryError::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn reference_data_is_preloaded() {
+        let repo = MemoryRepository::with_reference_data();
+
+        assert_eq!(repo.list_tax_years().await.unwrap(), vec![2026, 2025]);
+        let single = repo.get_filing_status_by_code("S").await.unwrap();
+        assert_eq!(single.id, 1);
+        assert_eq!(single.status_name, "Single");
+        let deduction = repo.get_standard_deduction(2025, single.id).await.unwrap();
+        assert_eq!(deduction.amount, dec!(15750.00));
+        let brackets = repo.get_tax_brackets(2025, single.id).await.unwrap();
+        assert_eq!(brackets.len(), 7);
+        assert_eq!(brackets[6].max_income, None);
+    }
+
+    #[tokio::test]
+    async fn missing_reference_rows_are_not_found() {
+        let repo = MemoryRepository::with_reference_data();
+
+        assert!(matches!(
+            repo.get_filing_status(99).await,
+            Err(RepositoryError::NotFound)
+        ));
+        assert!(matches!(
+            repo.get_niit_threshold(1999, 1).await,
+            Err(RepositoryError::NotFound)
+        ));
+        assert!(repo.get_tax_brackets(1999, 1).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn upserts_require_existing_tax_year() {
+        let repo = MemoryRepository::with_reference_data();
+
+        let result = repo
+            .upsert_qbi_threshold(&QbiThreshold {
+                tax_year: 1999,
+                filing_status_id: 1,
+                amount: dec!(100000.00),
+            })
+            .await;
+
+        assert!(matches!(result, Err(RepositoryError::Database(_))));
+    }
+
+    #[tokio::test]
+    async fn insert_tax_bracket_rejects_duplicate_min_income() {
+        let repo = MemoryRepository::with_reference_data();
+        let bracket = repo.get_tax_brackets(2025, 1).await.unwrap()[0].clone();
+
+        let result = repo.insert_tax_bracket(&bracket).await;
+
+        assert!(matches!(result, Err(RepositoryError::Database(_))));
+    }
+
+    #[tokio::test]
+    async fn get_filing_status_data_groups_brackets_in_order() {
+        let repo = MemoryRepository::with_reference_data();
+        repo.delete_tax_brackets(2025, 2).await.unwrap();
+
+        let data = repo.get_filing_status_data(2025).await.unwrap();
+
+        let ids: Vec<i32> = data.iter().map(|(fs, _, _)| fs.id).collect();
+        assert_eq!(ids, vec![1, 2, 3, 4, 5]);
+        assert!(data[1].2.is_empty());
+        let mins: Vec<_> = data[0].2.iter().map(|b| b.min_income).collect();
+        let mut sorted = mins.clone();
+        sorted.sort();
+        assert_eq!(mins, sorted);
+    }
+
+    #[tokio::test]
+    async fn create_estimate_replaces_same_scenario() {
+        let repo = MemoryRepository::with_reference_data();
+        let first = repo
+            .create_estimate(estimate_input(2025, DEFAULT_SCENARIO_NAME))
+            .await
+            .unwrap();
+        let mut computed = first.clone();
+        computed.computed = Some(TaxEstimateComputed {
+            se_tax: dec!(0),
+            total_tax: dec!(10000.00),
+            required_payment: dec!(9000.00),
+        });
+        repo.update_estimate(&computed).await.unwrap();
+
+        let mut input = estimate_input(2025, &format!(" {DEFAULT_SCENARIO_NAME} "));
+        input.expected_agi = dec!(120000.00);
+        let second = repo.create_estimate(input).await.unwrap();
+
+        assert_eq!(second.id, first.id);
+        assert_eq!(second.input.expected_agi, dec!(120000.00));
+        assert_eq!(second.input.scenario_name, DEFAULT_SCENARIO_NAME);
+        assert_eq!(second.computed, None);
+        assert_eq!(repo.list_estimates(None).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn create_estimate_requires_tax_year_config() {
+        let repo = MemoryRepository::with_reference_data();
+
+        let result = repo
+            .create_estimate(estimate_input(1999, DEFAULT_SCENARIO_NAME))
+            .await;
+
+        assert!(matches!(result, Err(RepositoryError::Database(_))));
+    }
+
+    #[tokio::test]
+    async fn update_estimate_rejects_taken_scenario_name() {
+        let repo = MemoryRepository::with_reference_data();
+        repo.create_estimate(estimate_input(2025, "expected"))
+            .await
+            .unwrap();
+        let mut optimistic = repo
+            .create_estimate(estimate_input(2025, "optimistic"))
+            .await
+            .unwrap();
+
+        optimistic.input.scenario_name = "expected".to_string();
+        let result = repo.update_estimate(&optimistic).await;
+
+        assert!(matches!(result, Err(RepositoryError::Database(_))));
+    }
+
+    #[tokio::test]
+    async fn list_estimates_filters_by_year_newest_first() {
+        let repo = MemoryRepository::with_reference_data();
+        let older = repo
+            .create_estimate(estimate_input(2025, "expected"))
+            .await
+            .unwrap();
+        let other_year = repo
+            .create_estimate(estimate_input(2026, "expected"))
+            .await
+            .unwrap();
+        let newer = repo
+            .create_estimate(estimate_input(2025, "optimistic"))
+            .await
+            .unwrap();
+        repo.update_estimate(&older).await.unwrap();
+
+        let ids: Vec<i64> = repo
+            .list_estimates(Some(2025))
+            .await
+            .unwrap()
+            .iter()
+            .map(|e| e.id)
+            .collect();
+        let by_name: Vec<i64> = repo
+            .list_estimates_by_name("expected", None)
+            .await
+            .unwrap()
+            .iter()
+            .map(|e| e.id)
+            .collect();
+
+        assert_eq!(ids, vec![older.id, newer.id]);
+        assert_eq!(by_name, vec![older.id, other_year.id]);
+    }
+
+    #[tokio::test]
+    async fn updates_are_recorded_as_revisions_and_restorable() {
+        let repo = MemoryRepository::with_reference_data();
+        let created = repo
+            .create_estimate(estimate_input(2025, DEFAULT_SCENARIO_NAME))
+            .await
+            .unwrap();
+        let mut changed = created.clone();
+        changed.input.expected_agi = dec!(150000.00);
+        repo.update_estimate(&changed).await.unwrap();
+
+        let revisions = repo.list_estimate_revisions(created.id).await.unwrap();
+        assert_eq!(
+            revisions.iter().map(|r| r.revision).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+
+        let restored = repo
+            .restore_estimate_revision(revisions[0].id)
+            .await
+            .unwrap();
+
+        assert_eq!(restored.input.expected_agi, dec!(90000.00));
+        assert_eq!(
+            repo.list_estimate_revisions(created.id)
+                .await
+                .unwrap()
+                .len(),
+            3
+        );
+        assert!(matches!(
+            repo.restore_estimate_revision(999).await,
+            Err(RepositoryError::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn deleted_estimates_move_to_trash_until_purged() {
+        let repo = MemoryRepository::with_reference_data();
+        let estimate = repo
+            .create_estimate(estimate_input(2025, DEFAULT_SCENARIO_NAME))
+            .await
+            .unwrap();
+
+        repo.delete_estimate(estimate.id).await.unwrap();
+
+        assert!(matches!(
+            repo.get_estimate(estimate.id).await,
+            Err(RepositoryError::NotFound)
+        ));
+        assert!(matches!(
+            repo.delete_estimate(estimate.id).await,
+            Err(RepositoryError::NotFound)
+        ));
+        assert_eq!(repo.list_deleted_estimates().await.unwrap().len(), 1);
+        assert_eq!(
+            repo.purge_deleted_estimates(Duration::days(30))
+                .await
+                .unwrap(),
+            0
+        );
+
+        let restored = repo.restore_deleted_estimate(estimate.id).await.unwrap();
+        assert_eq!(restored.deleted_at, None);
+        assert_eq!(
+            repo.list_estimate_revisions(estimate.id)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+
+        repo.delete_estimate(estimate.id).await.unwrap();
+        assert_eq!(
+            repo.purge_deleted_estimates(Duration::zero())
+                .await
+                .unwrap(),
+            1
+        );
+        assert!(repo.list_deleted_estimates().await.unwrap().is_empty());
+        assert!(
+            repo.list_estimate_revisions(estimate.id)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn commit_fails_if_repository_changed_after_begin() {
+        let repo = MemoryRepository::with_reference_data();
+        let tx = repo.begin().await.unwrap();
+        tx.create_estimate(estimate_input(2025, "in transaction"))
+            .await
+            .unwrap();
+
+        repo.create_estimate(estimate_input(2025, "outside"))
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            tx.commit().await,
+            Err(RepositoryError::Database(_))
+        ));
+        let names: Vec<_> = repo
+            .list_estimates(None)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|e| e.input.scenario_name)
+            .collect();
+        assert_eq!(names, vec!["outside"]);
+    }
+
+    async fn seeded_repository() -> MemoryRepository {
+        MemoryRepository::with_reference_data()
+    }
+
+    tax_core::repository_conformance_tests!(seeded_repository);
+}
@@ -0,0 +1,125 @@
+//! Checks that the in-memory backend's reference data matches what the SQLite
+//! seed files load, through every reference lookup on [`TaxRepository`].
+
+use pretty_assertions::assert_eq;
+use tax_core::{RepositoryError, TaxRepository};
+use tax_db_memory::MemoryRepository;
+use tax_db_sqlite::SqliteRepository;
+
+async fn seeded_sqlite() -> SqliteRepository {
+    let repo = SqliteRepository::new(":memory:")
+        .await
+        .expect("Failed to create in-memory database");
+    repo.run_migrations()
+        .await
+        .expect("Failed to run migrations");
+    repo.run_embedded_seeds()
+        .await
+        .expect("Failed to run seeds");
+    repo
+}
+
+#[tokio::test]
+async fn reference_data_matches_sqlite_seeds() {
+    let sqlite = seeded_sqlite().await;
+    let memory = MemoryRepository::with_reference_data();
+
+    let years = sqlite.list_tax_years().await.unwrap();
+    assert!(!years.is_empty(), "seeds should load at least one tax year");
+    assert_eq!(memory.list_tax_years().await.unwrap(), years);
+    let statuses = sqlite.list_filing_statuses().await.unwrap();
+    assert_eq!(memory.list_filing_statuses().await.unwrap(), statuses);
+    for status in &statuses {
+        assert_eq!(
+            memory.get_filing_status(status.id).await.unwrap(),
+            sqlite.get_filing_status(status.id).await.unwrap()
+        );
+        let code = status.status_code.as_str();
+        assert_eq!(
+            memory.get_filing_status_by_code(code).await.unwrap(),
+            sqlite.get_filing_status_by_code(code).await.unwrap()
+        );
+    }
+    assert_eq!(
+        memory.list_underpayment_interest_rates().await.unwrap(),
+        sqlite.list_underpayment_interest_rates().await.unwrap()
+    );
+
+    for &year in &years {
+        assert_eq!(
+            memory.get_tax_year_config(year).await.unwrap(),
+            sqlite.get_tax_year_config(year).await.unwrap()
+        );
+        assert_eq!(
+            memory.get_filing_status_data(year).await.unwrap(),
+            sqlite.get_filing_status_data(year).await.unwrap()
+        );
+        for status in &statuses {
+            let id = status.id;
+            assert_eq!(
+                memory.get_standard_deduction(year, id).await.unwrap(),
+                sqlite.get_standard_deduction(year, id).await.unwrap()
+            );
+            assert_eq!(
+                memory.get_tax_brackets(year, id).await.unwrap(),
+                sqlite.get_tax_brackets(year, id).await.unwrap()
+            );
+            assert_eq!(
+                memory
+                    .get_additional_medicare_threshold(year, id)
+                    .await
+                    .unwrap(),
+                sqlite
+                    .get_additional_medicare_threshold(year, id)
+                    .await
+                    .unwrap()
+            );
+            assert_eq!(
+                memory.get_niit_threshold(year, id).await.unwrap(),
+                sqlite.get_niit_threshold(year, id).await.unwrap()
+            );
+            assert_eq!(
+                memory.get_capital_gain_breakpoints(year, id).await.unwrap(),
+                sqlite.get_capital_gain_breakpoints(year, id).await.unwrap()
+            );
+            assert_eq!(
+                memory.get_qbi_threshold(year, id).await.unwrap(),
+                sqlite.get_qbi_threshold(year, id).await.unwrap()
+            );
+        }
+    }
+}
+
+/// A year neither backend has is `NotFound` from both, not empty data.
+#[tokio::test]
+async fn unknown_year_is_not_found_in_both() {
+    let sqlite = seeded_sqlite().await;
+    let memory = MemoryRepository::with_reference_data();
+
+    for repo in [&sqlite as &dyn TaxRepository, &memory] {
+        assert!(matches!(
+            repo.get_tax_year_config(1999).await,
+            Err(RepositoryError::NotFound)
+        ));
+        assert!(matches!(
+            repo.get_standard_deduction(1999, 1).await,
+            Err(RepositoryError::NotFound)
+        ));
+        assert!(matches!(
+            repo.get_additional_medicare_threshold(1999, 1).await,
+            Err(RepositoryError::NotFound)
+        ));
+        assert!(matches!(
+            repo.get_niit_threshold(1999, 1).await,
+            Err(RepositoryError::NotFound)
+        ));
+        assert!(matches!(
+            repo.get_capital_gain_breakpoints(1999, 1).await,
+            Err(RepositoryError::NotFound)
+        ));
+        assert!(matches!(
+            repo.get_qbi_threshold(1999, 1).await,
+            Err(RepositoryError::NotFound)
+        ));
+    }
+}
@@ -0,0 +1,215 @@
+-- PostgreSQL schema matching the final state of the SQLite migrations.
+--
+-- Every amount and rate is an unconstrained NUMERIC, which keeps the exact
+-- digits and scale that were written.
+
+CREATE TABLE tax_year_config (
+    tax_year INTEGER PRIMARY KEY,
+    ss_wage_max NUMERIC NOT NULL,
+    ss_tax_rate NUMERIC NOT NULL,
+    medicare_tax_rate NUMERIC NOT NULL,
+    se_tax_deductible_percentage NUMERIC NOT NULL,
+    se_deduction_factor NUMERIC NOT NULL,
+    required_payment_threshold NUMERIC NOT NULL,
+    min_se_threshold NUMERIC NOT NULL DEFAULT 400.00,
+    additional_medicare_tax_rate NUMERIC NOT NULL DEFAULT 0.009,
+    niit_rate NUMERIC NOT NULL DEFAULT 0.038,
+    prior_agi_threshold NUMERIC NOT NULL DEFAULT 150000.00,
+    prior_agi_threshold_mfs NUMERIC NOT NULL DEFAULT 75000.00,
+    prior_tax_multiplier NUMERIC NOT NULL DEFAULT 1.10
+);
+
+CREATE TABLE filing_status (
+    id INTEGER PRIMARY KEY,
+    status_code VARCHAR(3) NOT NULL UNIQUE,
+    status_name VARCHAR(50) NOT NULL
+);
+
+CREATE TABLE standard_deductions (
+    tax_year INTEGER NOT NULL REFERENCES tax_year_config (tax_year),
+    filing_status_id INTEGER NOT NULL REFERENCES filing_status (id),
+    amount NUMERIC NOT NULL,
+    PRIMARY KEY (tax_year, filing_status_id)
+);
+
+CREATE TABLE tax_brackets (
+    tax_year INTEGER NOT NULL REFERENCES tax_year_config (tax_year),
+    filing_status_id INTEGER NOT NULL REFERENCES filing_status (id),
+    min_income NUMERIC NOT NULL,
+    max_income NUMERIC,
+    tax_rate NUMERIC NOT NULL,
+    base_tax NUMERIC NOT NULL,
+    PRIMARY KEY (tax_year, filing_status_id, min_income)
+);
+
+CREATE TABLE additional_medicare_thresholds (
+    tax_year INTEGER NOT NULL REFERENCES tax_year_config (tax_year),
+    filing_status_id INTEGER NOT NULL REFERENCES filing_status (id),
+    amount NUMERIC NOT NULL,
+    PRIMARY KEY (tax_year, filing_status_id)
+);
+
+CREATE TABLE niit_thresholds (
+    tax_year INTEGER NOT NULL REFERENCES tax_year_config (tax_year),
+    filing_status_id INTEGER NOT NULL REFERENCES filing_status (id),
+    amount NUMERIC NOT NULL,
+    PRIMARY KEY (tax_year, filing_status_id)
+);
+
+CREATE TABLE underpayment_interest_rates (
+    year INTEGER NOT NULL,
+    quarter INTEGER NOT NULL CHECK (quarter BETWEEN 1 AND 4),
+    rate NUMERIC NOT NULL,
+    PRIMARY KEY (year, quarter)
+);
+
+CREATE TABLE capital_gain_breakpoints (
+    tax_year INTEGER NOT NULL REFERENCES tax_year_config (tax_year),
+    filing_status_id INTEGER NOT NULL REFERENCES filing_status (id),
+    zero_rate_max NUMERIC NOT NULL,
+    fifteen_rate_max NUMERIC NOT NULL,
+    PRIMARY KEY (tax_year, filing_status_id)
+);
+
+CREATE TABLE qbi_thresholds (
+    tax_year INTEGER NOT NULL REFERENCES tax_year_config (tax_year),
+    filing_status_id INTEGER NOT NULL REFERENCES filing_status (id),
+    amount NUMERIC NOT NULL,
+    PRIMARY KEY (tax_year, filing_status_id)
+);
+
+CREATE TABLE tax_estimate (
+    id BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    tax_year INTEGER NOT NULL REFERENCES tax_year_config (tax_year),
+    filing_status_id INTEGER NOT NULL REFERENCES filing_status (id),
+    scenario_name TEXT NOT NULL DEFAULT 'Default',
+
+    -- User-provided values (1040-ES Worksheet inputs)
+    expected_agi NUMERIC NOT NULL DEFAULT 0,
+    expected_deduction NUMERIC NOT NULL DEFAULT 0,
+    expected_qbi_deduction NUMERIC,
+    expected_amt NUMERIC,
+    expected_credits NUMERIC,
+    expected_other_taxes NUMERIC,
+    expected_withholding NUMERIC,
+    prior_year_tax NUMERIC,
+    prior_year_agi NUMERIC,
+
+    -- User-provided values (SE Worksheet and investment income inputs)
+    se_income NUMERIC,
+    expected_crp_payments NUMERIC,
+    expected_wages NUMERIC,
+    net_investment_income NUMERIC,
+    qualified_dividends NUMERIC,
+    net_capital_gain NUMERIC,
+
+    -- Calculated values
+    calculated_se_tax NUMERIC,
+    calculated_total_tax NUMERIC,
+    calculated_required_payment NUMERIC,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    deleted_at TIMESTAMPTZ,
+
+    UNIQUE (tax_year, filing_status_id, scenario_name)
+);
+
+CREATE INDEX ix_tax_estimate_deleted_at ON tax_estimate (deleted_at);
+
+-- Append-only history of every tax_estimate create and update.
+CREATE TABLE tax_estimate_revision (
+    id BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    estimate_id BIGINT NOT NULL REFERENCES tax_estimate (id) ON DELETE CASCADE,
+    revision BIGINT NOT NULL,
+
+    tax_year INTEGER NOT NULL,
+    filing_status_id INTEGER NOT NULL REFERENCES filing_status (id),
+    scenario_name TEXT NOT NULL,
+    expected_agi NUMERIC NOT NULL,
+    expected_deduction NUMERIC NOT NULL,
+    expected_qbi_deduction NUMERIC,
+    expected_amt NUMERIC,
+    expected_credits NUMERIC,
+    expected_other_taxes NUMERIC,
+    expected_withholding NUMERIC,
+    prior_year_tax NUMERIC,
+    prior_year_agi NUMERIC,
+    se_income NUMERIC,
+    expected_crp_payments NUMERIC,
+    expected_wages NUMERIC,
+    net_investment_income NUMERIC,
+    qualified_dividends NUMERIC,
+    net_capital_gain NUMERIC,
+    calculated_se_tax NUMERIC,
+    calculated_total_tax NUMERIC,
+    calculated_required_payment NUMERIC,
+
+    recorded_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    UNIQUE (estimate_id, revision)
+);
+
+CREATE FUNCTION record_tax_estimate_revision() RETURNS trigger AS $$
+BEGIN
+    INSERT INTO tax_estimate_revision (
+        estimate_id, revision, tax_year, filing_status_id, scenario_name, expected_agi,
+        expected_deduction, expected_qbi_deduction, expected_amt, expected_credits,
+        expected_other_taxes, expected_withholding, prior_year_tax, prior_year_agi,
+        se_income, expected_crp_payments, expected_wages, net_investment_income,
+        qualified_dividends, net_capital_gain, calculated_se_tax, calculated_total_tax,
+        calculated_required_payment, recorded_at
+    ) VALUES (
+        NEW.id,
+        (SELECT COALESCE(MAX(revision), 0) + 1
+         FROM tax_estimate_revision
+         WHERE estimate_id = NEW.id),
+        NEW.tax_year, NEW.filing_status_id, NEW.scenario_name, NEW.expected_agi,
+        NEW.expected_deduction, NEW.expected_qbi_deduction, NEW.expected_amt,
+        NEW.expected_credits, NEW.expected_other_taxes, NEW.expected_withholding,
+        NEW.prior_year_tax, NEW.prior_year_agi, NEW.se_income, NEW.expected_crp_payments,
+        NEW.expected_wages, NEW.net_investment_income, NEW.qualified_dividends,
+        NEW.net_capital_gain, NEW.calculated_se_tax, NEW.calculated_total_tax,
+        NEW.calculated_required_payment, NEW.updated_at
+    );
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER tr_tax_estimate_record_insert
+AFTER INSERT ON tax_estimate
+FOR EACH ROW EXECUTE FUNCTION record_tax_estimate_revision();
+
+-- Moving an estimate in or out of the trash is not a change to its values, so
+-- only updates that touch the estimate's data columns record a revision.
+CREATE TRIGGER tr_tax_estimate_record_update
+AFTER UPDATE OF
+    tax_year, filing_status_id, scenario_name, expected_agi, expected_deduction,
+    expected_qbi_deduction, expected_amt, expected_credits, expected_other_taxes,
+    expected_withholding, prior_year_tax, prior_year_agi, se_income, expected_crp_payments,
+    expected_wages, net_investment_income, qualified_dividends, net_capital_gain,
+    calculated_se_tax, calculated_total_tax, calculated_required_payment
+ON tax_estimate
+FOR EACH ROW EXECUTE FUNCTION record_tax_estimate_revision();
+
+-- Revisions are never edited, and only disappear along with their estimate.
+CREATE FUNCTION reject_tax_estimate_revision_change() RETURNS trigger AS $$
+BEGIN
+    IF TG_OP = 'DELETE'
+        AND NOT EXISTS (SELECT 1 FROM tax_estimate WHERE id = OLD.estimate_id) THEN
+        RETURN OLD;
+    END IF;
+    RAISE EXCEPTION 'tax_estimate_revision is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER tr_tax_estimate_revision_append_only
+BEFORE UPDATE OR DELETE ON tax_estimate_revision
+FOR EACH ROW EXECUTE FUNCTION reject_tax_estimate_revision_change();
+
+-- Seed files applied by PostgresRepository::run_seeds, with a checksum of the
+-- contents each was applied with.
+CREATE TABLE seed_history (
+    file_name TEXT PRIMARY KEY,
+    checksum TEXT NOT NULL,
+    applied_at TIMESTAMPTZ NOT NULL
+);
@@ -0,0 +1,5 @@
+pub mod factory;
+pub mod repository;
+
+pub use factory::PostgresRepositoryFactory;
+pub use repository::PostgresRepository;
@@ -0,0 +1,324 @@
+//! Integration tests against a running PostgreSQL server.
+//!
+//! Set `TAX_DB_POSTGRES_TEST_URL` to a database the tests may create schemas
+//! in, e.g. `postgres://postgres@127.0.0.1:5432/postgres`. Each test runs in a
+//! schema of its own, dropped when it finishes. The tests are ignored by
+//! default; run them with `cargo test -p tax-db-postgres -- --ignored`.
+
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use chrono::Duration;
+use pretty_assertions::assert_eq;
+use rust_decimal_macros::dec;
+use sqlx::postgres::{PgConnectOptions, PgPool, PgPoolOptions};
+use tax_core::db::{DbConfig, RepositoryRegistry};
+use tax_core::{
+    DEFAULT_SCENARIO_NAME, FilingStatusCode, RepositoryError, TaxEstimateComputed,
+    TaxEstimateInput, TaxRepository, UnderpaymentInterestRate,
+};
+use tax_db_postgres::{PostgresRepository, PostgresRepositoryFactory};
+
+const TEST_URL_VAR: &str = "TAX_DB_POSTGRES_TEST_URL";
+
+/// A migrated repository confined to a scratch schema.
+struct TestDb {
+    admin: PgPool,
+    schema: String,
+    repo: PostgresRepository,
+}
+
+impl TestDb {
+    async fn drop_schema(self) {
+        self.repo.pool().close().await;
+        sqlx::raw_sql(sqlx::AssertSqlSafe(format!(
+            "DROP SCHEMA {} CASCADE",
+            self.schema
+        )))
+        .execute(&self.admin)
+        .await
+        .expect("Failed to drop test schema");
+    }
+}
+
+fn test_url() -> String {
+    std::env::var(TEST_URL_VAR)
+        .unwrap_or_else(|_| panic!("{TEST_URL_VAR} must name a PostgreSQL database"))
+}
+
+async fn setup_test_db(url: &str) -> TestDb {
+    static NEXT: AtomicU32 = AtomicU32::new(0);
+    let schema = format!(
+        "tax_test_{}_{}",
+        std::process::id(),
+        NEXT.fetch_add(1, Ordering::Relaxed)
+    );
+
+    let admin = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(url)
+        .await
+        .expect("Failed to connect to PostgreSQL");
+    sqlx::raw_sql(sqlx::AssertSqlSafe(format!("CREATE SCHEMA {schema}")))
+        .execute(&admin)
+        .await
+        .expect("Failed to create test schema");
+
+    let options = PgConnectOptions::from_str(url)
+        .expect("Invalid test URL")
+        .options([("search_path", schema.as_str())]);
+    let pool = PgPoolOptions::new()
+        .connect_with(options)
+        .await
+        .expect("Failed to connect to test schema");
+    let repo = PostgresRepository::new_with_pool(pool).await;
+    repo.run_migrations()
+        .await
+        .expect("Failed to run migrations");
+    repo.run_embedded_seeds()
+        .await
+        .expect("Failed to run seeds");
+
+    TestDb {
+        admin,
+        schema,
+        repo,
+    }
+}
+
+fn estimate_input(scenario_name: &str) -> TaxEstimateInput {
+    TaxEstimateInput {
+        tax_year: 2025,
+        filing_status: FilingStatusCode::Single,
+        scenario_name: scenario_name.to_string(),
+        se_income: Some(dec!(50000.00)),
+        expected_crp_payments: None,
+        expected_wages: Some(dec!(50000.00)),
+        net_investment_income: None,
+        qualified_dividends: None,
+        net_capital_gain: None,
+        expected_agi: dec!(90000.00),
+        expected_deduction: dec!(15750.00),
+        expected_qbi_deduction: None,
+        expected_amt: None,
+        expected_credits: None,
+        expected_other_taxes: None,
+        expected_withholding: Some(dec!(8000.00)),
+        prior_year_tax: None,
+        prior_year_agi: None,
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires TAX_DB_POSTGRES_TEST_URL"]
+async fn passes_repository_conformance_suite() {
+    let url = test_url();
+    let db = setup_test_db(&url).await;
+
+    tax_core::testkit::run_all(&db.repo).await;
+
+    db.drop_schema().await;
+}
+
+#[tokio::test]
+#[ignore = "requires TAX_DB_POSTGRES_TEST_URL"]
+async fn seeds_load_reference_data_once() {
+    let url = test_url();
+    let db = setup_test_db(&url).await;
+    let repo = &db.repo;
+
+    assert!(repo.run_embedded_seeds().await.unwrap().is_empty());
+    assert_eq!(repo.list_tax_years().await.unwrap(), vec![2026, 2025]);
+    let single = repo.get_filing_status_by_code("S").await.unwrap();
+    assert_eq!(single.id, 1);
+    let config = repo.get_tax_year_config(2025).await.unwrap();
+    assert_eq!(config.ss_wage_max, dec!(176100.00));
+    let brackets = repo.get_tax_brackets(2025, single.id).await.unwrap();
+    assert_eq!(brackets.len(), 7);
+    assert_eq!(brackets[6].max_income, None);
+    let qss = repo.get_filing_status_by_code("QSS").await.unwrap();
+    for year in [2025, 2026] {
+        assert_eq!(
+            repo.get_additional_medicare_threshold(year, qss.id)
+                .await
+                .unwrap()
+                .amount,
+            dec!(200000.00)
+        );
+        assert_eq!(
+            repo.get_niit_threshold(year, qss.id).await.unwrap().amount,
+            dec!(250000.00)
+        );
+    }
+    assert!(matches!(
+        repo.get_tax_year_config(1999).await,
+        Err(RepositoryError::NotFound)
+    ));
+
+    db.drop_schema().await;
+}
+
+#[tokio::test]
+#[ignore = "requires TAX_DB_POSTGRES_TEST_URL"]
+async fn numeric_columns_keep_exact_values() {
+    let url = test_url();
+    let db = setup_test_db(&url).await;
+    let repo = &db.repo;
+
+    let rate = UnderpaymentInterestRate {
+        year: 2030,
+        quarter: 2,
+        rate: dec!(0.0712345678901234),
+    };
+    repo.upsert_underpayment_interest_rate(&rate).await.unwrap();
+    let rates = repo.list_underpayment_interest_rates().await.unwrap();
+    assert_eq!(rates.last(), Some(&rate));
+
+    let mut input = estimate_input(DEFAULT_SCENARIO_NAME);
+    input.expected_agi = dec!(123456789.123456789);
+    let estimate = repo.create_estimate(input).await.unwrap();
+    assert_eq!(
+        estimate.input.expected_agi.to_string(),
+        "123456789.123456789"
+    );
+    assert_eq!(estimate.input.expected_deduction.to_string(), "15750.00");
+
+    db.drop_schema().await;
+}
+
+#[tokio::test]
+#[ignore = "requires TAX_DB_POSTGRES_TEST_URL"]
+async fn estimates_round_trip_with_revisions() {
+    let url = test_url();
+    let db = setup_test_db(&url).await;
+    let repo = &db.repo;
+
+    let created = repo
+        .create_estimate(estimate_input(" Baseline "))
+        .await
+        .unwrap();
+    assert_eq!(created.input.scenario_name, "Baseline");
+    assert_eq!(created.computed, None);
+    assert_eq!(
+        repo.get_estimate_by_name(2025, FilingStatusCode::Single, "Baseline")
+            .await
+            .unwrap(),
+        created
+    );
+
+    let mut changed = created.clone();
+    changed.input.expected_agi = dec!(150000.00);
+    changed.computed = Some(TaxEstimateComputed {
+        se_tax: dec!(7064.78),
+        total_tax: dec!(25000.00),
+        required_payment: dec!(22500.00),
+    });
+    repo.update_estimate(&changed).await.unwrap();
+    let updated = repo.get_estimate(created.id).await.unwrap();
+    assert_eq!(updated.computed, changed.computed);
+
+    let revisions = repo.list_estimate_revisions(created.id).await.unwrap();
+    assert_eq!(
+        revisions.iter().map(|r| r.revision).collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+    let restored = repo
+        .restore_estimate_revision(revisions[0].id)
+        .await
+        .unwrap();
+    assert_eq!(restored.input.expected_agi, dec!(90000.00));
+    assert_eq!(restored.computed, None);
+
+    let other = repo.create_estimate(estimate_input("Raise")).await.unwrap();
+    let listed: Vec<_> = repo
+        .list_estimates(Some(2025))
+        .await
+        .unwrap()
+        .iter()
+        .map(|e| e.id)
+        .collect();
+    assert_eq!(listed, vec![other.id, created.id]);
+    assert!(repo.list_estimates(Some(2026)).await.unwrap().is_empty());
+
+    let mut clash = restored.clone();
+    clash.input.scenario_name = "Raise".to_string();
+    assert!(matches!(
+        repo.update_estimate(&clash).await,
+        Err(RepositoryError::Database(_))
+    ));
+
+    db.drop_schema().await;
+}
+
+#[tokio::test]
+#[ignore = "requires TAX_DB_POSTGRES_TEST_URL"]
+async fn deleted_estimates_move_to_trash_until_purged() {
+    let url = test_url();
+    let db = setup_test_db(&url).await;
+    let repo = &db.repo;
+
+    let estimate = repo
+        .create_estimate(estimate_input(DEFAULT_SCENARIO_NAME))
+        .await
+        .unwrap();
+    repo.delete_estimate(estimate.id).await.unwrap();
+
+    assert!(matches!(
+        repo.get_estimate(estimate.id).await,
+        Err(RepositoryError::NotFound)
+    ));
+    assert_eq!(repo.list_deleted_estimates().await.unwrap().len(), 1);
+    assert_eq!(
+        repo.purge_deleted_estimates(Duration::days(30))
+            .await
+            .unwrap(),
+        0
+    );
+
+    let restored = repo.restore_deleted_estimate(estimate.id).await.unwrap();
+    assert_eq!(restored.deleted_at, None);
+    assert_eq!(
+        repo.list_estimate_revisions(estimate.id)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+
+    repo.delete_estimate(estimate.id).await.unwrap();
+    assert_eq!(
+        repo.purge_deleted_estimates(Duration::zero())
+            .await
+            .unwrap(),
+        1
+    );
+    assert!(
+        repo.list_estimate_revisions(estimate.id)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    db.drop_schema().await;
+}
+
+#[tokio::test]
+#[ignore = "requires TAX_DB_POSTGRES_TEST_URL"]
+async fn registry_opens_postgres_urls() {
+    let url = test_url();
+    let db = setup_test_db(&url).await;
+
+    let mut registry = RepositoryRegistry::new();
+    registry.register(Box::new(PostgresRepositoryFactory));
+    let separator = if url.contains('?') { '&' } else { '?' };
+    let config = DbConfig {
+        backend: "postgres".to_string(),
+        connection_string: format!("{url}{separator}options=-c%20search_path%3D{}", db.schema),
+    };
+
+    let repo = registry.create(&config).await.unwrap();
+    assert_eq!(repo.list_tax_years().await.unwrap(), vec![2026, 2025]);
+    drop(repo);
+
+    db.drop_schema().await;
+}
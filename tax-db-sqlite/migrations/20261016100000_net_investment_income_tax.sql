@@ -0,0 +1,14 @@
+ALTER TABLE tax_year_config
+    ADD COLUMN niit_rate DECIMAL(5,4) NOT NULL DEFAULT 0.038;
+
+CREATE TABLE niit_thresholds (
+    tax_year INTEGER NOT NULL,
+    filing_status_id INTEGER NOT NULL,
+    amount DECIMAL(12,2) NOT NULL,
+    PRIMARY KEY (tax_year, filing_status_id),
+    FOREIGN KEY (tax_year) REFERENCES tax_year_config(tax_year),
+    FOREIGN KEY (filing_status_id) REFERENCES filing_status(id)
+);
+
+ALTER TABLE tax_estimate
+    ADD COLUMN net_investment_income DECIMAL(12,2);
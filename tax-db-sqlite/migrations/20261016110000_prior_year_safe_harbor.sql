@@ -0,0 +1,11 @@
+ALTER TABLE tax_year_config
+    ADD COLUMN prior_agi_threshold DECIMAL(12,2) NOT NULL DEFAULT 150000.00;
+
+ALTER TABLE tax_year_config
+    ADD COLUMN prior_agi_threshold_mfs DECIMAL(12,2) NOT NULL DEFAULT 75000.00;
+
+ALTER TABLE tax_year_config
+    ADD COLUMN prior_tax_multiplier DECIMAL(5,4) NOT NULL DEFAULT 1.10;
+
+ALTER TABLE tax_estimate
+    ADD COLUMN prior_year_agi DECIMAL(12,2);
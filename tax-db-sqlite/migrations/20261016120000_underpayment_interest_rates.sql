@@ -0,0 +1,6 @@
+CREATE TABLE underpayment_interest_rates (
+    year INTEGER NOT NULL,
+    quarter INTEGER NOT NULL CHECK (quarter BETWEEN 1 AND 4),
+    rate DECIMAL(5,4) NOT NULL,
+    PRIMARY KEY (year, quarter)
+);
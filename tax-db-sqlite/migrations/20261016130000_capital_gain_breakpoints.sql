@@ -0,0 +1,15 @@
+CREATE TABLE capital_gain_breakpoints (
+    tax_year INTEGER NOT NULL,
+    filing_status_id INTEGER NOT NULL,
+    zero_rate_max DECIMAL(12,2) NOT NULL,
+    fifteen_rate_max DECIMAL(12,2) NOT NULL,
+    PRIMARY KEY (tax_year, filing_status_id),
+    FOREIGN KEY (tax_year) REFERENCES tax_year_config(tax_year),
+    FOREIGN KEY (filing_status_id) REFERENCES filing_status(id)
+);
+
+ALTER TABLE tax_estimate
+    ADD COLUMN qualified_dividends DECIMAL(12,2);
+
+ALTER TABLE tax_estimate
+    ADD COLUMN net_capital_gain DECIMAL(12,2);
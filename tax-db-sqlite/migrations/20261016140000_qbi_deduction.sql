@@ -0,0 +1,8 @@
+CREATE TABLE qbi_thresholds (
+    tax_year INTEGER NOT NULL,
+    filing_status_id INTEGER NOT NULL,
+    amount DECIMAL(12,2) NOT NULL,
+    PRIMARY KEY (tax_year, filing_status_id),
+    FOREIGN KEY (tax_year) REFERENCES tax_year_config(tax_year),
+    FOREIGN KEY (filing_status_id) REFERENCES filing_status(id)
+);
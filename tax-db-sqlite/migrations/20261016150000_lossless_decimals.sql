@@ -0,0 +1,292 @@
+-- Store every monetary amount and rate as TEXT so decimals round-trip exactly.
+--
+-- Columns declared DECIMAL(p,s) get NUMERIC affinity in SQLite, which turns
+-- '0.3' into a binary REAL and '176100.00' into the INTEGER 176100 on insert.
+-- SQLite cannot change a column's type in place, so each table is rebuilt with
+-- TEXT columns and its rows are copied across. CAST(... AS TEXT) renders legacy
+-- REAL values with 15 significant digits, which recovers the decimal that was
+-- originally written.
+--
+-- tax_year_config is referenced by foreign keys from the other tables, so its
+-- rows are parked in a scratch table and re-inserted under the original name;
+-- the foreign key checks are deferred until the migration commits.
+
+PRAGMA defer_foreign_keys = ON;
+
+-- tax_year_config
+
+CREATE TABLE tax_year_config_old AS SELECT * FROM tax_year_config;
+
+DROP TABLE tax_year_config;
+
+CREATE TABLE tax_year_config (
+    tax_year INTEGER PRIMARY KEY,
+    ss_wage_max TEXT NOT NULL,
+    ss_tax_rate TEXT NOT NULL,
+    medicare_tax_rate TEXT NOT NULL,
+    se_tax_deductible_percentage TEXT NOT NULL,
+    se_deduction_factor TEXT NOT NULL,
+    required_payment_threshold TEXT NOT NULL,
+    min_se_threshold TEXT NOT NULL DEFAULT '400.00',
+    additional_medicare_tax_rate TEXT NOT NULL DEFAULT '0.009',
+    niit_rate TEXT NOT NULL DEFAULT '0.038',
+    prior_agi_threshold TEXT NOT NULL DEFAULT '150000.00',
+    prior_agi_threshold_mfs TEXT NOT NULL DEFAULT '75000.00',
+    prior_tax_multiplier TEXT NOT NULL DEFAULT '1.10'
+);
+
+INSERT INTO tax_year_config (
+    tax_year, ss_wage_max, ss_tax_rate, medicare_tax_rate,
+    se_tax_deductible_percentage, se_deduction_factor,
+    required_payment_threshold, min_se_threshold,
+    additional_medicare_tax_rate, niit_rate,
+    prior_agi_threshold, prior_agi_threshold_mfs, prior_tax_multiplier
+)
+SELECT
+    tax_year,
+    CAST(ss_wage_max AS TEXT),
+    CAST(ss_tax_rate AS TEXT),
+    CAST(medicare_tax_rate AS TEXT),
+    CAST(se_tax_deductible_percentage AS TEXT),
+    CAST(se_deduction_factor AS TEXT),
+    CAST(required_payment_threshold AS TEXT),
+    CAST(min_se_threshold AS TEXT),
+    CAST(additional_medicare_tax_rate AS TEXT),
+    CAST(niit_rate AS TEXT),
+    CAST(prior_agi_threshold AS TEXT),
+    CAST(prior_agi_threshold_mfs AS TEXT),
+    CAST(prior_tax_multiplier AS TEXT)
+FROM tax_year_config_old;
+
+DROP TABLE tax_year_config_old;
+
+-- standard_deductions
+
+CREATE TABLE standard_deductions_new (
+    tax_year INTEGER NOT NULL,
+    filing_status_id INTEGER NOT NULL,
+    amount TEXT NOT NULL,
+    PRIMARY KEY (tax_year, filing_status_id),
+    FOREIGN KEY (tax_year) REFERENCES tax_year_config(tax_year),
+    FOREIGN KEY (filing_status_id) REFERENCES filing_status(id)
+);
+
+INSERT INTO standard_deductions_new (tax_year, filing_status_id, amount)
+SELECT tax_year, filing_status_id, CAST(amount AS TEXT)
+FROM standard_deductions;
+
+DROP TABLE standard_deductions;
+
+ALTER TABLE standard_deductions_new RENAME TO standard_deductions;
+
+-- tax_brackets
+
+CREATE TABLE tax_brackets_new (
+    tax_year INTEGER NOT NULL,
+    filing_status_id INTEGER NOT NULL,
+    min_income TEXT NOT NULL,
+    max_income TEXT,
+    tax_rate TEXT NOT NULL,
+    base_tax TEXT NOT NULL,
+    PRIMARY KEY (tax_year, filing_status_id, min_income),
+    FOREIGN KEY (tax_year) REFERENCES tax_year_config(tax_year),
+    FOREIGN KEY (filing_status_id) REFERENCES filing_status(id)
+);
+
+INSERT INTO tax_brackets_new (
+    tax_year, filing_status_id, min_income, max_income, tax_rate, base_tax
+)
+SELECT
+    tax_year,
+    filing_status_id,
+    CAST(min_income AS TEXT),
+    CAST(max_income AS TEXT),
+    CAST(tax_rate AS TEXT),
+    CAST(base_tax AS TEXT)
+FROM tax_brackets;
+
+DROP TABLE tax_brackets;
+
+ALTER TABLE tax_brackets_new RENAME TO tax_brackets;
+
+-- additional_medicare_thresholds
+
+CREATE TABLE additional_medicare_thresholds_new (
+    tax_year INTEGER NOT NULL,
+    filing_status_id INTEGER NOT NULL,
+    amount TEXT NOT NULL,
+    PRIMARY KEY (tax_year, filing_status_id),
+    FOREIGN KEY (tax_year) REFERENCES tax_year_config(tax_year),
+    FOREIGN KEY (filing_status_id) REFERENCES filing_status(id)
+);
+
+INSERT INTO additional_medicare_thresholds_new (tax_year, filing_status_id, amount)
+SELECT tax_year, filing_status_id, CAST(amount AS TEXT)
+FROM additional_medicare_thresholds;
+
+DROP TABLE additional_medicare_thresholds;
+
+ALTER TABLE additional_medicare_thresholds_new RENAME TO additional_medicare_thresholds;
+
+-- niit_thresholds
+
+CREATE TABLE niit_thresholds_new (
+    tax_year INTEGER NOT NULL,
+    filing_status_id INTEGER NOT NULL,
+    amount TEXT NOT NULL,
+    PRIMARY KEY (tax_year, filing_status_id),
+    FOREIGN KEY (tax_year) REFERENCES tax_year_config(tax_year),
+    FOREIGN KEY (filing_status_id) REFERENCES filing_status(id)
+);
+
+INSERT INTO niit_thresholds_new (tax_year, filing_status_id, amount)
+SELECT tax_year, filing_status_id, CAST(amount AS TEXT)
+FROM niit_thresholds;
+
+DROP TABLE niit_thresholds;
+
+ALTER TABLE niit_thresholds_new RENAME TO niit_thresholds;
+
+-- underpayment_interest_rates
+
+CREATE TABLE underpayment_interest_rates_new (
+    year INTEGER NOT NULL,
+    quarter INTEGER NOT NULL CHECK (quarter BETWEEN 1 AND 4),
+    rate TEXT NOT NULL,
+    PRIMARY KEY (year, quarter)
+);
+
+INSERT INTO underpayment_interest_rates_new (year, quarter, rate)
+SELECT year, quarter, CAST(rate AS TEXT)
+FROM underpayment_interest_rates;
+
+DROP TABLE underpayment_interest_rates;
+
+ALTER TABLE underpayment_interest_rates_new RENAME TO underpayment_interest_rates;
+
+-- capital_gain_breakpoints
+
+CREATE TABLE capital_gain_breakpoints_new (
+    tax_year INTEGER NOT NULL,
+    filing_status_id INTEGER NOT NULL,
+    zero_rate_max TEXT NOT NULL,
+    fifteen_rate_max TEXT NOT NULL,
+    PRIMARY KEY (tax_year, filing_status_id),
+    FOREIGN KEY (tax_year) REFERENCES tax_year_config(tax_year),
+    FOREIGN KEY (filing_status_id) REFERENCES filing_status(id)
+);
+
+INSERT INTO capital_gain_breakpoints_new (
+    tax_year, filing_status_id, zero_rate_max, fifteen_rate_max
+)
+SELECT
+    tax_year,
+    filing_status_id,
+    CAST(zero_rate_max AS TEXT),
+    CAST(fifteen_rate_max AS TEXT)
+FROM capital_gain_breakpoints;
+
+DROP TABLE capital_gain_breakpoints;
+
+ALTER TABLE capital_gain_breakpoints_new RENAME TO capital_gain_breakpoints;
+
+-- qbi_thresholds
+
+CREATE TABLE qbi_thresholds_new (
+    tax_year INTEGER NOT NULL,
+    filing_status_id INTEGER NOT NULL,
+    amount TEXT NOT NULL,
+    PRIMARY KEY (tax_year, filing_status_id),
+    FOREIGN KEY (tax_year) REFERENCES tax_year_config(tax_year),
+    FOREIGN KEY (filing_status_id) REFERENCES filing_status(id)
+);
+
+INSERT INTO qbi_thresholds_new (tax_year, filing_status_id, amount)
+SELECT tax_year, filing_status_id, CAST(amount AS TEXT)
+FROM qbi_thresholds;
+
+DROP TABLE qbi_thresholds;
+
+ALTER TABLE qbi_thresholds_new RENAME TO qbi_thresholds;
+
+-- tax_estimate
+
+CREATE TABLE tax_estimate_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    tax_year INTEGER NOT NULL,
+
+    -- User-provided values (1040-ES Worksheet inputs)
+    filing_status_id INTEGER NOT NULL,
+    expected_agi TEXT NOT NULL DEFAULT '0',
+    expected_deduction TEXT NOT NULL DEFAULT '0',
+    expected_qbi_deduction TEXT,
+    expected_amt TEXT,
+    expected_credits TEXT,
+    expected_other_taxes TEXT,
+    expected_withholding TEXT,
+    prior_year_tax TEXT,
+
+    -- User-provided values (SE Worksheet inputs)
+    se_income TEXT,
+    expected_crp_payments TEXT,
+    expected_wages TEXT,
+
+    -- Calculated values
+    calculated_se_tax TEXT,
+    calculated_total_tax TEXT,
+    calculated_required_payment TEXT,
+
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+
+    -- Later additions (NIIT, prior-year safe harbor, capital gains)
+    net_investment_income TEXT,
+    prior_year_agi TEXT,
+    qualified_dividends TEXT,
+    net_capital_gain TEXT,
+
+    FOREIGN KEY (tax_year) REFERENCES tax_year_config(tax_year),
+    FOREIGN KEY (filing_status_id) REFERENCES filing_status(id)
+);
+
+INSERT INTO tax_estimate_new (
+    id, tax_year, filing_status_id, expected_agi, expected_deduction,
+    expected_qbi_deduction, expected_amt, expected_credits,
+    expected_other_taxes, expected_withholding, prior_year_tax,
+    se_income, expected_crp_payments, expected_wages,
+    calculated_se_tax, calculated_total_tax, calculated_required_payment,
+    created_at, updated_at,
+    net_investment_income, prior_year_agi, qualified_dividends, net_capital_gain
+)
+SELECT
+    id,
+    tax_year,
+    filing_status_id,
+    CAST(expected_agi AS TEXT),
+    CAST(expected_deduction AS TEXT),
+    CAST(expected_qbi_deduction AS TEXT),
+    CAST(expected_amt AS TEXT),
+    CAST(expected_credits AS TEXT),
+    CAST(expected_other_taxes AS TEXT),
+    CAST(expected_withholding AS TEXT),
+    CAST(prior_year_tax AS TEXT),
+    CAST(se_income AS TEXT),
+    CAST(expected_crp_payments AS TEXT),
+    CAST(expected_wages AS TEXT),
+    CAST(calculated_se_tax AS TEXT),
+    CAST(calculated_total_tax AS TEXT),
+    CAST(calculated_required_payment AS TEXT),
+    created_at,
+    updated_at,
+    CAST(net_investment_income AS TEXT),
+    CAST(prior_year_agi AS TEXT),
+    CAST(qualified_dividends AS TEXT),
+    CAST(net_capital_gain AS TEXT)
+FROM tax_estimate;
+
+DROP TABLE tax_estimate;
+
+ALTER TABLE tax_estimate_new RENAME TO tax_estimate;
+
+CREATE UNIQUE INDEX ux_tax_estimate_tax_year_filing_status
+    ON tax_estimate (tax_year, filing_status_id);
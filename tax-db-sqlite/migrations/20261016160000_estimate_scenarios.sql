@@ -0,0 +1,9 @@
+-- Allow several named what-if scenarios per tax year and filing status.
+-- Existing estimates become the "Default" scenario.
+ALTER TABLE tax_estimate
+    ADD COLUMN scenario_name TEXT NOT NULL DEFAULT 'Default';
+
+DROP INDEX ux_tax_estimate_tax_year_filing_status;
+
+CREATE UNIQUE INDEX ux_tax_estimate_tax_year_filing_status_scenario
+    ON tax_estimate (tax_year, filing_status_id, scenario_name);
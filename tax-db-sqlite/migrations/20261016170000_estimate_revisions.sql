@@ -0,0 +1,116 @@
+-- Append-only history of every tax_estimate create and update, so the
+-- numbers given to a client earlier in the year survive a later recalculation.
+
+CREATE TABLE tax_estimate_revision (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    estimate_id INTEGER NOT NULL,
+    revision INTEGER NOT NULL,
+
+    tax_year INTEGER NOT NULL,
+    filing_status_id INTEGER NOT NULL,
+    scenario_name TEXT NOT NULL,
+    expected_agi TEXT NOT NULL,
+    expected_deduction TEXT NOT NULL,
+    expected_qbi_deduction TEXT,
+    expected_amt TEXT,
+    expected_credits TEXT,
+    expected_other_taxes TEXT,
+    expected_withholding TEXT,
+    prior_year_tax TEXT,
+    se_income TEXT,
+    expected_crp_payments TEXT,
+    expected_wages TEXT,
+    net_investment_income TEXT,
+    prior_year_agi TEXT,
+    qualified_dividends TEXT,
+    net_capital_gain TEXT,
+    calculated_se_tax TEXT,
+    calculated_total_tax TEXT,
+    calculated_required_payment TEXT,
+
+    recorded_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (estimate_id, revision),
+    FOREIGN KEY (estimate_id) REFERENCES tax_estimate(id) ON DELETE CASCADE,
+    FOREIGN KEY (filing_status_id) REFERENCES filing_status(id)
+);
+
+-- Existing estimates start their history at revision 1.
+INSERT INTO tax_estimate_revision (
+    estimate_id, revision, tax_year, filing_status_id, scenario_name, expected_agi,
+    expected_deduction, expected_qbi_deduction, expected_amt, expected_credits,
+    expected_other_taxes, expected_withholding, prior_year_tax, se_income,
+    expected_crp_payments, expected_wages, net_investment_income, prior_year_agi,
+    qualified_dividends, net_capital_gain, calculated_se_tax, calculated_total_tax,
+    calculated_required_payment, recorded_at
+)
+SELECT
+    id, 1, tax_year, filing_status_id, scenario_name, expected_agi, expected_deduction,
+    expected_qbi_deduction, expected_amt, expected_credits, expected_other_taxes,
+    expected_withholding, prior_year_tax, se_income, expected_crp_payments, expected_wages,
+    net_investment_income, prior_year_agi, qualified_dividends, net_capital_gain,
+    calculated_se_tax, calculated_total_tax, calculated_required_payment, updated_at
+FROM tax_estimate;
+
+CREATE TRIGGER tr_tax_estimate_record_insert
+AFTER INSERT ON tax_estimate
+BEGIN
+    INSERT INTO tax_estimate_revision (
+        estimate_id, revision, tax_year, filing_status_id, scenario_name, expected_agi,
+        expected_deduction, expected_qbi_deduction, expected_amt, expected_credits,
+        expected_other_taxes, expected_withholding, prior_year_tax, se_income,
+        expected_crp_payments, expected_wages, net_investment_income, prior_year_agi,
+        qualified_dividends, net_capital_gain, calculated_se_tax, calculated_total_tax,
+        calculated_required_payment, recorded_at
+    ) VALUES (
+        NEW.id,
+        (SELECT COALESCE(MAX(revision), 0) + 1
+         FROM tax_estimate_revision
+         WHERE estimate_id = NEW.id),
+        NEW.tax_year, NEW.filing_status_id, NEW.scenario_name, NEW.expected_agi,
+        NEW.expected_deduction, NEW.expected_qbi_deduction, NEW.expected_amt,
+        NEW.expected_credits, NEW.expected_other_taxes, NEW.expected_withholding,
+        NEW.prior_year_tax, NEW.se_income, NEW.expected_crp_payments, NEW.expected_wages,
+        NEW.net_investment_income, NEW.prior_year_agi, NEW.qualified_dividends,
+        NEW.net_capital_gain, NEW.calculated_se_tax, NEW.calculated_total_tax,
+        NEW.calculated_required_payment, NEW.updated_at
+    );
+END;
+
+CREATE TRIGGER tr_tax_estimate_record_update
+AFTER UPDATE ON tax_estimate
+BEGIN
+    INSERT INTO tax_estimate_revision (
+        estimate_id, revision, tax_year, filing_status_id, scenario_name, expected_agi,
+        expected_deduction, expected_qbi_deduction, expected_amt, expected_credits,
+        expected_other_taxes, expected_withholding, prior_year_tax, se_income,
+        expected_crp_payments, expected_wages, net_investment_income, prior_year_agi,
+        qualified_dividends, net_capital_gain, calculated_se_tax, calculated_total_tax,
+        calculated_required_payment, recorded_at
+    ) VALUES (
+        NEW.id,
+        (SELECT COALESCE(MAX(revision), 0) + 1
+         FROM tax_estimate_revision
+         WHERE estimate_id = NEW.id),
+        NEW.tax_year, NEW.filing_status_id, NEW.scenario_name, NEW.expected_agi,
+        NEW.expected_deduction, NEW.expected_qbi_deduction, NEW.expected_amt,
+        NEW.expected_credits, NEW.expected_other_taxes, NEW.expected_withholding,
+        NEW.prior_year_tax, NEW.se_income, NEW.expected_crp_payments, NEW.expected_wages,
+        NEW.net_investment_income, NEW.prior_year_agi, NEW.qualified_dividends,
+        NEW.net_capital_gain, NEW.calculated_se_tax, NEW.calculated_total_tax,
+        NEW.calculated_required_payment, NEW.updated_at
+    );
+END;
+
+-- Revisions are never edited, and only disappear along with their estimate.
+CREATE TRIGGER tr_tax_estimate_revision_no_update
+BEFORE UPDATE ON tax_estimate_revision
+BEGIN
+    SELECT RAISE(ABORT, 'tax_estimate_revision is append-only');
+END;
+
+CREATE TRIGGER tr_tax_estimate_revision_no_delete
+BEFORE DELETE ON tax_estimate_revision
+WHEN EXISTS (SELECT 1 FROM tax_estimate WHERE id = OLD.estimate_id)
+BEGIN
+    SELECT RAISE(ABORT, 'tax_estimate_revision is append-only');
+END;
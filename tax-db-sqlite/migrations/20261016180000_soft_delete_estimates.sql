@@ -0,0 +1,41 @@
+-- Deleting an estimate moves it to the trash instead of removing the row.
+-- Trashed rows keep their revision history until they are purged.
+
+ALTER TABLE tax_estimate ADD COLUMN deleted_at TIMESTAMP;
+
+CREATE INDEX ix_tax_estimate_deleted_at ON tax_estimate (deleted_at);
+
+-- Moving an estimate in or out of the trash is not a change to its values, so
+-- only updates that touch the estimate's data columns record a revision.
+DROP TRIGGER tr_tax_estimate_record_update;
+
+CREATE TRIGGER tr_tax_estimate_record_update
+AFTER UPDATE OF
+    tax_year, filing_status_id, scenario_name, expected_agi, expected_deduction,
+    expected_qbi_deduction, expected_amt, expected_credits, expected_other_taxes,
+    expected_withholding, prior_year_tax, se_income, expected_crp_payments, expected_wages,
+    net_investment_income, prior_year_agi, qualified_dividends, net_capital_gain,
+    calculated_se_tax, calculated_total_tax, calculated_required_payment
+ON tax_estimate
+BEGIN
+    INSERT INTO tax_estimate_revision (
+        estimate_id, revision, tax_year, filing_status_id, scenario_name, expected_agi,
+        expected_deduction, expected_qbi_deduction, expected_amt, expected_credits,
+        expected_other_taxes, expected_withholding, prior_year_tax, se_income,
+        expected_crp_payments, expected_wages, net_investment_income, prior_year_agi,
+        qualified_dividends, net_capital_gain, calculated_se_tax, calculated_total_tax,
+        calculated_required_payment, recorded_at
+    ) VALUES (
+        NEW.id,
+        (SELECT COALESCE(MAX(revision), 0) + 1
+         FROM tax_estimate_revision
+         WHERE estimate_id = NEW.id),
+        NEW.tax_year, NEW.filing_status_id, NEW.scenario_name, NEW.expected_agi,
+        NEW.expected_deduction, NEW.expected_qbi_deduction, NEW.expected_amt,
+        NEW.expected_credits, NEW.expected_other_taxes, NEW.expected_withholding,
+        NEW.prior_year_tax, NEW.se_income, NEW.expected_crp_payments, NEW.expected_wages,
+        NEW.net_investment_income, NEW.prior_year_agi, NEW.qualified_dividends,
+        NEW.net_capital_gain, NEW.calculated_se_tax, NEW.calculated_total_tax,
+        NEW.calculated_required_payment, NEW.updated_at
+    );
+END;
@@ -0,0 +1,8 @@
+-- Seed files applied by SqliteRepository::run_seeds, with a checksum of the
+-- contents each was applied with. A seed is re-run only when it is new or its
+-- checksum changes.
+CREATE TABLE seed_history (
+    file_name TEXT PRIMARY KEY,
+    checksum TEXT NOT NULL,
+    applied_at TIMESTAMP NOT NULL
+);
@@ -1,8 +1,25 @@
 -- Seed 2025 tax year config
-INSERT OR IGNORE INTO tax_year_config (
+INSERT INTO tax_year_config (
     tax_year, ss_wage_max, ss_tax_rate, medicare_tax_rate,
     se_tax_deductible_percentage, se_deduction_factor,
-    required_payment_threshold, min_se_threshold
+    required_payment_threshold, min_se_threshold,
+    additional_medicare_tax_rate, niit_rate,
+    prior_agi_threshold, prior_agi_threshold_mfs, prior_tax_multiplier
 ) VALUES
-    (2025, 176100.00, 0.124, 0.029, 0.9235, 0.50, 1000.00, 400.00),
-    (2026, 184500.00, 0.124, 0.029, 0.9235, 0.50, 1000.00, 400.00);
\ No newline at end of file
+    (2025, '176100.00', '0.124', '0.029', '0.9235', '0.50', '1000.00', '400.00', '0.009', '0.038',
+     '150000.00', '75000.00', '1.10'),
+    (2026, '184500.00', '0.124', '0.029', '0.9235', '0.50', '1000.00', '400.00', '0.009', '0.038',
+     '150000.00', '75000.00', '1.10')
+ON CONFLICT (tax_year) DO UPDATE SET
+    ss_wage_max = excluded.ss_wage_max,
+    ss_tax_rate = excluded.ss_tax_rate,
+    medicare_tax_rate = excluded.medicare_tax_rate,
+    se_tax_deductible_percentage = excluded.se_tax_deductible_percentage,
+    se_deduction_factor = excluded.se_deduction_factor,
+    required_payment_threshold = excluded.required_payment_threshold,
+    min_se_threshold = excluded.min_se_threshold,
+    additional_medicare_tax_rate = excluded.additional_medicare_tax_rate,
+    niit_rate = excluded.niit_rate,
+    prior_agi_threshold = excluded.prior_agi_threshold,
+    prior_agi_threshold_mfs = excluded.prior_agi_threshold_mfs,
+    prior_tax_multiplier = excluded.prior_tax_multiplier;
@@ -1,12 +1,14 @@
 -- Seed 2025 standard deductions
-INSERT OR IGNORE INTO standard_deductions (tax_year, filing_status_id, amount) VALUES
-(2025, 1, 15750.00),
-(2025, 2, 31500.00),
-(2025, 3, 15750.00),
-(2025, 4, 23625.00),
-(2025, 5, 31500.00),
-(2026, 1, 16100.00),
-(2026, 2, 32200.00),
-(2026, 3, 16100.00),
-(2026, 4, 24150.00),
-(2026, 5, 32200.00);
\ No newline at end of file
+INSERT INTO standard_deductions (tax_year, filing_status_id, amount) VALUES
+(2025, 1, '15750.00'),
+(2025, 2, '31500.00'),
+(2025, 3, '15750.00'),
+(2025, 4, '23625.00'),
+(2025, 5, '31500.00'),
+(2026, 1, '16100.00'),
+(2026, 2, '32200.00'),
+(2026, 3, '16100.00'),
+(2026, 4, '24150.00'),
+(2026, 5, '32200.00')
+ON CONFLICT (tax_year, filing_status_id) DO UPDATE SET
+    amount = excluded.amount;
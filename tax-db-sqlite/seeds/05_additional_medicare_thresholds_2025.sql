@@ -0,0 +1,14 @@
+-- Seed Form 8959 Additional Medicare Tax thresholds
+INSERT INTO additional_medicare_thresholds (tax_year, filing_status_id, amount) VALUES
+(2025, 1, '200000.00'),
+(2025, 2, '250000.00'),
+(2025, 3, '125000.00'),
+(2025, 4, '200000.00'),
+(2025, 5, '200000.00'),
+(2026, 1, '200000.00'),
+(2026, 2, '250000.00'),
+(2026, 3, '125000.00'),
+(2026, 4, '200000.00'),
+(2026, 5, '200000.00')
+ON CONFLICT (tax_year, filing_status_id) DO UPDATE SET
+    amount = excluded.amount;
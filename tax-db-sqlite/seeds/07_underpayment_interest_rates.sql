@@ -0,0 +1,13 @@
+-- Seed IRS underpayment interest rates used by the Form 2210 penalty
+INSERT INTO underpayment_interest_rates (year, quarter, rate) VALUES
+(2024, 1, '0.08'),
+(2024, 2, '0.08'),
+(2024, 3, '0.08'),
+(2024, 4, '0.08'),
+(2025, 1, '0.07'),
+(2025, 2, '0.07'),
+(2025, 3, '0.07'),
+(2025, 4, '0.07'),
+(2026, 1, '0.07')
+ON CONFLICT (year, quarter) DO UPDATE SET
+    rate = excluded.rate;
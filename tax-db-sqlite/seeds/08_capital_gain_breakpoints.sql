@@ -0,0 +1,15 @@
+-- Seed qualified dividend and capital gain 0%/15% rate breakpoints
+INSERT INTO capital_gain_breakpoints (tax_year, filing_status_id, zero_rate_max, fifteen_rate_max) VALUES
+(2025, 1, '48350.00', '533400.00'),
+(2025, 2, '96700.00', '600050.00'),
+(2025, 3, '48350.00', '300000.00'),
+(2025, 4, '64750.00', '566700.00'),
+(2025, 5, '96700.00', '600050.00'),
+(2026, 1, '49450.00', '545500.00'),
+(2026, 2, '98900.00', '613700.00'),
+(2026, 3, '49450.00', '306850.00'),
+(2026, 4, '66200.00', '579600.00'),
+(2026, 5, '98900.00', '613700.00')
+ON CONFLICT (tax_year, filing_status_id) DO UPDATE SET
+    zero_rate_max = excluded.zero_rate_max,
+    fifteen_rate_max = excluded.fifteen_rate_max;
@@ -0,0 +1,14 @@
+-- Seed Form 8995 qualified business income deduction taxable income thresholds
+INSERT INTO qbi_thresholds (tax_year, filing_status_id, amount) VALUES
+(2025, 1, '197300.00'),
+(2025, 2, '394600.00'),
+(2025, 3, '197300.00'),
+(2025, 4, '197300.00'),
+(2025, 5, '197300.00'),
+(2026, 1, '201775.00'),
+(2026, 2, '403550.00'),
+(2026, 3, '201775.00'),
+(2026, 4, '201775.00'),
+(2026, 5, '201775.00')
+ON CONFLICT (tax_year, filing_status_id) DO UPDATE SET
+    amount = excluded.amount;
@@ -0,0 +1,420 @@
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use sqlx::{AssertSqlSafe, Connection, SqliteConnection};
+
+use crate::SqliteRepository;
+
+/// Schema name the backup file is attached under while it is restored.
+const SNAPSHOT: &str = "snapshot";
+
+impl SqliteRepository {
+    /// Write a consistent snapshot of the database to a new file at `path`.
+    ///
+    /// Uses `VACUUM INTO`, which reads through a single transaction, so the
+    /// snapshot includes everything committed so far (including pages still in
+    /// the WAL) and is safe to take while the app is running. Fails if `path`
+    /// already exists.
+    pub async fn backup_to(
+        &self,
+        path: &Path,
+    ) -> Result<()> {
+        if path.exists() {
+            bail!("Backup file '{}' already exists", path.display());
+        }
+
+        sqlx::query("VACUUM INTO ?")
+            .bind(path.to_string_lossy().into_owned())
+            .execute(self.pool())
+            .await
+            .with_context(|| format!("Failed to back up database to '{}'", path.display()))?;
+
+        tracing::info!("Backed up database to {}", path.display());
+        Ok(())
+    }
+
+    /// Replace the contents of the database with the backup at `path`.
+    ///
+    /// The backup must pass `PRAGMA quick_check` and have exactly the same
+    /// migrations applied as this database. Every table is then emptied and
+    /// refilled from the backup inside one transaction, so the restore either
+    /// lands completely or not at all, and open connections stay usable.
+    pub async fn restore_from(
+        &self,
+        path: &Path,
+    ) -> Result<()> {
+        if !path.is_file() {
+            bail!("Backup file '{}' not found", path.display());
+        }
+
+        let mut conn = self
+            .pool()
+            .acquire()
+            .await
+            .context("Failed to acquire a database connection")?;
+
+        sqlx::query(AssertSqlSafe(format!("ATTACH DATABASE ? AS {SNAPSHOT}")))
+            .bind(path.to_string_lossy().into_owned())
+            .execute(&mut *conn)
+            .await
+            .with_context(|| format!("Failed to open backup file '{}'", path.display()))?;
+
+        let restored = restore_attached(&mut conn).await;
+
+        sqlx::query(AssertSqlSafe(format!("DETACH DATABASE {SNAPSHOT}")))
+            .execute(&mut *conn)
+            .await
+            .context("Failed to close backup file")?;
+
+        restored.with_context(|| format!("Failed to restore backup '{}'", path.display()))?;
+        tracing::info!("Restored database from {}", path.display());
+        Ok(())
+    }
+}
+
+/// Version and checksum of every successfully applied migration in `schema`,
+/// in version order, or `None` if the schema has no migration table (i.e. it
+/// is not a tax database).
+async fn applied_migrations(
+    conn: &mut SqliteConnection,
+    schema: &str,
+) -> Result<Option<Vec<(i64, Vec<u8>)>>> {
+    let has_migrations: bool = sqlx::query_scalar(AssertSqlSafe(format!(
+        "SELECT COUNT(*) > 0 FROM {schema}.sqlite_master
+         WHERE type = 'table' AND name = '_sqlx_migrations'"
+    )))
+    .fetch_one(&mut *conn)
+    .await?;
+    if !has_migrations {
+        return Ok(None);
+    }
+
+    let migrations: Vec<(i64, Vec<u8>)> = sqlx::query_as(AssertSqlSafe(format!(
+        "SELECT version, checksum FROM {schema}._sqlx_migrations
+         WHERE success = 1 ORDER BY version"
+    )))
+    .fetch_all(&mut *conn)
+    .await?;
+    Ok(Some(migrations))
+}
+
+/// Validates the attached snapshot and copies it over `main`.
+async fn restore_attached(conn: &mut SqliteConnection) -> Result<()> {
+    let check: String = sqlx::query_scalar(AssertSqlSafe(format!("PRAGMA {SNAPSHOT}.quick_check")))
+        .fetch_one(&mut *conn)
+        .await
+        .context("Failed to check backup integrity")?;
+    if check != "ok" {
+        bail!("Backup failed integrity check: {check}");
+    }
+
+    let live = applied_migrations(conn, "main").await?.unwrap_or_default();
+    let Some(backup) = applied_migrations(conn, SNAPSHOT).await? else {
+        bail!("File is not a tax database backup");
+    };
+    if live != backup {
+        let latest = |migrations: &[(i64, Vec<u8>)]| {
+            migrations
+                .last()
+                .map_or_else(|| "(none)".to_string(), |(version, _)| version.to_string())
+        };
+        bail!(
+            "Backup migrations (latest {}) do not match database migrations (latest {})",
+            latest(&backup),
+            latest(&live)
+        );
+    }
+
+    // Enforcement is switched off for the copy, since tables are refilled one
+    // at a time, and the whole database is checked before committing instead.
+    // The pragma has no effect inside a transaction, so it wraps it.
+    sqlx::query("PRAGMA foreign_keys = OFF")
+        .execute(&mut *conn)
+        .await?;
+    let copied = copy_in_transaction(conn).await;
+    sqlx::query("PRAGMA foreign_keys = ON")
+        .execute(&mut *conn)
+        .await?;
+    copied
+}
+
+async fn copy_in_transaction(conn: &mut SqliteConnection) -> Result<()> {
+    let mut tx = conn.begin().await?;
+    match copy_snapshot(&mut tx).await {
+        Ok(()) => tx.commit().await.context("Failed to commit restored data"),
+        Err(e) => {
+            tx.rollback().await?;
+            Err(e)
+        }
+    }
+}
+
+/// Replaces every table in `main` with its counterpart in the snapshot.
+async fn copy_snapshot(conn: &mut SqliteConnection) -> Result<()> {
+    // Triggers would record restored estimates as new revisions and refuse to
+    // clear the revision table, so they are dropped and recreated around the
+    // copy.
+    let triggers: Vec<(String, String)> = sqlx::query_as(
+        "SELECT name, sql FROM main.sqlite_master WHERE type = 'trigger' ORDER BY name",
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+    for (name, _) in &triggers {
+        sqlx::query(AssertSqlSafe(format!("DROP TRIGGER main.\"{name}\"")))
+            .execute(&mut *conn)
+            .await?;
+    }
+
+    let tables: Vec<String> = sqlx::query_scalar(
+        "SELECT name FROM main.sqlite_master
+         WHERE type = 'table' AND name NOT LIKE 'sqlite_%' AND name <> '_sqlx_migrations'
+         ORDER BY name",
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+    for table in &tables {
+        sqlx::query(AssertSqlSafe(format!("DELETE FROM main.\"{table}\"")))
+            .execute(&mut *conn)
+            .await
+            .with_context(|| format!("Failed to clear table {table}"))?;
+        sqlx::query(AssertSqlSafe(format!(
+            "INSERT INTO main.\"{table}\" SELECT * FROM {SNAPSHOT}.\"{table}\""
+        )))
+        .execute(&mut *conn)
+        .await
+        .with_context(|| format!("Failed to restore table {table}"))?;
+    }
+
+    // Keep AUTOINCREMENT counters in step with the restored ids.
+    sqlx::query("DELETE FROM main.sqlite_sequence")
+        .execute(&mut *conn)
+        .await?;
+    sqlx::query(AssertSqlSafe(format!(
+        "INSERT INTO main.sqlite_sequence SELECT * FROM {SNAPSHOT}.sqlite_sequence"
+    )))
+    .execute(&mut *conn)
+    .await?;
+
+    for (_, sql) in &triggers {
+        sqlx::query(AssertSqlSafe(sql.clone()))
+            .execute(&mut *conn)
+            .await?;
+    }
+
+    let violations: Vec<(String,)> = sqlx::query_as("PRAGMA main.foreign_key_check")
+        .fetch_all(&mut *conn)
+        .await?;
+    if let Some((table,)) = violations.first() {
+        bail!("Backup has rows in {table} that reference missing records");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+    use tax_core::{DEFAULT_SCENARIO_NAME, FilingStatusCode, TaxEstimateInput, TaxRepository};
+
+    use super::*;
+
+    /// A fresh path under the system temp directory that does not exist yet.
+    fn scratch_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "tax-db-sqlite-{}-{}-{name}.db",
+            std::process::id(),
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    async fn seeded_repo() -> SqliteRepository {
+        let repo = SqliteRepository::new(":memory:")
+            .await
+            .expect("Failed to create in-memory database");
+        repo.run_migrations()
+            .await
+            .expect("Failed to run migrations");
+        repo.run_seeds(Path::new("./seeds"))
+            .await
+            .expect("Failed to run seeds");
+        repo
+    }
+
+    fn estimate_input(expected_agi: rust_decimal::Decimal) -> TaxEstimateInput {
+        TaxEstimateInput {
+            tax_year: 2025,
+            filing_status: FilingStatusCode::Single,
+            scenario_name: DEFAULT_SCENARIO_NAME.to_string(),
+            se_income: None,
+            expected_crp_payments: None,
+            expected_wages: None,
+            net_investment_income: None,
+            qualified_dividends: None,
+            net_capital_gain: None,
+            expected_agi,
+            expected_deduction: dec!(15750.00),
+            expected_qbi_deduction: None,
+            expected_amt: None,
+            expected_credits: None,
+            expected_other_taxes: None,
+            expected_withholding: None,
+            prior_year_tax: None,
+            prior_year_agi: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_backup_and_restore_round_trip() {
+        let repo = seeded_repo().await;
+        let saved = repo
+            .create_estimate(estimate_input(dec!(90000.00)))
+            .await
+            .expect("Should create estimate");
+        let path = scratch_path("round-trip");
+
+        repo.backup_to(&path).await.expect("Should back up");
+
+        let mut changed = saved.clone();
+        changed.input.expected_agi = dec!(250000.00);
+        repo.update_estimate(&changed)
+            .await
+            .expect("Should update estimate");
+        let mut other = estimate_input(dec!(1.00));
+        other.scenario_name = "scratch".to_string();
+        repo.create_estimate(other)
+            .await
+            .expect("Should create estimate");
+
+        repo.restore_from(&path).await.expect("Should restore");
+        let _ = std::fs::remove_file(&path);
+
+        let estimates = repo
+            .list_estimates(None)
+            .await
+            .expect("Should list estimates");
+        assert_eq!(estimates.len(), 1);
+        assert_eq!(estimates[0].id, saved.id);
+        assert_eq!(estimates[0].input.expected_agi, dec!(90000.00));
+        let revisions = repo
+            .list_estimate_revisions(saved.id)
+            .await
+            .expect("Should list revisions");
+        assert_eq!(revisions.len(), 1);
+
+        // Triggers are back in place after the restore.
+        repo.update_estimate(&changed)
+            .await
+            .expect("Should update estimate");
+        let revisions = repo
+            .list_estimate_revisions(saved.id)
+            .await
+            .expect("Should list revisions");
+        assert_eq!(revisions.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_backup_refuses_to_overwrite() {
+        let repo = seeded_repo().await;
+        let path = scratch_path("existing");
+        std::fs::write(&path, b"keep me").expect("Failed to create file");
+
+        let result = repo.backup_to(&path).await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            std::fs::read(&path).expect("Failed to read file"),
+            b"keep me"
+        );
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_restore_rejects_schema_version_mismatch() {
+        let repo = seeded_repo().await;
+        let path = scratch_path("old-schema");
+        repo.backup_to(&path).await.expect("Should back up");
+        let backup = SqliteRepository::new(&path.to_string_lossy())
+            .await
+            .expect("Failed to open backup");
+        sqlx::query(
+            "DELETE FROM _sqlx_migrations
+             WHERE version = (SELECT MAX(version) FROM _sqlx_migrations)",
+        )
+        .execute(backup.pool())
+        .await
+        .expect("Failed to rewind backup schema version");
+        backup.pool().close().await;
+
+        let result = repo.restore_from(&path).await;
+        let _ = std::fs::remove_file(&path);
+
+        let message = format!("{:#}", result.expect_err("Should reject old backup"));
+        assert!(message.contains("do not match"), "got {message}");
+    }
+
+    #[tokio::test]
+    async fn test_restore_rejects_changed_migration_checksum() {
+        let repo = seeded_repo().await;
+        let path = scratch_path("changed-migration");
+        repo.backup_to(&path).await.expect("Should back up");
+        let backup = SqliteRepository::new(&path.to_string_lossy())
+            .await
+            .expect("Failed to open backup");
+        sqlx::query(
+            "UPDATE _sqlx_migrations SET checksum = X'00'
+             WHERE version = (SELECT MIN(version) FROM _sqlx_migrations)",
+        )
+        .execute(backup.pool())
+        .await
+        .expect("Failed to change backup migration checksum");
+        backup.pool().close().await;
+
+        let result = repo.restore_from(&path).await;
+        let _ = std::fs::remove_file(&path);
+
+        let message = format!("{:#}", result.expect_err("Should reject changed migration"));
+        assert!(message.contains("do not match"), "got {message}");
+    }
+
+    #[tokio::test]
+    async fn test_restore_rejects_non_tax_database() {
+        let repo = seeded_repo().await;
+        let path = scratch_path("foreign");
+        let other = SqliteRepository::new(&path.to_string_lossy())
+            .await
+            .expect("Failed to create database");
+        sqlx::query("CREATE TABLE notes (body TEXT)")
+            .execute(other.pool())
+            .await
+            .expect("Failed to create table");
+        other.pool().close().await;
+
+        let result = repo.restore_from(&path).await;
+        let _ = std::fs::remove_file(&path);
+
+        let message = format!("{:#}", result.expect_err("Should reject foreign file"));
+        assert!(
+            message.contains("not a tax database backup"),
+            "got {message}"
+        );
+        assert!(
+            repo.list_tax_years()
+                .await
+                .expect("Database should be untouched")
+                .contains(&2025)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_restore_missing_file() {
+        let repo = seeded_repo().await;
+
+        let result = repo.restore_from(&scratch_path("missing")).await;
+
+        assert!(result.is_err());
+    }
+}
@@ -1,9 +1,18 @@
+use std::str::FromStr;
+
 use rust_decimal::Decimal;
-use rust_decimal::prelude::ToPrimitive;
 use sqlx::{Row, TypeInfo, ValueRef};
 use tax_core::RepositoryError;
 
-/// Get a decimal value from a row, handling both INTEGER and REAL SQLite types.
+/// Largest scale a [`Decimal`] can carry.
+const MAX_SCALE: u32 = 28;
+
+/// Get a decimal value from a row.
+///
+/// Amounts are stored as TEXT and parsed exactly. INTEGER values are accepted
+/// as whole amounts. REAL values are only accepted when the binary float is
+/// exactly representable as a [`Decimal`] (e.g. `1500.0` or `0.25`); anything
+/// else (e.g. `0.1`) is rejected rather than silently rounded.
 pub fn get_decimal(
     row: &sqlx::sqlite::SqliteRow,
     column: &str,
@@ -16,6 +25,23 @@ pub fn get_decimal(
     let type_name = type_info.name();
 
     match type_name {
+        "TEXT" => {
+            let val: String = row.try_get(column).map_err(|e| {
+                RepositoryError::Database(anyhow::anyhow!(
+                    "Failed to get TEXT from '{}': {}",
+                    column,
+                    e
+                ))
+            })?;
+            Decimal::from_str(&val).map_err(|e| {
+                RepositoryError::Database(anyhow::anyhow!(
+                    "Failed to parse '{}' in column '{}' as Decimal: {}",
+                    val,
+                    column,
+                    e
+                ))
+            })
+        }
         "INTEGER" => {
             let val: i64 = row.try_get(column).map_err(|e| {
                 RepositoryError::Database(anyhow::anyhow!(
@@ -34,11 +60,11 @@ pub fn get_decimal(
                     e
                 ))
             })?;
-            Decimal::try_from(val).map_err(|e| {
+            exact_decimal_from_f64(val).ok_or_else(|| {
                 RepositoryError::Database(anyhow::anyhow!(
-                    "Failed to convert {} to Decimal: {}",
+                    "REAL value {} in column '{}' cannot be represented exactly as a Decimal",
                     val,
-                    e
+                    column
                 ))
             })
         }
@@ -67,9 +93,57 @@ pub fn get_optional_decimal(
     get_decimal(row, column).map(Some)
 }
 
-/// Convert a Decimal to f64 for SQLite storage.
-pub fn decimal_to_f64(d: Decimal) -> f64 {
-    d.to_f64().unwrap_or(0.0)
+/// Convert a Decimal to its TEXT form for SQLite storage.
+///
+/// The scale is preserved, so `176100.00` is stored as `"176100.00"` and reads
+/// back bit-for-bit.
+pub fn decimal_to_text(d: Decimal) -> String {
+    d.to_string()
+}
+
+/// Convert a binary float to a [`Decimal`] only if no rounding is needed.
+///
+/// Every finite `f64` is `mantissa * 2^exponent`. With a negative exponent the
+/// exact decimal expansion is `mantissa * 5^-exponent / 10^-exponent`, which
+/// fits a [`Decimal`] only when the scale is at most 28 and the digits fit in
+/// 96 bits.
+fn exact_decimal_from_f64(val: f64) -> Option<Decimal> {
+    if !val.is_finite() {
+        return None;
+    }
+    if val == 0.0 {
+        return Some(Decimal::ZERO);
+    }
+
+    let bits = val.to_bits();
+    let negative = bits >> 63 == 1;
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & 0x000f_ffff_ffff_ffff;
+    let (mut mantissa, mut exponent) = if biased_exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | 0x0010_0000_0000_0000, biased_exponent - 1075)
+    };
+
+    let trailing = mantissa.trailing_zeros();
+    mantissa >>= trailing;
+    exponent += trailing as i32;
+
+    let (digits, scale) = if exponent >= 0 {
+        let digits = i128::from(mantissa).checked_mul(2i128.checked_pow(exponent as u32)?)?;
+        (digits, 0)
+    } else {
+        let scale = exponent.unsigned_abs();
+        if scale > MAX_SCALE {
+            return None;
+        }
+        (i128::from(mantissa).checked_mul(5i128.pow(scale))?, scale)
+    };
+
+    let digits = if negative { -digits } else { digits };
+    Decimal::try_from_i128_with_scale(digits, scale)
+        .ok()
+        .map(|d| d.normalize())
 }
 
 #[cfg(test)]
@@ -87,7 +161,8 @@ mod tests {
                 int_value INTEGER,
                 real_value REAL,
                 null_value REAL,
-                text_value TEXT
+                text_value TEXT,
+                blob_value BLOB
             )",
         )
         .execute(pool)
@@ -153,9 +228,9 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_get_decimal_from_real() {
+    async fn test_get_decimal_from_exact_real() {
         let pool = setup_test_db().await;
-        sqlx::query("INSERT INTO test_decimals (id, real_value) VALUES (1, 123.45)")
+        sqlx::query("INSERT INTO test_decimals (id, real_value) VALUES (1, 1500.25)")
             .execute(&pool)
             .await
             .expect("Failed to insert test data");
@@ -165,15 +240,15 @@ mod tests {
             .await
             .expect("Failed to fetch row");
 
-        let result = get_decimal(&row, "real_value").expect("Should get decimal from REAL");
+        let result = get_decimal(&row, "real_value").expect("Should get decimal from exact REAL");
 
-        assert_eq!(result, dec!(123.45));
+        assert_eq!(result, dec!(1500.25));
     }
 
     #[tokio::test]
-    async fn test_get_decimal_from_negative_real() {
+    async fn test_get_decimal_from_negative_exact_real() {
         let pool = setup_test_db().await;
-        sqlx::query("INSERT INTO test_decimals (id, real_value) VALUES (1, -456.78)")
+        sqlx::query("INSERT INTO test_decimals (id, real_value) VALUES (1, -456.5)")
             .execute(&pool)
             .await
             .expect("Failed to insert test data");
@@ -186,7 +261,103 @@ mod tests {
         let result =
             get_decimal(&row, "real_value").expect("Should get decimal from negative REAL");
 
-        assert_eq!(result, dec!(-456.78));
+        assert_eq!(result, dec!(-456.5));
+    }
+
+    #[tokio::test]
+    async fn test_get_decimal_rejects_inexact_real() {
+        let pool = setup_test_db().await;
+        sqlx::query("INSERT INTO test_decimals (id, real_value) VALUES (1, 123.45)")
+            .execute(&pool)
+            .await
+            .expect("Failed to insert test data");
+
+        let row = sqlx::query("SELECT real_value FROM test_decimals WHERE id = 1")
+            .fetch_one(&pool)
+            .await
+            .expect("Failed to fetch row");
+
+        let result = get_decimal(&row, "real_value");
+
+        let err = result.expect_err("Should reject REAL that is not an exact decimal");
+        let msg = database_msg(err);
+        assert_eq!(
+            msg,
+            "REAL value 123.45 in column 'real_value' cannot be represented exactly as a Decimal"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_decimal_rejects_float_sum() {
+        let pool = setup_test_db().await;
+        sqlx::query("INSERT INTO test_decimals (id, real_value) VALUES (1, ?)")
+            .bind(0.1_f64 + 0.2_f64)
+            .execute(&pool)
+            .await
+            .expect("Failed to insert test data");
+
+        let row = sqlx::query("SELECT real_value FROM test_decimals WHERE id = 1")
+            .fetch_one(&pool)
+            .await
+            .expect("Failed to fetch row");
+
+        let result = get_decimal(&row, "real_value");
+
+        assert!(
+            result.is_err(),
+            "0.1 + 0.2 as f64 must not read back as a Decimal"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_decimal_from_text() {
+        let pool = setup_test_db().await;
+        sqlx::query("INSERT INTO test_decimals (id, text_value) VALUES (1, '0.9235')")
+            .execute(&pool)
+            .await
+            .expect("Failed to insert test data");
+
+        let row = sqlx::query("SELECT text_value FROM test_decimals WHERE id = 1")
+            .fetch_one(&pool)
+            .await
+            .expect("Failed to fetch row");
+
+        let result = get_decimal(&row, "text_value").expect("Should get decimal from TEXT");
+
+        assert_eq!(result, dec!(0.9235));
+    }
+
+    #[tokio::test]
+    async fn test_get_decimal_text_round_trips_bit_for_bit() {
+        let pool = setup_test_db().await;
+        let values = [
+            dec!(0.1) + dec!(0.2),
+            dec!(176100.00),
+            dec!(-0.0001),
+            dec!(0.9235),
+        ];
+        for (id, value) in values.iter().enumerate() {
+            sqlx::query("INSERT INTO test_decimals (id, text_value) VALUES (?, ?)")
+                .bind(id as i64)
+                .bind(decimal_to_text(*value))
+                .execute(&pool)
+                .await
+                .expect("Failed to insert test data");
+        }
+
+        for (id, value) in values.iter().enumerate() {
+            let row = sqlx::query("SELECT text_value FROM test_decimals WHERE id = ?")
+                .bind(id as i64)
+                .fetch_one(&pool)
+                .await
+                .expect("Failed to fetch row");
+
+            let result = get_decimal(&row, "text_value").expect("Should get decimal from TEXT");
+
+            assert_eq!(result.serialize(), value.serialize());
+        }
+        assert_eq!(values[0].to_string(), "0.3");
+        assert_eq!(values[1].to_string(), "176100.00");
     }
 
     #[tokio::test]
@@ -235,7 +406,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_get_decimal_unexpected_type() {
+    async fn test_get_decimal_unparsable_text() {
         let pool = setup_test_db().await;
         sqlx::query("INSERT INTO test_decimals (id, text_value) VALUES (1, 'not a number')")
             .execute(&pool)
@@ -249,9 +420,30 @@ mod tests {
 
         let result = get_decimal(&row, "text_value");
 
+        let err = result.expect_err("Should return error for unparsable TEXT");
+        let msg = database_msg(err);
+        let expected = "Failed to parse 'not a number' in column 'text_value' as Decimal: ";
+        assert_eq!(&msg[..expected.len()], expected);
+    }
+
+    #[tokio::test]
+    async fn test_get_decimal_unexpected_type() {
+        let pool = setup_test_db().await;
+        sqlx::query("INSERT INTO test_decimals (id, blob_value) VALUES (1, x'01')")
+            .execute(&pool)
+            .await
+            .expect("Failed to insert test data");
+
+        let row = sqlx::query("SELECT blob_value FROM test_decimals WHERE id = 1")
+            .fetch_one(&pool)
+            .await
+            .expect("Failed to fetch row");
+
+        let result = get_decimal(&row, "blob_value");
+
         let err = result.expect_err("Should return error for unexpected type");
         let msg = database_msg(err);
-        assert_eq!(msg, "Unexpected type 'TEXT' for column 'text_value'");
+        assert_eq!(msg, "Unexpected type 'BLOB' for column 'blob_value'");
     }
 
     // get_optional_decimal tests
@@ -277,7 +469,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_optional_decimal_from_real() {
         let pool = setup_test_db().await;
-        sqlx::query("INSERT INTO test_decimals (id, real_value) VALUES (1, 999.99)")
+        sqlx::query("INSERT INTO test_decimals (id, real_value) VALUES (1, 999.75)")
             .execute(&pool)
             .await
             .expect("Failed to insert test data");
@@ -289,7 +481,7 @@ mod tests {
 
         let result = get_optional_decimal(&row, "real_value").expect("Should get Some from REAL");
 
-        assert_eq!(result, Some(dec!(999.99)));
+        assert_eq!(result, Some(dec!(999.75)));
     }
 
     #[tokio::test]
@@ -338,9 +530,9 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_get_optional_decimal_unexpected_type() {
+    async fn test_get_optional_decimal_from_text() {
         let pool = setup_test_db().await;
-        sqlx::query("INSERT INTO test_decimals (id, text_value) VALUES (1, 'text')")
+        sqlx::query("INSERT INTO test_decimals (id, text_value) VALUES (1, '176100.00')")
             .execute(&pool)
             .await
             .expect("Failed to insert test data");
@@ -350,57 +542,85 @@ mod tests {
             .await
             .expect("Failed to fetch row");
 
-        let result = get_optional_decimal(&row, "text_value");
+        let result = get_optional_decimal(&row, "text_value").expect("Should get Some from TEXT");
+
+        assert_eq!(result.map(|d| d.to_string()), Some("176100.00".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_get_optional_decimal_unexpected_type() {
+        let pool = setup_test_db().await;
+        sqlx::query("INSERT INTO test_decimals (id, blob_value) VALUES (1, x'02')")
+            .execute(&pool)
+            .await
+            .expect("Failed to insert test data");
+
+        let row = sqlx::query("SELECT blob_value FROM test_decimals WHERE id = 1")
+            .fetch_one(&pool)
+            .await
+            .expect("Failed to fetch row");
+
+        let result = get_optional_decimal(&row, "blob_value");
 
         let err = result.expect_err("Should return error for unexpected type");
         let msg = database_msg(err);
-        assert_eq!(msg, "Unexpected type 'TEXT' for column 'text_value'");
+        assert_eq!(msg, "Unexpected type 'BLOB' for column 'blob_value'");
     }
 
-    // decimal_to_f64 tests
+    // decimal_to_text tests
 
     #[test]
-    fn test_decimal_to_f64_positive() {
-        let decimal = dec!(123.456);
+    fn test_decimal_to_text_preserves_scale() {
+        let decimal = dec!(176100.00);
 
-        let result = decimal_to_f64(decimal);
+        let result = decimal_to_text(decimal);
 
-        assert_eq!(result, 123.456);
+        assert_eq!(result, "176100.00");
     }
 
     #[test]
-    fn test_decimal_to_f64_negative() {
+    fn test_decimal_to_text_negative() {
         let decimal = dec!(-789.012);
 
-        let result = decimal_to_f64(decimal);
+        let result = decimal_to_text(decimal);
 
-        assert_eq!(result, -789.012);
+        assert_eq!(result, "-789.012");
     }
 
     #[test]
-    fn test_decimal_to_f64_zero() {
+    fn test_decimal_to_text_zero() {
         let decimal = Decimal::ZERO;
 
-        let result = decimal_to_f64(decimal);
+        let result = decimal_to_text(decimal);
 
-        assert_eq!(result, 0.0);
+        assert_eq!(result, "0");
     }
 
     #[test]
-    fn test_decimal_to_f64_large_value() {
-        let decimal = dec!(999999999.99);
+    fn test_decimal_to_text_sum_is_exact() {
+        let decimal = dec!(0.1) + dec!(0.2);
 
-        let result = decimal_to_f64(decimal);
+        let result = decimal_to_text(decimal);
 
-        assert_eq!(result, 999999999.99);
+        assert_eq!(result, "0.3");
     }
 
-    #[test]
-    fn test_decimal_to_f64_small_fraction() {
-        let decimal = dec!(0.0001);
+    // exact_decimal_from_f64 tests
 
-        let result = decimal_to_f64(decimal);
+    #[test]
+    fn test_exact_decimal_from_f64_whole_and_dyadic() {
+        assert_eq!(exact_decimal_from_f64(176100.0), Some(dec!(176100)));
+        assert_eq!(exact_decimal_from_f64(0.5), Some(dec!(0.5)));
+        assert_eq!(exact_decimal_from_f64(-0.125), Some(dec!(-0.125)));
+        assert_eq!(exact_decimal_from_f64(0.0), Some(Decimal::ZERO));
+    }
 
-        assert_eq!(result, 0.0001);
+    #[test]
+    fn test_exact_decimal_from_f64_rejects_inexact() {
+        assert_eq!(exact_decimal_from_f64(0.1), None);
+        assert_eq!(exact_decimal_from_f64(0.9235), None);
+        assert_eq!(exact_decimal_from_f64(f64::NAN), None);
+        assert_eq!(exact_decimal_from_f64(f64::INFINITY), None);
+        assert_eq!(exact_decimal_from_f64(1e40), None);
     }
 }
@@ -7,24 +7,21 @@ use tax_core::db::{DbConfig, RepositoryFactory};
 
 use crate::repository::SqliteRepository;
 
-/// Resolve the seeds directory at runtime so it works in both development and
-/// packaged distribution.
+/// Resolve a seeds directory that overrides the embedded default seeds.
 ///
 /// Resolution order:
 /// 1. **`TAX_DB_SQLITE_SEEDS_DIR`** — if set, use this path (override for
-///    packagers or custom layouts).
+///    packagers or custom data).
 /// 2. **`./seeds`** — if the directory exists in the current working directory.
-/// 3. **Crate manifest dir** — `$CARGO_MANIFEST_DIR/seeds` as last resort
-///    (dev/tests when run from the build tree).
-fn seeds_dir() -> PathBuf {
+///
+/// Returns `None` when neither applies, in which case the seeds compiled into
+/// the crate are used (see [`SqliteRepository::run_embedded_seeds`]).
+fn seeds_dir() -> Option<PathBuf> {
     if let Ok(dir) = std::env::var("TAX_DB_SQLITE_SEEDS_DIR") {
-        return PathBuf::from(dir);
+        return Some(PathBuf::from(dir));
     }
     let cwd_seeds = PathBuf::from("./seeds");
-    if cwd_seeds.is_dir() {
-        return cwd_seeds;
-    }
-    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("seeds")
+    cwd_seeds.is_dir().then_some(cwd_seeds)
 }
 
 /// [`RepositoryFactory`] for SQLite.
@@ -54,9 +51,10 @@ impl RepositoryFactory for SqliteRepositoryFactory {
     ///   does not exist.
     /// * `":memory:"` — an ephemeral in-memory database (useful for tests).
     ///
-    /// Seed SQL files are loaded from a directory resolved at runtime (see
-    /// [`seeds_dir`]). For packaged distribution, set `TAX_DB_SQLITE_SEEDS_DIR`
-    /// or run with a `seeds` directory in the current working directory.
+    /// The default seed data is embedded in the binary, so a packaged build
+    /// needs no files next to it. To load custom data instead, set
+    /// `TAX_DB_SQLITE_SEEDS_DIR` or run with a `seeds` directory in the
+    /// current working directory (see [`seeds_dir`]).
     ///
     /// NOTE: if your `SqliteRepository::new` expects a sqlx-style URL
     /// (`sqlite:path?mode=rwc`) rather than a bare path, adjust the
@@ -71,9 +69,14 @@ impl RepositoryFactory for SqliteRepositoryFactory {
         repo.run_migrations()
             .await
             .map_err(RepositoryError::Database)?;
-        repo.run_seeds(&seeds_dir())
-            .await
-            .map_err(RepositoryError::Database)?;
+        let applied = match seeds_dir() {
+            Some(dir) => repo.run_seeds(&dir).await,
+            None => repo.run_embedded_seeds().await,
+        }
+        .map_err(RepositoryError::Database)?;
+        if !applied.is_empty() {
+            tracing::info!("Applied seed files: {}", applied.join(", "));
+        }
         Ok(Box::new(repo))
     }
 }
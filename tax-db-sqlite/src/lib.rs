@@ -1,3 +1,4 @@
+mod backup;
 mod decimal;
 pub mod factory;
 pub mod repository;
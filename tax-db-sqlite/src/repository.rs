@@ -1,21 +1,56 @@
+use std::ops::{Deref, DerefMut};
 use std::path::Path;
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
 use sqlx::{
-    AssertSqlSafe, Row,
-    sqlite::{SqliteConnectOptions, SqlitePool, SqlitePoolOptions, SqliteRow},
+    AssertSqlSafe, Row, Sqlite, Transaction,
+    pool::PoolConnection,
+    sqlite::{SqliteConnectOptions, SqliteConnection, SqlitePool, SqlitePoolOptions, SqliteRow},
 };
 use tax_core::{
-    FilingStatus, FilingStatusCode, RepositoryError, StandardDeduction, TaxBracket, TaxEstimate,
-    TaxEstimateComputed, TaxEstimateInput, TaxRepository, TaxYearConfig,
+    AdditionalMedicareThreshold, CapitalGainBreakpoints, FilingStatus, FilingStatusCode,
+    NiitThreshold, QbiThreshold, RepositoryError, StandardDeduction, TaxBracket, TaxEstimate,
+    TaxEstimateComputed, TaxEstimateInput, TaxEstimateRevision, TaxRepository, TaxTransaction,
+    TaxYearConfig, UnderpaymentInterestRate,
 };
+use tokio::sync::{Mutex, MutexGuard};
 
-use crate::decimal::{decimal_to_f64, get_decimal, get_optional_decimal};
+use crate::decimal::{decimal_to_text, get_decimal, get_optional_decimal};
 
 pub struct SqliteRepository {
     pool: SqlitePool,
+    /// Set on the repository returned by [`TaxRepository::begin`]; every
+    /// query then runs inside this transaction instead of on the pool.
+    transaction: Option<Mutex<Transaction<'static, Sqlite>>>,
+}
+
+/// The connection a single statement runs on.
+enum Conn<'a> {
+    Pooled(PoolConnection<Sqlite>),
+    Transaction(MutexGuard<'a, Transaction<'static, Sqlite>>),
+}
+
+impl Deref for Conn<'_> {
+    type Target = SqliteConnection;
+
+    fn deref(&self) -> &SqliteConnection {
+        match self {
+            Self::Pooled(conn) => conn,
+            Self::Transaction(transaction) => transaction,
+        }
+    }
+}
+
+impl DerefMut for Conn<'_> {
+    fn deref_mut(&mut self) -> &mut SqliteConnection {
+        match self {
+            Self::Pooled(conn) => conn,
+            Self::Transaction(transaction) => transaction,
+        }
+    }
 }
 
 impl SqliteRepository {
@@ -39,11 +74,17 @@ impl SqliteRepository {
 
         tracing::info!("Connected to database {database_url}");
 
-        Ok(Self { pool })
+        Ok(Self {
+            pool,
+            transaction: None,
+        })
     }
 
     pub async fn new_with_pool(pool: SqlitePool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            transaction: None,
+        }
     }
 
     /// Apply embedded SQLx migrations to the configured database.
@@ -55,12 +96,17 @@ impl SqliteRepository {
         Ok(())
     }
 
-    /// Load and execute all SQL seed files from the specified directory.
-    /// Files are executed in alphabetical order by filename.
+    /// Apply the SQL seed files in `seeds_dir` that are new or have changed
+    /// since they were last applied, in alphabetical order by filename.
+    ///
+    /// Each file runs in its own transaction together with its `seed_history`
+    /// row (file name and SHA-256 checksum), so a failing file leaves no
+    /// partial data behind and is retried on the next run. Returns the names
+    /// of the files that were applied.
     pub async fn run_seeds(
         &self,
         seeds_dir: &Path,
-    ) -> Result<()> {
+    ) -> Result<Vec<String>> {
         tracing::info!(
             "Running seeds for sqlite from {}",
             seeds_dir.to_string_lossy()
@@ -74,18 +120,84 @@ impl SqliteRepository {
 
         entries.sort_by_key(|entry| entry.file_name());
 
+        let mut seeds = Vec::with_capacity(entries.len());
         for entry in entries {
             let path = entry.path();
             let sql = std::fs::read_to_string(&path)
                 .with_context(|| format!("Failed to read seed file '{}'", path.display()))?;
+            seeds.push((entry.file_name().to_string_lossy().into_owned(), sql));
+        }
+
+        self.apply_seeds(seeds).await
+    }
 
+    /// Apply the default seed files compiled into this crate from
+    /// `tax-db-sqlite/seeds/`, with the same change tracking as
+    /// [`run_seeds`](Self::run_seeds).
+    ///
+    /// The embedded files are recorded under their original file names, so
+    /// switching between a seeds directory and the embedded set only
+    /// re-applies files whose contents differ.
+    pub async fn run_embedded_seeds(&self) -> Result<Vec<String>> {
+        tracing::info!("Running embedded seeds for sqlite");
+        self.apply_seeds(
+            EMBEDDED_SEEDS
+                .iter()
+                .map(|(name, sql)| (name.to_string(), sql.to_string()))
+                .collect(),
+        )
+        .await
+    }
+
+    async fn apply_seeds(
+        &self,
+        seeds: Vec<(String, String)>,
+    ) -> Result<Vec<String>> {
+        let mut applied = Vec::new();
+        for (file_name, sql) in seeds {
+            let checksum = seed_checksum(&sql);
+
+            let recorded: Option<String> =
+                sqlx::query_scalar("SELECT checksum FROM seed_history WHERE file_name = ?")
+                    .bind(&file_name)
+                    .fetch_optional(&self.pool)
+                    .await
+                    .context("Failed to read seed history")?;
+            if recorded.as_deref() == Some(checksum.as_str()) {
+                tracing::debug!("Seed {file_name} is up to date");
+                continue;
+            }
+
+            let mut tx = self
+                .pool
+                .begin()
+                .await
+                .context("Failed to start seed transaction")?;
             sqlx::raw_sql(AssertSqlSafe(sql))
-                .execute(&self.pool)
+                .execute(&mut *tx)
+                .await
+                .with_context(|| format!("Failed to execute seed file '{file_name}'"))?;
+            sqlx::query(
+                "INSERT INTO seed_history (file_name, checksum, applied_at) VALUES (?, ?, ?)
+                 ON CONFLICT (file_name) DO UPDATE SET
+                     checksum = excluded.checksum,
+                     applied_at = excluded.applied_at",
+            )
+            .bind(&file_name)
+            .bind(&checksum)
+            .bind(Utc::now())
+            .execute(&mut *tx)
+            .await
+            .context("Failed to record seed history")?;
+            tx.commit()
                 .await
-                .with_context(|| format!("Failed to execute seed file '{}'", path.display()))?;
+                .with_context(|| format!("Failed to commit seed file '{file_name}'"))?;
+
+            tracing::info!("Applied seed {file_name}");
+            applied.push(file_name);
         }
 
-        Ok(())
+        Ok(applied)
     }
 
     pub fn pool(&self) -> &SqlitePool {
@@ -98,6 +210,184 @@ impl SqliteRepository {
     ) -> Result<i32, RepositoryError> {
         Ok(self.get_filing_status_by_code(code.as_str()).await?.id)
     }
+
+    /// Upsert an estimate's scenario with `computed` as its results, or with
+    /// none when `computed` is `None`.
+    async fn upsert_estimate(
+        &self,
+        estimate: TaxEstimateInput,
+        computed: Option<&TaxEstimateComputed>,
+    ) -> Result<TaxEstimate, RepositoryError> {
+        let now: DateTime<Utc> = Utc::now();
+        let filing_status_id = self
+            .filing_status_id_for_code(estimate.filing_status)
+            .await?;
+
+        let id: Option<i64> = sqlx::query_scalar(
+            "INSERT INTO tax_estimate (
+                tax_year, filing_status_id, scenario_name, expected_agi, expected_deduction,
+                expected_qbi_deduction, expected_amt, expected_credits,
+                expected_other_taxes, expected_withholding, prior_year_tax,
+                se_income, expected_crp_payments, expected_wages,
+                net_investment_income, prior_year_agi, qualified_dividends,
+                net_capital_gain, calculated_se_tax, calculated_total_tax,
+                calculated_required_payment, created_at, updated_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT (tax_year, filing_status_id, scenario_name) DO UPDATE SET
+                expected_agi = excluded.expected_agi,
+                expected_deduction = excluded.expected_deduction,
+                expected_qbi_deduction = excluded.expected_qbi_deduction,
+                expected_amt = excluded.expected_amt,
+                expected_credits = excluded.expected_credits,
+                expected_other_taxes = excluded.expected_other_taxes,
+                expected_withholding = excluded.expected_withholding,
+                prior_year_tax = excluded.prior_year_tax,
+                se_income = excluded.se_income,
+                expected_crp_payments = excluded.expected_crp_payments,
+                expected_wages = excluded.expected_wages,
+                net_investment_income = excluded.net_investment_income,
+                prior_year_agi = excluded.prior_year_agi,
+                qualified_dividends = excluded.qualified_dividends,
+                net_capital_gain = excluded.net_capital_gain,
+                calculated_se_tax = excluded.calculated_se_tax,
+                calculated_total_tax = excluded.calculated_total_tax,
+                calculated_required_payment = excluded.calculated_required_payment,
+                updated_at = excluded.updated_at
+            WHERE tax_estimate.deleted_at IS NULL
+            RETURNING id",
+        )
+        .bind(estimate.tax_year)
+        .bind(filing_status_id)
+        .bind(estimate.scenario_name.trim())
+        .bind(decimal_to_text(estimate.expected_agi))
+        .bind(decimal_to_text(estimate.expected_deduction))
+        .bind(estimate.expected_qbi_deduction.map(decimal_to_text))
+        .bind(estimate.expected_amt.map(decimal_to_text))
+        .bind(estimate.expected_credits.map(decimal_to_text))
+        .bind(estimate.expected_other_taxes.map(decimal_to_text))
+        .bind(estimate.expected_withholding.map(decimal_to_text))
+        .bind(estimate.prior_year_tax.map(decimal_to_text))
+        .bind(estimate.se_income.map(decimal_to_text))
+        .bind(estimate.expected_crp_payments.map(decimal_to_text))
+        .bind(estimate.expected_wages.map(decimal_to_text))
+        .bind(estimate.net_investment_income.map(decimal_to_text))
+        .bind(estimate.prior_year_agi.map(decimal_to_text))
+        .bind(estimate.qualified_dividends.map(decimal_to_text))
+        .bind(estimate.net_capital_gain.map(decimal_to_text))
+        .bind(computed.map(|c| decimal_to_text(c.se_tax)))
+        .bind(computed.map(|c| decimal_to_text(c.total_tax)))
+        .bind(computed.map(|c| decimal_to_text(c.required_payment)))
+        .bind(now)
+        .bind(now)
+        .fetch_optional(&mut *self.conn().await?)
+        .await
+        .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        // The upsert skips a scenario whose estimate is in the trash.
+        let Some(id) = id else {
+            let trashed: i64 = sqlx::query_scalar(
+                "SELECT id FROM tax_estimate
+                WHERE tax_year = ? AND filing_status_id = ? AND scenario_name = ?",
+            )
+            .bind(estimate.tax_year)
+            .bind(filing_status_id)
+            .bind(estimate.scenario_name.trim())
+            .fetch_one(&mut *self.conn().await?)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+            return Err(RepositoryError::EstimateInTrash {
+                id: trashed,
+                scenario_name: estimate.scenario_name.trim().to_string(),
+            });
+        };
+        self.get_estimate(id).await
+    }
+
+    /// The open transaction, or a pooled connection when there is none.
+    async fn conn(&self) -> Result<Conn<'_>, RepositoryError> {
+        match &self.transaction {
+            Some(transaction) => Ok(Conn::Transaction(transaction.lock().await)),
+            None => self
+                .pool
+                .acquire()
+                .await
+                .map(Conn::Pooled)
+                .map_err(|e| RepositoryError::Database(e.into())),
+        }
+    }
+
+    fn into_transaction(self) -> Result<Transaction<'static, Sqlite>, RepositoryError> {
+        self.transaction
+            .map(Mutex::into_inner)
+            .ok_or_else(|| RepositoryError::Configuration("No transaction in progress".to_string()))
+    }
+}
+
+/// Default seed files, embedded at build time the way `sqlx::migrate!` embeds
+/// the migrations. Keep this list in sync with `seeds/`.
+const EMBEDDED_SEEDS: &[(&str, &str)] = &[
+    (
+        "01_filing_status.sql",
+        include_str!("../seeds/01_filing_status.sql"),
+    ),
+    (
+        "02_tax_year_config_2025.sql",
+        include_str!("../seeds/02_tax_year_config_2025.sql"),
+    ),
+    (
+        "03_standard_deductions_2025.sql",
+        include_str!("../seeds/03_standard_deductions_2025.sql"),
+    ),
+    (
+        "04_tax_brackets_2025.sql",
+        include_str!("../seeds/04_tax_brackets_2025.sql"),
+    ),
+    (
+        "05_additional_medicare_thresholds_2025.sql",
+        include_str!("../seeds/05_additional_medicare_thresholds_2025.sql"),
+    ),
+    (
+        "06_niit_thresholds_2025.sql",
+        include_str!("../seeds/06_niit_thresholds_2025.sql"),
+    ),
+    (
+        "07_underpayment_interest_rates.sql",
+        include_str!("../seeds/07_underpayment_interest_rates.sql"),
+    ),
+    (
+        "08_capital_gain_breakpoints.sql",
+        include_str!("../seeds/08_capital_gain_breakpoints.sql"),
+    ),
+    (
+        "09_qbi_thresholds.sql",
+        include_str!("../seeds/09_qbi_thresholds.sql"),
+    ),
+];
+
+/// Hex SHA-256 of a seed file's contents, as stored in `seed_history`.
+fn seed_checksum(sql: &str) -> String {
+    format!("{:x}", Sha256::digest(sql.as_bytes()))
+}
+
+/// Builds a `tax_estimate` SELECT joined to `filing_status`, with every column
+/// [`row_to_tax_estimate`] reads, followed by the given WHERE/ORDER BY suffix.
+macro_rules! estimates_query {
+    ($suffix:literal) => {
+        concat!(
+            "SELECT te.id, te.tax_year, te.scenario_name, te.expected_agi, te.expected_deduction,
+                te.expected_qbi_deduction, te.expected_amt, te.expected_credits,
+                te.expected_other_taxes, te.expected_withholding, te.prior_year_tax,
+                te.se_income, te.expected_crp_payments, te.expected_wages,
+                te.net_investment_income, te.prior_year_agi,
+                te.qualified_dividends, te.net_capital_gain,
+                te.calculated_se_tax, te.calculated_total_tax, te.calculated_required_payment,
+                te.created_at, te.updated_at, te.deleted_at,
+                fs.status_code AS filing_status_code
+             FROM tax_estimate te
+             JOIN filing_status fs ON fs.id = te.filing_status_id ",
+            $suffix
+        )
+    };
 }
 
 fn row_to_tax_estimate(row: &SqliteRow) -> Result<TaxEstimate, RepositoryError> {
@@ -137,9 +427,15 @@ fn row_to_tax_estimate(row: &SqliteRow) -> Result<TaxEstimate, RepositoryError>
                 .try_get("tax_year")
                 .map_err(|e| RepositoryError::Database(e.into()))?,
             filing_status,
+            scenario_name: row
+                .try_get("scenario_name")
+                .map_err(|e| RepositoryError::Database(e.into()))?,
             se_income: get_optional_decimal(row, "se_income")?,
             expected_crp_payments: get_optional_decimal(row, "expected_crp_payments")?,
             expected_wages: get_optional_decimal(row, "expected_wages")?,
+            net_investment_income: get_optional_decimal(row, "net_investment_income")?,
+            qualified_dividends: get_optional_decimal(row, "qualified_dividends")?,
+            net_capital_gain: get_optional_decimal(row, "net_capital_gain")?,
             expected_agi: get_decimal(row, "expected_agi")?,
             expected_deduction: get_decimal(row, "expected_deduction")?,
             expected_qbi_deduction: get_optional_decimal(row, "expected_qbi_deduction")?,
@@ -148,6 +444,7 @@ fn row_to_tax_estimate(row: &SqliteRow) -> Result<TaxEstimate, RepositoryError>
             expected_other_taxes: get_optional_decimal(row, "expected_other_taxes")?,
             expected_withholding: get_optional_decimal(row, "expected_withholding")?,
             prior_year_tax: get_optional_decimal(row, "prior_year_tax")?,
+            prior_year_agi: get_optional_decimal(row, "prior_year_agi")?,
         },
         computed,
         created_at: row.try_get::<DateTime<Utc>, _>("created_at").map_err(|e| {
@@ -156,6 +453,49 @@ fn row_to_tax_estimate(row: &SqliteRow) -> Result<TaxEstimate, RepositoryError>
         updated_at: row.try_get::<DateTime<Utc>, _>("updated_at").map_err(|e| {
             RepositoryError::Database(anyhow::anyhow!("Failed to get updated_at: {}", e))
         })?,
+        deleted_at: row
+            .try_get::<Option<DateTime<Utc>>, _>("deleted_at")
+            .map_err(|e| {
+                RepositoryError::Database(anyhow::anyhow!("Failed to get deleted_at: {}", e))
+            })?,
+    })
+}
+
+/// Builds a `tax_estimate_revision` SELECT aliased so that
+/// [`row_to_tax_estimate`] can read the snapshot, followed by the given suffix.
+macro_rules! revisions_query {
+    ($suffix:literal) => {
+        concat!(
+            "SELECT r.id AS revision_id, r.revision, r.estimate_id AS id, r.tax_year,
+                r.scenario_name, r.expected_agi, r.expected_deduction,
+                r.expected_qbi_deduction, r.expected_amt, r.expected_credits,
+                r.expected_other_taxes, r.expected_withholding, r.prior_year_tax,
+                r.se_income, r.expected_crp_payments, r.expected_wages,
+                r.net_investment_income, r.prior_year_agi,
+                r.qualified_dividends, r.net_capital_gain,
+                r.calculated_se_tax, r.calculated_total_tax, r.calculated_required_payment,
+                r.recorded_at AS created_at, r.recorded_at AS updated_at, NULL AS deleted_at,
+                fs.status_code AS filing_status_code
+             FROM tax_estimate_revision r
+             JOIN filing_status fs ON fs.id = r.filing_status_id ",
+            $suffix
+        )
+    };
+}
+
+fn row_to_tax_estimate_revision(row: &SqliteRow) -> Result<TaxEstimateRevision, RepositoryError> {
+    let snapshot = row_to_tax_estimate(row)?;
+    Ok(TaxEstimateRevision {
+        id: row
+            .try_get("revision_id")
+            .map_err(|e| RepositoryError::Database(e.into()))?,
+        estimate_id: snapshot.id,
+        revision: row
+            .try_get("revision")
+            .map_err(|e| RepositoryError::Database(e.into()))?,
+        input: snapshot.input,
+        computed: snapshot.computed,
+        recorded_at: snapshot.updated_at,
     })
 }
 
@@ -168,11 +508,13 @@ impl TaxRepository for SqliteRepository {
         let row = sqlx::query(
             "SELECT tax_year, ss_wage_max, ss_tax_rate, medicare_tax_rate,
                     se_tax_deductible_percentage, se_deduction_factor,
-                    required_payment_threshold, min_se_threshold
+                    required_payment_threshold, min_se_threshold,
+                    additional_medicare_tax_rate, niit_rate,
+                    prior_agi_threshold, prior_agi_threshold_mfs, prior_tax_multiplier
              FROM tax_year_config WHERE tax_year = ?",
         )
         .bind(year)
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *self.conn().await?)
         .await
         .map_err(|e| RepositoryError::Database(e.into()))?
         .ok_or(RepositoryError::NotFound)?;
@@ -188,12 +530,17 @@ impl TaxRepository for SqliteRepository {
             se_deduction_factor: get_decimal(&row, "se_deduction_factor")?,
             req_pmnt_threshold: get_decimal(&row, "required_payment_threshold")?,
             min_se_threshold: get_decimal(&row, "min_se_threshold")?,
+            addl_medicare_tax_rate: get_decimal(&row, "additional_medicare_tax_rate")?,
+            niit_rate: get_decimal(&row, "niit_rate")?,
+            prior_agi_threshold: get_decimal(&row, "prior_agi_threshold")?,
+            prior_agi_threshold_mfs: get_decimal(&row, "prior_agi_threshold_mfs")?,
+            prior_tax_multiplier: get_decimal(&row, "prior_tax_multiplier")?,
         })
     }
 
     async fn list_tax_years(&self) -> Result<Vec<i32>, RepositoryError> {
         let rows = sqlx::query("SELECT tax_year FROM tax_year_config ORDER BY tax_year DESC")
-            .fetch_all(&self.pool)
+            .fetch_all(&mut *self.conn().await?)
             .await
             .map_err(|e| RepositoryError::Database(e.into()))?;
 
@@ -212,7 +559,7 @@ impl TaxRepository for SqliteRepository {
         let row =
             sqlx::query("SELECT id, status_code, status_name FROM filing_status WHERE id = ?")
                 .bind(id)
-                .fetch_optional(&self.pool)
+                .fetch_optional(&mut *self.conn().await?)
                 .await
                 .map_err(|e| RepositoryError::Database(e.into()))?
                 .ok_or(RepositoryError::NotFound)?;
@@ -243,7 +590,7 @@ impl TaxRepository for SqliteRepository {
             "SELECT id, status_code, status_name FROM filing_status WHERE status_code = ?",
         )
         .bind(code)
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *self.conn().await?)
         .await
         .map_err(|e| RepositoryError::Database(e.into()))?
         .ok_or(RepositoryError::NotFound)?;
@@ -269,7 +616,7 @@ impl TaxRepository for SqliteRepository {
     async fn list_filing_statuses(&self) -> Result<Vec<FilingStatus>, RepositoryError> {
         let rows =
             sqlx::query("SELECT id, status_code, status_name FROM filing_status ORDER BY id")
-                .fetch_all(&self.pool)
+                .fetch_all(&mut *self.conn().await?)
                 .await
                 .map_err(|e| RepositoryError::Database(e.into()))?;
 
@@ -307,7 +654,7 @@ impl TaxRepository for SqliteRepository {
         )
         .bind(tax_year)
         .bind(filing_status_id)
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *self.conn().await?)
         .await
         .map_err(|e| RepositoryError::Database(e.into()))?
         .ok_or(RepositoryError::NotFound)?;
@@ -323,6 +670,229 @@ impl TaxRepository for SqliteRepository {
         })
     }
 
+    async fn get_additional_medicare_threshold(
+        &self,
+        tax_year: i32,
+        filing_status_id: i32,
+    ) -> Result<AdditionalMedicareThreshold, RepositoryError> {
+        let row = sqlx::query(
+            "SELECT tax_year, filing_status_id, amount
+             FROM additional_medicare_thresholds
+             WHERE tax_year = ? AND filing_status_id = ?",
+        )
+        .bind(tax_year)
+        .bind(filing_status_id)
+        .fetch_optional(&mut *self.conn().await?)
+        .await
+        .map_err(|e| RepositoryError::Database(e.into()))?
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(AdditionalMedicareThreshold {
+            tax_year: row
+                .try_get("tax_year")
+                .map_err(|e| RepositoryError::Database(e.into()))?,
+            filing_status_id: row
+                .try_get("filing_status_id")
+                .map_err(|e| RepositoryError::Database(e.into()))?,
+            amount: get_decimal(&row, "amount")?,
+        })
+    }
+
+    async fn get_niit_threshold(
+        &self,
+        tax_year: i32,
+        filing_status_id: i32,
+    ) -> Result<NiitThreshold, RepositoryError> {
+        let row = sqlx::query(
+            "SELECT tax_year, filing_status_id, amount
+             FROM niit_thresholds
+             WHERE tax_year = ? AND filing_status_id = ?",
+        )
+        .bind(tax_year)
+        .bind(filing_status_id)
+        .fetch_optional(&mut *self.conn().await?)
+        .await
+        .map_err(|e| RepositoryError::Database(e.into()))?
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(NiitThreshold {
+            tax_year: row
+                .try_get("tax_year")
+                .map_err(|e| RepositoryError::Database(e.into()))?,
+            filing_status_id: row
+                .try_get("filing_status_id")
+                .map_err(|e| RepositoryError::Database(e.into()))?,
+            amount: get_decimal(&row, "amount")?,
+        })
+    }
+
+    async fn upsert_niit_threshold(
+        &self,
+        threshold: &NiitThreshold,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query(
+            "INSERT INTO niit_thresholds (tax_year, filing_status_id, amount)
+             VALUES (?, ?, ?)
+             ON CONFLICT (tax_year, filing_status_id) DO UPDATE SET
+                amount = excluded.amount",
+        )
+        .bind(threshold.tax_year)
+        .bind(threshold.filing_status_id)
+        .bind(decimal_to_text(threshold.amount))
+        .execute(&mut *self.conn().await?)
+        .await
+        .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(())
+    }
+
+    async fn get_capital_gain_breakpoints(
+        &self,
+        tax_year: i32,
+        filing_status_id: i32,
+    ) -> Result<CapitalGainBreakpoints, RepositoryError> {
+        let row = sqlx::query(
+            "SELECT tax_year, filing_status_id, zero_rate_max, fifteen_rate_max
+             FROM capital_gain_breakpoints
+             WHERE tax_year = ? AND filing_status_id = ?",
+        )
+        .bind(tax_year)
+        .bind(filing_status_id)
+        .fetch_optional(&mut *self.conn().await?)
+        .await
+        .map_err(|e| RepositoryError::Database(e.into()))?
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(CapitalGainBreakpoints {
+            tax_year: row
+                .try_get("tax_year")
+                .map_err(|e| RepositoryError::Database(e.into()))?,
+            filing_status_id: row
+                .try_get("filing_status_id")
+                .map_err(|e| RepositoryError::Database(e.into()))?,
+            zero_rate_max: get_decimal(&row, "zero_rate_max")?,
+            fifteen_rate_max: get_decimal(&row, "fifteen_rate_max")?,
+        })
+    }
+
+    async fn upsert_capital_gain_breakpoints(
+        &self,
+        breakpoints: &CapitalGainBreakpoints,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query(
+            "INSERT INTO capital_gain_breakpoints
+                (tax_year, filing_status_id, zero_rate_max, fifteen_rate_max)
+             VALUES (?, ?, ?, ?)
+             ON CONFLICT (tax_year, filing_status_id) DO UPDATE SET
+                zero_rate_max = excluded.zero_rate_max,
+                fifteen_rate_max = excluded.fifteen_rate_max",
+        )
+        .bind(breakpoints.tax_year)
+        .bind(breakpoints.filing_status_id)
+        .bind(decimal_to_text(breakpoints.zero_rate_max))
+        .bind(decimal_to_text(breakpoints.fifteen_rate_max))
+        .execute(&mut *self.conn().await?)
+        .await
+        .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(())
+    }
+
+    async fn get_qbi_threshold(
+        &self,
+        tax_year: i32,
+        filing_status_id: i32,
+    ) -> Result<QbiThreshold, RepositoryError> {
+        let row = sqlx::query(
+            "SELECT tax_year, filing_status_id, amount
+             FROM qbi_thresholds
+             WHERE tax_year = ? AND filing_status_id = ?",
+        )
+        .bind(tax_year)
+        .bind(filing_status_id)
+        .fetch_optional(&mut *self.conn().await?)
+        .await
+        .map_err(|e| RepositoryError::Database(e.into()))?
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(QbiThreshold {
+            tax_year: row
+                .try_get("tax_year")
+                .map_err(|e| RepositoryError::Database(e.into()))?,
+            filing_status_id: row
+                .try_get("filing_status_id")
+                .map_err(|e| RepositoryError::Database(e.into()))?,
+            amount: get_decimal(&row, "amount")?,
+        })
+    }
+
+    async fn upsert_qbi_threshold(
+        &self,
+        threshold: &QbiThreshold,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query(
+            "INSERT INTO qbi_thresholds (tax_year, filing_status_id, amount)
+             VALUES (?, ?, ?)
+             ON CONFLICT (tax_year, filing_status_id) DO UPDATE SET
+                amount = excluded.amount",
+        )
+        .bind(threshold.tax_year)
+        .bind(threshold.filing_status_id)
+        .bind(decimal_to_text(threshold.amount))
+        .execute(&mut *self.conn().await?)
+        .await
+        .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(())
+    }
+
+    async fn list_underpayment_interest_rates(
+        &self
+    ) -> Result<Vec<UnderpaymentInterestRate>, RepositoryError> {
+        let rows = sqlx::query(
+            "SELECT year, quarter, rate
+             FROM underpayment_interest_rates
+             ORDER BY year, quarter",
+        )
+        .fetch_all(&mut *self.conn().await?)
+        .await
+        .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(UnderpaymentInterestRate {
+                    year: row
+                        .try_get("year")
+                        .map_err(|e| RepositoryError::Database(e.into()))?,
+                    quarter: row
+                        .try_get("quarter")
+                        .map_err(|e| RepositoryError::Database(e.into()))?,
+                    rate: get_decimal(row, "rate")?,
+                })
+            })
+            .collect()
+    }
+
+    async fn upsert_underpayment_interest_rate(
+        &self,
+        rate: &UnderpaymentInterestRate,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query(
+            "INSERT INTO underpayment_interest_rates (year, quarter, rate)
+             VALUES (?, ?, ?)
+             ON CONFLICT (year, quarter) DO UPDATE SET
+                rate = excluded.rate",
+        )
+        .bind(rate.year)
+        .bind(rate.quarter)
+        .bind(decimal_to_text(rate.rate))
+        .execute(&mut *self.conn().await?)
+        .await
+        .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(())
+    }
+
     async fn get_filing_status_data(
         &self,
         year: i32,
@@ -342,11 +912,11 @@ impl TaxRepository for SqliteRepository {
                  ON sd.filing_status_id = fs.id AND sd.tax_year = ?
              LEFT JOIN tax_brackets tb
                  ON tb.filing_status_id = fs.id AND tb.tax_year = ?
-             ORDER BY fs.id, tb.min_income",
+             ORDER BY fs.id, CAST(tb.min_income AS REAL)",
         )
         .bind(year)
         .bind(year)
-        .fetch_all(&self.pool)
+        .fetch_all(&mut *self.conn().await?)
         .await
         .map_err(|e| RepositoryError::Database(e.into()))?;
 
@@ -419,11 +989,11 @@ impl TaxRepository for SqliteRepository {
             "SELECT tax_year, filing_status_id, min_income, max_income, tax_rate, base_tax
              FROM tax_brackets
              WHERE tax_year = ? AND filing_status_id = ?
-             ORDER BY min_income",
+             ORDER BY CAST(min_income AS REAL)",
         )
         .bind(tax_year)
         .bind(filing_status_id)
-        .fetch_all(&self.pool)
+        .fetch_all(&mut *self.conn().await?)
         .await
         .map_err(|e| RepositoryError::Database(e.into()))?;
 
@@ -455,11 +1025,11 @@ impl TaxRepository for SqliteRepository {
         )
         .bind(bracket.tax_year)
         .bind(bracket.filing_status_id)
-        .bind(decimal_to_f64(bracket.min_income))
-        .bind(bracket.max_income.map(decimal_to_f64))
-        .bind(decimal_to_f64(bracket.tax_rate))
-        .bind(decimal_to_f64(bracket.base_tax))
-        .execute(&self.pool)
+        .bind(decimal_to_text(bracket.min_income))
+        .bind(bracket.max_income.map(decimal_to_text))
+        .bind(decimal_to_text(bracket.tax_rate))
+        .bind(decimal_to_text(bracket.base_tax))
+        .execute(&mut *self.conn().await?)
         .await
         .map_err(|e| RepositoryError::Database(e.into()))?;
 
@@ -474,7 +1044,7 @@ impl TaxRepository for SqliteRepository {
         sqlx::query("DELETE FROM tax_brackets WHERE tax_year = ? AND filing_status_id = ?")
             .bind(tax_year)
             .bind(filing_status_id)
-            .execute(&self.pool)
+            .execute(&mut *self.conn().await?)
             .await
             .map_err(|e| RepositoryError::Database(e.into()))?;
 
@@ -485,75 +1055,26 @@ impl TaxRepository for SqliteRepository {
         &self,
         estimate: TaxEstimateInput,
     ) -> Result<TaxEstimate, RepositoryError> {
-        let now: DateTime<Utc> = Utc::now();
-        let filing_status_id = self
-            .filing_status_id_for_code(estimate.filing_status)
-            .await?;
+        self.upsert_estimate(estimate, None).await
+    }
 
-        let id: i64 = sqlx::query_scalar(
-            "INSERT INTO tax_estimate (
-                tax_year, filing_status_id, expected_agi, expected_deduction,
-                expected_qbi_deduction, expected_amt, expected_credits,
-                expected_other_taxes, expected_withholding, prior_year_tax,
-                se_income, expected_crp_payments, expected_wages,
-                created_at, updated_at
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-            ON CONFLICT (tax_year, filing_status_id) DO UPDATE SET
-                expected_agi = excluded.expected_agi,
-                expected_deduction = excluded.expected_deduction,
-                expected_qbi_deduction = excluded.expected_qbi_deduction,
-                expected_amt = excluded.expected_amt,
-                expected_credits = excluded.expected_credits,
-                expected_other_taxes = excluded.expected_other_taxes,
-                expected_withholding = excluded.expected_withholding,
-                prior_year_tax = excluded.prior_year_tax,
-                se_income = excluded.se_income,
-                expected_crp_payments = excluded.expected_crp_payments,
-                expected_wages = excluded.expected_wages,
-                calculated_se_tax = NULL,
-                calculated_total_tax = NULL,
-                calculated_required_payment = NULL,
-                updated_at = excluded.updated_at
-            RETURNING id",
-        )
-        .bind(estimate.tax_year)
-        .bind(filing_status_id)
-        .bind(decimal_to_f64(estimate.expected_agi))
-        .bind(decimal_to_f64(estimate.expected_deduction))
-        .bind(estimate.expected_qbi_deduction.map(decimal_to_f64))
-        .bind(estimate.expected_amt.map(decimal_to_f64))
-        .bind(estimate.expected_credits.map(decimal_to_f64))
-        .bind(estimate.expected_other_taxes.map(decimal_to_f64))
-        .bind(estimate.expected_withholding.map(decimal_to_f64))
-        .bind(estimate.prior_year_tax.map(decimal_to_f64))
-        .bind(estimate.se_income.map(decimal_to_f64))
-        .bind(estimate.expected_crp_payments.map(decimal_to_f64))
-        .bind(estimate.expected_wages.map(decimal_to_f64))
-        .bind(now)
-        .bind(now)
-        .fetch_one(&self.pool)
-        .await
-        .map_err(|e| RepositoryError::Database(e.into()))?;
-        self.get_estimate(id).await
+    async fn create_calculated_estimate(
+        &self,
+        estimate: TaxEstimateInput,
+        computed: TaxEstimateComputed,
+    ) -> Result<TaxEstimate, RepositoryError> {
+        self.upsert_estimate(estimate, Some(&computed)).await
     }
 
     async fn get_estimate(
         &self,
         id: i64,
     ) -> Result<TaxEstimate, RepositoryError> {
-        let row = sqlx::query(
-            "SELECT te.id, te.tax_year, te.expected_agi, te.expected_deduction,
-                    te.expected_qbi_deduction, te.expected_amt, te.expected_credits,
-                    te.expected_other_taxes, te.expected_withholding, te.prior_year_tax,
-                    te.se_income, te.expected_crp_payments, te.expected_wages,
-                    te.calculated_se_tax, te.calculated_total_tax, te.calculated_required_payment,
-                    te.created_at, te.updated_at, fs.status_code AS filing_status_code
-             FROM tax_estimate te
-             JOIN filing_status fs ON fs.id = te.filing_status_id
-             WHERE te.id = ?",
-        )
+        let row = sqlx::query(estimates_query!(
+            "WHERE te.id = ? AND te.deleted_at IS NULL"
+        ))
         .bind(id)
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *self.conn().await?)
         .await
         .map_err(|e: sqlx::Error| RepositoryError::Database(e.into()))?
         .ok_or(RepositoryError::NotFound)?;
@@ -572,42 +1093,50 @@ impl TaxRepository for SqliteRepository {
         let (calculated_se_tax, calculated_total_tax, calculated_required_payment) =
             match &estimate.computed {
                 Some(computed) => (
-                    Some(decimal_to_f64(computed.se_tax)),
-                    Some(decimal_to_f64(computed.total_tax)),
-                    Some(decimal_to_f64(computed.required_payment)),
+                    Some(decimal_to_text(computed.se_tax)),
+                    Some(decimal_to_text(computed.total_tax)),
+                    Some(decimal_to_text(computed.required_payment)),
                 ),
                 None => (None, None, None),
             };
 
         let result = sqlx::query(
             "UPDATE tax_estimate SET
-                tax_year = ?, filing_status_id = ?, expected_agi = ?, expected_deduction = ?,
+                tax_year = ?, filing_status_id = ?, scenario_name = ?,
+                expected_agi = ?, expected_deduction = ?,
                 expected_qbi_deduction = ?, expected_amt = ?, expected_credits = ?,
                 expected_other_taxes = ?, expected_withholding = ?, prior_year_tax = ?,
                 se_income = ?, expected_crp_payments = ?, expected_wages = ?,
+                net_investment_income = ?, prior_year_agi = ?,
+                qualified_dividends = ?, net_capital_gain = ?,
                 calculated_se_tax = ?, calculated_total_tax = ?, calculated_required_payment = ?,
                 updated_at = ?
-             WHERE id = ?",
+             WHERE id = ? AND deleted_at IS NULL",
         )
         .bind(estimate.input.tax_year)
         .bind(filing_status_id)
-        .bind(decimal_to_f64(estimate.input.expected_agi))
-        .bind(decimal_to_f64(estimate.input.expected_deduction))
-        .bind(estimate.input.expected_qbi_deduction.map(decimal_to_f64))
-        .bind(estimate.input.expected_amt.map(decimal_to_f64))
-        .bind(estimate.input.expected_credits.map(decimal_to_f64))
-        .bind(estimate.input.expected_other_taxes.map(decimal_to_f64))
-        .bind(estimate.input.expected_withholding.map(decimal_to_f64))
-        .bind(estimate.input.prior_year_tax.map(decimal_to_f64))
-        .bind(estimate.input.se_income.map(decimal_to_f64))
-        .bind(estimate.input.expected_crp_payments.map(decimal_to_f64))
-        .bind(estimate.input.expected_wages.map(decimal_to_f64))
+        .bind(estimate.input.scenario_name.trim())
+        .bind(decimal_to_text(estimate.input.expected_agi))
+        .bind(decimal_to_text(estimate.input.expected_deduction))
+        .bind(estimate.input.expected_qbi_deduction.map(decimal_to_text))
+        .bind(estimate.input.expected_amt.map(decimal_to_text))
+        .bind(estimate.input.expected_credits.map(decimal_to_text))
+        .bind(estimate.input.expected_other_taxes.map(decimal_to_text))
+        .bind(estimate.input.expected_withholding.map(decimal_to_text))
+        .bind(estimate.input.prior_year_tax.map(decimal_to_text))
+        .bind(estimate.input.se_income.map(decimal_to_text))
+        .bind(estimate.input.expected_crp_payments.map(decimal_to_text))
+        .bind(estimate.input.expected_wages.map(decimal_to_text))
+        .bind(estimate.input.net_investment_income.map(decimal_to_text))
+        .bind(estimate.input.prior_year_agi.map(decimal_to_text))
+        .bind(estimate.input.qualified_dividends.map(decimal_to_text))
+        .bind(estimate.input.net_capital_gain.map(decimal_to_text))
         .bind(calculated_se_tax)
         .bind(calculated_total_tax)
         .bind(calculated_required_payment)
         .bind(now)
         .bind(estimate.id)
-        .execute(&self.pool)
+        .execute(&mut *self.conn().await?)
         .await
         .map_err(|e| RepositoryError::Database(e.into()))?;
 
@@ -622,11 +1151,14 @@ impl TaxRepository for SqliteRepository {
         &self,
         id: i64,
     ) -> Result<(), RepositoryError> {
-        let result = sqlx::query("DELETE FROM tax_estimate WHERE id = ?")
-            .bind(id)
-            .execute(&self.pool)
-            .await
-            .map_err(|e| RepositoryError::Database(e.into()))?;
+        let result = sqlx::query(
+            "UPDATE tax_estimate SET deleted_at = ? WHERE id = ? AND deleted_at IS NULL",
+        )
+        .bind(Utc::now())
+        .bind(id)
+        .execute(&mut *self.conn().await?)
+        .await
+        .map_err(|e| RepositoryError::Database(e.into()))?;
 
         if result.rows_affected() == 0 {
             return Err(RepositoryError::NotFound);
@@ -639,39 +1171,228 @@ impl TaxRepository for SqliteRepository {
         &self,
         tax_year: Option<i32>,
     ) -> Result<Vec<TaxEstimate>, RepositoryError> {
-        macro_rules! estimates_query {
-            ($suffix:literal) => {
-                concat!(
-                    "SELECT te.id, te.tax_year, te.expected_agi, te.expected_deduction,
-                        te.expected_qbi_deduction, te.expected_amt, te.expected_credits,
-                        te.expected_other_taxes, te.expected_withholding, te.prior_year_tax,
-                        te.se_income, te.expected_crp_payments, te.expected_wages,
-                        te.calculated_se_tax, te.calculated_total_tax, te.calculated_required_payment,
-                        te.created_at, te.updated_at, fs.status_code AS filing_status_code
-                 FROM tax_estimate te
-                 JOIN filing_status fs ON fs.id = te.filing_status_id ",
-                    $suffix
-                )
-            };
-        }
+        let rows =
+            match tax_year {
+                Some(year) => sqlx::query(estimates_query!(
+                    "WHERE te.tax_year = ? AND te.deleted_at IS NULL ORDER BY te.updated_at DESC"
+                ))
+                .bind(year)
+                .fetch_all(&mut *self.conn().await?)
+                .await,
+                None => {
+                    sqlx::query(estimates_query!(
+                        "WHERE te.deleted_at IS NULL ORDER BY te.updated_at DESC"
+                    ))
+                    .fetch_all(&mut *self.conn().await?)
+                    .await
+                }
+            }
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+        rows.iter().map(row_to_tax_estimate).collect()
+    }
+
+    async fn get_estimate_by_name(
+        &self,
+        tax_year: i32,
+        filing_status: FilingStatusCode,
+        scenario_name: &str,
+    ) -> Result<TaxEstimate, RepositoryError> {
+        let row = sqlx::query(estimates_query!(
+            "WHERE te.tax_year = ? AND fs.status_code = ? AND te.scenario_name = ?
+                AND te.deleted_at IS NULL"
+        ))
+        .bind(tax_year)
+        .bind(filing_status.as_str())
+        .bind(scenario_name.trim())
+        .fetch_optional(&mut *self.conn().await?)
+        .await
+        .map_err(|e| RepositoryError::Database(e.into()))?
+        .ok_or(RepositoryError::NotFound)?;
+
+        row_to_tax_estimate(&row)
+    }
+
+    async fn list_estimates_by_name(
+        &self,
+        scenario_name: &str,
+        tax_year: Option<i32>,
+    ) -> Result<Vec<TaxEstimate>, RepositoryError> {
         let rows = match tax_year {
             Some(year) => {
                 sqlx::query(estimates_query!(
-                    "WHERE te.tax_year = ? ORDER BY te.updated_at DESC"
+                    "WHERE te.scenario_name = ? AND te.tax_year = ? AND te.deleted_at IS NULL
+                     ORDER BY te.updated_at DESC"
                 ))
+                .bind(scenario_name.trim())
                 .bind(year)
-                .fetch_all(&self.pool)
+                .fetch_all(&mut *self.conn().await?)
                 .await
             }
-            None => {
-                sqlx::query(estimates_query!("ORDER BY te.updated_at DESC"))
-                    .fetch_all(&self.pool)
-                    .await
+            None => sqlx::query(estimates_query!(
+                "WHERE te.scenario_name = ? AND te.deleted_at IS NULL ORDER BY te.updated_at DESC"
+            ))
+            .bind(scenario_name.trim())
+            .fetch_all(&mut *self.conn().await?)
+            .await,
+        }
+        .map_err(|e| RepositoryError::Database(e.into()))?;
+        rows.iter().map(row_to_tax_estimate).collect()
+    }
+
+    async fn list_estimate_revisions(
+        &self,
+        estimate_id: i64,
+    ) -> Result<Vec<TaxEstimateRevision>, RepositoryError> {
+        let rows = sqlx::query(revisions_query!(
+            "WHERE r.estimate_id = ? ORDER BY r.revision ASC"
+        ))
+        .bind(estimate_id)
+        .fetch_all(&mut *self.conn().await?)
+        .await
+        .map_err(|e| RepositoryError::Database(e.into()))?;
+        rows.iter().map(row_to_tax_estimate_revision).collect()
+    }
+
+    async fn restore_estimate_revision(
+        &self,
+        revision_id: i64,
+    ) -> Result<TaxEstimate, RepositoryError> {
+        // Read, check and write on one connection, so nothing can take the
+        // scenario in between.
+        if self.transaction.is_none() {
+            let tx = self.begin().await?;
+            let restored = tx.restore_estimate_revision(revision_id).await?;
+            tx.commit().await?;
+            return Ok(restored);
+        }
+
+        let row = sqlx::query(revisions_query!("WHERE r.id = ?"))
+            .bind(revision_id)
+            .fetch_optional(&mut *self.conn().await?)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?
+            .ok_or(RepositoryError::NotFound)?;
+        let revision = row_to_tax_estimate_revision(&row)?;
+
+        let current = self.get_estimate(revision.estimate_id).await?;
+        let filing_status_id = self
+            .filing_status_id_for_code(revision.input.filing_status)
+            .await?;
+        let other: Option<(i64, bool)> = sqlx::query_as(
+            "SELECT id, deleted_at IS NOT NULL FROM tax_estimate
+            WHERE tax_year = ? AND filing_status_id = ? AND scenario_name = ? AND id <> ?",
+        )
+        .bind(revision.input.tax_year)
+        .bind(filing_status_id)
+        .bind(&revision.input.scenario_name)
+        .bind(current.id)
+        .fetch_optional(&mut *self.conn().await?)
+        .await
+        .map_err(|e| RepositoryError::Database(e.into()))?;
+        match other {
+            Some((id, true)) => {
+                return Err(RepositoryError::EstimateInTrash {
+                    id,
+                    scenario_name: revision.input.scenario_name,
+                });
             }
+            Some((id, false)) => {
+                return Err(RepositoryError::ScenarioTaken {
+                    id,
+                    scenario_name: revision.input.scenario_name,
+                });
+            }
+            None => {}
         }
+
+        self.update_estimate(&TaxEstimate {
+            input: revision.input,
+            computed: revision.computed,
+            ..current
+        })
+        .await?;
+        self.get_estimate(revision.estimate_id).await
+    }
+
+    async fn list_deleted_estimates(&self) -> Result<Vec<TaxEstimate>, RepositoryError> {
+        let rows = sqlx::query(estimates_query!(
+            "WHERE te.deleted_at IS NOT NULL ORDER BY te.deleted_at DESC"
+        ))
+        .fetch_all(&mut *self.conn().await?)
+        .await
         .map_err(|e| RepositoryError::Database(e.into()))?;
         rows.iter().map(row_to_tax_estimate).collect()
     }
+
+    async fn restore_deleted_estimate(
+        &self,
+        id: i64,
+    ) -> Result<TaxEstimate, RepositoryError> {
+        let result = sqlx::query(
+            "UPDATE tax_estimate SET deleted_at = NULL WHERE id = ? AND deleted_at IS NOT NULL",
+        )
+        .bind(id)
+        .execute(&mut *self.conn().await?)
+        .await
+        .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        self.get_estimate(id).await
+    }
+
+    async fn purge_deleted_estimates(
+        &self,
+        retention: chrono::Duration,
+    ) -> Result<u64, RepositoryError> {
+        let cutoff = Utc::now() - retention;
+        let result = sqlx::query(
+            "DELETE FROM tax_estimate
+             WHERE deleted_at IS NOT NULL AND julianday(deleted_at) < julianday(?)",
+        )
+        .bind(cutoff)
+        .execute(&mut *self.conn().await?)
+        .await
+        .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn begin(&self) -> Result<Box<dyn TaxTransaction>, RepositoryError> {
+        if self.transaction.is_some() {
+            return Err(RepositoryError::Configuration(
+                "Nested transactions are not supported".to_string(),
+            ));
+        }
+        let transaction = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+        Ok(Box::new(Self {
+            pool: self.pool.clone(),
+            transaction: Some(Mutex::new(transaction)),
+        }))
+    }
+}
+
+#[async_trait]
+impl TaxTransaction for SqliteRepository {
+    async fn commit(self: Box<Self>) -> Result<(), RepositoryError> {
+        self.into_transaction()?
+            .commit()
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<(), RepositoryError> {
+        self.into_transaction()?
+            .rollback()
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))
+    }
 }
 
 #[cfg(test)]
@@ -679,6 +1400,7 @@ mod tests {
     use pretty_assertions::assert_eq;
     use rust_decimal_macros::dec;
     use sqlx::sqlite::SqlitePoolOptions;
+    use tax_core::DEFAULT_SCENARIO_NAME;
 
     use super::*;
 
@@ -780,6 +1502,26 @@ mod tests {
             .execute(repo.pool())
             .await
             .expect("Failed to clear tax brackets");
+        sqlx::query("DELETE FROM additional_medicare_thresholds")
+            .execute(repo.pool())
+            .await
+            .expect("Failed to clear additional medicare thresholds");
+        sqlx::query("DELETE FROM niit_thresholds")
+            .execute(repo.pool())
+            .await
+            .expect("Failed to clear NIIT thresholds");
+        sqlx::query("DELETE FROM capital_gain_breakpoints")
+            .execute(repo.pool())
+            .await
+            .expect("Failed to clear capital gain breakpoints");
+        sqlx::query("DELETE FROM qbi_thresholds")
+            .execute(repo.pool())
+            .await
+            .expect("Failed to clear QBI thresholds");
+        sqlx::query("DELETE FROM underpayment_interest_rates")
+            .execute(repo.pool())
+            .await
+            .expect("Failed to clear underpayment interest rates");
         sqlx::query("DELETE FROM filing_status")
             .execute(repo.pool())
             .await
@@ -817,9 +1559,13 @@ mod tests {
         TaxEstimateInput {
             tax_year: 8888,
             filing_status: FilingStatusCode::Single,
+            scenario_name: DEFAULT_SCENARIO_NAME.to_string(),
             se_income: Some(dec!(50000.00)),
             expected_crp_payments: None,
             expected_wages: Some(dec!(50000.00)),
+            net_investment_income: Some(dec!(12000.00)),
+            qualified_dividends: Some(dec!(4000.00)),
+            net_capital_gain: Some(dec!(6000.00)),
             expected_agi: dec!(100000.00),
             expected_deduction: dec!(15000.00),
             expected_qbi_deduction: Some(dec!(5000.00)),
@@ -828,6 +1574,7 @@ mod tests {
             expected_other_taxes: None,
             expected_withholding: Some(dec!(8000.00)),
             prior_year_tax: Some(dec!(12000.00)),
+            prior_year_agi: Some(dec!(160000.00)),
         }
     }
 
@@ -835,9 +1582,13 @@ mod tests {
         TaxEstimateInput {
             tax_year: 8888,
             filing_status: FilingStatusCode::Single,
+            scenario_name: DEFAULT_SCENARIO_NAME.to_string(),
             se_income: None,
             expected_crp_payments: None,
             expected_wages: None,
+            net_investment_income: None,
+            qualified_dividends: None,
+            net_capital_gain: None,
             expected_agi: dec!(75000.00),
             expected_deduction: dec!(15000.00),
             expected_qbi_deduction: None,
@@ -846,6 +1597,7 @@ mod tests {
             expected_other_taxes: None,
             expected_withholding: None,
             prior_year_tax: None,
+            prior_year_agi: None,
         }
     }
 
@@ -867,6 +1619,11 @@ mod tests {
         assert_eq!(config.se_deduction_factor, dec!(0.55));
         assert_eq!(config.req_pmnt_threshold, dec!(1500.00));
         assert_eq!(config.min_se_threshold, dec!(400.00));
+        assert_eq!(config.addl_medicare_tax_rate, dec!(0.009));
+        assert_eq!(config.niit_rate, dec!(0.038));
+        assert_eq!(config.prior_agi_threshold, dec!(150000.00));
+        assert_eq!(config.prior_agi_threshold_mfs, dec!(75000.00));
+        assert_eq!(config.prior_tax_multiplier, dec!(1.10));
     }
 
     #[tokio::test]
@@ -902,6 +1659,26 @@ mod tests {
             .execute(repo.pool())
             .await
             .expect("Failed to clear tax brackets");
+        sqlx::query("DELETE FROM additional_medicare_thresholds")
+            .execute(repo.pool())
+            .await
+            .expect("Failed to clear additional medicare thresholds");
+        sqlx::query("DELETE FROM niit_thresholds")
+            .execute(repo.pool())
+            .await
+            .expect("Failed to clear NIIT thresholds");
+        sqlx::query("DELETE FROM capital_gain_breakpoints")
+            .execute(repo.pool())
+            .await
+            .expect("Failed to clear capital gain breakpoints");
+        sqlx::query("DELETE FROM qbi_thresholds")
+            .execute(repo.pool())
+            .await
+            .expect("Failed to clear QBI thresholds");
+        sqlx::query("DELETE FROM underpayment_interest_rates")
+            .execute(repo.pool())
+            .await
+            .expect("Failed to clear underpayment interest rates");
         sqlx::query("DELETE FROM filing_status")
             .execute(repo.pool())
             .await
@@ -1002,132 +1779,442 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_get_tax_brackets() {
+    async fn test_get_additional_medicare_threshold() {
         let repo = setup_test_db().await;
-        insert_test_tax_brackets(&repo).await;
+        insert_test_tax_year_config(&repo).await;
+        setup_clean_filing_status(&repo).await;
 
-        let brackets = repo
-            .get_tax_brackets(9999, 99)
+        sqlx::query(
+            "INSERT INTO additional_medicare_thresholds (tax_year, filing_status_id, amount)
+             VALUES (9999, 99, 210000.00)",
+        )
+        .execute(repo.pool())
+        .await
+        .expect("Failed to insert test additional medicare threshold");
+
+        let threshold = repo
+            .get_additional_medicare_threshold(9999, 99)
             .await
-            .expect("Should find test tax brackets");
+            .expect("Should find test additional medicare threshold");
 
-        assert_eq!(brackets.len(), 3);
+        assert_eq!(threshold.tax_year, 9999);
+        assert_eq!(threshold.filing_status_id, 99);
+        assert_eq!(threshold.amount, dec!(210000.00));
+    }
 
-        assert_eq!(brackets[0].min_income, dec!(0));
-        assert_eq!(brackets[0].max_income, Some(dec!(10000)));
-        assert_eq!(brackets[0].tax_rate, dec!(0.10));
-        assert_eq!(brackets[0].base_tax, dec!(0));
+    #[tokio::test]
+    async fn test_get_additional_medicare_threshold_not_found() {
+        let repo = setup_test_db().await;
 
-        assert_eq!(brackets[1].min_income, dec!(10000));
-        assert_eq!(brackets[1].max_income, Some(dec!(50000)));
-        assert_eq!(brackets[1].tax_rate, dec!(0.15));
-        assert_eq!(brackets[1].base_tax, dec!(1000));
+        let result = repo.get_additional_medicare_threshold(1999, 1).await;
 
-        assert_eq!(brackets[2].min_income, dec!(50000));
-        assert!(brackets[2].max_income.is_none());
-        assert_eq!(brackets[2].tax_rate, dec!(0.25));
-        assert_eq!(brackets[2].base_tax, dec!(7000));
+        assert!(matches!(result, Err(RepositoryError::NotFound)));
     }
 
     #[tokio::test]
-    async fn test_get_tax_brackets_empty() {
+    async fn test_get_niit_threshold() {
         let repo = setup_test_db().await;
+        insert_test_tax_year_config(&repo).await;
+        setup_clean_filing_status(&repo).await;
 
-        let brackets = repo
-            .get_tax_brackets(1999, 1)
+        sqlx::query(
+            "INSERT INTO niit_thresholds (tax_year, filing_status_id, amount)
+             VALUES (9999, 99, 210000.00)",
+        )
+        .execute(repo.pool())
+        .await
+        .expect("Failed to insert test NIIT threshold");
+
+        let threshold = repo
+            .get_niit_threshold(9999, 99)
             .await
-            .expect("Should return empty vec");
+            .expect("Should find test NIIT threshold");
 
-        assert!(brackets.is_empty());
+        assert_eq!(threshold.tax_year, 9999);
+        assert_eq!(threshold.filing_status_id, 99);
+        assert_eq!(threshold.amount, dec!(210000.00));
     }
 
     #[tokio::test]
-    async fn test_insert_tax_bracket() {
+    async fn test_get_niit_threshold_not_found() {
+        let repo = setup_test_db().await;
+
+        let result = repo.get_niit_threshold(1999, 1).await;
+
+        assert!(matches!(result, Err(RepositoryError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_upsert_niit_threshold() {
         let repo = setup_test_db().await;
         insert_test_tax_year_config(&repo).await;
         setup_clean_filing_status(&repo).await;
 
-        let bracket = TaxBracket {
+        let mut threshold = NiitThreshold {
             tax_year: 9999,
             filing_status_id: 99,
-            min_income: dec!(0),
-            max_income: Some(dec!(20000)),
-            tax_rate: dec!(0.12),
-            base_tax: dec!(0),
+            amount: dec!(200000.00),
         };
-
-        repo.insert_tax_bracket(&bracket)
+        repo.upsert_niit_threshold(&threshold)
             .await
-            .expect("Should insert bracket");
+            .expect("Should insert NIIT threshold");
 
-        let brackets = repo
-            .get_tax_brackets(9999, 99)
+        threshold.amount = dec!(225000.00);
+        repo.upsert_niit_threshold(&threshold)
             .await
-            .expect("Should get brackets");
+            .expect("Should update NIIT threshold");
 
-        assert_eq!(brackets.len(), 1);
-        assert_eq!(brackets[0].min_income, dec!(0));
-        assert_eq!(brackets[0].max_income, Some(dec!(20000)));
-        assert_eq!(brackets[0].tax_rate, dec!(0.12));
-        assert_eq!(brackets[0].base_tax, dec!(0));
+        let stored = repo
+            .get_niit_threshold(9999, 99)
+            .await
+            .expect("Should find NIIT threshold");
+        assert_eq!(stored, threshold);
     }
 
     #[tokio::test]
-    async fn test_insert_tax_bracket_with_null_max() {
+    async fn test_get_capital_gain_breakpoints() {
         let repo = setup_test_db().await;
         insert_test_tax_year_config(&repo).await;
         setup_clean_filing_status(&repo).await;
 
-        let bracket = TaxBracket {
-            tax_year: 9999,
-            filing_status_id: 99,
-            min_income: dec!(100000),
-            max_income: None,
-            tax_rate: dec!(0.37),
-            base_tax: dec!(25000),
-        };
+        sqlx::query(
+            "INSERT INTO capital_gain_breakpoints
+                (tax_year, filing_status_id, zero_rate_max, fifteen_rate_max)
+             VALUES (9999, 99, 50000.00, 550000.00)",
+        )
+        .execute(repo.pool())
+        .await
+        .expect("Failed to insert test capital gain breakpoints");
 
-        repo.insert_tax_bracket(&bracket)
+        let breakpoints = repo
+            .get_capital_gain_breakpoints(9999, 99)
             .await
-            .expect("Should insert bracket");
+            .expect("Should find test capital gain breakpoints");
 
-        let brackets = repo
-            .get_tax_brackets(9999, 99)
-            .await
-            .expect("Should get brackets");
+        assert_eq!(breakpoints.tax_year, 9999);
+        assert_eq!(breakpoints.filing_status_id, 99);
+        assert_eq!(breakpoints.zero_rate_max, dec!(50000.00));
+        assert_eq!(breakpoints.fifteen_rate_max, dec!(550000.00));
+    }
 
-        assert_eq!(brackets.len(), 1);
-        assert_eq!(brackets[0].max_income, None);
+    #[tokio::test]
+    async fn test_get_capital_gain_breakpoints_not_found() {
+        let repo = setup_test_db().await;
+
+        let result = repo.get_capital_gain_breakpoints(1999, 1).await;
+
+        assert!(matches!(result, Err(RepositoryError::NotFound)));
     }
 
     #[tokio::test]
-    async fn test_delete_tax_brackets() {
+    async fn test_upsert_capital_gain_breakpoints() {
         let repo = setup_test_db().await;
-        insert_test_tax_brackets(&repo).await;
+        insert_test_tax_year_config(&repo).await;
+        setup_clean_filing_status(&repo).await;
 
-        let brackets_before = repo
-            .get_tax_brackets(9999, 99)
+        let mut breakpoints = CapitalGainBreakpoints {
+            tax_year: 9999,
+            filing_status_id: 99,
+            zero_rate_max: dec!(48350.00),
+            fifteen_rate_max: dec!(533400.00),
+        };
+        repo.upsert_capital_gain_breakpoints(&breakpoints)
             .await
-            .expect("Should get brackets");
-        assert_eq!(brackets_before.len(), 3);
+            .expect("Should insert capital gain breakpoints");
 
-        repo.delete_tax_brackets(9999, 99)
+        breakpoints.fifteen_rate_max = dec!(545500.00);
+        repo.upsert_capital_gain_breakpoints(&breakpoints)
             .await
-            .expect("Should delete brackets");
+            .expect("Should update capital gain breakpoints");
 
-        let brackets_after = repo
-            .get_tax_brackets(9999, 99)
+        let stored = repo
+            .get_capital_gain_breakpoints(9999, 99)
             .await
-            .expect("Should get brackets");
-        assert!(brackets_after.is_empty());
+            .expect("Should find capital gain breakpoints");
+        assert_eq!(stored, breakpoints);
     }
 
     #[tokio::test]
-    async fn test_delete_tax_brackets_nonexistent() {
+    async fn test_get_qbi_threshold() {
         let repo = setup_test_db().await;
+        insert_test_tax_year_config(&repo).await;
+        setup_clean_filing_status(&repo).await;
 
-        // Should not error when deleting nonexistent brackets
-        repo.delete_tax_brackets(9999, 99)
+        sqlx::query(
+            "INSERT INTO qbi_thresholds (tax_year, filing_status_id, amount)
+             VALUES (9999, 99, 197300.00)",
+        )
+        .execute(repo.pool())
+        .await
+        .expect("Failed to insert test QBI threshold");
+
+        let threshold = repo
+            .get_qbi_threshold(9999, 99)
+            .await
+            .expect("Should find test QBI threshold");
+
+        assert_eq!(threshold.tax_year, 9999);
+        assert_eq!(threshold.filing_status_id, 99);
+        assert_eq!(threshold.amount, dec!(197300.00));
+    }
+
+    #[tokio::test]
+    async fn test_get_qbi_threshold_not_found() {
+        let repo = setup_test_db().await;
+
+        let result = repo.get_qbi_threshold(1999, 1).await;
+
+        assert!(matches!(result, Err(RepositoryError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_upsert_qbi_threshold() {
+        let repo = setup_test_db().await;
+        insert_test_tax_year_config(&repo).await;
+        setup_clean_filing_status(&repo).await;
+
+        let mut threshold = QbiThreshold {
+            tax_year: 9999,
+            filing_status_id: 99,
+            amount: dec!(197300.00),
+        };
+        repo.upsert_qbi_threshold(&threshold)
+            .await
+            .expect("Should insert QBI threshold");
+
+        threshold.amount = dec!(201775.00);
+        repo.upsert_qbi_threshold(&threshold)
+            .await
+            .expect("Should update QBI threshold");
+
+        let stored = repo
+            .get_qbi_threshold(9999, 99)
+            .await
+            .expect("Should find QBI threshold");
+        assert_eq!(stored, threshold);
+    }
+
+    #[tokio::test]
+    async fn test_list_underpayment_interest_rates_ordered() {
+        let repo = setup_test_db().await;
+
+        sqlx::query(
+            "INSERT INTO underpayment_interest_rates (year, quarter, rate)
+             VALUES (9999, 2, 0.06), (9998, 4, 0.08), (9999, 1, 0.07)",
+        )
+        .execute(repo.pool())
+        .await
+        .expect("Failed to insert test underpayment interest rates");
+
+        let rates = repo
+            .list_underpayment_interest_rates()
+            .await
+            .expect("Should list underpayment interest rates");
+
+        let keys: Vec<(i32, u32)> = rates.iter().map(|r| (r.year, r.quarter)).collect();
+        assert_eq!(keys, vec![(9998, 4), (9999, 1), (9999, 2)]);
+        assert_eq!(rates[2].rate, dec!(0.06));
+    }
+
+    #[tokio::test]
+    async fn test_upsert_underpayment_interest_rate() {
+        let repo = setup_test_db().await;
+
+        let mut rate = UnderpaymentInterestRate {
+            year: 9999,
+            quarter: 3,
+            rate: dec!(0.07),
+        };
+        repo.upsert_underpayment_interest_rate(&rate)
+            .await
+            .expect("Should insert underpayment interest rate");
+
+        rate.rate = dec!(0.06);
+        repo.upsert_underpayment_interest_rate(&rate)
+            .await
+            .expect("Should update underpayment interest rate");
+
+        let rates = repo
+            .list_underpayment_interest_rates()
+            .await
+            .expect("Should list underpayment interest rates");
+        assert_eq!(rates, vec![rate]);
+    }
+
+    #[tokio::test]
+    async fn test_upsert_underpayment_interest_rate_rejects_invalid_quarter() {
+        let repo = setup_test_db().await;
+
+        let result = repo
+            .upsert_underpayment_interest_rate(&UnderpaymentInterestRate {
+                year: 9999,
+                quarter: 5,
+                rate: dec!(0.07),
+            })
+            .await;
+
+        assert!(matches!(result, Err(RepositoryError::Database(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_tax_brackets() {
+        let repo = setup_test_db().await;
+        insert_test_tax_brackets(&repo).await;
+
+        let brackets = repo
+            .get_tax_brackets(9999, 99)
+            .await
+            .expect("Should find test tax brackets");
+
+        assert_eq!(brackets.len(), 3);
+
+        assert_eq!(brackets[0].min_income, dec!(0));
+        assert_eq!(brackets[0].max_income, Some(dec!(10000)));
+        assert_eq!(brackets[0].tax_rate, dec!(0.10));
+        assert_eq!(brackets[0].base_tax, dec!(0));
+
+        assert_eq!(brackets[1].min_income, dec!(10000));
+        assert_eq!(brackets[1].max_income, Some(dec!(50000)));
+        assert_eq!(brackets[1].tax_rate, dec!(0.15));
+        assert_eq!(brackets[1].base_tax, dec!(1000));
+
+        assert_eq!(brackets[2].min_income, dec!(50000));
+        assert!(brackets[2].max_income.is_none());
+        assert_eq!(brackets[2].tax_rate, dec!(0.25));
+        assert_eq!(brackets[2].base_tax, dec!(7000));
+    }
+
+    #[tokio::test]
+    async fn test_get_tax_brackets_empty() {
+        let repo = setup_test_db().await;
+
+        let brackets = repo
+            .get_tax_brackets(1999, 1)
+            .await
+            .expect("Should return empty vec");
+
+        assert!(brackets.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_insert_tax_bracket() {
+        let repo = setup_test_db().await;
+        insert_test_tax_year_config(&repo).await;
+        setup_clean_filing_status(&repo).await;
+
+        let bracket = TaxBracket {
+            tax_year: 9999,
+            filing_status_id: 99,
+            min_income: dec!(0),
+            max_income: Some(dec!(20000)),
+            tax_rate: dec!(0.12),
+            base_tax: dec!(0),
+        };
+
+        repo.insert_tax_bracket(&bracket)
+            .await
+            .expect("Should insert bracket");
+
+        let brackets = repo
+            .get_tax_brackets(9999, 99)
+            .await
+            .expect("Should get brackets");
+
+        assert_eq!(brackets.len(), 1);
+        assert_eq!(brackets[0].min_income, dec!(0));
+        assert_eq!(brackets[0].max_income, Some(dec!(20000)));
+        assert_eq!(brackets[0].tax_rate, dec!(0.12));
+        assert_eq!(brackets[0].base_tax, dec!(0));
+    }
+
+    #[tokio::test]
+    async fn test_get_tax_brackets_ordered_numerically() {
+        let repo = setup_test_db().await;
+        insert_test_tax_year_config(&repo).await;
+        setup_clean_filing_status(&repo).await;
+
+        for (min_income, max_income) in [
+            (dec!(100000), None),
+            (dec!(9000), Some(dec!(100000))),
+            (dec!(0), Some(dec!(9000))),
+        ] {
+            repo.insert_tax_bracket(&TaxBracket {
+                tax_year: 9999,
+                filing_status_id: 99,
+                min_income,
+                max_income,
+                tax_rate: dec!(0.10),
+                base_tax: dec!(0),
+            })
+            .await
+            .expect("Should insert bracket");
+        }
+
+        let brackets = repo
+            .get_tax_brackets(9999, 99)
+            .await
+            .expect("Should get brackets");
+
+        let mins: Vec<_> = brackets.iter().map(|b| b.min_income).collect();
+        assert_eq!(mins, vec![dec!(0), dec!(9000), dec!(100000)]);
+    }
+
+    #[tokio::test]
+    async fn test_insert_tax_bracket_with_null_max() {
+        let repo = setup_test_db().await;
+        insert_test_tax_year_config(&repo).await;
+        setup_clean_filing_status(&repo).await;
+
+        let bracket = TaxBracket {
+            tax_year: 9999,
+            filing_status_id: 99,
+            min_income: dec!(100000),
+            max_income: None,
+            tax_rate: dec!(0.37),
+            base_tax: dec!(25000),
+        };
+
+        repo.insert_tax_bracket(&bracket)
+            .await
+            .expect("Should insert bracket");
+
+        let brackets = repo
+            .get_tax_brackets(9999, 99)
+            .await
+            .expect("Should get brackets");
+
+        assert_eq!(brackets.len(), 1);
+        assert_eq!(brackets[0].max_income, None);
+    }
+
+    #[tokio::test]
+    async fn test_delete_tax_brackets() {
+        let repo = setup_test_db().await;
+        insert_test_tax_brackets(&repo).await;
+
+        let brackets_before = repo
+            .get_tax_brackets(9999, 99)
+            .await
+            .expect("Should get brackets");
+        assert_eq!(brackets_before.len(), 3);
+
+        repo.delete_tax_brackets(9999, 99)
+            .await
+            .expect("Should delete brackets");
+
+        let brackets_after = repo
+            .get_tax_brackets(9999, 99)
+            .await
+            .expect("Should get brackets");
+        assert!(brackets_after.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_delete_tax_brackets_nonexistent() {
+        let repo = setup_test_db().await;
+
+        // Should not error when deleting nonexistent brackets
+        repo.delete_tax_brackets(9999, 99)
             .await
             .expect("Should succeed even if no brackets exist");
     }
@@ -1189,6 +2276,10 @@ mod tests {
         assert_eq!(created.input.se_income, Some(dec!(50000.00)));
         assert_eq!(created.input.expected_crp_payments, None);
         assert_eq!(created.input.expected_wages, Some(dec!(50000.00)));
+        assert_eq!(created.input.net_investment_income, Some(dec!(12000.00)));
+        assert_eq!(created.input.prior_year_agi, Some(dec!(160000.00)));
+        assert_eq!(created.input.qualified_dividends, Some(dec!(4000.00)));
+        assert_eq!(created.input.net_capital_gain, Some(dec!(6000.00)));
         assert_eq!(created.computed, None);
 
         let fetched = repo
@@ -1199,6 +2290,46 @@ mod tests {
         assert_eq!(fetched.input.expected_agi, dec!(100000.00));
     }
 
+    #[tokio::test]
+    async fn test_estimate_decimals_round_trip_bit_for_bit() {
+        let repo = setup_test_db().await;
+        setup_test_data_for_estimates(&repo).await;
+
+        let mut new_estimate = create_test_estimate();
+        new_estimate.expected_agi = dec!(0.1) + dec!(0.2);
+        new_estimate.se_income = Some(dec!(176100.00));
+        new_estimate.expected_credits = Some(dec!(1234567890.0123));
+        let created = repo
+            .create_estimate(new_estimate)
+            .await
+            .expect("Should create estimate");
+
+        let fetched = repo
+            .get_estimate(created.id)
+            .await
+            .expect("Should fetch estimate");
+
+        assert_eq!(fetched.input.expected_agi.to_string(), "0.3");
+        assert_eq!(
+            fetched.input.expected_agi.serialize(),
+            (dec!(0.1) + dec!(0.2)).serialize()
+        );
+        let se_income = fetched.input.se_income.expect("se_income should be set");
+        assert_eq!(se_income.serialize(), dec!(176100.00).serialize());
+        assert_eq!(se_income.to_string(), "176100.00");
+        assert_eq!(
+            fetched.input.expected_credits.map(|d| d.serialize()),
+            Some(dec!(1234567890.0123).serialize())
+        );
+
+        let stored: String = sqlx::query_scalar("SELECT se_income FROM tax_estimate WHERE id = ?")
+            .bind(created.id)
+            .fetch_one(repo.pool())
+            .await
+            .expect("Should read raw se_income");
+        assert_eq!(stored, "176100.00");
+    }
+
     #[tokio::test]
     async fn test_get_estimate_not_found() {
         let repo = setup_test_db().await;
@@ -1295,6 +2426,380 @@ mod tests {
         assert!(matches!(result, Err(RepositoryError::NotFound)));
     }
 
+    #[tokio::test]
+    async fn test_delete_estimate_moves_it_to_trash() {
+        let repo = setup_test_db().await;
+        setup_test_data_for_estimates(&repo).await;
+
+        let created = repo
+            .create_estimate(create_minimal_test_estimate())
+            .await
+            .expect("Should create estimate");
+        repo.delete_estimate(created.id)
+            .await
+            .expect("Should delete estimate");
+
+        let live = repo
+            .list_estimates(None)
+            .await
+            .expect("Should list estimates");
+        assert!(live.is_empty());
+        let trashed = repo
+            .list_deleted_estimates()
+            .await
+            .expect("Should list deleted estimates");
+        assert_eq!(trashed.len(), 1);
+        assert_eq!(trashed[0].id, created.id);
+        assert!(trashed[0].deleted_at.is_some());
+
+        assert!(matches!(
+            repo.delete_estimate(created.id).await,
+            Err(RepositoryError::NotFound)
+        ));
+        assert!(matches!(
+            repo.update_estimate(&created).await,
+            Err(RepositoryError::NotFound)
+        ));
+        let revisions = repo
+            .list_estimate_revisions(created.id)
+            .await
+            .expect("Should list revisions");
+        assert_eq!(revisions.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_restore_deleted_estimate() {
+        let repo = setup_test_db().await;
+        setup_test_data_for_estimates(&repo).await;
+
+        let created = repo
+            .create_estimate(create_minimal_test_estimate())
+            .await
+            .expect("Should create estimate");
+        repo.delete_estimate(created.id)
+            .await
+            .expect("Should delete estimate");
+
+        let restored = repo
+            .restore_deleted_estimate(created.id)
+            .await
+            .expect("Should restore estimate");
+
+        assert_eq!(restored.input, created.input);
+        assert_eq!(restored.deleted_at, None);
+        assert!(
+            repo.list_deleted_estimates()
+                .await
+                .expect("Should list deleted estimates")
+                .is_empty()
+        );
+        assert!(matches!(
+            repo.restore_deleted_estimate(created.id).await,
+            Err(RepositoryError::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_estimate_leaves_trashed_scenario_in_trash() {
+        let repo = setup_test_db().await;
+        setup_test_data_for_estimates(&repo).await;
+
+        let created = repo
+            .create_estimate(create_minimal_test_estimate())
+            .await
+            .expect("Should create estimate");
+        repo.delete_estimate(created.id)
+            .await
+            .expect("Should delete estimate");
+
+        let mut input = create_minimal_test_estimate();
+        input.expected_agi = dec!(82000.00);
+        let result = repo.create_estimate(input).await;
+
+        assert!(matches!(
+            result,
+            Err(RepositoryError::EstimateInTrash { id, .. }) if id == created.id
+        ));
+        let trashed = repo
+            .list_deleted_estimates()
+            .await
+            .expect("Should list trash");
+        assert_eq!(trashed.len(), 1);
+        assert_eq!(trashed[0].input, created.input);
+        assert_eq!(
+            repo.list_estimate_revisions(created.id)
+                .await
+                .expect("Should list revisions")
+                .len(),
+            1,
+            "a refused save records no revision"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_purge_deleted_estimates_respects_retention() {
+        let repo = setup_test_db().await;
+        setup_test_data_for_estimates(&repo).await;
+
+        let old = repo
+            .create_estimate(create_minimal_test_estimate())
+            .await
+            .expect("Should create estimate");
+        let mut recent_input = create_minimal_test_estimate();
+        recent_input.scenario_name = "optimistic".to_string();
+        let recent = repo
+            .create_estimate(recent_input)
+            .await
+            .expect("Should create estimate");
+        repo.delete_estimate(old.id)
+            .await
+            .expect("Should delete estimate");
+        repo.delete_estimate(recent.id)
+            .await
+            .expect("Should delete estimate");
+        sqlx::query("UPDATE tax_estimate SET deleted_at = ? WHERE id = ?")
+            .bind(Utc::now() - chrono::Duration::days(60))
+            .bind(old.id)
+            .execute(repo.pool())
+            .await
+            .expect("Failed to backdate deletion");
+
+        let purged = repo
+            .purge_deleted_estimates(chrono::Duration::days(30))
+            .await
+            .expect("Should purge deleted estimates");
+
+        assert_eq!(purged, 1);
+        let trashed = repo
+            .list_deleted_estimates()
+            .await
+            .expect("Should list deleted estimates");
+        assert_eq!(trashed.len(), 1);
+        assert_eq!(trashed[0].id, recent.id);
+        assert!(
+            repo.list_estimate_revisions(old.id)
+                .await
+                .expect("Should list revisions")
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_estimate_revisions_records_create_and_update() {
+        let repo = setup_test_db().await;
+        setup_test_data_for_estimates(&repo).await;
+
+        let mut created = repo
+            .create_estimate(create_minimal_test_estimate())
+            .await
+            .expect("Should create estimate");
+        created.input.expected_agi = dec!(120000.00);
+        created.computed = Some(TaxEstimateComputed {
+            se_tax: dec!(0),
+            total_tax: dec!(14000.00),
+            required_payment: dec!(12600.00),
+        });
+        repo.update_estimate(&created)
+            .await
+            .expect("Should update estimate");
+
+        let revisions = repo
+            .list_estimate_revisions(created.id)
+            .await
+            .expect("Should list revisions");
+
+        assert_eq!(revisions.len(), 2);
+        assert_eq!(revisions[0].revision, 1);
+        assert_eq!(revisions[0].estimate_id, created.id);
+        assert_eq!(revisions[0].input.expected_agi, dec!(75000.00));
+        assert_eq!(revisions[0].computed, None);
+        assert_eq!(revisions[1].revision, 2);
+        assert_eq!(revisions[1].input.expected_agi, dec!(120000.00));
+        assert_eq!(revisions[1].computed, created.computed);
+    }
+
+    #[tokio::test]
+    async fn test_restore_estimate_revision() {
+        let repo = setup_test_db().await;
+        setup_test_data_for_estimates(&repo).await;
+
+        let mut created = repo
+            .create_estimate(create_minimal_test_estimate())
+            .await
+            .expect("Should create estimate");
+        created.input.expected_agi = dec!(120000.00);
+        repo.update_estimate(&created)
+            .await
+            .expect("Should update estimate");
+        let first = repo
+            .list_estimate_revisions(created.id)
+            .await
+            .expect("Should list revisions")
+            .remove(0);
+
+        let restored = repo
+            .restore_estimate_revision(first.id)
+            .await
+            .expect("Should restore revision");
+
+        assert_eq!(restored.id, created.id);
+        assert_eq!(restored.input, first.input);
+        let revisions = repo
+            .list_estimate_revisions(created.id)
+            .await
+            .expect("Should list revisions");
+        assert_eq!(revisions.len(), 3);
+        assert_eq!(revisions[2].input.expected_agi, dec!(75000.00));
+    }
+
+    #[tokio::test]
+    async fn test_restore_estimate_revision_not_found() {
+        let repo = setup_test_db().await;
+
+        let result = repo.restore_estimate_revision(99999).await;
+
+        assert!(matches!(result, Err(RepositoryError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_estimate_revisions_are_append_only() {
+        let repo = setup_test_db().await;
+        setup_test_data_for_estimates(&repo).await;
+
+        let created = repo
+            .create_estimate(create_minimal_test_estimate())
+            .await
+            .expect("Should create estimate");
+
+        let update = sqlx::query("UPDATE tax_estimate_revision SET expected_agi = '1'")
+            .execute(repo.pool())
+            .await;
+        assert!(update.is_err());
+        let delete = sqlx::query("DELETE FROM tax_estimate_revision")
+            .execute(repo.pool())
+            .await;
+        assert!(delete.is_err());
+
+        sqlx::query("DELETE FROM tax_estimate WHERE id = ?")
+            .bind(created.id)
+            .execute(repo.pool())
+            .await
+            .expect("Should delete estimate along with its history");
+        let revisions = repo
+            .list_estimate_revisions(created.id)
+            .await
+            .expect("Should list revisions");
+        assert!(revisions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_named_scenarios_coexist() {
+        let repo = setup_test_db().await;
+        setup_test_data_for_estimates(&repo).await;
+
+        let mut ids = Vec::new();
+        for (name, agi) in [
+            ("conservative", dec!(80000.00)),
+            ("expected", dec!(100000.00)),
+            ("optimistic", dec!(120000.00)),
+        ] {
+            let mut input = create_minimal_test_estimate();
+            input.scenario_name = name.to_string();
+            input.expected_agi = agi;
+            let created = repo
+                .create_estimate(input)
+                .await
+                .expect("Should create scenario");
+            ids.push(created.id);
+        }
+        ids.dedup();
+        assert_eq!(ids.len(), 3, "each scenario should get its own row");
+
+        let all = repo
+            .list_estimates(Some(8888))
+            .await
+            .expect("Should list estimates");
+        assert_eq!(all.len(), 3);
+
+        let optimistic = repo
+            .get_estimate_by_name(8888, FilingStatusCode::Single, "optimistic")
+            .await
+            .expect("Should find optimistic scenario");
+        assert_eq!(optimistic.input.scenario_name, "optimistic");
+        assert_eq!(optimistic.input.expected_agi, dec!(120000.00));
+    }
+
+    #[tokio::test]
+    async fn test_create_estimate_same_scenario_replaces_inputs() {
+        let repo = setup_test_db().await;
+        setup_test_data_for_estimates(&repo).await;
+
+        let mut input = create_minimal_test_estimate();
+        input.scenario_name = "expected".to_string();
+        let first = repo
+            .create_estimate(input.clone())
+            .await
+            .expect("Should create scenario");
+        input.expected_agi = dec!(110000.00);
+        let second = repo
+            .create_estimate(input)
+            .await
+            .expect("Should save scenario again");
+
+        assert_eq!(first.id, second.id);
+        assert_eq!(second.input.expected_agi, dec!(110000.00));
+    }
+
+    #[tokio::test]
+    async fn test_get_estimate_by_name_not_found() {
+        let repo = setup_test_db().await;
+        setup_test_data_for_estimates(&repo).await;
+        repo.create_estimate(create_minimal_test_estimate())
+            .await
+            .expect("Should create estimate");
+
+        let result = repo
+            .get_estimate_by_name(8888, FilingStatusCode::Single, "missing")
+            .await;
+
+        assert!(matches!(result, Err(RepositoryError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_list_estimates_by_name() {
+        let repo = setup_test_db().await;
+        setup_test_data_for_estimates(&repo).await;
+
+        for (year, name) in [(8888, "expected"), (8887, "expected"), (8888, "optimistic")] {
+            let mut input = create_minimal_test_estimate();
+            input.tax_year = year;
+            input.scenario_name = name.to_string();
+            repo.create_estimate(input)
+                .await
+                .expect("Should create scenario");
+        }
+
+        let expected = repo
+            .list_estimates_by_name("expected", None)
+            .await
+            .expect("Should list by name");
+        assert_eq!(expected.len(), 2);
+        assert!(expected.iter().all(|e| e.input.scenario_name == "expected"));
+
+        let expected_8887 = repo
+            .list_estimates_by_name("expected", Some(8887))
+            .await
+            .expect("Should list by name and year");
+        assert_eq!(expected_8887.len(), 1);
+        assert_eq!(expected_8887[0].input.tax_year, 8887);
+
+        let none = repo
+            .list_estimates_by_name("pessimistic", None)
+            .await
+            .expect("Should list by name");
+        assert!(none.is_empty());
+    }
+
     #[tokio::test]
     async fn test_list_estimates() {
         let repo = setup_test_db().await;
@@ -1303,9 +2808,13 @@ mod tests {
         let estimate_8888 = TaxEstimateInput {
             tax_year: 8888,
             filing_status: FilingStatusCode::Single,
+            scenario_name: DEFAULT_SCENARIO_NAME.to_string(),
             se_income: None,
             expected_crp_payments: None,
             expected_wages: None,
+            net_investment_income: None,
+            qualified_dividends: None,
+            net_capital_gain: None,
             expected_agi: dec!(100000.00),
             expected_deduction: dec!(15000.00),
             expected_qbi_deduction: None,
@@ -1314,14 +2823,19 @@ mod tests {
             expected_other_taxes: None,
             expected_withholding: None,
             prior_year_tax: None,
+            prior_year_agi: None,
         };
 
         let estimate_8887 = TaxEstimateInput {
             tax_year: 8887,
             filing_status: FilingStatusCode::Single,
+            scenario_name: DEFAULT_SCENARIO_NAME.to_string(),
             se_income: None,
             expected_crp_payments: None,
             expected_wages: None,
+            net_investment_income: None,
+            qualified_dividends: None,
+            net_capital_gain: None,
             expected_agi: dec!(90000.00),
             expected_deduction: dec!(14000.00),
             expected_qbi_deduction: None,
@@ -1330,6 +2844,7 @@ mod tests {
             expected_other_taxes: None,
             expected_withholding: None,
             prior_year_tax: None,
+            prior_year_agi: None,
         };
 
         let first = repo
@@ -1342,7 +2857,7 @@ mod tests {
             .expect("Should upsert estimate");
         assert_eq!(
             first.id, second.id,
-            "same tax year and filing status should update the existing row"
+            "same tax year, filing status and scenario should update the existing row"
         );
         repo.create_estimate(estimate_8887)
             .await
@@ -1398,6 +2913,10 @@ mod tests {
             .await
             .expect("Should find 2025 config");
         assert_eq!(config.tax_year, 2025);
+        assert_eq!(config.prior_agi_threshold_mfs, dec!(75000.00));
+        assert_eq!(config.prior_tax_multiplier, dec!(1.10));
+        assert_eq!(config.ss_wage_max.to_string(), "176100.00");
+        assert_eq!(config.se_tax_deduct_pcnt.to_string(), "0.9235");
 
         // Verify standard deductions were seeded
         let deduction = repo
@@ -1413,8 +2932,293 @@ mod tests {
             .await
             .expect("Should find tax brackets");
         assert_eq!(brackets.len(), 7);
+
+        // Verify additional medicare thresholds were seeded
+        let threshold = repo
+            .get_additional_medicare_threshold(2025, 3)
+            .await
+            .expect("Should find additional medicare threshold");
+        assert_eq!(threshold.amount, dec!(125000.00));
+
+        // Verify NIIT thresholds were seeded
+        let threshold = repo
+            .get_niit_threshold(2025, 2)
+            .await
+            .expect("Should find NIIT threshold");
+        assert_eq!(threshold.amount, dec!(250000.00));
+
+        // Verify capital gain breakpoints were seeded
+        let breakpoints = repo
+            .get_capital_gain_breakpoints(2025, 4)
+            .await
+            .expect("Should find capital gain breakpoints");
+        assert_eq!(breakpoints.zero_rate_max, dec!(64750.00));
+        assert_eq!(breakpoints.fifteen_rate_max, dec!(566700.00));
+
+        // Verify QBI thresholds were seeded
+        let threshold = repo
+            .get_qbi_threshold(2025, 2)
+            .await
+            .expect("Should find QBI threshold");
+        assert_eq!(threshold.amount, dec!(394600.00));
+
+        // Verify underpayment interest rates were seeded
+        let rates = repo
+            .list_underpayment_interest_rates()
+            .await
+            .expect("Should list underpayment interest rates");
+        let rate_2025_q2 = rates
+            .iter()
+            .find(|r| r.year == 2025 && r.quarter == 2)
+            .expect("Should find 2025 Q2 rate");
+        assert_eq!(rate_2025_q2.rate, dec!(0.07));
+    }
+
+    #[tokio::test]
+    async fn test_lossless_decimals_migration_converts_real_columns() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("Failed to create in-memory database");
+        let migrator = sqlx::migrate!("./migrations");
+        migrator
+            .run_to(20261016140000, &pool)
+            .await
+            .expect("Failed to run migrations preceding lossless decimals");
+
+        sqlx::raw_sql(
+            "INSERT INTO filing_status (id, status_code, status_name) VALUES (1, 'S', 'Single');
+             INSERT INTO tax_year_config (
+                 tax_year, ss_wage_max, ss_tax_rate, medicare_tax_rate,
+                 se_tax_deductible_percentage, se_deduction_factor,
+                 required_payment_threshold
+             ) VALUES (2025, 176100.00, 0.124, 0.029, 0.9235, 0.50, 1000.00);
+             INSERT INTO standard_deductions (tax_year, filing_status_id, amount)
+                 VALUES (2025, 1, 15750.00);
+             INSERT INTO tax_brackets
+                 (tax_year, filing_status_id, min_income, max_income, tax_rate, base_tax)
+                 VALUES (2025, 1, 11925, 48475, 0.12, 1192.50);
+             INSERT INTO tax_estimate (tax_year, filing_status_id, expected_agi, se_income)
+                 VALUES (2025, 1, 100000.10, 0.3);",
+        )
+        .execute(&pool)
+        .await
+        .expect("Failed to insert legacy REAL data");
+
+        migrator
+            .run(&pool)
+            .await
+            .expect("Failed to run lossless decimals migration");
+
+        let types: Vec<String> = sqlx::query_scalar(
+            "SELECT typeof(ss_wage_max) FROM tax_year_config
+             UNION ALL SELECT typeof(se_tax_deductible_percentage) FROM tax_year_config
+             UNION ALL SELECT typeof(amount) FROM standard_deductions
+             UNION ALL SELECT typeof(base_tax) FROM tax_brackets
+             UNION ALL SELECT typeof(se_income) FROM tax_estimate",
+        )
+        .fetch_all(&pool)
+        .await
+        .expect("Should read column types");
+        assert!(types.iter().all(|t| t == "text"), "got {types:?}");
+
+        let repo = SqliteRepository::new_with_pool(pool).await;
+        let config = repo
+            .get_tax_year_config(2025)
+            .await
+            .expect("Should read migrated config");
+        assert_eq!(config.ss_wage_max, dec!(176100.00));
+        assert_eq!(config.se_tax_deduct_pcnt, dec!(0.9235));
+        assert_eq!(config.min_se_threshold, dec!(400.00));
+
+        let brackets = repo
+            .get_tax_brackets(2025, 1)
+            .await
+            .expect("Should read migrated brackets");
+        assert_eq!(brackets[0].base_tax, dec!(1192.50));
+
+        let estimates = repo
+            .list_estimates(Some(2025))
+            .await
+            .expect("Should read migrated estimate");
+        assert_eq!(estimates[0].input.expected_agi, dec!(100000.10));
+        assert_eq!(estimates[0].input.se_income, Some(dec!(0.3)));
     }
 
+    /// Copies `./seeds` into a fresh temp directory that a test may modify.
+    fn scratch_seeds_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "tax-db-sqlite-seeds-{}-{}-{name}",
+            std::process::id(),
+            Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        std::fs::create_dir_all(&dir).expect("Failed to create seeds dir");
+        for entry in std::fs::read_dir("./seeds").expect("Failed to read seeds") {
+            let entry = entry.expect("Failed to read seed entry");
+            std::fs::copy(entry.path(), dir.join(entry.file_name())).expect("Failed to copy seed");
+        }
+        dir
+    }
+
+    #[tokio::test]
+    async fn test_run_seeds_skips_unchanged_files() {
+        let repo = setup_test_db().await;
+        clear_all_data(&repo).await;
+        let seeds_dir = std::path::Path::new("./seeds");
+
+        let first = repo.run_seeds(seeds_dir).await.expect("Should run seeds");
+        let second = repo.run_seeds(seeds_dir).await.expect("Should run seeds");
+
+        assert_eq!(first.len(), 9);
+        assert_eq!(first[0], "01_filing_status.sql");
+        assert!(second.is_empty(), "got {second:?}");
+    }
+
+    #[tokio::test]
+    async fn test_run_seeds_reapplies_changed_file_without_touching_estimates() {
+        let repo = setup_test_db().await;
+        clear_all_data(&repo).await;
+        let seeds_dir = scratch_seeds_dir("changed");
+        repo.run_seeds(&seeds_dir).await.expect("Should run seeds");
+        let mut input = create_minimal_test_estimate();
+        input.tax_year = 2025;
+        let estimate = repo
+            .create_estimate(input)
+            .await
+            .expect("Should create estimate");
+
+        let path = seeds_dir.join("03_standard_deductions_2025.sql");
+        let sql = std::fs::read_to_string(&path).expect("Failed to read seed");
+        std::fs::write(
+            &path,
+            sql.replace("(2025, 1, '15750.00')", "(2025, 1, '15800.00')"),
+        )
+        .expect("Failed to write seed");
+        let applied = repo.run_seeds(&seeds_dir).await.expect("Should run seeds");
+        let _ = std::fs::remove_dir_all(&seeds_dir);
+
+        assert_eq!(applied, vec!["03_standard_deductions_2025.sql".to_string()]);
+        let deduction = repo
+            .get_standard_deduction(2025, 1)
+            .await
+            .expect("Should find standard deduction");
+        assert_eq!(deduction.amount, dec!(15800.00));
+        let fetched = repo
+            .get_estimate(estimate.id)
+            .await
+            .expect("Estimate should be untouched");
+        assert_eq!(fetched, estimate);
+    }
+
+    #[tokio::test]
+    async fn test_run_seeds_rolls_back_failed_file() {
+        let repo = setup_test_db().await;
+        clear_all_data(&repo).await;
+        let seeds_dir = scratch_seeds_dir("failed");
+        std::fs::write(
+            seeds_dir.join("99_broken.sql"),
+            "INSERT INTO underpayment_interest_rates (year, quarter, rate) VALUES (2030, 1, '0.09');
+             INSERT INTO no_such_table VALUES (1);",
+        )
+        .expect("Failed to write seed");
+
+        let result = repo.run_seeds(&seeds_dir).await;
+        let _ = std::fs::remove_dir_all(&seeds_dir);
+
+        assert!(result.is_err());
+        let rates = repo
+            .list_underpayment_interest_rates()
+            .await
+            .expect("Should list underpayment interest rates");
+        assert!(rates.iter().all(|r| r.year != 2030));
+        let recorded: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM seed_history WHERE file_name = '99_broken.sql'",
+        )
+        .fetch_one(repo.pool())
+        .await
+        .expect("Should read seed history");
+        assert_eq!(recorded, 0);
+    }
+
+    #[test]
+    fn test_embedded_seeds_match_seeds_directory() {
+        let mut on_disk: Vec<(String, String)> = std::fs::read_dir("./seeds")
+            .expect("Failed to read seeds")
+            .map(|entry| {
+                let entry = entry.expect("Failed to read seed entry");
+                let sql = std::fs::read_to_string(entry.path()).expect("Failed to read seed");
+                (entry.file_name().to_string_lossy().into_owned(), sql)
+            })
+            .collect();
+        on_disk.sort();
+
+        let embedded: Vec<(String, String)> = EMBEDDED_SEEDS
+            .iter()
+            .map(|(name, sql)| (name.to_string(), sql.to_string()))
+            .collect();
+
+        assert_eq!(embedded, on_disk);
+    }
+
+    #[tokio::test]
+    async fn test_run_embedded_seeds() {
+        let repo = setup_test_db().await;
+        clear_all_data(&repo).await;
+
+        let applied = repo
+            .run_embedded_seeds()
+            .await
+            .expect("Should run embedded seeds");
+
+        assert_eq!(applied.len(), EMBEDDED_SEEDS.len());
+        let config = repo
+            .get_tax_year_config(2025)
+            .await
+            .expect("Should find 2025 config");
+        assert_eq!(config.ss_wage_max, dec!(176100.00));
+        let from_directory = repo
+            .run_seeds(std::path::Path::new("./seeds"))
+            .await
+            .expect("Should run seeds");
+        assert!(from_directory.is_empty(), "got {from_directory:?}");
+    }
+
+    #[tokio::test]
+    async fn test_seeded_additional_medicare_thresholds() {
+        let repo = setup_seeded_db().await;
+
+        // Form 8959: $250,000 married filing jointly, $125,000 married
+        // filing separately, $200,000 for everyone else (including QSS).
+        let expected = [
+            (FilingStatusCode::Single, dec!(200000.00)),
+            (FilingStatusCode::MarriedFilingJointly, dec!(250000.00)),
+            (FilingStatusCode::MarriedFilingSeparately, dec!(125000.00)),
+            (FilingStatusCode::HeadOfHousehold, dec!(200000.00)),
+            (FilingStatusCode::QualifyingSurvivingSpouse, dec!(200000.00)),
+        ];
+        for year in [2025, 2026] {
+            for (status, amount) in expected {
+                let threshold = repo
+                    .get_additional_medicare_threshold(year, status.filing_status_to_id())
+                    .await
+                    .expect("Should find seeded additional medicare threshold");
+                assert_eq!(threshold.amount, amount, "{year} {}", status.as_str());
+            }
+        }
+    }
+
+    async fn setup_seeded_db() -> SqliteRepository {
+        let repo = setup_test_db().await;
+        clear_all_data(&repo).await;
+        repo.run_embedded_seeds()
+            .await
+            .expect("Failed to run embedded seeds");
+        repo
+    }
+
+    tax_core::repository_conformance_tests!(setup_seeded_db);
+
     #[tokio::test]
     async fn test_run_seeds_nonexistent_directory() {
         let repo = setup_test_db().await;
@@ -7,30 +7,47 @@ use gpui::{App, ParentElement, Window, px};
 use gpui_component::WindowExt;
 use gpui_component::dialog::DialogButtonProps;
 use rust_decimal::Decimal;
+use serde::Serialize;
 use tax_core::calculations::{
-    EstimatedTaxWorksheetResult, SeWorksheet, SeWorksheetConfig, SeWorksheetResult,
+    AdditionalMedicareTaxConfig, AdditionalMedicareTaxResult, AdditionalMedicareTaxWorksheet,
+    AnnualizedIncomeInput, AnnualizedIncomeResult, AnnualizedIncomeWorksheet,
+    EstimatedTaxWorksheet, EstimatedTaxWorksheetContext, EstimatedTaxWorksheetResult,
+    EstimatedTaxWorksheetTrace, NiitConfig, NiitResult, NiitWorksheet, QbiConfig, QbiError,
+    QbiResult, QbiWorksheet, QdcgConfig, SeWorksheet, SeWorksheetConfig, SeWorksheetResult,
+    UnderpaymentPenaltyCalculator, UnderpaymentPenaltyInput, UnderpaymentPenaltyResult,
 };
 use tax_core::{TaxEstimate, TaxEstimateComputed, TaxEstimateInput};
 use tracing::debug;
 
 use tax_core::db::{DbConfig, RepositoryRegistry, TaxRepository};
-use tax_core::models::{FilingStatus, StandardDeduction, TaxBracket, TaxYearConfig};
+use tax_core::models::{
+    AdditionalMedicareThreshold, CapitalGainBreakpoints, FilingStatus, FilingStatusCode,
+    NiitThreshold, QbiThreshold, StandardDeduction, TaxBracket, TaxYearConfig,
+    UnderpaymentInterestRate,
+};
+use tax_db_memory::MemoryRepositoryFactory;
+use tax_db_postgres::PostgresRepositoryFactory;
 use tax_db_sqlite::SqliteRepositoryFactory;
 
 use crate::components::{ErrorDialog, EstimatedIncomeForm, SeWorksheetForm};
-use crate::models::SeWorksheetModel;
 use crate::repository::TaxRepo;
 use crate::utils::{currency, percent};
 
 // ─── public data types ───────────────────────────────────────────────────────
 
 /// Reference data for one filing status: the status row itself, its
-/// standard deduction for the year, and every bracket that applies.
+/// standard deduction for the year, every bracket that applies, its
+/// Additional Medicare Tax, NIIT and QBI thresholds, and its capital gain
+/// rate breakpoints.
 #[derive(Debug, Clone)]
 pub struct FilingStatusData {
     pub filing_status: FilingStatus,
     pub standard_deduction: StandardDeduction,
     pub tax_brackets: Vec<TaxBracket>,
+    pub additional_medicare_threshold: AdditionalMedicareThreshold,
+    pub niit_threshold: NiitThreshold,
+    pub capital_gain_breakpoints: CapitalGainBreakpoints,
+    pub qbi_threshold: QbiThreshold,
 }
 
 /// Everything the calculator needs to know about a single tax year,
@@ -40,6 +57,51 @@ pub struct TaxYearData {
     pub config: TaxYearConfig,
     /// One entry per filing status, each carrying its deduction and brackets.
     pub statuses: Vec<FilingStatusData>,
+    /// Every known quarterly underpayment interest rate, for the Form 2210
+    /// penalty. Not specific to the year, since the penalty runs into the
+    /// following one.
+    pub interest_rates: Vec<UnderpaymentInterestRate>,
+}
+
+impl TaxYearData {
+    /// Reference data for `filing_status`, if the year has any.
+    pub fn status(
+        &self,
+        filing_status: FilingStatusCode,
+    ) -> Option<&FilingStatusData> {
+        self.statuses
+            .iter()
+            .find(|s| s.filing_status.status_code == filing_status)
+    }
+}
+
+/// Every worksheet result behind one estimate.  Built by
+/// [`calculate_estimate`].
+#[derive(Debug, Clone, Serialize)]
+pub struct EstimateCalculation {
+    pub self_employment: SeWorksheetResult,
+    pub additional_medicare: AdditionalMedicareTaxResult,
+    pub niit: NiitResult,
+    /// Form 8995, or `None` when the deduction was entered by hand, there is
+    /// no qualified business income, or taxable income is above the Form
+    /// 8995 threshold.
+    pub qbi: Option<QbiResult>,
+    pub worksheet: EstimatedTaxWorksheetTrace,
+    pub result: EstimatedTaxWorksheetResult,
+    /// Anything the user should act on that did not stop the calculation,
+    /// such as a QBI deduction that needs Form 8995-A.
+    pub notes: Vec<String>,
+}
+
+impl EstimateCalculation {
+    /// The results stored alongside a saved estimate.
+    pub fn computed(&self) -> TaxEstimateComputed {
+        TaxEstimateComputed {
+            se_tax: self.self_employment.self_employment_tax,
+            total_tax: self.result.total_estimated_tax,
+            required_payment: self.result.required_annual_payment,
+        }
+    }
 }
 
 // ─── registry ────────────────────────────────────────────────────────────────
@@ -48,15 +110,18 @@ pub struct TaxYearData {
 /// Adding a new backend later is one line here.
 pub fn build_registry() -> RepositoryRegistry {
     let mut registry = RepositoryRegistry::new();
+    registry.register(Box::new(MemoryRepositoryFactory));
     registry.register(Box::new(SqliteRepositoryFactory));
+    registry.register(Box::new(PostgresRepositoryFactory));
     registry
 }
 
 // ─── loading ─────────────────────────────────────────────────────────────────
 
 /// Pull every piece of reference data the calculator needs for `year`:
-/// the year config, every filing status, and its standard deduction +
-/// tax brackets.
+/// the year config, every filing status, and its standard deduction,
+/// tax brackets, Additional Medicare Tax, NIIT and QBI thresholds, and
+/// capital gain rate breakpoints, plus the underpayment interest rates.
 ///
 /// Logs each stage at `debug` level so the caller can trace progress
 /// without cluttering normal output.
@@ -65,23 +130,41 @@ pub async fn load_tax_year_data(
     year: i32,
 ) -> anyhow::Result<TaxYearData> {
     debug!("loading tax-year data for {year}");
-    let (config, status_rows) = tokio::try_join!(
+    let (config, status_rows, interest_rates) = tokio::try_join!(
         repo.get_tax_year_config(year),
         repo.get_filing_status_data(year),
+        repo.list_underpayment_interest_rates(),
     )?;
 
-    let statuses = status_rows
-        .into_iter()
-        .map(
-            |(filing_status, standard_deduction, tax_brackets)| FilingStatusData {
-                filing_status,
-                standard_deduction,
-                tax_brackets,
-            },
-        )
-        .collect();
+    let mut statuses = Vec::with_capacity(status_rows.len());
+    for (filing_status, standard_deduction, tax_brackets) in status_rows {
+        let (
+            additional_medicare_threshold,
+            niit_threshold,
+            capital_gain_breakpoints,
+            qbi_threshold,
+        ) = tokio::try_join!(
+            repo.get_additional_medicare_threshold(year, filing_status.id),
+            repo.get_niit_threshold(year, filing_status.id),
+            repo.get_capital_gain_breakpoints(year, filing_status.id),
+            repo.get_qbi_threshold(year, filing_status.id),
+        )?;
+        statuses.push(FilingStatusData {
+            filing_status,
+            standard_deduction,
+            tax_brackets,
+            additional_medicare_threshold,
+            niit_threshold,
+            capital_gain_breakpoints,
+            qbi_threshold,
+        });
+    }
 
-    Ok(TaxYearData { config, statuses })
+    Ok(TaxYearData {
+        config,
+        statuses,
+        interest_rates,
+    })
 }
 
 // ─── formatting helpers ──────────────────────────────────────────────────────
@@ -106,6 +189,27 @@ impl fmt::Display for FilingStatusData {
             "  Standard deduction: {}",
             currency(&self.standard_deduction.amount)
         )?;
+        writeln!(
+            f,
+            "  Additional Medicare threshold: {}",
+            currency(&self.additional_medicare_threshold.amount)
+        )?;
+        writeln!(
+            f,
+            "  NIIT threshold: {}",
+            currency(&self.niit_threshold.amount)
+        )?;
+        writeln!(
+            f,
+            "  Capital gain 0% rate up to: {}, 15% rate up to: {}",
+            currency(&self.capital_gain_breakpoints.zero_rate_max),
+            currency(&self.capital_gain_breakpoints.fifteen_rate_max)
+        )?;
+        writeln!(
+            f,
+            "  QBI deduction threshold: {}",
+            currency(&self.qbi_threshold.amount)
+        )?;
         writeln!(f, "  Tax brackets:")?;
 
         for b in &self.tax_brackets {
@@ -172,24 +276,241 @@ fn run_se_worksheet(
         })
 }
 
-pub async fn save_tax_estimate(
-    form_input: &TaxEstimateInput,
-    calculated: &EstimatedTaxWorksheetResult,
-    se_model: &SeWorksheetModel,
+/// Run Form 8959 for the given wages and net SE earnings (SE worksheet
+/// line 3) using the year's rate and the filing status threshold.
+pub fn additional_medicare_tax_estimate(
+    config: &TaxYearConfig,
+    threshold: &AdditionalMedicareThreshold,
+    wages: Decimal,
+    se_net_earnings: Decimal,
+) -> Result<AdditionalMedicareTaxResult> {
+    let worksheet = AdditionalMedicareTaxWorksheet::new(
+        AdditionalMedicareTaxConfig::from_reference(config, threshold),
+    );
+    let estimate = worksheet
+        .calculate(wages, se_net_earnings)
+        .with_context(|| {
+            format!(
+                "Additional Medicare Tax calculation failed (wages={wages}, se_net_earnings={se_net_earnings})"
+            )
+        })?;
+    tracing::debug!("Additional Medicare Tax Result=\n{}", estimate);
+    Ok(estimate)
+}
+
+/// Run Form 8960 for the given net investment income and modified AGI
+/// using the year's rate and the filing status threshold.
+pub fn niit_estimate(
+    config: &TaxYearConfig,
+    threshold: &NiitThreshold,
+    net_investment_income: Decimal,
+    modified_agi: Decimal,
+) -> Result<NiitResult> {
+    let worksheet = NiitWorksheet::new(NiitConfig::from_reference(config, threshold));
+    let estimate = worksheet
+        .calculate(net_investment_income, modified_agi)
+        .with_context(|| {
+            format!(
+                "NIIT calculation failed (net_investment_income={net_investment_income}, modified_agi={modified_agi})"
+            )
+        })?;
+    tracing::debug!("NIIT Result=\n{}", estimate);
+    Ok(estimate)
+}
+
+/// Run the Form 8995 simplified computation for the given qualified
+/// business income using the filing status threshold.
+pub fn qbi_deduction_estimate(
+    threshold: &QbiThreshold,
+    qualified_business_income: Decimal,
+    taxable_income: Decimal,
+    net_capital_gain: Decimal,
+) -> Result<QbiResult> {
+    let worksheet = QbiWorksheet::new(QbiConfig::from_reference(threshold));
+    let estimate = worksheet
+        .calculate(qualified_business_income, taxable_income, net_capital_gain)
+        .with_context(|| {
+            format!(
+                "QBI deduction calculation failed (qualified_business_income={qualified_business_income}, taxable_income={taxable_income})"
+            )
+        })?;
+    tracing::debug!("QBI Deduction Result=\n{}", estimate);
+    Ok(estimate)
+}
+
+/// Run Form 2210 Schedule AI with the filing status's tax brackets and the
+/// year's self-employment tax configuration.
+pub fn annualized_income_estimate(
+    config: &TaxYearConfig,
+    status_data: &FilingStatusData,
+    input: &AnnualizedIncomeInput,
+) -> Result<AnnualizedIncomeResult> {
+    let worksheet = AnnualizedIncomeWorksheet::new(
+        &status_data.tax_brackets,
+        SeWorksheetConfig::from_tax_year_config(config),
+    );
+    let estimate = worksheet
+        .calculate(input)
+        .context("Annualized income installment calculation failed")?;
+    tracing::debug!("Annualized Income Result=\n{}", estimate);
+    Ok(estimate)
+}
+
+/// Figure the Form 2210 underpayment penalty with the known quarterly
+/// interest rates.
+pub fn underpayment_penalty_estimate(
+    interest_rates: &[UnderpaymentInterestRate],
+    input: &UnderpaymentPenaltyInput,
+) -> Result<UnderpaymentPenaltyResult> {
+    let estimate = UnderpaymentPenaltyCalculator::new(interest_rates)
+        .calculate(input)
+        .context("Underpayment penalty calculation failed")?;
+    tracing::debug!("Underpayment Penalty Result=\n{}", estimate);
+    Ok(estimate)
+}
+
+/// Run the whole estimate for `input` against the year's reference data:
+/// the SE worksheet, Forms 8959 and 8960, Form 8995 when the QBI deduction
+/// is blank and taxable income is within its threshold, and finally the
+/// estimated tax worksheet.  The estimate form, `tax-estimate` and batch
+/// recalculation all go through this, so they always agree.
+pub fn calculate_estimate(
+    data: &TaxYearData,
+    input: &TaxEstimateInput,
+) -> Result<EstimateCalculation> {
+    let config = &data.config;
+    let status_data = data.status(input.filing_status).with_context(|| {
+        format!(
+            "No {} reference data for filing status {}",
+            config.tax_year,
+            input.filing_status.as_str()
+        )
+    })?;
+
+    let se_income = input.se_income.unwrap_or_default();
+    let wages = input.expected_wages.unwrap_or_default();
+    let self_employment = se_tax_estimate(
+        config,
+        se_income,
+        input.expected_crp_payments.unwrap_or_default(),
+        wages,
+    )?;
+    let additional_medicare = additional_medicare_tax_estimate(
+        config,
+        &status_data.additional_medicare_threshold,
+        wages,
+        self_employment.net_earnings,
+    )?;
+    let niit = niit_estimate(
+        config,
+        &status_data.niit_threshold,
+        input.net_investment_income.unwrap_or_default(),
+        input.expected_agi,
+    )?;
+
+    // A blank QBI deduction is computed with Form 8995 from SE income less
+    // the deductible part of SE tax.  Above the Form 8995 threshold it needs
+    // Form 8995-A, so it is left at zero with a note asking for that figure.
+    let mut notes = Vec::new();
+    let qualified_business_income =
+        QbiWorksheet::qualified_business_income(se_income, self_employment.se_tax_deduction);
+    let qbi = if input.expected_qbi_deduction.is_none() && qualified_business_income > Decimal::ZERO
+    {
+        match qbi_deduction_estimate(
+            &status_data.qbi_threshold,
+            qualified_business_income,
+            (input.expected_agi - input.expected_deduction).max(Decimal::ZERO),
+            input.qualified_dividends.unwrap_or_default()
+                + input.net_capital_gain.unwrap_or_default(),
+        ) {
+            Ok(qbi) => Some(qbi),
+            Err(error) => match error.downcast_ref() {
+                Some(QbiError::ThresholdExceeded {
+                    taxable_income,
+                    threshold,
+                }) => {
+                    notes.push(format!(
+                        "Taxable income of {} is above the Form 8995 threshold of {}, so the \
+                         QBI deduction was not computed. Enter the deduction from Form 8995-A \
+                         in the QBI deduction field.",
+                        currency(taxable_income),
+                        currency(threshold)
+                    ));
+                    None
+                }
+                _ => return Err(error),
+            },
+        }
+    } else {
+        None
+    };
+
+    let context = EstimatedTaxWorksheetContext {
+        qbi_deduction: qbi.as_ref().map_or(Decimal::ZERO, |q| q.deduction),
+        self_employment_tax: self_employment.self_employment_tax,
+        additional_medicare_tax: additional_medicare.total_tax,
+        net_investment_income_tax: niit.net_investment_income_tax,
+        refundable_credits: Decimal::ZERO,
+        is_farmer_or_fisher: false,
+        required_payment_threshold: config.req_pmnt_threshold,
+        prior_year_agi_threshold: config.prior_agi_threshold_for(input.filing_status),
+        high_income_prior_year_multiplier: config.prior_tax_multiplier,
+    };
+    let worksheet = EstimatedTaxWorksheet::new(&status_data.tax_brackets)
+        .with_capital_gain_breakpoints(QdcgConfig::from_reference(
+            &status_data.capital_gain_breakpoints,
+        ))
+        .trace(&input.to_estimated_tax_worksheet_input(&context))
+        .context("Estimated tax calculation failed")?;
+    let result = EstimatedTaxWorksheetResult::from(&worksheet);
+    tracing::debug!("Estimated Tax Worksheet=\n{}", worksheet);
+
+    Ok(EstimateCalculation {
+        self_employment,
+        additional_medicare,
+        niit,
+        qbi,
+        worksheet,
+        result,
+        notes,
+    })
+}
+
+/// Save estimates imported from a CSV file in one transaction, so a failure
+/// part way through saves none of them. Each row replaces any saved estimate
+/// with the same tax year, filing status and scenario name. The preview
+/// rejects rows matching an estimate in the trash, but one trashed since then
+/// still fails the import. Returns how many were saved.
+pub async fn import_estimates(
+    inputs: Vec<TaxEstimateInput>,
     repo: Arc<dyn TaxRepository>,
-) -> Result<()> {
-    let created: TaxEstimate = repo.create_estimate(form_input.clone()).await?;
+) -> Result<usize> {
+    let count = inputs.len();
+    let tx = repo.begin().await?;
+    for input in inputs {
+        tx.create_estimate(input).await?;
+    }
+    tx.commit().await?;
 
-    let mut updated: TaxEstimate = created.clone();
-    updated.computed = Some(TaxEstimateComputed {
-        se_tax: se_model.line_10_total_se_tax.unwrap_or_default(),
-        total_tax: calculated.total_estimated_tax,
-        required_payment: calculated.required_annual_payment,
-    });
+    Ok(count)
+}
 
-    repo.update_estimate(&updated).await?;
+/// Save estimates together with the results calculated for them, all in one
+/// transaction.  Each replaces any saved estimate with the same tax year,
+/// filing status and scenario name, unless that estimate is in the trash.
+/// Returns the saved records.
+pub async fn save_calculated_estimates(
+    estimates: Vec<(TaxEstimateInput, TaxEstimateComputed)>,
+    repo: &dyn TaxRepository,
+) -> Result<Vec<TaxEstimate>> {
+    let tx = repo.begin().await?;
+    let mut saved = Vec::with_capacity(estimates.len());
+    for (input, computed) in estimates {
+        saved.push(tx.create_calculated_estimate(input, computed).await?);
+    }
+    tx.commit().await?;
 
-    Ok(())
+    Ok(saved)
 }
 
 // ─── tests ───────────────────────────────────────────────────────────────────
@@ -200,10 +521,13 @@ mod tests {
     use rust_decimal_macros::dec;
 
     use tax_core::models::{
-        FilingStatus, FilingStatusCode, StandardDeduction, TaxBracket, TaxYearConfig,
+        AdditionalMedicareThreshold, CapitalGainBreakpoints, FilingStatus, FilingStatusCode,
+        NiitThreshold, QbiThreshold, StandardDeduction, TaxBracket, TaxYearConfig,
     };
 
-    use super::{FilingStatusData, TaxYearData};
+    use tax_core::{DEFAULT_SCENARIO_NAME, TaxEstimateInput};
+
+    use super::{FilingStatusData, TaxYearData, calculate_estimate};
 
     // ── test-data builders ──────────────────────────────────────────────
     // Each builder produces the minimal, realistic shape needed by the
@@ -220,6 +544,11 @@ mod tests {
             se_deduction_factor: dec!(0.9235),
             req_pmnt_threshold: dec!(1_000),
             min_se_threshold: dec!(400),
+            addl_medicare_tax_rate: dec!(0.009),
+            niit_rate: dec!(0.038),
+            prior_agi_threshold: dec!(150000.00),
+            prior_agi_threshold_mfs: dec!(75000.00),
+            prior_tax_multiplier: dec!(1.10),
         }
     }
 
@@ -255,6 +584,27 @@ mod tests {
                     base_tax: dec!(1_160),
                 },
             ],
+            additional_medicare_threshold: AdditionalMedicareThreshold {
+                tax_year: 2025,
+                filing_status_id: 1,
+                amount: dec!(200_000),
+            },
+            niit_threshold: NiitThreshold {
+                tax_year: 2025,
+                filing_status_id: 1,
+                amount: dec!(200_000),
+            },
+            capital_gain_breakpoints: CapitalGainBreakpoints {
+                tax_year: 2025,
+                filing_status_id: 1,
+                zero_rate_max: dec!(48_350),
+                fifteen_rate_max: dec!(533_400),
+            },
+            qbi_threshold: QbiThreshold {
+                tax_year: 2025,
+                filing_status_id: 1,
+                amount: dec!(197_300),
+            },
         }
     }
 
@@ -280,6 +630,27 @@ mod tests {
                 tax_rate: dec!(0.10),
                 base_tax: dec!(0),
             }],
+            additional_medicare_threshold: AdditionalMedicareThreshold {
+                tax_year: 2025,
+                filing_status_id: 2,
+                amount: dec!(250_000),
+            },
+            niit_threshold: NiitThreshold {
+                tax_year: 2025,
+                filing_status_id: 2,
+                amount: dec!(250_000),
+            },
+            capital_gain_breakpoints: CapitalGainBreakpoints {
+                tax_year: 2025,
+                filing_status_id: 2,
+                zero_rate_max: dec!(96_700),
+                fifteen_rate_max: dec!(600_050),
+            },
+            qbi_threshold: QbiThreshold {
+                tax_year: 2025,
+                filing_status_id: 2,
+                amount: dec!(394_600),
+            },
         }
     }
 
@@ -306,6 +677,7 @@ mod tests {
         let data = TaxYearData {
             config: sample_config(),
             statuses: vec![single_status_data(), mfj_status_data()],
+            interest_rates: Vec::new(),
         };
         let out = format!("{}", data);
 
@@ -315,6 +687,125 @@ mod tests {
             "second status present"
         );
         assert!(out.contains("$30000.00"), "MFJ deduction distinguishes it");
+        assert!(
+            out.contains("Additional Medicare threshold: $250000.00"),
+            "MFJ threshold present"
+        );
+        assert!(
+            out.contains("NIIT threshold: $250000.00"),
+            "MFJ NIIT threshold present"
+        );
+        assert!(
+            out.contains("Capital gain 0% rate up to: $96700.00, 15% rate up to: $600050.00"),
+            "MFJ capital gain breakpoints present"
+        );
+        assert!(
+            out.contains("QBI deduction threshold: $394600.00"),
+            "MFJ QBI threshold present"
+        );
         assert!(out.contains("\n\n"), "blank-line separator between blocks");
     }
+
+    fn sample_input() -> TaxEstimateInput {
+        TaxEstimateInput {
+            tax_year: 2025,
+            filing_status: FilingStatusCode::Single,
+            scenario_name: DEFAULT_SCENARIO_NAME.to_string(),
+            se_income: Some(dec!(50_000)),
+            expected_crp_payments: None,
+            expected_wages: None,
+            net_investment_income: None,
+            qualified_dividends: None,
+            net_capital_gain: None,
+            expected_agi: dec!(60_000),
+            expected_deduction: dec!(15_000),
+            expected_qbi_deduction: None,
+            expected_amt: None,
+            expected_credits: None,
+            expected_other_taxes: None,
+            expected_withholding: None,
+            prior_year_tax: None,
+            prior_year_agi: None,
+        }
+    }
+
+    fn sample_year() -> TaxYearData {
+        TaxYearData {
+            config: sample_config(),
+            statuses: vec![single_status_data(), mfj_status_data()],
+            interest_rates: Vec::new(),
+        }
+    }
+
+    /// A blank QBI deduction runs Form 8995, and every worksheet result
+    /// feeds the estimated tax worksheet.
+    #[test]
+    fn calculate_estimate_runs_every_worksheet() {
+        let calc = calculate_estimate(&sample_year(), &sample_input()).unwrap();
+
+        let qbi = calc.qbi.as_ref().expect("blank QBI deduction is computed");
+        assert_eq!(calc.worksheet.line_2b_qbi_deduction, qbi.deduction);
+        assert_eq!(
+            calc.worksheet.line_9_self_employment_tax,
+            calc.self_employment.self_employment_tax
+        );
+        assert!(calc.self_employment.self_employment_tax > dec!(0));
+        assert_eq!(calc.additional_medicare.total_tax, dec!(0));
+        assert_eq!(calc.niit.net_investment_income_tax, dec!(0));
+        assert!(calc.notes.is_empty());
+
+        let computed = calc.computed();
+        assert_eq!(computed.se_tax, calc.self_employment.self_employment_tax);
+        assert_eq!(computed.total_tax, calc.result.total_estimated_tax);
+        assert_eq!(
+            computed.required_payment,
+            calc.result.required_annual_payment
+        );
+    }
+
+    #[test]
+    fn calculate_estimate_uses_an_entered_qbi_deduction() {
+        let mut input = sample_input();
+        input.expected_qbi_deduction = Some(dec!(1_234));
+
+        let calc = calculate_estimate(&sample_year(), &input).unwrap();
+
+        assert!(calc.qbi.is_none());
+        assert_eq!(calc.worksheet.line_2b_qbi_deduction, dec!(1_234));
+    }
+
+    /// Above the Form 8995 threshold a blank QBI deduction is left at zero
+    /// with a note asking for Form 8995-A, instead of failing.
+    #[test]
+    fn calculate_estimate_skips_form_8995_above_its_threshold() {
+        let mut input = sample_input();
+        input.expected_agi = dec!(250_000);
+
+        let calc = calculate_estimate(&sample_year(), &input).unwrap();
+
+        assert!(calc.qbi.is_none());
+        assert_eq!(calc.worksheet.line_2b_qbi_deduction, dec!(0));
+        assert_eq!(
+            calc.notes,
+            vec![
+                "Taxable income of $235000.00 is above the Form 8995 threshold of $197300.00, \
+                 so the QBI deduction was not computed. Enter the deduction from Form 8995-A \
+                 in the QBI deduction field."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn calculate_estimate_rejects_a_filing_status_without_reference_data() {
+        let mut input = sample_input();
+        input.filing_status = FilingStatusCode::HeadOfHousehold;
+
+        let error = calculate_estimate(&sample_year(), &input).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "No 2025 reference data for filing status HOH"
+        );
+    }
 }
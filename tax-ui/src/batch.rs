@@ -0,0 +1,361 @@
+//! Batch recalculation of many estimates at once.
+//!
+//! Takes the rows of a CSV in the [`crate::csv_loader`] format, runs each
+//! valid one through [`calculate_estimate`], and writes one output row per
+//! input row. Reference data is loaded once per tax year, not once per row,
+//! and the rows are calculated in parallel.
+//!
+//! The output CSV starts with the input columns, under the names
+//! [`crate::csv_loader`] reads, followed by every SE worksheet and
+//! estimated tax worksheet line, the Form 8959, 8960 and 8995 amounts, a
+//! `notes` column and an `error` column. A row that could not be read or
+//! calculated has only its error (and whatever inputs were read) filled in.
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::num::NonZeroUsize;
+
+use rust_decimal::Decimal;
+use tax_core::{TaxEstimateInput, TaxRepository};
+
+use crate::app::{EstimateCalculation, TaxYearData, calculate_estimate, load_tax_year_data};
+use crate::csv_loader::ImportRow;
+
+/// Outcome of one input row.
+#[derive(Debug, Clone)]
+pub struct BatchRow {
+    /// Row number, counting from 1 at the first row after the header.
+    pub row: usize,
+    /// The estimate read from the row, if it could be read.
+    pub input: Option<TaxEstimateInput>,
+    /// Every worksheet result, or why the row could not be calculated.
+    pub result: Result<EstimateCalculation, String>,
+}
+
+impl BatchRow {
+    /// Whether the row was calculated.
+    pub fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+/// Reference data for every tax year the rows need, or why it could not be
+/// loaded.
+type YearData = HashMap<i32, Result<TaxYearData, String>>;
+
+/// Calculate every row of a batch.
+///
+/// Each tax year's reference data is loaded from `repo` once, then the rows
+/// are calculated across all available cores on tokio's blocking thread pool,
+/// so the caller's runtime keeps serving other tasks meanwhile. Must be
+/// called from within a tokio runtime. Bad rows never stop the rest:
+/// a row that failed to parse, whose year could not be loaded, or whose
+/// calculation failed comes back with its error. Rows are returned in input
+/// order.
+pub async fn recalculate(
+    rows: Vec<ImportRow>,
+    repo: &dyn TaxRepository,
+) -> Vec<BatchRow> {
+    let mut years: YearData = HashMap::new();
+    for row in &rows {
+        if let Ok(input) = &row.result
+            && !years.contains_key(&input.tax_year)
+        {
+            let data = load_tax_year_data(repo, input.tax_year)
+                .await
+                .map_err(|error| {
+                    format!(
+                        "Failed to load {} reference data: {error:#}",
+                        input.tax_year
+                    )
+                });
+            years.insert(input.tax_year, data);
+        }
+    }
+
+    tokio::task::spawn_blocking(move || calculate_rows(rows, &years))
+        .await
+        .expect("batch calculation panicked")
+}
+
+/// Calculate `rows` against already loaded reference data, splitting them
+/// into one chunk per available core.
+fn calculate_rows(
+    rows: Vec<ImportRow>,
+    years: &YearData,
+) -> Vec<BatchRow> {
+    let threads = std::thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    let chunk_size = rows.len().div_ceil(threads).max(1);
+
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = rows
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|row| calculate_row(row, years))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("batch worker panicked"))
+            .collect()
+    })
+}
+
+fn calculate_row(
+    row: &ImportRow,
+    years: &YearData,
+) -> BatchRow {
+    let input = match &row.result {
+        Ok(input) => input,
+        Err(error) => {
+            return BatchRow {
+                row: row.row,
+                input: None,
+                result: Err(error.to_string()),
+            };
+        }
+    };
+
+    let result = match years.get(&input.tax_year) {
+        Some(Ok(data)) => calculate_estimate(data, input).map_err(|error| format!("{error:#}")),
+        Some(Err(error)) => Err(error.clone()),
+        None => Err(format!("No {} reference data loaded", input.tax_year)),
+    };
+    BatchRow {
+        row: row.row,
+        input: Some(input.clone()),
+        result,
+    }
+}
+
+/// Column headers of the output CSV, in order.
+pub const COLUMNS: [&str; 54] = [
+    "row",
+    "tax_year",
+    "filing_status",
+    "scenario_name",
+    "expected_agi",
+    "expected_deduction",
+    "expected_qbi_deduction",
+    "expected_amt",
+    "expected_credits",
+    "expected_other_taxes",
+    "expected_withholding",
+    "prior_year_tax",
+    "prior_year_agi",
+    "se_income",
+    "expected_crp_payments",
+    "expected_wages",
+    "net_investment_income",
+    "qualified_dividends",
+    "net_capital_gain",
+    "se_line_2_combined_se_income",
+    "se_line_3_net_earnings",
+    "se_line_4_medicare_tax",
+    "se_line_7_remaining_ss_base",
+    "se_line_8_ss_taxable_earnings",
+    "se_line_9_social_security_tax",
+    "se_line_10_self_employment_tax",
+    "se_line_11_se_tax_deduction",
+    "additional_medicare_tax",
+    "net_investment_income_tax",
+    "qbi_deduction",
+    "line_1_adjusted_gross_income",
+    "line_2a_deduction",
+    "line_2b_qbi_deduction",
+    "line_2c_total_deductions",
+    "line_3_taxable_income",
+    "line_4_tax",
+    "line_5_alternative_minimum_tax",
+    "line_6_tax_before_credits",
+    "line_7_credits",
+    "line_8_tax_after_credits",
+    "line_9_self_employment_tax",
+    "line_10_other_taxes",
+    "line_11a_total_tax",
+    "line_11b_refundable_credits",
+    "line_11c_total_estimated_tax",
+    "line_12a_current_year_requirement",
+    "line_12b_prior_year_safe_harbor",
+    "line_12c_required_annual_payment",
+    "line_13_withholding",
+    "line_14a_underpayment",
+    "line_14b_tax_less_withholding",
+    "estimated_payments_required",
+    "notes",
+    "error",
+];
+
+fn amount(value: Option<Decimal>) -> String {
+    value.map(|d| d.to_string()).unwrap_or_default()
+}
+
+/// One output record for `row`, matching [`COLUMNS`].
+fn record(row: &BatchRow) -> Vec<String> {
+    let mut record = Vec::with_capacity(COLUMNS.len());
+    record.push(row.row.to_string());
+
+    match &row.input {
+        Some(input) => record.extend([
+            input.tax_year.to_string(),
+            input.filing_status.as_str().to_string(),
+            input.scenario_name.clone(),
+            amount(Some(input.expected_agi)),
+            amount(Some(input.expected_deduction)),
+            amount(input.expected_qbi_deduction),
+            amount(input.expected_amt),
+            amount(input.expected_credits),
+            amount(input.expected_other_taxes),
+            amount(input.expected_withholding),
+            amount(input.prior_year_tax),
+            amount(input.prior_year_agi),
+            amount(input.se_income),
+            amount(input.expected_crp_payments),
+            amount(input.expected_wages),
+            amount(input.net_investment_income),
+            amount(input.qualified_dividends),
+            amount(input.net_capital_gain),
+        ]),
+        None => record.extend(std::iter::repeat_n(String::new(), 18)),
+    }
+
+    match &row.result {
+        Ok(calc) => {
+            let se = &calc.self_employment;
+            let ws = &calc.worksheet;
+            record.extend(
+                [
+                    se.combined_se_income,
+                    se.net_earnings,
+                    se.medicare_tax,
+                    se.remaining_ss_base,
+                    se.ss_taxable_earnings,
+                    se.social_security_tax,
+                    se.self_employment_tax,
+                    se.se_tax_deduction,
+                    calc.additional_medicare.total_tax,
+                    calc.niit.net_investment_income_tax,
+                ]
+                .map(|d| amount(Some(d))),
+            );
+            record.push(amount(calc.qbi.as_ref().map(|q| q.deduction)));
+            record.extend(
+                [
+                    ws.line_1_adjusted_gross_income,
+                    ws.line_2a_deduction,
+                    ws.line_2b_qbi_deduction,
+                    ws.line_2c_total_deductions,
+                    ws.line_3_taxable_income,
+                    ws.line_4_tax,
+                    ws.line_5_alternative_minimum_tax,
+                    ws.line_6_tax_before_credits,
+                    ws.line_7_credits,
+                    ws.line_8_tax_after_credits,
+                    ws.line_9_self_employment_tax,
+                    ws.line_10_other_taxes,
+                    ws.line_11a_total_tax,
+                    ws.line_11b_refundable_credits,
+                    ws.line_11c_total_estimated_tax,
+                    ws.line_12a_current_year_requirement,
+                    ws.line_12b_prior_year_safe_harbor,
+                    ws.line_12c_required_annual_payment,
+                    ws.line_13_withholding,
+                    ws.line_14a_underpayment,
+                    ws.line_14b_tax_less_withholding,
+                ]
+                .map(|d| amount(Some(d))),
+            );
+            record.push(ws.estimated_payments_required.to_string());
+            record.push(calc.notes.join(" "));
+            record.push(String::new());
+        }
+        Err(error) => {
+            record.resize(COLUMNS.len() - 1, String::new());
+            record.push(error.clone());
+        }
+    }
+    record
+}
+
+/// Write one CSV record per batch row, under a [`COLUMNS`] header.
+pub fn write_csv(
+    rows: &[BatchRow],
+    writer: impl Write,
+) -> Result<(), csv::Error> {
+    let mut csv_writer = csv::Writer::from_writer(writer);
+    csv_writer.write_record(COLUMNS)?;
+    for row in rows {
+        csv_writer.write_record(record(row))?;
+    }
+    csv_writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::csv_loader::preview_from_str;
+
+    fn output(rows: &[BatchRow]) -> Vec<csv::StringRecord> {
+        let mut bytes = Vec::new();
+        write_csv(rows, &mut bytes).unwrap();
+        csv::Reader::from_reader(bytes.as_slice())
+            .records()
+            .map(Result::unwrap)
+            .collect()
+    }
+
+    #[test]
+    fn every_record_has_a_cell_per_column() {
+        let rows = calculate_rows(
+            preview_from_str(
+                "tax_year,filing_status,expected_agi,expected_deduction\n2025,S,1,2\n",
+            ),
+            &HashMap::new(),
+        );
+
+        let records = output(&rows);
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].len(), COLUMNS.len());
+    }
+
+    #[test]
+    fn rows_without_reference_data_report_an_error_in_order() {
+        let csv = "\
+tax_year,filing_status,expected_agi,expected_deduction
+2025,S,75000,15000
+2025,XX,75000,15000
+2024,MFJ,90000,30000
+";
+        let mut years: YearData = HashMap::new();
+        years.insert(2024, Err("Failed to load 2024 reference data".to_string()));
+
+        let rows = calculate_rows(preview_from_str(csv), &years);
+
+        assert_eq!(rows.iter().map(|r| r.row).collect::<Vec<_>>(), [1, 2, 3]);
+        assert!(rows.iter().all(|r| !r.is_ok()));
+        assert_eq!(
+            rows[0].result.as_ref().unwrap_err(),
+            "No 2025 reference data loaded"
+        );
+        assert!(rows[1].input.is_none());
+
+        let records = output(&rows);
+        let error = COLUMNS.len() - 1;
+        assert_eq!(&records[0][1], "2025");
+        assert_eq!(&records[1][1], "");
+        assert_eq!(
+            &records[1][error],
+            "unrecognised filing status 'XX' on row 2"
+        );
+        assert_eq!(&records[2][error], "Failed to load 2024 reference data");
+    }
+}
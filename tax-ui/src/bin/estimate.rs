@@ -0,0 +1,663 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tax_core::db::DbConfig;
+use tax_core::{
+    DEFAULT_SCENARIO_NAME, FilingStatusCode, TaxEstimate, TaxEstimateInput, TaxRepository,
+};
+use tax_ui::app::{
+    EstimateCalculation, TaxYearData, build_registry, calculate_estimate, load_tax_year_data,
+    save_calculated_estimates,
+};
+use tax_ui::batch;
+use tax_ui::config::{AppConfig, ConfigStore, DatabaseBackend, TomlConfigStore};
+use tax_ui::csv_loader;
+use tax_ui::utils::currency;
+
+/// Calculate estimated taxes without the desktop window.
+///
+/// Reference data comes from the database in the application's config file,
+/// unless `--database` names another one.
+///
+/// Estimates can be given with flags, as a JSON file holding one
+/// `TaxEstimateInput` object or an array of them, or as a CSV file in the
+/// File > Import CSV format.
+///
+/// `batch` recalculates every row of such a CSV file and writes all the
+/// computed lines, and any per-row errors, to an output CSV. It still writes
+/// every row when some fail, but then exits with an error.
+#[derive(Parser, Debug)]
+#[command(name = "tax-estimate")]
+#[command(version, about, long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Database URL or SQLite file path (default: the one in the app config)
+    #[arg(short, long, global = true)]
+    database: Option<String>,
+
+    /// Database backend: sqlite or postgres (default: the one in the app config)
+    #[arg(short, long, global = true)]
+    backend: Option<DatabaseBackend>,
+
+    /// Output format
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Table)]
+    format: OutputFormat,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Calculate estimates given with flags, a JSON file or a CSV file
+    Calculate {
+        #[command(flatten)]
+        input: Box<InputArgs>,
+
+        /// Save each estimate and its results to the database
+        #[arg(long, default_value_t = false)]
+        save: bool,
+    },
+    /// Recalculate every row of a CSV file and write the results as CSV;
+    /// fails if any row could not be calculated
+    Batch {
+        /// CSV file in the File > Import CSV format
+        input: PathBuf,
+
+        /// File to write the results to (default: standard output)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// List saved estimates, most recently updated first
+    List {
+        /// Only list estimates for this tax year
+        #[arg(long)]
+        year: Option<i32>,
+    },
+    /// Show one saved estimate
+    Show {
+        /// ID of the estimate, as printed by `list`
+        id: i64,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    Table,
+    Json,
+}
+
+#[derive(Args, Debug)]
+struct InputArgs {
+    /// JSON file holding one estimate input or an array of them
+    #[arg(long, conflicts_with = "csv")]
+    json: Option<PathBuf>,
+
+    /// CSV file in the File > Import CSV format
+    #[arg(long)]
+    csv: Option<PathBuf>,
+
+    #[command(flatten)]
+    flags: EstimateFlags,
+}
+
+/// One estimate given field by field; amounts left out are blank.
+#[derive(Args, Debug)]
+#[group(id = "flags", multiple = true, conflicts_with_all = ["json", "csv"])]
+struct EstimateFlags {
+    /// Tax year of the estimate
+    #[arg(long, required_unless_present_any = ["json", "csv"])]
+    tax_year: Option<i32>,
+
+    /// Filing status: S, MFJ, MFS, HOH or QSS (default: S)
+    #[arg(long, value_parser = parse_filing_status)]
+    filing_status: Option<FilingStatusCode>,
+
+    /// Scenario name (default: "Default")
+    #[arg(long)]
+    scenario: Option<String>,
+
+    /// Expected adjusted gross income
+    #[arg(long, required_unless_present_any = ["json", "csv"])]
+    agi: Option<Decimal>,
+
+    /// Expected deduction
+    #[arg(long, required_unless_present_any = ["json", "csv"])]
+    deduction: Option<Decimal>,
+
+    /// Expected self-employment income
+    #[arg(long)]
+    se_income: Option<Decimal>,
+
+    /// Expected Conservation Reserve Program payments
+    #[arg(long)]
+    crp_payments: Option<Decimal>,
+
+    /// Expected wages
+    #[arg(long)]
+    wages: Option<Decimal>,
+
+    /// Expected net investment income
+    #[arg(long)]
+    net_investment_income: Option<Decimal>,
+
+    /// Expected qualified dividends
+    #[arg(long)]
+    qualified_dividends: Option<Decimal>,
+
+    /// Expected net capital gain
+    #[arg(long)]
+    net_capital_gain: Option<Decimal>,
+
+    /// QBI deduction; computed with Form 8995 when left out
+    #[arg(long)]
+    qbi_deduction: Option<Decimal>,
+
+    /// Expected alternative minimum tax
+    #[arg(long)]
+    amt: Option<Decimal>,
+
+    /// Expected credits
+    #[arg(long)]
+    credits: Option<Decimal>,
+
+    /// Expected other taxes
+    #[arg(long)]
+    other_taxes: Option<Decimal>,
+
+    /// Expected income tax withholding
+    #[arg(long)]
+    withholding: Option<Decimal>,
+
+    /// Prior year's tax
+    #[arg(long)]
+    prior_year_tax: Option<Decimal>,
+
+    /// Prior year's adjusted gross income
+    #[arg(long)]
+    prior_year_agi: Option<Decimal>,
+}
+
+fn parse_filing_status(s: &str) -> Result<FilingStatusCode> {
+    FilingStatusCode::try_from(s)
+}
+
+impl EstimateFlags {
+    fn to_input(&self) -> Result<TaxEstimateInput> {
+        let (Some(tax_year), Some(expected_agi), Some(expected_deduction)) =
+            (self.tax_year, self.agi, self.deduction)
+        else {
+            bail!("--tax-year, --agi and --deduction are required");
+        };
+        Ok(TaxEstimateInput {
+            tax_year,
+            filing_status: self.filing_status.unwrap_or_default(),
+            scenario_name: self
+                .scenario
+                .clone()
+                .unwrap_or_else(|| DEFAULT_SCENARIO_NAME.to_string()),
+            se_income: self.se_income,
+            expected_crp_payments: self.crp_payments,
+            expected_wages: self.wages,
+            net_investment_income: self.net_investment_income,
+            qualified_dividends: self.qualified_dividends,
+            net_capital_gain: self.net_capital_gain,
+            expected_agi,
+            expected_deduction,
+            expected_qbi_deduction: self.qbi_deduction,
+            expected_amt: self.amt,
+            expected_credits: self.credits,
+            expected_other_taxes: self.other_taxes,
+            expected_withholding: self.withholding,
+            prior_year_tax: self.prior_year_tax,
+            prior_year_agi: self.prior_year_agi,
+        })
+    }
+}
+
+/// A JSON input file holds either a single estimate or a list of them.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JsonInputs {
+    One(Box<TaxEstimateInput>),
+    Many(Vec<TaxEstimateInput>),
+}
+
+impl InputArgs {
+    /// The estimates to calculate, each checked with
+    /// [`TaxEstimateInput::validate_for_submit`].
+    fn load(&self) -> Result<Vec<TaxEstimateInput>> {
+        if let Some(path) = &self.csv {
+            return load_csv(path);
+        }
+        let inputs = match &self.json {
+            Some(path) => load_json(path)?,
+            None => vec![self.flags.to_input()?],
+        };
+
+        let mut problems = Vec::new();
+        for (idx, input) in inputs.iter().enumerate() {
+            if let Err(errors) = input.validate_for_submit() {
+                problems.push(format!(
+                    "estimate {} is invalid: {}",
+                    idx + 1,
+                    errors.join("; ")
+                ));
+            }
+        }
+        if !problems.is_empty() {
+            bail!(problems.join("\n"));
+        }
+        Ok(inputs)
+    }
+}
+
+fn load_json(path: &Path) -> Result<Vec<TaxEstimateInput>> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to open: {}", path.display()))?;
+    let inputs: JsonInputs = serde_json::from_str(&text)
+        .with_context(|| format!("Failed to parse JSON: {}", path.display()))?;
+    Ok(match inputs {
+        JsonInputs::One(input) => vec![*input],
+        JsonInputs::Many(inputs) => inputs,
+    })
+}
+
+fn load_csv(path: &Path) -> Result<Vec<TaxEstimateInput>> {
+    let rows = csv_loader::preview_from_file(path)
+        .with_context(|| format!("Failed to open: {}", path.display()))?;
+
+    let mut inputs = Vec::with_capacity(rows.len());
+    let mut problems = Vec::new();
+    for row in rows {
+        match row.result {
+            Ok(input) => inputs.push(input),
+            Err(error) => problems.push(error.to_string()),
+        }
+    }
+    if !problems.is_empty() {
+        bail!(
+            "Failed to parse CSV: {}\n{}",
+            path.display(),
+            problems.join("\n")
+        );
+    }
+    Ok(inputs)
+}
+
+/// The configured database, with any `--database`/`--backend` overrides.
+/// Falls back to the application defaults when there is no config file.
+fn db_config(cli: &Cli) -> Result<DbConfig> {
+    let store = TomlConfigStore::default_location()?;
+    let mut config = if store.exists() {
+        store.load()?
+    } else {
+        AppConfig::default()
+    };
+    if let Some(database) = &cli.database {
+        config.database_url = database.clone();
+    }
+    if let Some(backend) = cli.backend {
+        config.database_backend = backend;
+    }
+    Ok(DbConfig {
+        backend: config.database_backend.as_str().to_string(),
+        connection_string: config.database_url,
+    })
+}
+
+/// One calculated estimate as printed with `--format json`.
+#[derive(Serialize)]
+struct CalculatedEstimate<'a> {
+    /// Set when the estimate was saved.
+    id: Option<i64>,
+    input: &'a TaxEstimateInput,
+    calculation: &'a EstimateCalculation,
+}
+
+async fn calculate(
+    repo: &dyn TaxRepository,
+    inputs: Vec<TaxEstimateInput>,
+    save: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    let mut years: HashMap<i32, TaxYearData> = HashMap::new();
+    let mut calculations = Vec::with_capacity(inputs.len());
+    for input in &inputs {
+        if let Entry::Vacant(entry) = years.entry(input.tax_year) {
+            let data = load_tax_year_data(repo, input.tax_year)
+                .await
+                .with_context(|| format!("Failed to load {} reference data", input.tax_year))?;
+            entry.insert(data);
+        }
+        let calculation = calculate_estimate(&years[&input.tax_year], input)
+            .with_context(|| format!("Failed to calculate: {input}"))?;
+        calculations.push(calculation);
+    }
+
+    let ids: Vec<Option<i64>> = if save {
+        let estimates = inputs
+            .iter()
+            .cloned()
+            .zip(calculations.iter().map(EstimateCalculation::computed))
+            .collect();
+        save_calculated_estimates(estimates, repo)
+            .await
+            .context("Failed to save estimates")?
+            .into_iter()
+            .map(|estimate| Some(estimate.id))
+            .collect()
+    } else {
+        vec![None; inputs.len()]
+    };
+
+    match format {
+        OutputFormat::Json => {
+            let output: Vec<CalculatedEstimate> = inputs
+                .iter()
+                .zip(&calculations)
+                .zip(ids)
+                .map(|((input, calculation), id)| CalculatedEstimate {
+                    id,
+                    input,
+                    calculation,
+                })
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&output)?);
+        }
+        OutputFormat::Table => {
+            for (idx, ((input, calculation), id)) in
+                inputs.iter().zip(&calculations).zip(ids).enumerate()
+            {
+                if idx > 0 {
+                    println!();
+                }
+                print!("{}", calculation_table(input, calculation, id));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The worksheet lines of one calculation, preceded by the amounts figured
+/// on the supporting forms.
+fn calculation_table(
+    input: &TaxEstimateInput,
+    calculation: &EstimateCalculation,
+    id: Option<i64>,
+) -> String {
+    let mut out = format!(
+        "{} {} '{}'",
+        input.tax_year,
+        input.filing_status.as_str(),
+        input.scenario_name
+    );
+    if let Some(id) = id {
+        out.push_str(&format!(" (saved as id {id})"));
+    }
+    out.push('\n');
+
+    let qbi = calculation.qbi.as_ref().map(|qbi| qbi.deduction);
+    let supporting = [
+        (
+            "Self-employment tax",
+            Some(calculation.self_employment.self_employment_tax),
+        ),
+        (
+            "Additional Medicare Tax (Form 8959)",
+            Some(calculation.additional_medicare.total_tax),
+        ),
+        (
+            "Net investment income tax (Form 8960)",
+            Some(calculation.niit.net_investment_income_tax),
+        ),
+        ("QBI deduction (Form 8995)", qbi),
+    ];
+    for (label, amount) in supporting {
+        let amount = amount.map_or_else(|| "entered".to_string(), |a| currency(&a));
+        out.push_str(&format!("{label:<63}: {amount}\n"));
+    }
+    out.push_str(&format!("{}\n", calculation.worksheet));
+    for note in &calculation.notes {
+        out.push_str(&format!("Note: {note}\n"));
+    }
+    out
+}
+
+fn estimates_table(estimates: &[TaxEstimate]) -> String {
+    let mut out = format!(
+        "{:>5}  {:<4}  {:<6}  {:<20}  {:>14}  {:>16}  {}\n",
+        "ID", "Year", "Status", "Scenario", "Total tax", "Required payment", "Updated"
+    );
+    for estimate in estimates {
+        let (total_tax, required_payment) = match &estimate.computed {
+            Some(computed) => (
+                currency(&computed.total_tax),
+                currency(&computed.required_payment),
+            ),
+            None => (String::new(), String::new()),
+        };
+        out.push_str(&format!(
+            "{:>5}  {:<4}  {:<6}  {:<20}  {:>14}  {:>16}  {}\n",
+            estimate.id,
+            estimate.input.tax_year,
+            estimate.input.filing_status.as_str(),
+            estimate.input.scenario_name,
+            total_tax,
+            required_payment,
+            estimate.updated_at.format("%Y-%m-%d %H:%M"),
+        ));
+    }
+    out
+}
+
+fn estimate_table(estimate: &TaxEstimate) -> String {
+    let input = &estimate.input;
+    let amount = |value: Option<Decimal>| value.map(|d| currency(&d)).unwrap_or_default();
+    let mut rows = vec![
+        ("ID", estimate.id.to_string()),
+        ("Tax year", input.tax_year.to_string()),
+        (
+            "Filing status",
+            input.filing_status.to_long_str().to_string(),
+        ),
+        ("Scenario", input.scenario_name.clone()),
+        ("Self-employment income", amount(input.se_income)),
+        ("CRP payments", amount(input.expected_crp_payments)),
+        ("Wages", amount(input.expected_wages)),
+        ("Net investment income", amount(input.net_investment_income)),
+        ("Qualified dividends", amount(input.qualified_dividends)),
+        ("Net capital gain", amount(input.net_capital_gain)),
+        ("Adjusted gross income", currency(&input.expected_agi)),
+        ("Deduction", currency(&input.expected_deduction)),
+        ("QBI deduction", amount(input.expected_qbi_deduction)),
+        ("Alternative minimum tax", amount(input.expected_amt)),
+        ("Credits", amount(input.expected_credits)),
+        ("Other taxes", amount(input.expected_other_taxes)),
+        ("Withholding", amount(input.expected_withholding)),
+        ("Prior year tax", amount(input.prior_year_tax)),
+        ("Prior year AGI", amount(input.prior_year_agi)),
+    ];
+    if let Some(computed) = &estimate.computed {
+        rows.push(("Self-employment tax", currency(&computed.se_tax)));
+        rows.push(("Total estimated tax", currency(&computed.total_tax)));
+        rows.push((
+            "Required annual payment",
+            currency(&computed.required_payment),
+        ));
+    }
+    rows.push((
+        "Updated",
+        estimate
+            .updated_at
+            .format("%Y-%m-%d %H:%M:%S UTC")
+            .to_string(),
+    ));
+
+    rows.into_iter()
+        .map(|(label, value)| format!("{label:<24}: {value}\n"))
+        .collect()
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    let db_config = db_config(&cli)?;
+    let repo = build_registry().create(&db_config).await.with_context(|| {
+        format!(
+            "Failed to connect to database: {}",
+            db_config.connection_string
+        )
+    })?;
+
+    match &cli.command {
+        Command::Calculate { input, save } => {
+            let inputs = input.load()?;
+            calculate(&*repo, inputs, *save, cli.format).await?;
+        }
+        Command::Batch { input, output } => {
+            let rows = csv_loader::preview_from_file(input)
+                .with_context(|| format!("Failed to open: {}", input.display()))?;
+            let results = batch::recalculate(rows, &*repo).await;
+            match output {
+                Some(path) => {
+                    let file = File::create(path)
+                        .with_context(|| format!("Failed to create: {}", path.display()))?;
+                    batch::write_csv(&results, BufWriter::new(file))
+                        .with_context(|| format!("Failed to write: {}", path.display()))?;
+                }
+                None => batch::write_csv(&results, std::io::stdout().lock())?,
+            }
+            let failed = results.iter().filter(|row| !row.is_ok()).count();
+            let summary = format!(
+                "Calculated {} of {} rows; {failed} failed.",
+                results.len() - failed,
+                results.len()
+            );
+            if failed > 0 {
+                bail!(summary);
+            }
+            eprintln!("{summary}");
+        }
+        Command::List { year } => {
+            let estimates = repo
+                .list_estimates(*year)
+                .await
+                .context("Failed to list estimates")?;
+            match cli.format {
+                OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&estimates)?),
+                OutputFormat::Table => print!("{}", estimates_table(&estimates)),
+            }
+        }
+        Command::Show { id } => {
+            let estimate = repo
+                .get_estimate(*id)
+                .await
+                .with_context(|| format!("Failed to load estimate {id}"))?;
+            match cli.format {
+                OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&estimate)?),
+                OutputFormat::Table => print!("{}", estimate_table(&estimate)),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn cli_definition_is_valid() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn flags_build_an_estimate_input() {
+        let cli = Cli::parse_from([
+            "tax-estimate",
+            "calculate",
+            "--tax-year",
+            "2025",
+            "--filing-status",
+            "MFJ",
+            "--agi",
+            "150000",
+            "--deduction",
+            "30000",
+            "--se-income",
+            "40000",
+        ]);
+        let Command::Calculate { input, save } = cli.command else {
+            panic!("expected calculate");
+        };
+        let inputs = input.load().unwrap();
+
+        assert!(!save);
+        assert_eq!(inputs.len(), 1);
+        assert_eq!(
+            inputs[0].filing_status,
+            FilingStatusCode::MarriedFilingJointly
+        );
+        assert_eq!(inputs[0].scenario_name, DEFAULT_SCENARIO_NAME);
+        assert_eq!(inputs[0].expected_agi, dec!(150000));
+        assert_eq!(inputs[0].se_income, Some(dec!(40000)));
+        assert_eq!(inputs[0].expected_wages, None);
+    }
+
+    #[test]
+    fn flags_cannot_be_mixed_with_an_input_file() {
+        let result = Cli::try_parse_from([
+            "tax-estimate",
+            "calculate",
+            "--csv",
+            "estimates.csv",
+            "--agi",
+            "150000",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn an_input_file_needs_no_flags() {
+        let cli = Cli::try_parse_from(["tax-estimate", "calculate", "--json", "estimates.json"]);
+
+        assert!(cli.is_ok());
+    }
+
+    #[test]
+    fn json_input_may_be_one_estimate_or_many() {
+        let one = r#"{
+            "tax_year": 2025, "filing_status": "Single", "scenario_name": "Default",
+            "se_income": null, "expected_crp_payments": null, "expected_wages": null,
+            "net_investment_income": null, "qualified_dividends": null,
+            "net_capital_gain": null, "expected_agi": "75000", "expected_deduction": "15000",
+            "expected_qbi_deduction": null, "expected_amt": null, "expected_credits": null,
+            "expected_other_taxes": null, "expected_withholding": null,
+            "prior_year_tax": null, "prior_year_agi": null
+        }"#;
+        let many = format!("[{one}, {one}]");
+
+        let JsonInputs::One(input) = serde_json::from_str(one).unwrap() else {
+            panic!("expected a single estimate");
+        };
+        assert_eq!(input.expected_agi, dec!(75000));
+        let JsonInputs::Many(inputs) = serde_json::from_str(&many).unwrap() else {
+            panic!("expected a list of estimates");
+        };
+        assert_eq!(inputs.len(), 2);
+    }
+}
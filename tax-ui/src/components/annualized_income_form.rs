@@ -0,0 +1,231 @@
+use gpui::{
+    App, ClickEvent, Context, Div, Entity, IntoElement, ParentElement, Render, SharedString,
+    Styled, TextAlign, Window, div, px,
+};
+use gpui_component::input::{Input, InputState};
+use gpui_component::{h_flex, v_flex};
+use rust_decimal::Decimal;
+use tax_core::calculations::{
+    ANNUALIZATION_MONTHS, AnnualizedIncomePeriod, AnnualizedIncomeResult, InstallmentMethod,
+};
+
+use crate::components::{
+    SE_LABEL_WIDTH, make_button, make_decimal_input, make_header_row, set_input_value, theme,
+};
+use crate::utils::parse_optional_decimal;
+
+/// Width of each annualization-period column.
+const PERIOD_COLUMN_WIDTH: f32 = 110.0;
+
+/// One row of per-period inputs (columns for 3, 5, 8 and 12 months).
+type PeriodInputs = [Entity<InputState>; 4];
+
+/// Form 2210 Schedule AI inputs: year-to-date amounts through the end of
+/// each annualization period, plus the installments from the last calculation.
+pub struct AnnualizedIncomeForm {
+    /// Line 1: income before the deductible part of SE tax.
+    income: PeriodInputs,
+    /// Line 4: itemized deductions (blank when taking the standard deduction).
+    itemized_deductions: PeriodInputs,
+    /// Line 9: qualified business income deduction.
+    qbi_deduction: PeriodInputs,
+    /// Part II: net profit subject to self-employment tax.
+    se_income: PeriodInputs,
+    /// Part II: wages subject to social security tax.
+    wages: PeriodInputs,
+    /// Income tax withheld.
+    withholding: PeriodInputs,
+
+    result: Option<AnnualizedIncomeResult>,
+}
+
+impl AnnualizedIncomeForm {
+    pub fn new(
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        Self {
+            income: make_period_inputs("Income", window, cx),
+            itemized_deductions: make_period_inputs("Itemized", window, cx),
+            qbi_deduction: make_period_inputs("QBI deduction", window, cx),
+            se_income: make_period_inputs("SE income", window, cx),
+            wages: make_period_inputs("Wages", window, cx),
+            withholding: make_period_inputs("Withholding", window, cx),
+            result: None,
+        }
+    }
+
+    /// Collects the entered amounts for each annualization period. Blank
+    /// cells are treated as zero.
+    pub fn to_periods(
+        &self,
+        cx: &App,
+    ) -> [AnnualizedIncomePeriod; 4] {
+        let value = |inputs: &PeriodInputs, index: usize| -> Decimal {
+            parse_optional_decimal(inputs[index].read(cx).value().as_str()).unwrap_or_default()
+        };
+
+        std::array::from_fn(|index| AnnualizedIncomePeriod {
+            income: value(&self.income, index),
+            itemized_deductions: value(&self.itemized_deductions, index),
+            qbi_deduction: value(&self.qbi_deduction, index),
+            se_income: value(&self.se_income, index),
+            wages: value(&self.wages, index),
+            withholding: value(&self.withholding, index),
+        })
+    }
+
+    /// Stores the result of the last Schedule AI calculation for display.
+    pub fn set_result(
+        &mut self,
+        result: Option<AnnualizedIncomeResult>,
+    ) {
+        self.result = result;
+    }
+
+    fn clear(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        for inputs in [
+            &self.income,
+            &self.itemized_deductions,
+            &self.qbi_deduction,
+            &self.se_income,
+            &self.wages,
+            &self.withholding,
+        ] {
+            for input in inputs {
+                set_input_value(input, "", window, cx);
+            }
+        }
+        self.result = None;
+        cx.notify();
+    }
+
+    /// Estimated payment per installment under `method`, if calculated.
+    fn installments(
+        &self,
+        method: InstallmentMethod,
+    ) -> [Option<Decimal>; 4] {
+        match &self.result {
+            Some(result) => result.installments(method).map(Some),
+            None => [None; 4],
+        }
+    }
+}
+
+impl Render for AnnualizedIncomeForm {
+    fn render(
+        &mut self,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let this = cx.entity().clone();
+
+        v_flex()
+            .gap_2()
+            .p_4()
+            .child(make_header_row("Year-to-date amounts through:"))
+            .child(period_header_row())
+            .child(period_input_row("1. Income: $", &self.income))
+            .child(period_input_row(
+                "4. Itemized deductions: $",
+                &self.itemized_deductions,
+            ))
+            .child(period_input_row("9. QBI deduction: $", &self.qbi_deduction))
+            .child(period_input_row("SE income: $", &self.se_income))
+            .child(period_input_row("SS wages: $", &self.wages))
+            .child(period_input_row("Withholding: $", &self.withholding))
+            .child(make_header_row("Estimated payment due:"))
+            .child(period_display_row(
+                "Regular (25%): $",
+                self.installments(InstallmentMethod::Regular),
+            ))
+            .child(period_display_row(
+                "Annualized income: $",
+                self.installments(InstallmentMethod::AnnualizedIncome),
+            ))
+            .child(h_flex().gap_2().justify_end().mt_4().child(make_button(
+                "annualized_income_clear",
+                "Clear",
+                true,
+                move |_ev: &ClickEvent, window: &mut Window, app_cx: &mut App| {
+                    this.update(app_cx, |form, cx| {
+                        form.clear(window, cx);
+                    });
+                },
+            )))
+    }
+}
+
+fn make_period_inputs(
+    placeholder: &'static str,
+    window: &mut Window,
+    cx: &mut Context<AnnualizedIncomeForm>,
+) -> PeriodInputs {
+    std::array::from_fn(|_| make_decimal_input(placeholder, 2, window, cx))
+}
+
+fn period_label(label: impl Into<SharedString>) -> Div {
+    div()
+        .w(px(SE_LABEL_WIDTH))
+        .text_align(TextAlign::Right)
+        .child(label.into())
+}
+
+fn period_header_row() -> Div {
+    h_flex()
+        .items_center()
+        .gap_2()
+        .child(period_label(""))
+        .children(ANNUALIZATION_MONTHS.iter().map(|months| {
+            div()
+                .w(px(PERIOD_COLUMN_WIDTH))
+                .text_align(TextAlign::Right)
+                .child(format!("{months} months"))
+        }))
+}
+
+fn period_input_row(
+    label: &'static str,
+    inputs: &PeriodInputs,
+) -> Div {
+    h_flex()
+        .items_center()
+        .gap_2()
+        .child(period_label(label))
+        .children(
+            inputs
+                .iter()
+                .map(|input| Input::new(input).w(px(PERIOD_COLUMN_WIDTH))),
+        )
+}
+
+fn period_display_row(
+    label: &'static str,
+    values: [Option<Decimal>; 4],
+) -> Div {
+    h_flex()
+        .items_center()
+        .gap_2()
+        .child(period_label(label))
+        .children(values.into_iter().map(|value| {
+            div()
+                .w(px(PERIOD_COLUMN_WIDTH))
+                .px_2()
+                .py_1()
+                .rounded_md()
+                .border_1()
+                .border_color(theme::DISPLAY_FIELD_BORDER)
+                .bg(theme::DISPLAY_FIELD_BG)
+                .text_color(theme::DISPLAY_FIELD_TEXT)
+                .text_align(TextAlign::Right)
+                .child(
+                    value
+                        .map(|d| format!("{d:.2}"))
+                        .unwrap_or_else(|| "—".to_string()),
+                )
+        }))
+}
@@ -0,0 +1,162 @@
+use std::rc::Rc;
+
+use gpui::{
+    App, ClickEvent, Context, InteractiveElement as _, IntoElement, ParentElement, Render,
+    StatefulInteractiveElement as _, Styled, Window, div, px,
+};
+use gpui_component::{ActiveTheme, WindowExt, h_flex, v_flex};
+use tax_core::TaxEstimateInput;
+
+use crate::components::{make_button, make_header_row};
+use crate::csv_loader::ImportRow;
+use crate::utils::currency;
+
+/// Preview line for a row: what it would save, or why it can't be imported,
+/// e.g. "Row 1: 2025 S — Default — AGI $75000.00".
+fn row_text(row: &ImportRow) -> String {
+    match &row.result {
+        Ok(input) => format!(
+            "Row {}: {} {} — {} — AGI {}",
+            row.row,
+            input.tax_year,
+            input.filing_status.as_str(),
+            input.scenario_name,
+            currency(&input.expected_agi)
+        ),
+        Err(e) => e.to_string(),
+    }
+}
+
+/// Heading over the preview, e.g. "3 rows ready to import, 1 with errors".
+fn summary_text(rows: &[ImportRow]) -> String {
+    let invalid = rows.iter().filter(|r| r.result.is_err()).count();
+    let valid = rows.len() - invalid;
+    let noun = if valid == 1 { "row" } else { "rows" };
+    match invalid {
+        0 => format!("{valid} {noun} ready to import"),
+        _ => format!("{valid} {noun} ready to import, {invalid} with errors"),
+    }
+}
+
+/// Previews the rows of a CSV file before they are imported as estimates.
+///
+/// Each row is listed with the estimate it would save, or with its errors
+/// (shown in the theme's danger color). **Import** hands the valid rows to
+/// the provided callback and dismisses the dialog; rows with errors are
+/// skipped. It is disabled when no row is valid. **Cancel** dismisses the
+/// dialog without action.
+pub struct CsvImportPreview {
+    rows: Vec<ImportRow>,
+    on_import: Rc<dyn Fn(Vec<TaxEstimateInput>, &mut Window, &mut App)>,
+}
+
+impl CsvImportPreview {
+    /// Creates a preview of `rows`, as returned by
+    /// [`crate::csv_loader::preview_from_file`].
+    pub fn new(
+        rows: Vec<ImportRow>,
+        on_import: Rc<dyn Fn(Vec<TaxEstimateInput>, &mut Window, &mut App)>,
+    ) -> Self {
+        Self { rows, on_import }
+    }
+
+    /// The estimates from every row that parsed and validated.
+    pub fn valid_inputs(&self) -> Vec<TaxEstimateInput> {
+        self.rows
+            .iter()
+            .filter_map(|r| r.result.as_ref().ok().cloned())
+            .collect()
+    }
+}
+
+impl Render for CsvImportPreview {
+    fn render(
+        &mut self,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let inputs = self.valid_inputs();
+        let can_import = !inputs.is_empty();
+        let on_import = self.on_import.clone();
+        let danger = cx.theme().danger;
+
+        v_flex()
+            .gap_2()
+            .p_4()
+            .child(make_header_row(summary_text(&self.rows)))
+            .child(
+                div()
+                    .id("csv-import-rows")
+                    .max_h(px(360.))
+                    .overflow_y_scroll()
+                    .child(v_flex().gap_1().children(self.rows.iter().map(|row| {
+                        let line = div().px_2().child(row_text(row));
+                        if row.result.is_err() {
+                            line.text_color(danger)
+                        } else {
+                            line
+                        }
+                    }))),
+            )
+            .child(
+                h_flex()
+                    .justify_end()
+                    .gap_2()
+                    .mt_4()
+                    .child(make_button(
+                        "import-csv-rows",
+                        "Import",
+                        can_import,
+                        move |_ev: &ClickEvent, window: &mut Window, cx: &mut App| {
+                            window.close_dialog(cx);
+                            on_import(inputs.clone(), window, cx);
+                        },
+                    ))
+                    .child(make_button(
+                        "cancel-csv-import",
+                        "Cancel",
+                        true,
+                        |_ev: &ClickEvent, window: &mut Window, cx: &mut App| {
+                            window.close_dialog(cx);
+                        },
+                    )),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::csv_loader::preview_from_str;
+
+    const CSV: &str = "\
+tax_year,filing_status,expected_agi,expected_deduction
+2025,S,75000.00,15750.00
+2025,BOGUS,1.00,1.00
+";
+
+    #[test]
+    fn row_text_shows_estimate_or_error() {
+        let rows = preview_from_str(CSV);
+
+        assert_eq!(
+            row_text(&rows[0]),
+            "Row 1: 2025 S — Default — AGI $75000.00"
+        );
+        assert_eq!(
+            row_text(&rows[1]),
+            "unrecognised filing status 'BOGUS' on row 2"
+        );
+    }
+
+    #[test]
+    fn summary_text_counts_valid_and_invalid_rows() {
+        let rows = preview_from_str(CSV);
+
+        assert_eq!(summary_text(&rows), "1 row ready to import, 1 with errors");
+        assert_eq!(summary_text(&rows[..1]), "1 row ready to import");
+        assert_eq!(summary_text(&[]), "0 rows ready to import");
+    }
+}
@@ -0,0 +1,53 @@
+use gpui::{Div, ParentElement, Styled};
+use gpui_component::v_flex;
+use tax_core::calculations::{EstimatedTaxWorksheetLine, EstimatedTaxWorksheetTrace};
+
+use crate::components::{make_display_row, make_header_row};
+
+/// Row label for a worksheet line, e.g. "12a. Multiply line 11c by 90%:".
+fn line_label(line: &EstimatedTaxWorksheetLine) -> String {
+    format!("{}. {}:", line.line, line.label)
+}
+
+/// Summary shown below line 14b.
+fn payments_required_label(trace: &EstimatedTaxWorksheetTrace) -> &'static str {
+    if trace.estimated_payments_required {
+        "Estimated payments required"
+    } else {
+        "No estimated payments required"
+    }
+}
+
+/// Read-only Form 1040-ES Estimated Tax Worksheet (lines 1–14b) from the
+/// last calculation, laid out to reconcile against the paper form.
+pub(crate) fn make_est_tax_worksheet_view(trace: &EstimatedTaxWorksheetTrace) -> Div {
+    v_flex()
+        .gap_2()
+        .p_4()
+        .children(
+            trace
+                .lines()
+                .iter()
+                .map(|line| make_display_row(line_label(line), Some(line.amount))),
+        )
+        .child(make_header_row(payments_required_label(trace)))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn line_label_prefixes_line_number() {
+        let line = EstimatedTaxWorksheetLine {
+            line: "12a",
+            label: "Multiply line 11c by 90%".to_string(),
+            amount: dec!(9000.00),
+        };
+
+        assert_eq!(line_label(&line), "12a. Multiply line 11c by 90%:");
+    }
+}
@@ -13,32 +13,39 @@ use gpui_component::{
 };
 use regex::Regex;
 use rust_decimal::Decimal;
-use tax_core::calculations::{
-    EstimatedTaxWorksheet, EstimatedTaxWorksheetContext, EstimatedTaxWorksheetInput,
-    EstimatedTaxWorksheetResult,
+use tax_core::calculations::{AnnualizedIncomeInput, InstallmentMethod, InstallmentSchedule};
+use tax_core::{
+    DEFAULT_SCENARIO_NAME, FilingStatusCode, TaxEstimate, TaxEstimateInput, TaxYearConfig,
 };
-use tax_core::{FilingStatusCode, TaxEstimate, TaxEstimateInput, TaxYearConfig};
 
-use crate::app::{FilingStatusData, save_tax_estimate};
-use crate::components::{ErrorDialog, show_err};
+use crate::app::{
+    EstimateCalculation, FilingStatusData, annualized_income_estimate, calculate_estimate,
+    save_calculated_estimates,
+};
+use crate::components::{ErrorDialog, make_est_tax_worksheet_view, show_err};
 use crate::instructions::{UiInstructionField, help_for_field};
 use crate::models::SeWorksheetModel;
 use crate::repository::TaxRepo;
 use crate::{
     components::{
-        ResultForm, SeWorksheetForm, make_button, make_decimal_input, make_header_row,
-        make_input_row, make_input_row_with_help, make_integer_input, make_select_row,
-        set_input_value,
+        AnnualizedIncomeForm, ResultForm, SeWorksheetForm, UnderpaymentPenaltyForm, make_button,
+        make_decimal_input, make_header_row, make_input_row, make_input_row_with_help,
+        make_integer_input, make_select_row, make_text_input, set_input_value,
     },
     repository::ActiveTaxYear,
     utils::{optional_decimal_input_text, parse_decimal, parse_optional_decimal},
 };
 
+const REGULAR_METHOD_LABEL: &str = "Regular (25% each)";
+const ANNUALIZED_METHOD_LABEL: &str = "Annualized income";
+
 #[derive(Clone, Debug)]
 pub struct EstimatedIncomeForm {
     worksheet: Entity<SeWorksheetForm>,
     tax_year: Entity<InputState>,
     filing_status: Entity<SelectState<Vec<SharedString>>>,
+    // What-if label; estimates are saved per tax year, filing status and scenario.
+    scenario_name: Entity<InputState>,
 
     // 1040-ES Worksheet inputs
     // Line 1: adjusted gross income you expect for the year (see form instructions).
@@ -47,17 +54,29 @@ pub struct EstimatedIncomeForm {
     expected_deduction: Entity<InputState>,
     // Line 2b: qualified business income deduction, if applicable.
     expected_qbi_deduction: Entity<InputState>,
+    // Line 4: qualified dividends and net capital gain, taxed at 0/15/20% through the
+    // Qualified Dividends and Capital Gain Tax Worksheet.
+    qualified_dividends: Entity<InputState>,
+    net_capital_gain: Entity<InputState>,
     // Line 5: alternative minimum tax from Form 6251.
     expected_amt: Entity<InputState>,
     // Line 7: credits (do not include withholding on this line).
     expected_credits: Entity<InputState>,
     // Line 10: other taxes (see worksheet instructions).
     expected_other_taxes: Entity<InputState>,
+    // Form 8960 line 8: net investment income; the NIIT is added to line 10.
+    net_investment_income: Entity<InputState>,
     // Line 13: income tax withheld and estimated to be withheld (including pensions,
     // annuities, certain deferred income, and Additional Medicare Tax withholding).
     expected_withholding: Entity<InputState>,
     // Line 12b: required annual payment based on prior year's tax (per worksheet instructions).
     prior_year_tax: Entity<InputState>,
+    // Line 12b: prior year AGI; above the threshold the prior-year tax is multiplied by 110%.
+    prior_year_agi: Entity<InputState>,
+    // Whether installments are 25% of line 12c or follow Form 2210 Schedule AI.
+    installment_method: Entity<SelectState<Vec<SharedString>>>,
+    annualized: Entity<AnnualizedIncomeForm>,
+    penalty: Entity<UnderpaymentPenaltyForm>,
     is_tax_year_ready: bool,
     results: Entity<ResultForm>,
 }
@@ -113,19 +132,43 @@ impl EstimatedIncomeForm {
         .detach();
 
         let filing_status = cx.new(|cx| SelectState::new(statuses, initial_index, window, cx));
+        let scenario_name = make_text_input("Scenario name", window, cx);
+        set_input_value(&scenario_name, DEFAULT_SCENARIO_NAME, window, cx);
         let results = cx.new(|_| ResultForm::default());
+        let installment_methods = vec![
+            SharedString::from(REGULAR_METHOD_LABEL),
+            SharedString::from(ANNUALIZED_METHOD_LABEL),
+        ];
+        let installment_method = cx.new(|cx| {
+            SelectState::new(
+                installment_methods,
+                Some(IndexPath::default().row(0)),
+                window,
+                cx,
+            )
+        });
+        let annualized = cx.new(|cx| AnnualizedIncomeForm::new(window, cx));
+        let penalty = cx.new(|cx| UnderpaymentPenaltyForm::new(window, cx));
         Self {
             worksheet,
             tax_year,
             filing_status,
+            scenario_name,
             expected_agi: make_decimal_input("Exp AGI", 2, window, cx),
             expected_deduction: make_decimal_input("Exp deduction", 2, window, cx),
             expected_qbi_deduction: make_decimal_input("Exp QBI deduction", 2, window, cx),
+            qualified_dividends: make_decimal_input("Exp qualified dividends", 2, window, cx),
+            net_capital_gain: make_decimal_input("Exp net capital gain", 2, window, cx),
             expected_amt: make_decimal_input("Exp AMT", 2, window, cx),
             expected_credits: make_decimal_input("Exp tax credits", 2, window, cx),
             expected_other_taxes: make_decimal_input("Exp other taxes", 2, window, cx),
+            net_investment_income: make_decimal_input("Exp net investment income", 2, window, cx),
             expected_withholding: make_decimal_input("Exp inc tax withheld", 2, window, cx),
             prior_year_tax: make_decimal_input("Prior year tax liability", 2, window, cx),
+            prior_year_agi: make_decimal_input("Prior year AGI", 2, window, cx),
+            installment_method,
+            annualized,
+            penalty,
             is_tax_year_ready: false,
             results,
         }
@@ -208,9 +251,19 @@ impl EstimatedIncomeForm {
         let input = TaxEstimateInput {
             tax_year,
             filing_status,
+            scenario_name: self.scenario_name.read(cx).value().trim().to_string(),
             se_income: se_model.line_1a_expected_se_income,
             expected_crp_payments: se_model.line_1b_expected_crp_payments,
             expected_wages: se_model.line_6_expected_wages,
+            net_investment_income: parse_optional_decimal(
+                self.net_investment_income.read(cx).value().as_str(),
+            ),
+            qualified_dividends: parse_optional_decimal(
+                self.qualified_dividends.read(cx).value().as_str(),
+            ),
+            net_capital_gain: parse_optional_decimal(
+                self.net_capital_gain.read(cx).value().as_str(),
+            ),
             expected_agi,
             expected_deduction,
             expected_qbi_deduction: parse_optional_decimal(
@@ -227,12 +280,81 @@ impl EstimatedIncomeForm {
                 self.expected_withholding.read(cx).value().as_str(),
             ),
             prior_year_tax: parse_optional_decimal(self.prior_year_tax.read(cx).value().as_str()),
+            prior_year_agi: parse_optional_decimal(self.prior_year_agi.read(cx).value().as_str()),
         };
 
         input.validate_for_submit()?;
         Ok(input)
     }
 
+    /// Returns the selected installment method, defaulting to regular.
+    fn installment_method(
+        &self,
+        cx: &App,
+    ) -> InstallmentMethod {
+        match self.installment_method.read(cx).selected_value() {
+            Some(label) if label.as_ref() == ANNUALIZED_METHOD_LABEL => {
+                InstallmentMethod::AnnualizedIncome
+            }
+            _ => InstallmentMethod::Regular,
+        }
+    }
+
+    /// Builds the installment schedule for the selected method. The
+    /// annualized income method runs Schedule AI over the amounts entered in
+    /// the Annualized Income dialog and shows its result there.
+    ///
+    /// The required installments before withholding are handed to the
+    /// Penalty dialog.
+    fn installment_schedule(
+        &self,
+        tax_year: i32,
+        config: &TaxYearConfig,
+        status_data: &FilingStatusData,
+        calculation: &EstimateCalculation,
+        cx: &mut Context<Self>,
+    ) -> anyhow::Result<InstallmentSchedule> {
+        let result = &calculation.result;
+        let worksheet = &calculation.worksheet;
+        let method = self.installment_method(cx);
+        let (schedule, required, ai_result) = if method == InstallmentMethod::Regular {
+            (
+                InstallmentSchedule::from_worksheet_result(tax_year, result)?,
+                InstallmentSchedule::calculate(tax_year, result.required_annual_payment)?,
+                None,
+            )
+        } else {
+            let ai_input = AnnualizedIncomeInput {
+                periods: self.annualized.read(cx).to_periods(cx),
+                standard_deduction: status_data.standard_deduction.amount,
+                credits: worksheet.line_7_credits,
+                // Line 10 already includes Additional Medicare Tax and NIIT.
+                other_taxes: worksheet.line_5_alternative_minimum_tax
+                    + worksheet.line_10_other_taxes,
+                required_annual_payment: result.required_annual_payment,
+            };
+            let ai_result = annualized_income_estimate(config, status_data, &ai_input)?;
+            (
+                InstallmentSchedule::from_amounts(tax_year, ai_result.installments(method))?,
+                InstallmentSchedule::from_amounts(
+                    tax_year,
+                    ai_result.required_installments(method),
+                )?,
+                Some(ai_result),
+            )
+        };
+
+        self.annualized.update(cx, |form, cx| {
+            form.set_result(ai_result);
+            cx.notify();
+        });
+        self.penalty.update(cx, |form, cx| {
+            form.set_required(Some(required), worksheet.line_13_withholding);
+            cx.notify();
+        });
+        Ok(schedule)
+    }
+
     /// Returns the raw tax year value, parsed if valid.
     pub fn tax_year(
         &self,
@@ -242,9 +364,9 @@ impl EstimatedIncomeForm {
         value.trim().parse::<i32>().ok()
     }
 
-    /// Populates the form's tax year, filing status, SE worksheet fields,
-    /// and the 1040-ES worksheet inputs from a previously saved
-    /// [`TaxEstimate`]. When the estimate carries computed results, those
+    /// Populates the form's tax year, filing status, scenario name, SE
+    /// worksheet fields, and the 1040-ES worksheet inputs from a previously
+    /// saved [`TaxEstimate`]. When the estimate carries computed results, those
     /// are shown in the results panel; otherwise the panel is cleared.
     ///
     /// Triggers [`ActiveTaxYear::load`] so the tax-year config is fetched
@@ -272,6 +394,7 @@ impl EstimatedIncomeForm {
         self.filing_status.update(cx, |state, is_cx| {
             state.set_selected_index(Some(IndexPath::default().row(idx)), window, is_cx);
         });
+        set_input_value(&self.scenario_name, input.scenario_name.clone(), window, cx);
 
         set_decimal_input(&self.expected_agi, input.expected_agi, window, cx);
         set_decimal_input(
@@ -286,6 +409,13 @@ impl EstimatedIncomeForm {
             window,
             cx,
         );
+        set_optional_decimal_input(
+            &self.qualified_dividends,
+            input.qualified_dividends,
+            window,
+            cx,
+        );
+        set_optional_decimal_input(&self.net_capital_gain, input.net_capital_gain, window, cx);
         set_optional_decimal_input(&self.expected_amt, input.expected_amt, window, cx);
         set_optional_decimal_input(&self.expected_credits, input.expected_credits, window, cx);
         set_optional_decimal_input(
@@ -294,6 +424,12 @@ impl EstimatedIncomeForm {
             window,
             cx,
         );
+        set_optional_decimal_input(
+            &self.net_investment_income,
+            input.net_investment_income,
+            window,
+            cx,
+        );
         set_optional_decimal_input(
             &self.expected_withholding,
             input.expected_withholding,
@@ -301,6 +437,7 @@ impl EstimatedIncomeForm {
             cx,
         );
         set_optional_decimal_input(&self.prior_year_tax, input.prior_year_tax, window, cx);
+        set_optional_decimal_input(&self.prior_year_agi, input.prior_year_agi, window, cx);
 
         self.results.update(cx, |rf, rf_cx| {
             if let Some(ref computed) = estimate.computed {
@@ -311,6 +448,13 @@ impl EstimatedIncomeForm {
             rf_cx.notify();
         });
 
+        // The installment schedule is not persisted, so there is nothing to
+        // figure a penalty against until the estimate is recalculated.
+        self.penalty.update(cx, |form, cx| {
+            form.set_required(None, Decimal::ZERO);
+            cx.notify();
+        });
+
         self.worksheet.update(cx, |ws, ws_cx| {
             ws.populate_from_estimate(input, window, ws_cx);
         });
@@ -318,6 +462,16 @@ impl EstimatedIncomeForm {
         cx.notify();
     }
 
+    /// Calculates the estimate on screen and saves it to the open project,
+    /// the same as the **Calculate SE Tax** button.
+    pub fn save(
+        &self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.call_calculate_tax_estimate(window, cx);
+    }
+
     fn input_from_form_or_show_errors(
         &self,
         se_model: &SeWorksheetModel,
@@ -352,53 +506,61 @@ impl EstimatedIncomeForm {
             return;
         };
 
-        let config: &TaxYearConfig = &tax_year_data.config;
-        let filing_status: FilingStatusCode = form_input.filing_status;
-        let filing_data: &Vec<FilingStatusData> = &tax_year_data.statuses;
-
-        let Some(filing_status_data) = filing_data
-            .iter()
-            .find(|f: &&FilingStatusData| f.filing_status.status_code == filing_status)
-        else {
-            // TODO: Handle this error situation
-            return;
+        let calculation = match calculate_estimate(&tax_year_data, &form_input) {
+            Ok(calculation) => calculation,
+            Err(error) => {
+                tracing::warn!(error = ?error, "Estimate calculation failed");
+                ErrorDialog::show("Calculation failed", &[format!("{error:#}")], window, cx);
+                return;
+            }
         };
-
-        let worksheet_context = EstimatedTaxWorksheetContext {
-            self_employment_tax: se_model.line_10_total_se_tax.unwrap_or_default(),
-            refundable_credits: Decimal::ZERO,
-            is_farmer_or_fisher: false,
-            required_payment_threshold: config.req_pmnt_threshold,
+        let Some(filing_status_data) = tax_year_data.status(form_input.filing_status) else {
+            // calculate_estimate has already checked this status is loaded.
+            return;
         };
-        let inputs: EstimatedTaxWorksheetInput =
-            form_input.to_estimated_tax_worksheet_input(&worksheet_context);
 
-        let tax_worksheet: EstimatedTaxWorksheet =
-            EstimatedTaxWorksheet::new(&filing_status_data.tax_brackets);
-        let result: EstimatedTaxWorksheetResult = match tax_worksheet.calculate(&inputs) {
-            Ok(result) => result,
+        let installments = match self.installment_schedule(
+            form_input.tax_year,
+            &tax_year_data.config,
+            filing_status_data,
+            &calculation,
+            cx,
+        ) {
+            Ok(schedule) => schedule,
             Err(error) => {
-                tracing::warn!(%error, "Estimated tax calculation failed");
-                ErrorDialog::show("Calculation failed", &[error.to_string()], window, cx);
+                tracing::warn!(error = ?error, "Installment schedule calculation failed");
+                ErrorDialog::show("Calculation failed", &[format!("{error:#}")], window, cx);
                 return;
             }
         };
 
-        let se_tax = se_model.line_10_total_se_tax.unwrap_or_default();
         self.results.update(cx, |rf, cx| {
-            rf.set_from_calculation(se_tax, &result);
+            rf.set_from_calculation(
+                calculation.self_employment.self_employment_tax,
+                calculation.additional_medicare.total_tax,
+                calculation.niit.net_investment_income_tax,
+                calculation.worksheet.line_2b_qbi_deduction,
+                &calculation.worksheet,
+                installments,
+            );
             cx.notify();
         });
         cx.notify();
 
-        tracing::info!(input = %form_input, %result, "Estimated taxes");
+        tracing::info!(input = %form_input, result = %calculation.result, "Estimated taxes");
+        if !calculation.notes.is_empty() {
+            ErrorDialog::show("Check the estimate", &calculation.notes, window, cx);
+        }
 
+        let computed = calculation.computed();
         let window_handle = window.window_handle();
         cx.spawn(async move |_this, async_cx| {
             let repo = match async_cx.update(|app_cx: &mut App| {
                 TaxRepo::try_get(app_cx)
                     .map(|tax_repo| tax_repo.tax_repository_arc())
-                    .ok_or_else(|| anyhow::anyhow!("TaxRepo not initialized for save_tax_estimate"))
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("TaxRepo not initialized for save_calculated_estimates")
+                    })
             }) {
                 Ok(Ok(repo)) => repo,
                 Ok(Err(e)) | Err(e) => {
@@ -408,8 +570,8 @@ impl EstimatedIncomeForm {
                 }
             };
 
-            if let Err(e) = save_tax_estimate(&form_input, &result, &se_model, repo).await {
-                tracing::error!(error = ?e, "save_tax_estimate failed");
+            if let Err(e) = save_calculated_estimates(vec![(form_input, computed)], &*repo).await {
+                tracing::error!(error = ?e, "save_calculated_estimates failed");
                 show_err(window_handle, async_cx, e);
             }
         })
@@ -441,6 +603,65 @@ impl EstimatedIncomeForm {
         });
     }
 
+    fn call_annualized_income_dialog(
+        &self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let form_for_dialog = self.annualized.clone();
+
+        window.open_dialog(cx, move |dialog, _window, _cx| {
+            dialog
+                .overlay_closable(false)
+                .w(px(780.0))
+                .margin_top(px(-20.0))
+                .title("Annualized Income Installments (Form 2210, Schedule AI)")
+                .child(form_for_dialog.clone())
+                .button_props(DialogButtonProps::default().cancel_text("Close"))
+                .footer(|_ok, cancel, window, cx| vec![cancel(window, cx)])
+        });
+    }
+
+    fn call_penalty_dialog(
+        &self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let form_for_dialog = self.penalty.clone();
+
+        window.open_dialog(cx, move |dialog, _window, _cx| {
+            dialog
+                .overlay_closable(false)
+                .w(px(640.0))
+                .margin_top(px(-20.0))
+                .title("Underpayment Penalty (Form 2210)")
+                .child(form_for_dialog.clone())
+                .button_props(DialogButtonProps::default().cancel_text("Close"))
+                .footer(|_ok, cancel, window, cx| vec![cancel(window, cx)])
+        });
+    }
+
+    fn call_tax_worksheet_dialog(
+        &self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(trace) = self.results.read(cx).worksheet_trace().cloned() else {
+            return;
+        };
+
+        window.open_dialog(cx, move |dialog, _window, _cx| {
+            dialog
+                .overlay_closable(false)
+                .w(px(640.0))
+                .margin_top(px(-20.0))
+                .title("Estimated Tax Worksheet (Form 1040-ES)")
+                .child(make_est_tax_worksheet_view(&trace))
+                .button_props(DialogButtonProps::default().cancel_text("Close"))
+                .footer(|_ok, cancel, window, cx| vec![cancel(window, cx)])
+        });
+    }
+
     fn render_results(
         &self,
         cx: &mut Context<Self>,
@@ -478,6 +699,30 @@ impl EstimatedIncomeForm {
                     this.call_se_worksheet_dialog(window, cx);
                 }),
             ))
+            .child(make_button(
+                "open-tax-worksheet",
+                "Tax Worksheet",
+                self.results.read(cx).worksheet_trace().is_some(),
+                cx.listener(|this, _ev, window, cx| {
+                    this.call_tax_worksheet_dialog(window, cx);
+                }),
+            ))
+            .child(make_button(
+                "open-annualized-income",
+                "Annualized Income",
+                self.is_tax_year_ready,
+                cx.listener(|this, _ev, window, cx| {
+                    this.call_annualized_income_dialog(window, cx);
+                }),
+            ))
+            .child(make_button(
+                "open-penalty",
+                "Penalty",
+                self.is_tax_year_ready,
+                cx.listener(|this, _ev, window, cx| {
+                    this.call_penalty_dialog(window, cx);
+                }),
+            ))
     }
 
     fn render_side_base(&self) -> Div {
@@ -496,6 +741,13 @@ impl EstimatedIncomeForm {
                 "Filing Status:",
                 Select::new(&self.filing_status).w_full().render(window, cx),
             ))
+            .child(make_input_row(&self.scenario_name, "Scenario"))
+            .child(make_select_row(
+                "Installments:",
+                Select::new(&self.installment_method)
+                    .w_full()
+                    .render(window, cx),
+            ))
     }
 
     fn render_right_side(
@@ -522,6 +774,16 @@ impl EstimatedIncomeForm {
                 "QBI deduction: $",
                 help_for_field(UiInstructionField::ExpectedQbiDeduction, selected_year),
             ))
+            .child(make_input_row_with_help(
+                &self.qualified_dividends,
+                "Qualified div.: $",
+                help_for_field(UiInstructionField::QualifiedDividends, selected_year),
+            ))
+            .child(make_input_row_with_help(
+                &self.net_capital_gain,
+                "Net capital gain: $",
+                help_for_field(UiInstructionField::NetCapitalGain, selected_year),
+            ))
             .child(make_input_row_with_help(
                 &self.expected_amt,
                 "AMT: $",
@@ -537,6 +799,11 @@ impl EstimatedIncomeForm {
                 "Other taxes: $",
                 help_for_field(UiInstructionField::ExpectedOtherTaxes, selected_year),
             ))
+            .child(make_input_row_with_help(
+                &self.net_investment_income,
+                "Net invest. income: $",
+                help_for_field(UiInstructionField::NetInvestmentIncome, selected_year),
+            ))
             .child(make_input_row_with_help(
                 &self.expected_withholding,
                 "Withholding: $",
@@ -547,6 +814,11 @@ impl EstimatedIncomeForm {
                 "Prior year tax: $",
                 help_for_field(UiInstructionField::PriorYearTax, selected_year),
             ))
+            .child(make_input_row_with_help(
+                &self.prior_year_agi,
+                "Prior year AGI: $",
+                help_for_field(UiInstructionField::PriorYearAgi, selected_year),
+            ))
     }
 }
 
@@ -650,8 +922,14 @@ mod tests {
                     se_deduction_factor: Decimal::ZERO,
                     req_pmnt_threshold: Decimal::ZERO,
                     min_se_threshold: Decimal::ZERO,
+                    addl_medicare_tax_rate: Decimal::ZERO,
+                    niit_rate: Decimal::ZERO,
+                    prior_agi_threshold: Decimal::ZERO,
+                    prior_agi_threshold_mfs: Decimal::ZERO,
+                    prior_tax_multiplier: Decimal::ZERO,
                 },
                 statuses: Vec::new(),
+                interest_rates: Vec::new(),
             }),
         }
     }
@@ -14,17 +14,22 @@ use tax_core::TaxEstimate;
 use crate::components::make_button;
 
 /// Dropdown selector over a list of previously saved [`TaxEstimate`] records.
+/// Each row shows the estimate's scenario name alongside its tax year and
+/// filing status, so what-if versions of the same return can be told apart.
 ///
 /// Renders a dropdown with **Select** and **Cancel** buttons beneath it. The
 /// dropdown's first row is blank, representing "no selection". **Select** is
 /// disabled while the blank row is chosen; once a real estimate is selected it
 /// becomes enabled, fires the provided callback with that estimate, and
-/// dismisses the dialog. **Cancel** dismisses the dialog without action.
+/// dismisses the dialog. **History** is enabled under the same condition and
+/// hands the estimate to the history callback after dismissing the dialog.
+/// **Cancel** dismisses the dialog without action.
 pub struct EstimateSelector {
     estimates: Vec<TaxEstimate>,
     labels: Vec<SharedString>,
     select: Entity<SelectState<Vec<SharedString>>>,
     on_select: Rc<dyn Fn(&TaxEstimate, &mut Window, &mut App)>,
+    on_history: Rc<dyn Fn(&TaxEstimate, &mut Window, &mut App)>,
     _select_subscription: Subscription,
 }
 
@@ -32,18 +37,20 @@ impl EstimateSelector {
     /// Creates a new selector from saved estimates.
     ///
     /// `on_select` is invoked with the chosen estimate when the user clicks
-    /// **Select**.
+    /// **Select**, and `on_history` when the user clicks **History**.
     pub fn new(
         estimates: Vec<TaxEstimate>,
         on_select: Rc<dyn Fn(&TaxEstimate, &mut Window, &mut App)>,
+        on_history: Rc<dyn Fn(&TaxEstimate, &mut Window, &mut App)>,
         window: &mut Window,
         cx: &mut Context<Self>,
     ) -> Self {
         let mut labels: Vec<SharedString> = vec![SharedString::from("")];
         labels.extend(estimates.iter().map(|e| {
             SharedString::from(format!(
-                "#{} — {} {} (updated {})",
+                "#{} — {} — {} {} (updated {})",
                 e.id,
+                e.input.scenario_name,
                 e.input.tax_year,
                 e.input.filing_status.as_str(),
                 e.updated_at.format("%Y-%m-%d %H:%M"),
@@ -63,6 +70,7 @@ impl EstimateSelector {
             labels,
             select,
             on_select,
+            on_history,
             _select_subscription: subscription,
         }
     }
@@ -93,7 +101,9 @@ impl Render for EstimateSelector {
         cx: &mut Context<Self>,
     ) -> impl IntoElement {
         let this = cx.entity().clone();
+        let history_this = this.clone();
         let on_select = self.on_select.clone();
+        let on_history = self.on_history.clone();
         let can_select = self.selected_estimate(cx).is_some();
 
         v_flex()
@@ -119,6 +129,18 @@ impl Render for EstimateSelector {
                             window.close_dialog(cx);
                         },
                     ))
+                    .child(make_button(
+                        "estimate-history",
+                        "History",
+                        can_select,
+                        move |_ev: &ClickEvent, window: &mut Window, cx: &mut App| {
+                            let selected = history_this.read(cx).selected_estimate(cx).cloned();
+                            window.close_dialog(cx);
+                            if let Some(ref estimate) = selected {
+                                on_history(estimate, window, cx);
+                            }
+                        },
+                    ))
                     .child(make_button(
                         "cancel-estimate",
                         "Cancel",
@@ -0,0 +1,204 @@
+use std::rc::Rc;
+
+use gpui::{
+    App, AppContext, ClickEvent, Context, Entity, IntoElement, ParentElement, Render, RenderOnce,
+    SharedString, Styled, Subscription, Window, px,
+};
+use gpui_component::{
+    IndexPath, WindowExt, h_flex,
+    select::{Select, SelectState},
+    v_flex,
+};
+use tax_core::TaxEstimate;
+
+use crate::components::{make_button, make_select_row};
+use crate::exporter::ExportFormat;
+
+const ALL_YEARS_LABEL: &str = "All years";
+
+/// Distinct tax years of `estimates`, newest first.
+fn tax_years(estimates: &[TaxEstimate]) -> Vec<i32> {
+    let mut years: Vec<i32> = estimates.iter().map(|e| e.input.tax_year).collect();
+    years.sort_unstable_by(|a, b| b.cmp(a));
+    years.dedup();
+    years
+}
+
+/// Chooses what to export: every saved estimate or those of one tax year
+/// (as [`tax_core::TaxRepository::list_estimates`] filters them), and the
+/// file format.
+///
+/// **Export** hands the choice to the provided callback and dismisses the
+/// dialog. **Cancel** dismisses the dialog without action.
+pub struct ExportDialog {
+    years: Vec<i32>,
+    year_select: Entity<SelectState<Vec<SharedString>>>,
+    format_select: Entity<SelectState<Vec<SharedString>>>,
+    on_export: Rc<dyn Fn(Option<i32>, ExportFormat, &mut Window, &mut App)>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ExportDialog {
+    /// Creates the dialog, offering the tax years of the saved `estimates`.
+    pub fn new(
+        estimates: &[TaxEstimate],
+        on_export: Rc<dyn Fn(Option<i32>, ExportFormat, &mut Window, &mut App)>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let years = tax_years(estimates);
+        let mut year_labels = vec![SharedString::from(ALL_YEARS_LABEL)];
+        year_labels.extend(years.iter().map(|y| SharedString::from(y.to_string())));
+        let format_labels: Vec<SharedString> = ExportFormat::ALL
+            .iter()
+            .map(|f| SharedString::from(f.label()))
+            .collect();
+
+        let year_select = cx
+            .new(|cx| SelectState::new(year_labels, Some(IndexPath::default().row(0)), window, cx));
+        let format_select = cx.new(|cx| {
+            SelectState::new(format_labels, Some(IndexPath::default().row(0)), window, cx)
+        });
+
+        let subscriptions = vec![
+            cx.observe(&year_select, |_this, _select, cx| cx.notify()),
+            cx.observe(&format_select, |_this, _select, cx| cx.notify()),
+        ];
+
+        Self {
+            years,
+            year_select,
+            format_select,
+            on_export,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    /// The chosen tax year; `None` for all years.
+    pub fn selected_year(
+        &self,
+        cx: &App,
+    ) -> Option<i32> {
+        let selected = self.year_select.read(cx).selected_value()?;
+        self.years
+            .iter()
+            .copied()
+            .find(|year| year.to_string() == selected.as_ref())
+    }
+
+    /// The chosen file format, defaulting to CSV.
+    pub fn selected_format(
+        &self,
+        cx: &App,
+    ) -> ExportFormat {
+        self.format_select
+            .read(cx)
+            .selected_value()
+            .and_then(|selected| {
+                ExportFormat::ALL
+                    .into_iter()
+                    .find(|f| f.label() == selected.as_ref())
+            })
+            .unwrap_or(ExportFormat::Csv)
+    }
+}
+
+impl Render for ExportDialog {
+    fn render(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let this = cx.entity().clone();
+        let on_export = self.on_export.clone();
+
+        v_flex()
+            .gap_2()
+            .p_4()
+            .child(make_select_row(
+                "Tax year:",
+                Select::new(&self.year_select)
+                    .w(px(220.))
+                    .render(window, cx),
+            ))
+            .child(make_select_row(
+                "Format:",
+                Select::new(&self.format_select)
+                    .w(px(220.))
+                    .render(window, cx),
+            ))
+            .child(
+                h_flex()
+                    .justify_end()
+                    .gap_2()
+                    .mt_4()
+                    .child(make_button(
+                        "export-estimates",
+                        "Export",
+                        true,
+                        move |_ev: &ClickEvent, window: &mut Window, cx: &mut App| {
+                            let dialog = this.read(cx);
+                            let year = dialog.selected_year(cx);
+                            let format = dialog.selected_format(cx);
+                            window.close_dialog(cx);
+                            on_export(year, format, window, cx);
+                        },
+                    ))
+                    .child(make_button(
+                        "cancel-export",
+                        "Cancel",
+                        true,
+                        |_ev: &ClickEvent, window: &mut Window, cx: &mut App| {
+                            window.close_dialog(cx);
+                        },
+                    )),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+    use tax_core::{DEFAULT_SCENARIO_NAME, FilingStatusCode, TaxEstimateInput};
+
+    use super::*;
+
+    fn estimate(tax_year: i32) -> TaxEstimate {
+        TaxEstimate {
+            id: i64::from(tax_year),
+            input: TaxEstimateInput {
+                tax_year,
+                filing_status: FilingStatusCode::Single,
+                scenario_name: DEFAULT_SCENARIO_NAME.to_string(),
+                se_income: None,
+                expected_crp_payments: None,
+                expected_wages: None,
+                net_investment_income: None,
+                qualified_dividends: None,
+                net_capital_gain: None,
+                expected_agi: dec!(90000.00),
+                expected_deduction: dec!(15750.00),
+                expected_qbi_deduction: None,
+                expected_amt: None,
+                expected_credits: None,
+                expected_other_taxes: None,
+                expected_withholding: None,
+                prior_year_tax: None,
+                prior_year_agi: None,
+            },
+            computed: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+        }
+    }
+
+    #[test]
+    fn tax_years_are_distinct_and_newest_first() {
+        let estimates = [estimate(2025), estimate(2026), estimate(2025)];
+
+        assert_eq!(tax_years(&estimates), vec![2026, 2025]);
+    }
+}
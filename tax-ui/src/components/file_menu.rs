@@ -18,7 +18,11 @@ gpui::actions!(
         SaveProject,
         SaveProjectAs,
         CloseProject,
-        LoadEstimate
+        LoadEstimate,
+        ImportCsv,
+        ExportEstimates,
+        BackupDatabase,
+        RestoreDatabase
     ]
 );
 
@@ -55,12 +59,17 @@ pub fn build_menu_bar() -> impl gpui::IntoElement {
                 menu.menu_with_icon("New Project", IconName::File, Box::new(NewProject))
                     .menu_with_icon("Open Project", IconName::FolderOpen, Box::new(OpenProject))
                     .menu("Load Estimate", Box::new(LoadEstimate))
+                    .menu("Import CSV...", Box::new(ImportCsv))
+                    .menu("Export...", Box::new(ExportEstimates))
                     .separator()
                     .menu("Save", Box::new(SaveProject))
                     .menu("Save As...", Box::new(SaveProjectAs))
                     .separator()
                     .menu("Close Project", Box::new(CloseProject))
                     .separator()
+                    .menu("Back Up Database...", Box::new(BackupDatabase))
+                    .menu("Restore Database...", Box::new(RestoreDatabase))
+                    .separator()
                     .menu("Quit", Box::new(Quit))
             }),
     )
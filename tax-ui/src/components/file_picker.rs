@@ -20,6 +20,26 @@ pub async fn get_file_path(
     Some(file.path().to_path_buf())
 }
 
+/// Opens an async save dialog with the given filters, starting directory and
+/// suggested file name.
+pub async fn get_save_file_path(
+    location: String,
+    file_name: String,
+    filters: Vec<(String, Vec<String>)>,
+) -> Option<PathBuf> {
+    let mut dialog = AsyncFileDialog::new()
+        .set_directory(&location)
+        .set_file_name(file_name);
+
+    for (name, extensions) in &filters {
+        let ext_refs: Vec<&str> = extensions.iter().map(|s| s.as_str()).collect();
+        dialog = dialog.add_filter(name, &ext_refs);
+    }
+
+    let file = dialog.save_file().await?;
+    Some(file.path().to_path_buf())
+}
+
 /// Opens an async file picker dialog to select a directory.
 pub async fn get_folder_path(location: String) -> Option<PathBuf> {
     let dialog = AsyncFileDialog::new().set_directory(&location);
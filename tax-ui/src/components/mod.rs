@@ -1,10 +1,16 @@
+mod annualized_income_form;
+mod csv_import;
 mod dialogs;
+mod est_tax_worksheet_view;
 mod estimate_form;
 mod estimate_selector;
+mod export_dialog;
 mod file_menu;
 mod file_picker;
 mod filters;
+mod penalty_form;
 mod results_form;
+mod revision_history;
 mod se_worksheet_form;
 mod theme;
 mod window;
@@ -23,14 +29,21 @@ use gpui_component::{
     ActiveTheme, Disableable, Icon, IconName, Sizable, StyledExt, h_flex, v_flex,
 };
 
+pub use annualized_income_form::AnnualizedIncomeForm;
+pub use csv_import::CsvImportPreview;
 pub use dialogs::ErrorDialog;
+pub(crate) use est_tax_worksheet_view::make_est_tax_worksheet_view;
 pub use estimate_form::EstimatedIncomeForm;
 pub use estimate_selector::EstimateSelector;
+pub use export_dialog::ExportDialog;
+pub use penalty_form::UnderpaymentPenaltyForm;
 pub use results_form::ResultForm;
+pub use revision_history::RevisionHistory;
+pub(crate) use revision_history::open_revision_history;
 
 pub use file_menu::{
-    CloseProject, LoadEstimate, NewProject, OpenProject, SaveProject, SaveProjectAs,
-    bind_menu_keys, build_menu_bar,
+    BackupDatabase, CloseProject, ExportEstimates, ImportCsv, LoadEstimate, NewProject,
+    OpenProject, RestoreDatabase, SaveProject, SaveProjectAs, bind_menu_keys, build_menu_bar,
 };
 use gpui_component::input::{Input, InputState, MaskPattern};
 use rust_decimal::Decimal;
@@ -141,6 +154,21 @@ pub fn make_integer_input<V: 'static>(
     })
 }
 
+/// Creates a free-text, single-line [`InputState`]. Generic over the owning
+/// view type.
+pub fn make_text_input<V: 'static>(
+    placeholder: impl Into<SharedString>,
+    window: &mut Window,
+    cx: &mut Context<V>,
+) -> Entity<InputState> {
+    cx.new(|closure_cx| {
+        InputState::new(window, closure_cx)
+            .placeholder(placeholder.into())
+            .clean_on_escape()
+            .multi_line(false)
+    })
+}
+
 // ---------------------------------------------------------------------------
 // Flexible row builders (EstimatedIncomeForm — fills available width)
 // ---------------------------------------------------------------------------
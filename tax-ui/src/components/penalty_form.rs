@@ -0,0 +1,419 @@
+use chrono::NaiveDate;
+use gpui::prelude::FluentBuilder;
+use gpui::{
+    App, AppContext, ClickEvent, Context, Div, Entity, IntoElement, ParentElement, Render,
+    RenderOnce, SharedString, Styled, TextAlign, Window, div, px,
+};
+use gpui_component::input::{Input, InputState};
+use gpui_component::select::{Select, SelectState};
+use gpui_component::{IndexPath, h_flex, v_flex};
+use rust_decimal::Decimal;
+use tax_core::calculations::{
+    InstallmentSchedule, Payment, UnderpaymentPenaltyInput, UnderpaymentPenaltyResult,
+    WithholdingTiming,
+};
+
+use crate::app::underpayment_penalty_estimate;
+use crate::components::{
+    ErrorDialog, SE_LABEL_WIDTH, make_button, make_decimal_input, make_display_row,
+    make_header_row, set_input_value, theme,
+};
+use crate::repository::ActiveTaxYear;
+use crate::utils::{parse_decimal, parse_optional_date};
+
+/// Width of the date and amount columns.
+const COLUMN_WIDTH: f32 = 130.0;
+
+/// Number of payment and withholding rows offered in the dialog.
+const ROW_COUNT: usize = 4;
+
+const EVENLY_LABEL: &str = "Evenly on due dates";
+const ACTUAL_DATES_LABEL: &str = "Actual dates withheld";
+
+/// Date and amount inputs for one payment or withholding entry.
+struct DatedAmountInputs {
+    date: Entity<InputState>,
+    amount: Entity<InputState>,
+}
+
+/// Form 2210 penalty inputs: the estimated payments actually made and when
+/// withholding is treated as paid, checked against the required installments
+/// from the last calculation.
+pub struct UnderpaymentPenaltyForm {
+    payments: [DatedAmountInputs; ROW_COUNT],
+    withholding_timing: Entity<SelectState<Vec<SharedString>>>,
+    /// Used only when withholding is credited on the actual dates.
+    withholding: [DatedAmountInputs; ROW_COUNT],
+
+    /// Required installments from the last calculation, before withholding.
+    required: Option<InstallmentSchedule>,
+    /// Expected withholding from the last calculation, spread evenly by default.
+    expected_withholding: Decimal,
+
+    result: Option<UnderpaymentPenaltyResult>,
+}
+
+impl UnderpaymentPenaltyForm {
+    pub fn new(
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let timings = vec![
+            SharedString::from(EVENLY_LABEL),
+            SharedString::from(ACTUAL_DATES_LABEL),
+        ];
+        let withholding_timing =
+            cx.new(|cx| SelectState::new(timings, Some(IndexPath::default().row(0)), window, cx));
+        // Show or hide the withholding rows when the timing changes.
+        cx.observe(&withholding_timing, |_this, _timing, cx| cx.notify())
+            .detach();
+
+        Self {
+            payments: make_dated_amount_inputs("Payment", window, cx),
+            withholding_timing,
+            withholding: make_dated_amount_inputs("Withheld", window, cx),
+            required: None,
+            expected_withholding: Decimal::ZERO,
+            result: None,
+        }
+    }
+
+    /// Stores the required installments and expected withholding from the
+    /// last estimate calculation. Any earlier penalty result is discarded.
+    pub fn set_required(
+        &mut self,
+        required: Option<InstallmentSchedule>,
+        expected_withholding: Decimal,
+    ) {
+        self.required = required;
+        self.expected_withholding = expected_withholding;
+        self.result = None;
+    }
+
+    fn uses_actual_withholding_dates(
+        &self,
+        cx: &App,
+    ) -> bool {
+        self.withholding_timing
+            .read(cx)
+            .selected_value()
+            .is_some_and(|label| label.as_ref() == ACTUAL_DATES_LABEL)
+    }
+
+    /// Collects the penalty inputs. Rows left blank are skipped.
+    fn to_input(
+        &self,
+        cx: &App,
+    ) -> Result<UnderpaymentPenaltyInput, Vec<String>> {
+        let Some(required) = &self.required else {
+            return Err(vec![
+                "Calculate the estimate first to get the required installments".to_string(),
+            ]);
+        };
+
+        let mut errors = Vec::new();
+        let payments = dated_amounts("Payment", &self.payments, cx, &mut errors);
+        let withholding = if self.uses_actual_withholding_dates(cx) {
+            WithholdingTiming::ActualDates(dated_amounts(
+                "Withholding",
+                &self.withholding,
+                cx,
+                &mut errors,
+            ))
+        } else {
+            WithholdingTiming::Evenly(self.expected_withholding)
+        };
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        Ok(UnderpaymentPenaltyInput {
+            required: required.clone(),
+            payments,
+            withholding,
+        })
+    }
+
+    fn calculate(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let input = match self.to_input(cx) {
+            Ok(input) => input,
+            Err(errors) => {
+                ErrorDialog::show("Validation failed", &errors, window, cx);
+                return;
+            }
+        };
+        let interest_rates = ActiveTaxYear::get(cx)
+            .tax_year_data
+            .as_ref()
+            .map(|data| data.interest_rates.clone())
+            .unwrap_or_default();
+
+        match underpayment_penalty_estimate(&interest_rates, &input) {
+            Ok(result) => self.result = Some(result),
+            Err(error) => {
+                tracing::warn!(error = ?error, "Underpayment penalty calculation failed");
+                ErrorDialog::show("Calculation failed", &[format!("{error:#}")], window, cx);
+            }
+        }
+        cx.notify();
+    }
+
+    fn clear(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        for row in self.payments.iter().chain(&self.withholding) {
+            set_input_value(&row.date, "", window, cx);
+            set_input_value(&row.amount, "", window, cx);
+        }
+        self.result = None;
+        cx.notify();
+    }
+
+    /// Rows for the penalty section: the calculated periods, or the required
+    /// installments with empty values before the penalty is calculated.
+    fn penalty_rows(&self) -> Vec<Div> {
+        if let Some(result) = &self.result {
+            return result
+                .periods
+                .iter()
+                .map(|period| {
+                    penalty_row(
+                        installment_label(period.number, period.due_date),
+                        Some(period.underpayment),
+                        Some(period.penalty),
+                    )
+                })
+                .collect();
+        }
+
+        self.required
+            .iter()
+            .flat_map(|schedule| &schedule.installments)
+            .map(|installment| {
+                penalty_row(
+                    installment_label(installment.number, installment.due_date),
+                    None,
+                    None,
+                )
+            })
+            .collect()
+    }
+}
+
+impl Render for UnderpaymentPenaltyForm {
+    fn render(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let this = cx.entity().clone();
+        let actual_withholding = self.uses_actual_withholding_dates(cx);
+        let uses_projected_rate = self
+            .result
+            .as_ref()
+            .is_some_and(|result| result.uses_projected_rate);
+
+        v_flex()
+            .gap_2()
+            .p_4()
+            .child(make_header_row("Estimated tax payments made:"))
+            .child(column_header_row("Date (YYYY-MM-DD)", "Amount"))
+            .children(
+                self.payments
+                    .iter()
+                    .zip(1..)
+                    .map(|(row, number)| dated_amount_row(format!("Payment {number}: $"), row)),
+            )
+            .child(
+                h_flex()
+                    .items_center()
+                    .gap_2()
+                    .child(column_label("Withholding:"))
+                    .child(
+                        Select::new(&self.withholding_timing)
+                            .w(px(COLUMN_WIDTH * 2.0))
+                            .render(window, cx),
+                    ),
+            )
+            .when(actual_withholding, |this| {
+                this.children(
+                    self.withholding.iter().zip(1..).map(|(row, number)| {
+                        dated_amount_row(format!("Withheld {number}: $"), row)
+                    }),
+                )
+            })
+            .child(make_header_row("Underpayment penalty:"))
+            .child(column_header_row("Underpaid", "Penalty"))
+            .children(self.penalty_rows())
+            .child(make_display_row(
+                "Total penalty:",
+                self.result.as_ref().map(|r| r.total_penalty()),
+            ))
+            .when(uses_projected_rate, |this| {
+                this.child(
+                    div()
+                        .text_color(theme::HEADER_ACCENT)
+                        .child("Includes quarters figured at the latest announced rate."),
+                )
+            })
+            .child(
+                h_flex()
+                    .gap_2()
+                    .justify_end()
+                    .mt_4()
+                    .child(make_button("penalty_calculate", "Calculate", true, {
+                        let this = this.clone();
+                        move |_ev: &ClickEvent, window: &mut Window, app_cx: &mut App| {
+                            this.update(app_cx, |form, cx| {
+                                form.calculate(window, cx);
+                            });
+                        }
+                    }))
+                    .child(make_button(
+                        "penalty_clear",
+                        "Clear",
+                        true,
+                        move |_ev: &ClickEvent, window: &mut Window, app_cx: &mut App| {
+                            this.update(app_cx, |form, cx| {
+                                form.clear(window, cx);
+                            });
+                        },
+                    )),
+            )
+    }
+}
+
+/// Row label for an installment, e.g. "Payment 2 due Jun 16, 2025".
+fn installment_label(
+    number: u8,
+    due_date: NaiveDate,
+) -> String {
+    format!("Payment {number} due {}", due_date.format("%b %-d, %Y"))
+}
+
+/// Reads the non-blank rows as dated amounts, recording a message in
+/// `errors` for each row that cannot be parsed.
+fn dated_amounts(
+    label: &str,
+    rows: &[DatedAmountInputs],
+    cx: &App,
+    errors: &mut Vec<String>,
+) -> Vec<Payment> {
+    let mut amounts = Vec::new();
+    for (row, number) in rows.iter().zip(1..) {
+        let date_text = row.date.read(cx).value();
+        let amount_text = row.amount.read(cx).value();
+        if date_text.trim().is_empty() && amount_text.trim().is_empty() {
+            continue;
+        }
+
+        let date = match parse_optional_date(date_text.as_str()) {
+            Ok(Some(date)) => Some(date),
+            Ok(None) => {
+                errors.push(format!("{label} {number}: date is required"));
+                None
+            }
+            Err(e) => {
+                errors.push(format!("{label} {number}: {e}"));
+                None
+            }
+        };
+        let amount = match parse_decimal(amount_text.as_str()) {
+            Ok(amount) => Some(amount),
+            Err(e) => {
+                errors.push(format!("{label} {number}: {e}"));
+                None
+            }
+        };
+
+        if let (Some(date), Some(amount)) = (date, amount) {
+            amounts.push(Payment { date, amount });
+        }
+    }
+    amounts
+}
+
+fn make_dated_amount_inputs(
+    placeholder: &'static str,
+    window: &mut Window,
+    cx: &mut Context<UnderpaymentPenaltyForm>,
+) -> [DatedAmountInputs; ROW_COUNT] {
+    std::array::from_fn(|_| DatedAmountInputs {
+        date: cx.new(|closure_cx| {
+            InputState::new(window, closure_cx)
+                .placeholder("YYYY-MM-DD")
+                .clean_on_escape()
+                .multi_line(false)
+        }),
+        amount: make_decimal_input(placeholder, 2, window, cx),
+    })
+}
+
+fn column_label(label: impl Into<SharedString>) -> Div {
+    div()
+        .w(px(SE_LABEL_WIDTH))
+        .text_align(TextAlign::Right)
+        .child(label.into())
+}
+
+fn column_header_row(
+    first: &'static str,
+    second: &'static str,
+) -> Div {
+    h_flex()
+        .items_center()
+        .gap_2()
+        .child(column_label(""))
+        .children([first, second].map(|header| {
+            div()
+                .w(px(COLUMN_WIDTH))
+                .text_align(TextAlign::Right)
+                .child(header)
+        }))
+}
+
+fn dated_amount_row(
+    label: String,
+    row: &DatedAmountInputs,
+) -> Div {
+    h_flex()
+        .items_center()
+        .gap_2()
+        .child(column_label(label))
+        .child(Input::new(&row.date).w(px(COLUMN_WIDTH)))
+        .child(Input::new(&row.amount).w(px(COLUMN_WIDTH)))
+}
+
+fn penalty_row(
+    label: String,
+    underpayment: Option<Decimal>,
+    penalty: Option<Decimal>,
+) -> Div {
+    h_flex()
+        .items_center()
+        .gap_2()
+        .child(column_label(label))
+        .children([underpayment, penalty].map(|value| {
+            div()
+                .w(px(COLUMN_WIDTH))
+                .px_2()
+                .py_1()
+                .rounded_md()
+                .border_1()
+                .border_color(theme::DISPLAY_FIELD_BORDER)
+                .bg(theme::DISPLAY_FIELD_BG)
+                .text_color(theme::DISPLAY_FIELD_TEXT)
+                .text_align(TextAlign::Right)
+                .child(
+                    value
+                        .map(|d| format!("{d:.2}"))
+                        .unwrap_or_else(|| "—".to_string()),
+                )
+        }))
+}
@@ -7,24 +7,49 @@
 //     pub required_payment: Decimal,
 // }
 
+use gpui::prelude::FluentBuilder;
 use gpui::{Context, IntoElement, ParentElement, Render, Styled, Window};
 use gpui_component::v_flex;
 use rust_decimal::Decimal;
 use tax_core::TaxEstimateComputed;
-use tax_core::calculations::EstimatedTaxWorksheetResult;
+use tax_core::calculations::{
+    EstimatedTaxWorksheetResult, EstimatedTaxWorksheetTrace, Installment, InstallmentSchedule,
+};
 
 use crate::components::{make_display_row, make_header_row};
+use crate::utils::percent;
 
 const SE_LABEL: &str = "Self-Employment Tax";
+const ADDL_MEDICARE_LABEL: &str = "Additional Medicare Tax";
+const NIIT_LABEL: &str = "Net Investment Income Tax";
+const QBI_LABEL: &str = "QBI Deduction";
 const TOTAL_TAX_LABEL: &str = "Total Tax Due";
+const PRIOR_YEAR_LABEL: &str = "Prior-year safe harbor";
 const PAYMENTS_LABEL: &str = "Required annual payment";
+const INSTALLMENTS_HEADER: &str = "Quarterly Installments";
+
+/// Row label for an installment, e.g. "Payment 2 due Jun 16, 2025".
+fn installment_label(installment: &Installment) -> String {
+    format!(
+        "Payment {} due {}",
+        installment.number,
+        installment.due_date.format("%b %-d, %Y")
+    )
+}
 
 /// Read-only summary of the last successful estimated-tax calculation.
 #[derive(Clone, Debug, Default)]
 pub struct ResultForm {
     calculated_se_tax: Option<Decimal>,
+    calculated_addl_medicare_tax: Option<Decimal>,
+    calculated_niit: Option<Decimal>,
+    calculated_qbi_deduction: Option<Decimal>,
     calculated_total_tax: Option<Decimal>,
+    calculated_prior_year_multiplier: Option<Decimal>,
+    calculated_prior_year_safe_harbor: Option<Decimal>,
     calculated_payment: Option<Decimal>,
+    installments: Option<InstallmentSchedule>,
+    worksheet_trace: Option<EstimatedTaxWorksheetTrace>,
 }
 
 impl ResultForm {
@@ -37,28 +62,71 @@ impl ResultForm {
     pub fn set_from_calculation(
         &mut self,
         se_tax: Decimal,
-        result: &EstimatedTaxWorksheetResult,
+        addl_medicare_tax: Decimal,
+        niit: Decimal,
+        qbi_deduction: Decimal,
+        trace: &EstimatedTaxWorksheetTrace,
+        installments: InstallmentSchedule,
     ) {
+        let result = EstimatedTaxWorksheetResult::from(trace);
         self.calculated_se_tax = Some(se_tax);
+        self.calculated_addl_medicare_tax = Some(addl_medicare_tax);
+        self.calculated_niit = Some(niit);
+        self.calculated_qbi_deduction = Some(qbi_deduction);
         self.calculated_total_tax = Some(result.total_estimated_tax);
+        self.calculated_prior_year_multiplier = Some(result.prior_year_tax_multiplier);
+        self.calculated_prior_year_safe_harbor = Some(result.prior_year_safe_harbor);
         self.calculated_payment = Some(result.required_annual_payment);
+        self.installments = Some(installments);
+        self.worksheet_trace = Some(trace.clone());
+    }
+
+    /// Line-by-line Estimated Tax Worksheet from the last calculation.
+    pub fn worksheet_trace(&self) -> Option<&EstimatedTaxWorksheetTrace> {
+        self.worksheet_trace.as_ref()
+    }
+
+    /// Label for the line 12b row, naming the multiplier that was applied
+    /// (e.g. "Prior-year safe harbor (110.00%)") when one is known.
+    fn prior_year_label(&self) -> String {
+        match self.calculated_prior_year_multiplier {
+            Some(multiplier) => format!("{PRIOR_YEAR_LABEL} ({})", percent(&multiplier)),
+            None => PRIOR_YEAR_LABEL.to_string(),
+        }
     }
 
     /// Fills display fields from a previously persisted [`TaxEstimateComputed`].
+    /// Additional Medicare Tax, NIIT, the QBI deduction, the prior-year safe
+    /// harbor, the installment schedule and the worksheet trace are not
+    /// persisted, so they are left empty.
     pub fn set_from_computed(
         &mut self,
         computed: &TaxEstimateComputed,
     ) {
         self.calculated_se_tax = Some(computed.se_tax);
+        self.calculated_addl_medicare_tax = None;
+        self.calculated_niit = None;
+        self.calculated_qbi_deduction = None;
         self.calculated_total_tax = Some(computed.total_tax);
+        self.calculated_prior_year_multiplier = None;
+        self.calculated_prior_year_safe_harbor = None;
         self.calculated_payment = Some(computed.required_payment);
+        self.installments = None;
+        self.worksheet_trace = None;
     }
 
     /// Resets the form so no results are displayed.
     pub fn clear(&mut self) {
         self.calculated_se_tax = None;
+        self.calculated_addl_medicare_tax = None;
+        self.calculated_niit = None;
+        self.calculated_qbi_deduction = None;
         self.calculated_total_tax = None;
+        self.calculated_prior_year_multiplier = None;
+        self.calculated_prior_year_safe_harbor = None;
         self.calculated_payment = None;
+        self.installments = None;
+        self.worksheet_trace = None;
     }
 }
 
@@ -73,8 +141,25 @@ impl Render for ResultForm {
             .gap_4()
             .child(make_header_row("Calculated Results"))
             .child(make_display_row(SE_LABEL, self.calculated_se_tax))
+            .child(make_display_row(
+                ADDL_MEDICARE_LABEL,
+                self.calculated_addl_medicare_tax,
+            ))
+            .child(make_display_row(NIIT_LABEL, self.calculated_niit))
+            .child(make_display_row(QBI_LABEL, self.calculated_qbi_deduction))
             .child(make_display_row(TOTAL_TAX_LABEL, self.calculated_total_tax))
+            .child(make_display_row(
+                self.prior_year_label(),
+                self.calculated_prior_year_safe_harbor,
+            ))
             .child(make_display_row(PAYMENTS_LABEL, self.calculated_payment))
+            .when_some(self.installments.as_ref(), |this, schedule| {
+                this.child(make_header_row(INSTALLMENTS_HEADER)).children(
+                    schedule.installments.iter().map(|installment| {
+                        make_display_row(installment_label(installment), Some(installment.amount))
+                    }),
+                )
+            })
     }
 }
 
@@ -105,14 +190,46 @@ mod tests {
     fn clear_resets_all_fields() {
         let mut form = ResultForm {
             calculated_se_tax: Some(dec!(1.00)),
+            calculated_addl_medicare_tax: Some(dec!(4.00)),
+            calculated_niit: Some(dec!(5.00)),
+            calculated_qbi_deduction: Some(dec!(7.00)),
             calculated_total_tax: Some(dec!(2.00)),
+            calculated_prior_year_multiplier: Some(dec!(1.10)),
+            calculated_prior_year_safe_harbor: Some(dec!(6.00)),
             calculated_payment: Some(dec!(3.00)),
+            installments: Some(InstallmentSchedule::calculate(2025, dec!(3.00)).unwrap()),
+            worksheet_trace: None,
         };
         form.clear();
 
         assert_eq!(form.calculated_se_tax, None);
+        assert_eq!(form.calculated_addl_medicare_tax, None);
+        assert_eq!(form.calculated_niit, None);
+        assert_eq!(form.calculated_qbi_deduction, None);
         assert_eq!(form.calculated_total_tax, None);
+        assert_eq!(form.calculated_prior_year_multiplier, None);
+        assert_eq!(form.calculated_prior_year_safe_harbor, None);
         assert_eq!(form.calculated_payment, None);
+        assert_eq!(form.installments, None);
         assert_eq!(form.has_results(), false);
     }
+
+    #[test]
+    fn prior_year_label_names_multiplier() {
+        let mut form = ResultForm::default();
+        assert_eq!(form.prior_year_label(), "Prior-year safe harbor");
+
+        form.calculated_prior_year_multiplier = Some(dec!(1.10));
+        assert_eq!(form.prior_year_label(), "Prior-year safe harbor (110.00%)");
+    }
+
+    #[test]
+    fn installment_label_formats_due_date() {
+        let schedule = InstallmentSchedule::calculate(2025, dec!(4000.00)).unwrap();
+
+        assert_eq!(
+            installment_label(&schedule.installments[1]),
+            "Payment 2 due Jun 16, 2025"
+        );
+    }
 }
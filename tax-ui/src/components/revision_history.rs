@@ -0,0 +1,335 @@
+use std::rc::Rc;
+
+use gpui::{
+    App, AppContext, ClickEvent, Context, Entity, IntoElement, ParentElement, Render, RenderOnce,
+    SharedString, Styled, Subscription, Window, div, px,
+};
+use gpui_component::{
+    IndexPath, WindowExt, h_flex,
+    select::{Select, SelectState},
+    v_flex,
+};
+use tax_core::{TaxEstimate, TaxEstimateFieldChange, TaxEstimateRevision};
+
+use crate::components::{
+    make_button, make_header_row, make_labeled_row, make_select_row, show_err,
+};
+use crate::repository::TaxRepo;
+
+/// Dropdown label for a revision, e.g. "Revision 2 — 2025-09-15 10:30".
+fn revision_label(revision: &TaxEstimateRevision) -> String {
+    format!(
+        "Revision {} — {}",
+        revision.revision,
+        revision.recorded_at.format("%Y-%m-%d %H:%M")
+    )
+}
+
+/// Diff cell text for a changed field, e.g. "90000.00 → 120000.00".
+fn change_text(change: &TaxEstimateFieldChange) -> String {
+    format!("{} → {}", change.before, change.after)
+}
+
+/// Browses the saved revisions of one [`TaxEstimate`], showing how a chosen
+/// revision differs from another revision or from the estimate as currently
+/// saved.
+///
+/// **Restore** writes the chosen revision back as the estimate's current
+/// values (recorded as a new revision), hands the restored estimate to the
+/// provided callback, and dismisses the dialog. **Cancel** dismisses the
+/// dialog without action.
+pub struct RevisionHistory {
+    current: TaxEstimate,
+    revisions: Vec<TaxEstimateRevision>,
+    revision_labels: Vec<SharedString>,
+    compare_labels: Vec<SharedString>,
+    revision_select: Entity<SelectState<Vec<SharedString>>>,
+    compare_select: Entity<SelectState<Vec<SharedString>>>,
+    on_restore: Rc<dyn Fn(&TaxEstimate, &mut Window, &mut App)>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl RevisionHistory {
+    /// Creates a history view over `revisions` (oldest first) of `current`.
+    ///
+    /// The newest revision before the current one is selected initially and
+    /// compared against the current estimate.
+    pub fn new(
+        current: TaxEstimate,
+        revisions: Vec<TaxEstimateRevision>,
+        on_restore: Rc<dyn Fn(&TaxEstimate, &mut Window, &mut App)>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let revision_labels: Vec<SharedString> = revisions
+            .iter()
+            .map(|r| SharedString::from(revision_label(r)))
+            .collect();
+        let mut compare_labels = vec![SharedString::from("Current")];
+        compare_labels.extend(revision_labels.iter().cloned());
+
+        let initial = revisions.len().saturating_sub(2);
+        let revision_select = cx.new(|cx| {
+            SelectState::new(
+                revision_labels.clone(),
+                Some(IndexPath::default().row(initial)),
+                window,
+                cx,
+            )
+        });
+        let compare_select = cx.new(|cx| {
+            SelectState::new(
+                compare_labels.clone(),
+                Some(IndexPath::default().row(0)),
+                window,
+                cx,
+            )
+        });
+
+        let subscriptions = vec![
+            cx.observe(&revision_select, |_this, _select, cx| cx.notify()),
+            cx.observe(&compare_select, |_this, _select, cx| cx.notify()),
+        ];
+
+        Self {
+            current,
+            revisions,
+            revision_labels,
+            compare_labels,
+            revision_select,
+            compare_select,
+            on_restore,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    /// Returns the revision chosen in the first dropdown, if any.
+    pub fn selected_revision(
+        &self,
+        cx: &App,
+    ) -> Option<&TaxEstimateRevision> {
+        let selected = self.revision_select.read(cx).selected_value()?;
+        let idx = self
+            .revision_labels
+            .iter()
+            .position(|label| label.as_ref() == selected.as_ref())?;
+        self.revisions.get(idx)
+    }
+
+    /// Fields that differ between the selected revision and the comparison
+    /// target, which is either another revision or the current estimate.
+    fn changes(
+        &self,
+        cx: &App,
+    ) -> Vec<TaxEstimateFieldChange> {
+        let Some(revision) = self.selected_revision(cx) else {
+            return Vec::new();
+        };
+        let compare_idx = self
+            .compare_select
+            .read(cx)
+            .selected_value()
+            .and_then(|selected| {
+                self.compare_labels
+                    .iter()
+                    .position(|label| label.as_ref() == selected.as_ref())
+            })
+            .unwrap_or(0);
+        match compare_idx {
+            0 => revision.changes_to(&self.current),
+            idx => self
+                .revisions
+                .get(idx - 1)
+                .map(|other| other.changes_from(revision))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl Render for RevisionHistory {
+    fn render(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let this = cx.entity().clone();
+        let on_restore = self.on_restore.clone();
+        let can_restore = self.selected_revision(cx).is_some();
+        let changes = self.changes(cx);
+
+        let diff = if changes.is_empty() {
+            v_flex().child(make_header_row("No differences"))
+        } else {
+            v_flex().gap_1().children(changes.iter().map(|change| {
+                make_labeled_row(format!("{}:", change.field))
+                    .child(div().px_2().child(change_text(change)))
+            }))
+        };
+
+        v_flex()
+            .gap_2()
+            .p_4()
+            .child(make_select_row(
+                "Revision:",
+                Select::new(&self.revision_select)
+                    .w(px(260.))
+                    .render(window, cx),
+            ))
+            .child(make_select_row(
+                "Compare with:",
+                Select::new(&self.compare_select)
+                    .w(px(260.))
+                    .render(window, cx),
+            ))
+            .child(diff)
+            .child(
+                h_flex()
+                    .justify_end()
+                    .gap_2()
+                    .mt_4()
+                    .child(make_button(
+                        "restore-revision",
+                        "Restore",
+                        can_restore,
+                        move |_ev: &ClickEvent, window: &mut Window, cx: &mut App| {
+                            let Some(revision_id) =
+                                this.read(cx).selected_revision(cx).map(|r| r.id)
+                            else {
+                                return;
+                            };
+                            let Some(repo) = TaxRepo::try_get(cx) else {
+                                tracing::warn!("TaxRepo not initialised; cannot restore revision");
+                                return;
+                            };
+                            let window_handle = window.window_handle();
+                            let on_restore = on_restore.clone();
+                            cx.spawn(async move |async_cx| {
+                                match repo.restore_estimate_revision(revision_id).await {
+                                    Ok(estimate) => {
+                                        tracing::info!("Restored revision: {}", estimate);
+                                        let _ = window_handle.update(async_cx, |_, window, cx| {
+                                            on_restore(&estimate, window, cx);
+                                            window.close_dialog(cx);
+                                        });
+                                    }
+                                    Err(e) => {
+                                        tracing::error!(error = %e, "Failed to restore revision");
+                                        show_err(window_handle, async_cx, e.into());
+                                    }
+                                }
+                            })
+                            .detach();
+                        },
+                    ))
+                    .child(make_button(
+                        "cancel-revision",
+                        "Cancel",
+                        true,
+                        |_ev: &ClickEvent, window: &mut Window, cx: &mut App| {
+                            window.close_dialog(cx);
+                        },
+                    )),
+            )
+    }
+}
+
+/// Fetches the revisions of `estimate` and opens them in a
+/// [`RevisionHistory`] dialog. `on_restore` receives the estimate after a
+/// revision has been restored.
+pub(crate) fn open_revision_history(
+    estimate: TaxEstimate,
+    on_restore: Rc<dyn Fn(&TaxEstimate, &mut Window, &mut App)>,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    let Some(repo) = TaxRepo::try_get(cx) else {
+        tracing::warn!("TaxRepo not initialised; cannot load estimate history");
+        return;
+    };
+    let window_handle = window.window_handle();
+
+    cx.spawn(
+        async move |async_cx| match repo.list_estimate_revisions(estimate.id).await {
+            Ok(revisions) if revisions.is_empty() => {
+                tracing::info!("No revisions found for estimate {}", estimate.id);
+            }
+            Ok(revisions) => {
+                tracing::info!(
+                    "Found {} revision(s) for estimate {}",
+                    revisions.len(),
+                    estimate.id
+                );
+                let _ = window_handle.update(async_cx, move |_, window, cx| {
+                    let history = cx.new(|history_cx| {
+                        RevisionHistory::new(estimate, revisions, on_restore, window, history_cx)
+                    });
+                    window.open_dialog(cx, move |dialog, _w, _cx| {
+                        dialog
+                            .title("Estimate History")
+                            .w(px(560.0))
+                            .child(history.clone())
+                    });
+                });
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to load estimate history");
+                show_err(window_handle, async_cx, e.into());
+            }
+        },
+    )
+    .detach();
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+    use tax_core::{DEFAULT_SCENARIO_NAME, FilingStatusCode, TaxEstimateInput};
+
+    use super::*;
+
+    #[test]
+    fn revision_label_shows_number_and_timestamp() {
+        let revision = TaxEstimateRevision {
+            id: 7,
+            estimate_id: 1,
+            revision: 2,
+            input: TaxEstimateInput {
+                tax_year: 2025,
+                filing_status: FilingStatusCode::Single,
+                scenario_name: DEFAULT_SCENARIO_NAME.to_string(),
+                se_income: None,
+                expected_crp_payments: None,
+                expected_wages: None,
+                net_investment_income: None,
+                qualified_dividends: None,
+                net_capital_gain: None,
+                expected_agi: dec!(90000.00),
+                expected_deduction: dec!(15750.00),
+                expected_qbi_deduction: None,
+                expected_amt: None,
+                expected_credits: None,
+                expected_other_taxes: None,
+                expected_withholding: None,
+                prior_year_tax: None,
+                prior_year_agi: None,
+            },
+            computed: None,
+            recorded_at: Utc.with_ymd_and_hms(2025, 9, 15, 10, 30, 0).unwrap(),
+        };
+
+        assert_eq!(revision_label(&revision), "Revision 2 — 2025-09-15 10:30");
+    }
+
+    #[test]
+    fn change_text_shows_before_and_after() {
+        let change = TaxEstimateFieldChange {
+            field: "Expected AGI",
+            before: "90000.00".to_string(),
+            after: "120000.00".to_string(),
+        };
+
+        assert_eq!(change_text(&change), "90000.00 → 120000.00");
+    }
+}
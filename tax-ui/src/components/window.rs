@@ -3,23 +3,35 @@
 use std::rc::Rc;
 
 use gpui::{
-    App, AppContext, Context, Entity, InteractiveElement as _, IntoElement, ParentElement, Render,
-    Styled, Subscription, Window, div, px,
+    AnyWindowHandle, App, AppContext, AsyncApp, Context, Entity, InteractiveElement as _,
+    IntoElement, ParentElement, PromptLevel, Render, Styled, Subscription, WeakEntity, Window, div,
+    px,
 };
 use gpui_component::{Root, StyledExt, WindowExt, v_flex};
-use tax_core::TaxEstimate;
+use tax_core::{TaxEstimate, TaxEstimateInput};
 use tracing::info;
 
 #[cfg(not(target_os = "linux"))]
 use crate::Quit;
+use crate::app::import_estimates;
 #[cfg(not(target_os = "macos"))]
 use crate::components::build_menu_bar;
+use crate::components::file_picker::{get_file_path, get_save_file_path};
 use crate::components::{
-    EstimateSelector, EstimatedIncomeForm, LoadEstimate, SeWorksheetForm, show_err,
+    BackupDatabase, CloseProject, CsvImportPreview, EstimateSelector, EstimatedIncomeForm,
+    ExportDialog, ExportEstimates, ImportCsv, LoadEstimate, NewProject, OpenProject,
+    RestoreDatabase, SaveProject, SaveProjectAs, SeWorksheetForm, open_revision_history, show_err,
 };
+use crate::config::AppConfig;
+use crate::csv_loader::{preview_from_file, reject_trashed_scenarios};
+use crate::exporter::{ExportFormat, default_export_file_name, export_to_file};
 #[cfg(not(target_os = "linux"))]
 use crate::quit;
-use crate::repository::TaxRepo;
+use crate::repository::{
+    ActiveTaxYear, PROJECT_FILE_EXTENSION, TaxRepo, backup_database, backup_directory,
+    close_project, default_backup_file_name, open_project, project_file_filters, project_name,
+    remove_project_file, restore_database, with_project_extension,
+};
 
 pub struct AppWindow {
     _window_close_subscription: Subscription,
@@ -84,10 +96,21 @@ impl AppWindow {
                                         form.populate_from_estimate(&estimate, window, form_cx);
                                     });
                                 });
+                            let on_restore = on_select.clone();
+                            let on_history: Rc<dyn Fn(&TaxEstimate, &mut Window, &mut App)> =
+                                Rc::new(move |estimate, window, cx| {
+                                    open_revision_history(
+                                        estimate.clone(),
+                                        on_restore.clone(),
+                                        window,
+                                        cx,
+                                    );
+                                });
                             let selector = view_cx.new(|sel_cx| {
                                 EstimateSelector::new(
                                     estimates_opt.take().unwrap(),
                                     on_select,
+                                    on_history,
                                     window,
                                     sel_cx,
                                 )
@@ -110,6 +133,442 @@ impl AppWindow {
         .detach();
     }
 
+    /// Asks for a CSV file of estimates and previews its rows. The valid ones
+    /// are saved when the preview is confirmed; a row that would save over an
+    /// estimate in the trash is shown as an error.
+    fn handle_import_csv(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(repo) = TaxRepo::try_get(cx) else {
+            tracing::warn!("TaxRepo not initialised; cannot import estimates");
+            return;
+        };
+        let config = AppConfig::get(cx).clone();
+        let window_handle = window.window_handle();
+        let location = backup_directory(&config.database_url)
+            .to_string_lossy()
+            .into_owned();
+
+        cx.spawn(async move |this, async_cx| {
+            let filters = vec![("CSV".to_string(), vec!["csv".to_string()])];
+            let Some(path) = get_file_path(location, filters).await else {
+                return;
+            };
+            tracing::info!("Previewing CSV import from {}", path.display());
+            let mut rows = match preview_from_file(&path) {
+                Ok(rows) => rows,
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to read CSV file");
+                    let e =
+                        anyhow::Error::new(e).context(format!("Failed to read {}", path.display()));
+                    show_err(window_handle, async_cx, e);
+                    return;
+                }
+            };
+            match repo.list_deleted_estimates().await {
+                Ok(trashed) => reject_trashed_scenarios(&mut rows, &trashed),
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to list deleted estimates");
+                    let e = anyhow::Error::new(e).context("Failed to check the trash");
+                    show_err(window_handle, async_cx, e);
+                    return;
+                }
+            }
+
+            let _ = window_handle.update(async_cx, |_, window, cx| {
+                let on_import: Rc<dyn Fn(Vec<TaxEstimateInput>, &mut Window, &mut App)> =
+                    Rc::new(move |inputs, _window, cx| {
+                        let repo = repo.clone();
+                        let this = this.clone();
+                        let path = path.clone();
+                        cx.spawn(async move |async_cx| {
+                            match import_estimates(inputs, repo.tax_repository_arc()).await {
+                                Ok(count) => {
+                                    tracing::info!("Imported {count} estimate(s)");
+                                    let _ = this.update(async_cx, |app_window, cx| {
+                                        app_window.status_message = Some(format!(
+                                            "Imported {count} estimate(s) from {}",
+                                            path.display()
+                                        ));
+                                        cx.notify();
+                                    });
+                                }
+                                Err(e) => {
+                                    tracing::error!(error = %e, "Failed to import estimates");
+                                    show_err(window_handle, async_cx, e);
+                                }
+                            }
+                        })
+                        .detach();
+                    });
+                let preview = cx.new(|_| CsvImportPreview::new(rows, on_import));
+                window.open_dialog(cx, move |dialog, _w, _cx| {
+                    dialog
+                        .title("Import Estimates from CSV")
+                        .w(px(600.0))
+                        .child(preview.clone())
+                });
+            });
+        })
+        .detach();
+    }
+
+    /// Asks which tax year and format to export, then where to write the
+    /// file, and writes the matching saved estimates to it.
+    fn handle_export_estimates(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(repo) = TaxRepo::try_get(cx) else {
+            tracing::warn!("TaxRepo not initialised; cannot export estimates");
+            return;
+        };
+        let config = AppConfig::get(cx).clone();
+        let window_handle = window.window_handle();
+
+        cx.spawn(async move |this, async_cx| {
+            let estimates = match repo.list_estimates(None).await {
+                Ok(estimates) if estimates.is_empty() => {
+                    tracing::info!("No saved estimates to export");
+                    let _ = this.update(async_cx, |app_window, cx| {
+                        app_window.status_message = Some("No saved estimates to export".into());
+                        cx.notify();
+                    });
+                    return;
+                }
+                Ok(estimates) => estimates,
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to load estimates");
+                    show_err(window_handle, async_cx, e.into());
+                    return;
+                }
+            };
+
+            let _ = window_handle.update(async_cx, |_, window, cx| {
+                let on_export: Rc<dyn Fn(Option<i32>, ExportFormat, &mut Window, &mut App)> =
+                    Rc::new(move |tax_year, format, _window, cx| {
+                        let repo = repo.clone();
+                        let this = this.clone();
+                        let location = backup_directory(&config.database_url)
+                            .to_string_lossy()
+                            .into_owned();
+                        cx.spawn(async move |async_cx| {
+                            let filters = vec![(
+                                format.label().to_string(),
+                                vec![format.extension().to_string()],
+                            )];
+                            let file_name = default_export_file_name(tax_year, format);
+                            let Some(path) = get_save_file_path(location, file_name, filters).await
+                            else {
+                                return;
+                            };
+                            let result = async {
+                                let estimates = repo.list_estimates(tax_year).await?;
+                                export_to_file(&estimates, format, &path)?;
+                                anyhow::Ok(estimates.len())
+                            }
+                            .await;
+                            match result {
+                                Ok(count) => {
+                                    tracing::info!(
+                                        "Exported {count} estimate(s) to {}",
+                                        path.display()
+                                    );
+                                    let _ = this.update(async_cx, |app_window, cx| {
+                                        app_window.status_message = Some(format!(
+                                            "Exported {count} estimate(s) to {}",
+                                            path.display()
+                                        ));
+                                        cx.notify();
+                                    });
+                                }
+                                Err(e) => {
+                                    tracing::error!(error = %e, "Failed to export estimates");
+                                    show_err(window_handle, async_cx, e);
+                                }
+                            }
+                        })
+                        .detach();
+                    });
+                let dialog_view =
+                    cx.new(|dialog_cx| ExportDialog::new(&estimates, on_export, window, dialog_cx));
+                window.open_dialog(cx, move |dialog, _w, _cx| {
+                    dialog
+                        .title("Export Estimates")
+                        .w(px(420.0))
+                        .child(dialog_view.clone())
+                });
+            });
+        })
+        .detach();
+    }
+
+    fn handle_backup_database(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let config = AppConfig::get(cx).clone();
+        let window_handle = window.window_handle();
+        let location = backup_directory(&config.database_url)
+            .to_string_lossy()
+            .into_owned();
+        let file_name = default_backup_file_name(chrono::Local::now());
+
+        cx.spawn(async move |this, async_cx| {
+            let filters = vec![("SQLite database".to_string(), vec!["db".to_string()])];
+            let Some(path) = get_save_file_path(location, file_name, filters).await else {
+                return;
+            };
+            tracing::info!("Backing up database to {}", path.display());
+            match backup_database(&config.database_url, config.database_backend, &path).await {
+                Ok(()) => {
+                    let _ = this.update(async_cx, |app_window, cx| {
+                        app_window.status_message =
+                            Some(format!("Backed up database to {}", path.display()));
+                        cx.notify();
+                    });
+                }
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to back up database");
+                    show_err(window_handle, async_cx, e);
+                }
+            }
+        })
+        .detach();
+    }
+
+    fn handle_restore_database(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let config = AppConfig::get(cx).clone();
+        let window_handle = window.window_handle();
+        let location = backup_directory(&config.database_url)
+            .to_string_lossy()
+            .into_owned();
+
+        cx.spawn(async move |this, async_cx| {
+            let filters = vec![("SQLite database".to_string(), vec!["db".to_string()])];
+            let Some(path) = get_file_path(location, filters).await else {
+                return;
+            };
+
+            let Ok(answer) = window_handle.update(async_cx, |_, window, cx| {
+                let detail = format!(
+                    "Every saved estimate and all tax tables will be replaced with the \
+                     contents of {}.",
+                    path.display()
+                );
+                window.prompt(
+                    PromptLevel::Warning,
+                    "Restore database from backup?",
+                    Some(&detail),
+                    &["Restore", "Cancel"],
+                    cx,
+                )
+            }) else {
+                return;
+            };
+            if answer.await != Ok(0) {
+                return;
+            }
+
+            tracing::info!("Restoring database from {}", path.display());
+            match restore_database(&config.database_url, config.database_backend, &path).await {
+                Ok(()) => {
+                    let _ = this.update(async_cx, |app_window, cx| {
+                        app_window.status_message =
+                            Some(format!("Restored database from {}", path.display()));
+                        ActiveTaxYear::reload(cx);
+                        cx.notify();
+                    });
+                }
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to restore database");
+                    show_err(window_handle, async_cx, e);
+                }
+            }
+        })
+        .detach();
+    }
+
+    /// Asks where to create a new project, then opens it with an empty form.
+    /// A file already at that path is replaced.
+    fn handle_new_project(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let config = AppConfig::get(cx).clone();
+        let window_handle = window.window_handle();
+        let location = backup_directory(&config.database_url)
+            .to_string_lossy()
+            .into_owned();
+        let file_name = format!("household.{PROJECT_FILE_EXTENSION}");
+
+        cx.spawn(async move |this, async_cx| {
+            let Some(path) =
+                get_save_file_path(location, file_name, project_file_filters(false)).await
+            else {
+                return;
+            };
+            let path = with_project_extension(path);
+            tracing::info!("Creating project {}", path.display());
+            let result = async {
+                remove_project_file(&path, &config.database_url)?;
+                open_project(&path, async_cx).await
+            }
+            .await;
+            Self::project_switched(
+                &this,
+                window_handle,
+                async_cx,
+                result.map(|()| format!("Created project {}", path.display())),
+            );
+        })
+        .detach();
+    }
+
+    fn handle_open_project(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let config = AppConfig::get(cx).clone();
+        let window_handle = window.window_handle();
+        let location = backup_directory(&config.database_url)
+            .to_string_lossy()
+            .into_owned();
+
+        cx.spawn(async move |this, async_cx| {
+            let Some(path) = get_file_path(location, project_file_filters(true)).await else {
+                return;
+            };
+            tracing::info!("Opening project {}", path.display());
+            let result = open_project(&path, async_cx).await;
+            Self::project_switched(
+                &this,
+                window_handle,
+                async_cx,
+                result.map(|()| format!("Opened project {}", path.display())),
+            );
+        })
+        .detach();
+    }
+
+    /// Estimates are written to the project as they are calculated, so
+    /// saving calculates and saves the estimate on screen.
+    fn handle_save_project(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.form
+            .update(cx, |form, form_cx| form.save(window, form_cx));
+    }
+
+    /// Copies the open project to a new file and continues in the copy.
+    fn handle_save_project_as(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let config = AppConfig::get(cx).clone();
+        let window_handle = window.window_handle();
+        let location = backup_directory(&config.database_url)
+            .to_string_lossy()
+            .into_owned();
+        let file_name = format!(
+            "{}.{PROJECT_FILE_EXTENSION}",
+            project_name(&config.database_url)
+        );
+
+        cx.spawn(async move |this, async_cx| {
+            let Some(path) =
+                get_save_file_path(location, file_name, project_file_filters(false)).await
+            else {
+                return;
+            };
+            let path = with_project_extension(path);
+            tracing::info!("Saving project as {}", path.display());
+            let result = async {
+                remove_project_file(&path, &config.database_url)?;
+                backup_database(&config.database_url, config.database_backend, &path).await?;
+                open_project(&path, async_cx).await
+            }
+            .await;
+            match result {
+                Ok(()) => {
+                    let _ = this.update(async_cx, |app_window, cx| {
+                        app_window.status_message =
+                            Some(format!("Saved project as {}", path.display()));
+                        cx.notify();
+                    });
+                }
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to save project");
+                    show_err(window_handle, async_cx, e);
+                }
+            }
+        })
+        .detach();
+    }
+
+    fn handle_close_project(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let closed = project_name(&AppConfig::get(cx).database_url);
+        let window_handle = window.window_handle();
+
+        cx.spawn(async move |this, async_cx| {
+            tracing::info!("Closing project {closed}");
+            let result = close_project(async_cx).await;
+            Self::project_switched(
+                &this,
+                window_handle,
+                async_cx,
+                result.map(|()| format!("Closed project {closed}")),
+            );
+        })
+        .detach();
+    }
+
+    /// Finishes a new, open or close: the previous project's form is
+    /// replaced with an empty one and `result`'s message shown in the status
+    /// bar, or the error is reported.
+    fn project_switched(
+        this: &WeakEntity<Self>,
+        window_handle: AnyWindowHandle,
+        async_cx: &mut AsyncApp,
+        result: anyhow::Result<String>,
+    ) {
+        match result {
+            Ok(message) => {
+                let _ = window_handle.update(async_cx, |_, window, cx| {
+                    let _ = this.update(cx, |app_window, view_cx| {
+                        let worksheet =
+                            view_cx.new(|form_cx| SeWorksheetForm::new(window, form_cx));
+                        app_window.form = view_cx
+                            .new(|form_cx| EstimatedIncomeForm::new(worksheet, window, form_cx));
+                        app_window.status_message = Some(message);
+                        view_cx.notify();
+                    });
+                });
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to switch project");
+                show_err(window_handle, async_cx, e);
+            }
+        }
+    }
+
     fn main_body(&self) -> impl IntoElement {
         v_flex().size_full().p_5().gap_4().child(self.form.clone())
     }
@@ -130,11 +589,16 @@ impl AppWindow {
         root.child(self.main_body())
     }
 
-    fn render_status_bar(&self) -> impl IntoElement {
-        let status_text = self
-            .status_message
-            .clone()
-            .unwrap_or_else(|| "Ready".to_string());
+    fn render_status_bar(
+        &self,
+        cx: &App,
+    ) -> impl IntoElement {
+        let status_text = self.status_message.clone().unwrap_or_else(|| {
+            format!(
+                "Project: {}",
+                project_name(&AppConfig::get(cx).database_url)
+            )
+        });
 
         div()
             .w_full()
@@ -153,15 +617,42 @@ impl Render for AppWindow {
     ) -> impl IntoElement {
         div()
             .id("app-window")
+            .on_action(cx.listener(|this, _: &NewProject, window, cx| {
+                this.handle_new_project(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &OpenProject, window, cx| {
+                this.handle_open_project(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &SaveProject, window, cx| {
+                this.handle_save_project(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &SaveProjectAs, window, cx| {
+                this.handle_save_project_as(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &CloseProject, window, cx| {
+                this.handle_close_project(window, cx);
+            }))
             .on_action(cx.listener(|this, _: &LoadEstimate, window, cx| {
                 this.handle_load_estimate(window, cx);
             }))
+            .on_action(cx.listener(|this, _: &ImportCsv, window, cx| {
+                this.handle_import_csv(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &ExportEstimates, window, cx| {
+                this.handle_export_estimates(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &BackupDatabase, window, cx| {
+                this.handle_backup_database(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &RestoreDatabase, window, cx| {
+                this.handle_restore_database(window, cx);
+            }))
             .v_flex()
             .gap_2()
             .size_full()
             .items_center()
             .child(self.render_body())
-            .child(self.render_status_bar())
+            .child(self.render_status_bar(cx))
             .children(Root::render_sheet_layer(window, cx))
             .children(Root::render_dialog_layer(window, cx))
             .children(Root::render_notification_layer(window, cx))
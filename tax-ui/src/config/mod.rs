@@ -16,7 +16,7 @@ use std::str::FromStr;
 pub enum DatabaseBackend {
     #[default]
     Sqlite,
-    // Postgres,
+    Postgres,
     // MySql,
 }
 
@@ -25,6 +25,7 @@ impl DatabaseBackend {
     pub const fn as_str(&self) -> &'static str {
         match self {
             Self::Sqlite => "sqlite",
+            Self::Postgres => "postgres",
         }
     }
 }
@@ -58,6 +59,7 @@ impl FromStr for DatabaseBackend {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_ascii_lowercase().as_str() {
             "sqlite" => Ok(Self::Sqlite),
+            "postgres" => Ok(Self::Postgres),
             other => anyhow::bail!("unknown database backend: {other:?}"),
         }
     }
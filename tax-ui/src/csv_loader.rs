@@ -10,6 +10,7 @@
 //! |-------------------------|----------|---------|--------------------------------------------|
 //! | `tax_year` | yes | integer | e.g. `2025` |
 //! | `filing_status` | yes | string | One of: `S`, `MFJ`, `MFS`, `HOH`, `QSS` |
+//! | `scenario_name` | no | string | Defaults to `Default` when absent or empty |
 //! | `expected_agi` | yes | decimal | e.g. `75000.00` |
 //! | `expected_deduction` | yes | decimal | Deduction amount, regardless of source |
 //! | `expected_qbi_deduction`| no | decimal | Leave cell empty for `None` |
@@ -18,29 +19,38 @@
 //! | `expected_other_taxes` | no | decimal | Leave cell empty for `None` |
 //! | `expected_withholding` | no | decimal | Leave cell empty for `None` |
 //! | `prior_year_tax` | no | decimal | Leave cell empty for `None` |
+//! | `prior_year_agi` | no | decimal | Leave cell empty for `None` |
 //! | `se_income` | no | decimal | Leave cell empty for `None` |
 //! | `expected_crp_payments` | no | decimal | Leave cell empty for `None` |
 //! | `expected_wages` | no | decimal | Leave cell empty for `None` |
+//! | `net_investment_income` | no | decimal | Leave cell empty for `None` |
+//! | `qualified_dividends` | no | decimal | Leave cell empty for `None` |
+//! | `net_capital_gain` | no | decimal | Leave cell empty for `None` |
 
 use rust_decimal::Decimal;
 use serde::Deserialize;
-use tax_core::{FilingStatusCode, TaxEstimateInput};
+use tax_core::{DEFAULT_SCENARIO_NAME, FilingStatusCode, TaxEstimate, TaxEstimateInput};
 
 #[derive(Debug, Deserialize)]
 struct CsvRow {
     tax_year: i32,
     filing_status: String,
+    scenario_name: Option<String>,
     expected_agi: Decimal,
     expected_deduction: Decimal,
     se_income: Option<Decimal>,
     expected_crp_payments: Option<Decimal>,
     expected_wages: Option<Decimal>,
+    net_investment_income: Option<Decimal>,
+    qualified_dividends: Option<Decimal>,
+    net_capital_gain: Option<Decimal>,
     expected_qbi_deduction: Option<Decimal>,
     expected_amt: Option<Decimal>,
     expected_credits: Option<Decimal>,
     expected_other_taxes: Option<Decimal>,
     expected_withholding: Option<Decimal>,
     prior_year_tax: Option<Decimal>,
+    prior_year_agi: Option<Decimal>,
 }
 
 /// Errors that can occur while loading or converting CSV data.
@@ -55,6 +65,23 @@ pub enum CsvLoadError {
     /// recognised codes.
     #[error("unrecognised filing status '{status}' on row {row}")]
     InvalidFilingStatus { status: String, row: usize },
+
+    /// A row was read but fails [`TaxEstimateInput::validate_for_submit`].
+    #[error("row {row} is invalid: {}", errors.join("; "))]
+    InvalidRow { row: usize, errors: Vec<String> },
+
+    /// A row would save over an estimate that is in the trash.
+    #[error("row {row} would replace estimate '{scenario_name}', which is in the trash")]
+    InTrash { row: usize, scenario_name: String },
+}
+
+/// One data row of a CSV import, after parsing and validation.
+#[derive(Debug)]
+pub struct ImportRow {
+    /// Row number, counting from 1 at the first row after the header.
+    pub row: usize,
+    /// The estimate to save, or why the row can't be imported.
+    pub result: Result<TaxEstimateInput, CsvLoadError>,
 }
 
 /// Convert a single CSV row into a [`TaxEstimateInput`].
@@ -69,12 +96,21 @@ fn convert_row(
         }
     })?;
 
+    let scenario_name = row
+        .scenario_name
+        .filter(|name| !name.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_SCENARIO_NAME.to_string());
+
     Ok(TaxEstimateInput {
         tax_year: row.tax_year,
         filing_status,
+        scenario_name,
         se_income: row.se_income,
         expected_crp_payments: row.expected_crp_payments,
         expected_wages: row.expected_wages,
+        net_investment_income: row.net_investment_income,
+        qualified_dividends: row.qualified_dividends,
+        net_capital_gain: row.net_capital_gain,
         expected_agi: row.expected_agi,
         expected_deduction: row.expected_deduction,
         expected_qbi_deduction: row.expected_qbi_deduction,
@@ -83,6 +119,7 @@ fn convert_row(
         expected_other_taxes: row.expected_other_taxes,
         expected_withholding: row.expected_withholding,
         prior_year_tax: row.prior_year_tax,
+        prior_year_agi: row.prior_year_agi,
     })
 }
 
@@ -104,6 +141,66 @@ pub fn load_from_str(input: &str) -> Result<Vec<TaxEstimateInput>, CsvLoadError>
         .collect()
 }
 
+/// Parse CSV text for import, keeping every row's outcome.
+///
+/// Unlike [`load_from_str`], a bad row doesn't stop the rest: each row is
+/// parsed and checked with [`TaxEstimateInput::validate_for_submit`] on its
+/// own, so the valid rows can be saved and the others reported.
+pub fn preview_from_str(input: &str) -> Vec<ImportRow> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .trim(csv::Trim::All)
+        .flexible(false)
+        .from_reader(input.as_bytes());
+
+    reader
+        .deserialize::<CsvRow>()
+        .enumerate()
+        .map(|(idx, result)| {
+            let row = idx + 1;
+            let result = result
+                .map_err(CsvLoadError::from)
+                .and_then(|csv_row| convert_row(csv_row, row))
+                .and_then(|input| match input.validate_for_submit() {
+                    Ok(()) => Ok(input),
+                    Err(errors) => Err(CsvLoadError::InvalidRow { row, errors }),
+                });
+            ImportRow { row, result }
+        })
+        .collect()
+}
+
+/// Mark every valid row that would save over an estimate in `trashed` as an
+/// error. Saving over a trashed estimate is refused, and would otherwise fail
+/// the whole import.
+pub fn reject_trashed_scenarios(
+    rows: &mut [ImportRow],
+    trashed: &[TaxEstimate],
+) {
+    for row in rows {
+        let Ok(input) = &row.result else {
+            continue;
+        };
+        let scenario_name = input.scenario_name.trim();
+        if trashed.iter().any(|estimate| {
+            estimate.input.tax_year == input.tax_year
+                && estimate.input.filing_status == input.filing_status
+                && estimate.input.scenario_name == scenario_name
+        }) {
+            row.result = Err(CsvLoadError::InTrash {
+                row: row.row,
+                scenario_name: scenario_name.to_string(),
+            });
+        }
+    }
+}
+
+/// Read a file from disk and delegate to [`preview_from_str`].
+pub fn preview_from_file(path: &std::path::Path) -> std::io::Result<Vec<ImportRow>> {
+    let contents = std::fs::read_to_string(path)?;
+    Ok(preview_from_str(&contents))
+}
+
 /// Convenience wrapper: read a file from disk and delegate to [`load_from_str`].
 pub fn load_from_file(
     path: &std::path::Path
@@ -126,8 +223,8 @@ tax_year,filing_status,expected_agi,expected_deduction
 ";
 
     const FULL_CSV: &str = "\
-tax_year,filing_status,expected_agi,expected_deduction,expected_qbi_deduction,expected_amt,expected_credits,expected_other_taxes,expected_withholding,prior_year_tax,se_income,expected_crp_payments,expected_wages
-2025,MFJ,200000.00,32000.00,5000.00,1500.00,500.00,300.00,35000.00,38000.00,40000.00,2000.00,180000.00
+tax_year,filing_status,expected_agi,expected_deduction,expected_qbi_deduction,expected_amt,expected_credits,expected_other_taxes,expected_withholding,prior_year_tax,se_income,expected_crp_payments,expected_wages,net_investment_income,prior_year_agi,qualified_dividends,net_capital_gain
+2025,MFJ,200000.00,32000.00,5000.00,1500.00,500.00,300.00,35000.00,38000.00,40000.00,2000.00,180000.00,12000.00,190000.00,4000.00,8000.00
 ";
 
     #[test]
@@ -137,8 +234,13 @@ tax_year,filing_status,expected_agi,expected_deduction,expected_qbi_deduction,ex
         assert_eq!(estimates.len(), 1);
         assert_eq!(estimates[0].tax_year, 2025);
         assert_eq!(estimates[0].filing_status, FilingStatusCode::Single);
+        assert_eq!(estimates[0].scenario_name, DEFAULT_SCENARIO_NAME);
         assert_eq!(estimates[0].expected_agi, dec!(75000.00));
         assert_eq!(estimates[0].expected_deduction, dec!(14600.00));
+        assert_eq!(estimates[0].net_investment_income, None);
+        assert_eq!(estimates[0].prior_year_agi, None);
+        assert_eq!(estimates[0].qualified_dividends, None);
+        assert_eq!(estimates[0].net_capital_gain, None);
     }
 
     #[test]
@@ -157,9 +259,91 @@ tax_year,filing_status,expected_agi,expected_deduction,expected_qbi_deduction,ex
         assert_eq!(estimate.expected_other_taxes, Some(dec!(300.00)));
         assert_eq!(estimate.expected_withholding, Some(dec!(35000.00)));
         assert_eq!(estimate.prior_year_tax, Some(dec!(38000.00)));
+        assert_eq!(estimate.prior_year_agi, Some(dec!(190000.00)));
         assert_eq!(estimate.se_income, Some(dec!(40000.00)));
         assert_eq!(estimate.expected_crp_payments, Some(dec!(2000.00)));
         assert_eq!(estimate.expected_wages, Some(dec!(180000.00)));
+        assert_eq!(estimate.net_investment_income, Some(dec!(12000.00)));
+        assert_eq!(estimate.qualified_dividends, Some(dec!(4000.00)));
+        assert_eq!(estimate.net_capital_gain, Some(dec!(8000.00)));
+    }
+
+    #[test]
+    fn scenario_name_column_labels_each_row() {
+        let csv = "\
+tax_year,filing_status,scenario_name,expected_agi,expected_deduction
+2025,S,conservative,60000.00,15750.00
+2025,S,optimistic,90000.00,15750.00
+2025,S,,75000.00,15750.00
+";
+        let estimates = load_from_str(csv).expect("should parse scenario CSV");
+
+        let names: Vec<_> = estimates.iter().map(|e| e.scenario_name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["conservative", "optimistic", DEFAULT_SCENARIO_NAME]
+        );
+    }
+
+    #[test]
+    fn preview_reports_each_bad_row_and_keeps_the_rest() {
+        let csv = "\
+tax_year,filing_status,expected_agi,expected_deduction
+2025,S,75000.00,15750.00
+2025,BOGUS,1.00,1.00
+2025,MFJ,lots,31500.00
+1999,HOH,58000.00,23625.00
+2025,MFJ,150000.00,31500.00
+";
+        let rows = preview_from_str(csv);
+
+        assert_eq!(
+            rows.iter().map(|r| r.row).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5]
+        );
+        let valid: Vec<_> = rows.iter().filter_map(|r| r.result.as_ref().ok()).collect();
+        assert_eq!(valid.len(), 2);
+        assert_eq!(valid[1].expected_agi, dec!(150000.00));
+
+        let errors: Vec<_> = rows
+            .iter()
+            .filter_map(|r| r.result.as_ref().err().map(ToString::to_string))
+            .collect();
+        assert_eq!(errors[0], "unrecognised filing status 'BOGUS' on row 2");
+        assert!(errors[1].contains("record 3"), "{}", errors[1]);
+        assert!(
+            errors[2].starts_with("row 4 is invalid: Tax year must be between"),
+            "{}",
+            errors[2]
+        );
+    }
+
+    #[test]
+    fn preview_rejects_rows_that_match_a_trashed_estimate() {
+        let csv = "\
+tax_year,filing_status,scenario_name,expected_agi,expected_deduction
+2025,S, optimistic ,75000.00,15750.00
+2025,S,conservative,75000.00,15750.00
+2025,MFJ,optimistic,150000.00,31500.00
+";
+        let mut rows = preview_from_str(csv);
+        let trashed = TaxEstimate {
+            id: 7,
+            input: rows[0].result.as_ref().unwrap().clone(),
+            computed: None,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+            deleted_at: Some(chrono::Utc::now()),
+        };
+
+        reject_trashed_scenarios(&mut rows, &[trashed]);
+
+        assert_eq!(
+            rows[0].result.as_ref().unwrap_err().to_string(),
+            "row 1 would replace estimate 'optimistic', which is in the trash"
+        );
+        assert!(rows[1].result.is_ok());
+        assert!(rows[2].result.is_ok());
     }
 
     #[test]
@@ -0,0 +1,420 @@
+//! Exporter for saved tax estimates.
+//!
+//! Writes [`TaxEstimate`] records (inputs, computed results and timestamps)
+//! in one of three formats:
+//!
+//! * **CSV** — one row per estimate. The input columns use the names
+//!   [`crate::csv_loader`] reads, so an exported file can be imported again
+//!   unchanged; the extra columns (`id`, the computed results and the
+//!   timestamps) are ignored on import.
+//! * **JSON** — an array of estimates in their serde representation.
+//! * **Excel** — an `.xlsx` workbook, written with `rust_xlsxwriter`, with
+//!   the same columns as the CSV on a single sheet named `Estimates`.
+//!   Amounts are written as numbers.
+
+use std::io::Write;
+use std::path::Path;
+
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use rust_xlsxwriter::{Workbook, XlsxError};
+use tax_core::TaxEstimate;
+
+/// Errors that can occur while writing an export.
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    #[error("CSV write error: {0}")]
+    Csv(#[from] csv::Error),
+
+    #[error("JSON write error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Excel write error: {0}")]
+    Xlsx(#[from] XlsxError),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// File format of an export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    Excel,
+}
+
+impl ExportFormat {
+    pub const ALL: [Self; 3] = [Self::Csv, Self::Json, Self::Excel];
+
+    /// Name shown in menus and file dialogs.
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::Csv => "CSV",
+            Self::Json => "JSON",
+            Self::Excel => "Excel workbook",
+        }
+    }
+
+    /// File extension, without the dot.
+    pub const fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+            Self::Excel => "xlsx",
+        }
+    }
+}
+
+/// Suggested file name for an export, e.g. `estimates-2025.csv`, or
+/// `estimates.csv` when every tax year is included.
+pub fn default_export_file_name(
+    tax_year: Option<i32>,
+    format: ExportFormat,
+) -> String {
+    match tax_year {
+        Some(year) => format!("estimates-{year}.{}", format.extension()),
+        None => format!("estimates.{}", format.extension()),
+    }
+}
+
+/// Column headers, in the order every tabular format writes them.
+pub const COLUMNS: [&str; 24] = [
+    "id",
+    "tax_year",
+    "filing_status",
+    "scenario_name",
+    "expected_agi",
+    "expected_deduction",
+    "expected_qbi_deduction",
+    "expected_amt",
+    "expected_credits",
+    "expected_other_taxes",
+    "expected_withholding",
+    "prior_year_tax",
+    "prior_year_agi",
+    "se_income",
+    "expected_crp_payments",
+    "expected_wages",
+    "net_investment_income",
+    "qualified_dividends",
+    "net_capital_gain",
+    "se_tax",
+    "total_tax",
+    "required_payment",
+    "created_at",
+    "updated_at",
+];
+
+/// A single cell of a tabular export.
+#[derive(Debug, Clone, PartialEq)]
+enum Cell {
+    Text(String),
+    Number(Decimal),
+    Empty,
+}
+
+impl Cell {
+    fn amount(value: Option<Decimal>) -> Self {
+        value.map_or(Self::Empty, Self::Number)
+    }
+
+    /// The cell as CSV text. Numbers keep their scale, so `15750.00` stays
+    /// `15750.00`.
+    fn to_text(&self) -> String {
+        match self {
+            Self::Text(s) => s.clone(),
+            Self::Number(d) => d.to_string(),
+            Self::Empty => String::new(),
+        }
+    }
+}
+
+/// One row of cells for `estimate`, matching [`COLUMNS`].
+fn row(estimate: &TaxEstimate) -> Vec<Cell> {
+    let input = &estimate.input;
+    let computed = estimate.computed.as_ref();
+    vec![
+        Cell::Number(Decimal::from(estimate.id)),
+        Cell::Number(Decimal::from(input.tax_year)),
+        Cell::Text(input.filing_status.as_str().to_string()),
+        Cell::Text(input.scenario_name.clone()),
+        Cell::amount(Some(input.expected_agi)),
+        Cell::amount(Some(input.expected_deduction)),
+        Cell::amount(input.expected_qbi_deduction),
+        Cell::amount(input.expected_amt),
+        Cell::amount(input.expected_credits),
+        Cell::amount(input.expected_other_taxes),
+        Cell::amount(input.expected_withholding),
+        Cell::amount(input.prior_year_tax),
+        Cell::amount(input.prior_year_agi),
+        Cell::amount(input.se_income),
+        Cell::amount(input.expected_crp_payments),
+        Cell::amount(input.expected_wages),
+        Cell::amount(input.net_investment_income),
+        Cell::amount(input.qualified_dividends),
+        Cell::amount(input.net_capital_gain),
+        Cell::amount(computed.map(|c| c.se_tax)),
+        Cell::amount(computed.map(|c| c.total_tax)),
+        Cell::amount(computed.map(|c| c.required_payment)),
+        Cell::Text(estimate.created_at.to_rfc3339()),
+        Cell::Text(estimate.updated_at.to_rfc3339()),
+    ]
+}
+
+/// Write `estimates` to `writer` in the given format.
+pub fn export(
+    estimates: &[TaxEstimate],
+    format: ExportFormat,
+    writer: impl Write,
+) -> Result<(), ExportError> {
+    match format {
+        ExportFormat::Csv => write_csv(estimates, writer),
+        ExportFormat::Json => write_json(estimates, writer),
+        ExportFormat::Excel => write_xlsx(estimates, writer),
+    }
+}
+
+/// Convenience wrapper: create (or truncate) the file at `path` and delegate
+/// to [`export`].
+pub fn export_to_file(
+    estimates: &[TaxEstimate],
+    format: ExportFormat,
+    path: &Path,
+) -> Result<(), ExportError> {
+    let file = std::fs::File::create(path)?;
+    let mut writer = std::io::BufWriter::new(file);
+    export(estimates, format, &mut writer)?;
+    writer.flush()?;
+    Ok(())
+}
+
+fn write_csv(
+    estimates: &[TaxEstimate],
+    writer: impl Write,
+) -> Result<(), ExportError> {
+    let mut csv_writer = csv::Writer::from_writer(writer);
+    csv_writer.write_record(COLUMNS)?;
+    for estimate in estimates {
+        csv_writer.write_record(row(estimate).iter().map(Cell::to_text))?;
+    }
+    csv_writer.flush()?;
+    Ok(())
+}
+
+fn write_json(
+    estimates: &[TaxEstimate],
+    writer: impl Write,
+) -> Result<(), ExportError> {
+    serde_json::to_writer_pretty(writer, estimates)?;
+    Ok(())
+}
+
+fn write_xlsx(
+    estimates: &[TaxEstimate],
+    mut writer: impl Write,
+) -> Result<(), ExportError> {
+    let mut workbook = Workbook::new();
+    let sheet = workbook.add_worksheet();
+    sheet.set_name("Estimates")?;
+    sheet.write_row(0, 0, COLUMNS)?;
+    for (idx, estimate) in estimates.iter().enumerate() {
+        let sheet_row = u32::try_from(idx + 1).map_err(|_| XlsxError::RowColumnLimitError)?;
+        for (col, cell) in (0u16..).zip(row(estimate)) {
+            match cell {
+                Cell::Text(text) => sheet.write_string(sheet_row, col, text)?,
+                Cell::Number(number) => {
+                    sheet.write_number(sheet_row, col, number.to_f64().unwrap_or_default())?
+                }
+                Cell::Empty => continue,
+            };
+        }
+    }
+
+    writer.write_all(&workbook.save_to_buffer()?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use calamine::{Data, Reader, Xlsx};
+    use chrono::{TimeZone, Utc};
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+    use tax_core::{FilingStatusCode, TaxEstimateComputed, TaxEstimateInput};
+
+    use super::*;
+    use crate::csv_loader;
+
+    fn estimates() -> Vec<TaxEstimate> {
+        let created_at = Utc.with_ymd_and_hms(2025, 9, 15, 10, 30, 0).unwrap();
+        vec![
+            TaxEstimate {
+                id: 1,
+                input: TaxEstimateInput {
+                    tax_year: 2025,
+                    filing_status: FilingStatusCode::Single,
+                    scenario_name: "Smith, \"expected\" & <more>".to_string(),
+                    se_income: Some(dec!(50000.00)),
+                    expected_crp_payments: None,
+                    expected_wages: Some(dec!(60000.00)),
+                    net_investment_income: Some(dec!(3000.00)),
+                    qualified_dividends: Some(dec!(1500.00)),
+                    net_capital_gain: None,
+                    expected_agi: dec!(100000.00),
+                    expected_deduction: dec!(15750.00),
+                    expected_qbi_deduction: Some(dec!(5000.00)),
+                    expected_amt: None,
+                    expected_credits: Some(dec!(2000.00)),
+                    expected_other_taxes: None,
+                    expected_withholding: Some(dec!(8000.00)),
+                    prior_year_tax: Some(dec!(12000.00)),
+                    prior_year_agi: Some(dec!(140000.00)),
+                },
+                computed: Some(TaxEstimateComputed {
+                    se_tax: dec!(7064.78),
+                    total_tax: dec!(25000.00),
+                    required_payment: dec!(22500.00),
+                }),
+                created_at,
+                updated_at: created_at,
+                deleted_at: None,
+            },
+            TaxEstimate {
+                id: 2,
+                input: TaxEstimateInput {
+                    tax_year: 2026,
+                    filing_status: FilingStatusCode::MarriedFilingJointly,
+                    scenario_name: "Default".to_string(),
+                    se_income: None,
+                    expected_crp_payments: None,
+                    expected_wages: None,
+                    net_investment_income: None,
+                    qualified_dividends: None,
+                    net_capital_gain: None,
+                    expected_agi: dec!(90000),
+                    expected_deduction: dec!(31500.00),
+                    expected_qbi_deduction: None,
+                    expected_amt: None,
+                    expected_credits: None,
+                    expected_other_taxes: None,
+                    expected_withholding: None,
+                    prior_year_tax: None,
+                    prior_year_agi: None,
+                },
+                computed: None,
+                created_at,
+                updated_at: created_at,
+                deleted_at: None,
+            },
+        ]
+    }
+
+    fn export_bytes(format: ExportFormat) -> Vec<u8> {
+        let mut out = Vec::new();
+        export(&estimates(), format, &mut out).expect("export should succeed");
+        out
+    }
+
+    #[test]
+    fn csv_export_round_trips_through_the_importer() {
+        let csv = String::from_utf8(export_bytes(ExportFormat::Csv)).unwrap();
+
+        let imported = csv_loader::load_from_str(&csv).expect("export should import");
+
+        let inputs: Vec<_> = estimates().into_iter().map(|e| e.input).collect();
+        assert_eq!(imported, inputs);
+    }
+
+    #[test]
+    fn csv_export_includes_computed_results_and_timestamps() {
+        let csv = String::from_utf8(export_bytes(ExportFormat::Csv)).unwrap();
+        let mut lines = csv.lines();
+
+        assert_eq!(lines.next(), Some(COLUMNS.join(",").as_str()));
+        let first = lines.next().unwrap();
+        assert!(
+            first.ends_with(
+                ",7064.78,25000.00,22500.00,2025-09-15T10:30:00+00:00,2025-09-15T10:30:00+00:00"
+            ),
+            "{first}"
+        );
+        let second = lines.next().unwrap();
+        assert!(
+            second.starts_with("2,2026,MFJ,Default,90000,31500.00,"),
+            "{second}"
+        );
+    }
+
+    #[test]
+    fn json_export_round_trips() {
+        let json = export_bytes(ExportFormat::Json);
+
+        let parsed: Vec<TaxEstimate> = serde_json::from_slice(&json).unwrap();
+
+        assert_eq!(parsed, estimates());
+    }
+
+    /// Every cell of the `Estimates` sheet of an exported workbook.
+    fn xlsx_rows(xlsx: Vec<u8>) -> Vec<Vec<Data>> {
+        let mut workbook: Xlsx<_> =
+            Xlsx::new(std::io::Cursor::new(xlsx)).expect("export should be a workbook");
+        let range = workbook
+            .worksheet_range("Estimates")
+            .expect("workbook should have an Estimates sheet");
+        range.rows().map(<[Data]>::to_vec).collect()
+    }
+
+    #[test]
+    fn xlsx_export_has_a_header_and_a_row_per_estimate() {
+        let rows = xlsx_rows(export_bytes(ExportFormat::Excel));
+
+        assert_eq!(rows.len(), 3);
+        let header: Vec<_> = COLUMNS
+            .iter()
+            .map(|c| Data::String(c.to_string()))
+            .collect();
+        assert_eq!(rows[0], header);
+        assert_eq!(rows[1][0], Data::Float(1.0));
+        assert_eq!(
+            rows[1][3],
+            Data::String("Smith, \"expected\" & <more>".to_string())
+        );
+        assert_eq!(rows[1][4], Data::Float(100000.0));
+        assert_eq!(rows[2][6], Data::Empty);
+        assert_eq!(rows[2][19], Data::Empty);
+    }
+
+    #[test]
+    fn xlsx_export_keeps_control_characters_readable() {
+        let mut estimates = estimates();
+        estimates[0].input.scenario_name = "tab\there\u{1}bell\u{7}".to_string();
+        let mut out = Vec::new();
+        export(&estimates, ExportFormat::Excel, &mut out).expect("export should succeed");
+
+        let rows = xlsx_rows(out);
+
+        assert_eq!(
+            rows[1][3],
+            Data::String("tab\there\u{1}bell\u{7}".to_string())
+        );
+    }
+
+    #[test]
+    fn default_export_file_name_names_the_year() {
+        assert_eq!(
+            default_export_file_name(Some(2025), ExportFormat::Excel),
+            "estimates-2025.xlsx"
+        );
+        assert_eq!(
+            default_export_file_name(None, ExportFormat::Json),
+            "estimates.json"
+        );
+    }
+
+    #[test]
+    fn formats_have_distinct_extensions() {
+        let extensions: Vec<_> = ExportFormat::ALL.iter().map(|f| f.extension()).collect();
+        assert_eq!(extensions, vec!["csv", "json", "xlsx"]);
+    }
+}
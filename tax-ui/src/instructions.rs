@@ -17,11 +17,15 @@ pub(crate) enum UiInstructionField {
     ExpectedAgi,
     ExpectedDeduction,
     ExpectedQbiDeduction,
+    QualifiedDividends,
+    NetCapitalGain,
     ExpectedAmt,
     ExpectedCredits,
     ExpectedOtherTaxes,
+    NetInvestmentIncome,
     ExpectedWithholding,
     PriorYearTax,
+    PriorYearAgi,
     SeIncome,
     CrpPayments,
     SeLine2,
@@ -45,11 +49,15 @@ impl UiInstructionField {
                 FieldSpec::new("1040-es", "expected_qbi_deduction"),
                 FieldSpec::new("8995", "expected_qbi_deduction"),
             ],
+            Self::QualifiedDividends => vec![FieldSpec::new("qdcg", "qualified_dividends")],
+            Self::NetCapitalGain => vec![FieldSpec::new("qdcg", "net_capital_gain")],
             Self::ExpectedAmt => vec![FieldSpec::new("1040-es", "expected_amt")],
             Self::ExpectedCredits => vec![FieldSpec::new("1040-es", "expected_credits")],
             Self::ExpectedOtherTaxes => vec![FieldSpec::new("1040-es", "expected_other_taxes")],
+            Self::NetInvestmentIncome => vec![FieldSpec::new("8960", "net_investment_income")],
             Self::ExpectedWithholding => vec![FieldSpec::new("1040-es", "expected_withholding")],
             Self::PriorYearTax => vec![FieldSpec::new("1040-es", "prior_year_tax")],
+            Self::PriorYearAgi => vec![FieldSpec::new("1040-es", "prior_year_agi")],
             Self::SeIncome => vec![FieldSpec::new("se-worksheet", "se_income")],
             Self::CrpPayments => vec![FieldSpec::new("se-worksheet", "crp_payments")],
             Self::SeLine2 => vec![FieldSpec::new("se-worksheet", "line_2")],
@@ -286,4 +294,35 @@ mod tests {
         );
         assert!(help.paragraphs.iter().any(|p| p.contains("Form 8995")));
     }
+
+    #[test]
+    fn net_investment_income_falls_back_to_latest_form_8960_year() {
+        let help = help_for_field(UiInstructionField::NetInvestmentIncome, Some(2026))
+            .expect("NIIT help should resolve");
+
+        assert_eq!(help.label, "Net investment income");
+        assert!(help.paragraphs.iter().any(|p| p.contains("3.8%")));
+    }
+
+    #[test]
+    fn qualified_dividends_mentions_capital_gain_rates() {
+        let help = help_for_field(UiInstructionField::QualifiedDividends, Some(2026))
+            .expect("qualified dividends help should resolve");
+
+        assert_eq!(help.label, "Qualified dividends");
+        assert!(
+            help.paragraphs
+                .iter()
+                .any(|p| p.contains("0%, 15%, or 20%"))
+        );
+    }
+
+    #[test]
+    fn prior_year_agi_mentions_high_income_threshold() {
+        let help = help_for_field(UiInstructionField::PriorYearAgi, Some(2025))
+            .expect("prior year AGI help should resolve");
+
+        assert_eq!(help.label, "Prior year AGI");
+        assert!(help.paragraphs.iter().any(|p| p.contains("110%")));
+    }
 }
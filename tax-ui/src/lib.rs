@@ -1,7 +1,9 @@
 pub mod app;
+pub mod batch;
 pub mod components;
 pub mod config;
 pub mod csv_loader;
+pub mod exporter;
 mod instructions;
 pub mod logging;
 pub mod models;
@@ -17,10 +19,12 @@ use gpui::{Menu, MenuItem};
 
 use tracing::info;
 
+#[cfg(target_os = "macos")]
 use crate::components::{
-    CloseProject, LoadEstimate, NewProject, OpenProject, SaveProject, SaveProjectAs,
-    bind_menu_keys, init_theme_colors,
+    BackupDatabase, CloseProject, ExportEstimates, ImportCsv, NewProject, OpenProject,
+    RestoreDatabase, SaveProject, SaveProjectAs,
 };
+use crate::components::{LoadEstimate, bind_menu_keys, init_theme_colors};
 use crate::config::{AppConfig, TomlConfigStore};
 use crate::repository::ActiveTaxYear;
 #[cfg(target_os = "linux")]
@@ -85,11 +89,6 @@ pub fn setup_app(app_cx: &mut App) {
 
     app_cx.on_action(quit);
 
-    register_action(app_cx, stub_file_action::<NewProject>("NewProject"));
-    register_action(app_cx, stub_file_action::<OpenProject>("OpenProject"));
-    register_action(app_cx, stub_file_action::<SaveProject>("SaveProject"));
-    register_action(app_cx, stub_file_action::<SaveProjectAs>("SaveProjectAs"));
-    register_action(app_cx, stub_file_action::<CloseProject>("CloseProject"));
     register_action(app_cx, stub_file_action::<LoadEstimate>("LoadEstimate"));
 
     bind_menu_keys(app_cx);
@@ -107,11 +106,16 @@ pub fn setup_app(app_cx: &mut App) {
                 MenuItem::action("New Project", NewProject),
                 MenuItem::action("Open Project", OpenProject),
                 MenuItem::action("Load Estimate", LoadEstimate),
+                MenuItem::action("Import CSV...", ImportCsv),
+                MenuItem::action("Export...", ExportEstimates),
                 MenuItem::separator(),
                 MenuItem::action("Save", SaveProject),
                 MenuItem::action("Save As...", SaveProjectAs),
                 MenuItem::separator(),
                 MenuItem::action("Close Project", CloseProject),
+                MenuItem::separator(),
+                MenuItem::action("Back Up Database...", BackupDatabase),
+                MenuItem::action("Restore Database...", RestoreDatabase),
             ],
         },
     ]);
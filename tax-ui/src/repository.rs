@@ -1,13 +1,19 @@
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local};
 use gpui::{App, AsyncApp, BorrowAppContext, Global};
 use rust_decimal::Decimal;
-use tax_core::{RepositoryError, TaxEstimate, TaxRepository, TaxYearConfig, db::DbConfig};
+use tax_core::{
+    RepositoryError, TaxEstimate, TaxEstimateRevision, TaxRepository, TaxYearConfig, db::DbConfig,
+};
+
+use tax_db_sqlite::SqliteRepository;
 
 use crate::{
     app::{TaxYearData, build_registry, load_tax_year_data},
-    config::AppConfig,
+    config::{AppConfig, DatabaseBackend},
 };
 
 // ---------------------------------------------------------------------------
@@ -65,10 +71,32 @@ impl TaxRepo {
     ) -> Result<Vec<TaxEstimate>, RepositoryError> {
         self.0.list_estimates(tax_year).await
     }
+
+    /// Lists estimates in the trash, most recently deleted first.
+    pub async fn list_deleted_estimates(&self) -> Result<Vec<TaxEstimate>, RepositoryError> {
+        self.0.list_deleted_estimates().await
+    }
+
+    /// Lists every saved revision of an estimate, oldest first.
+    pub async fn list_estimate_revisions(
+        &self,
+        estimate_id: i64,
+    ) -> Result<Vec<TaxEstimateRevision>, RepositoryError> {
+        self.0.list_estimate_revisions(estimate_id).await
+    }
+
+    /// Writes a revision back as its estimate's current values.
+    pub async fn restore_estimate_revision(
+        &self,
+        revision_id: i64,
+    ) -> Result<TaxEstimate, RepositoryError> {
+        self.0.restore_estimate_revision(revision_id).await
+    }
 }
 
 /// Build the repository from `AppConfig` and install it as a global.
-/// Call once during startup, *after* `AppConfig::init`.
+/// Call during startup, *after* `AppConfig::init`, and again whenever
+/// [`AppConfig::database_url`] changes (see [`open_project`]).
 pub async fn init_repository(cx: &mut gpui::AsyncApp) -> Result<()> {
     let (url, backend) = cx.update(|cx| {
         let cfg = AppConfig::get(cx);
@@ -87,6 +115,188 @@ pub async fn init_repository(cx: &mut gpui::AsyncApp) -> Result<()> {
     Ok(())
 }
 
+// ---------------------------------------------------------------------------
+// Backup and restore
+// ---------------------------------------------------------------------------
+
+/// Suggested file name for a backup taken at `now`,
+/// e.g. `taxes-backup-20251015-0930.db`.
+pub fn default_backup_file_name(now: DateTime<Local>) -> String {
+    format!("taxes-backup-{}.db", now.format("%Y%m%d-%H%M"))
+}
+
+/// Directory the backup file dialogs start in: the one holding the database.
+pub fn backup_directory(database_url: &str) -> PathBuf {
+    Path::new(database_url)
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// Opens the configured database with its backend's own repository type.
+/// Backup and restore are backend-specific, so they are not part of
+/// [`TaxRepository`] and bypass the shared [`TaxRepo`] handle.
+async fn open_sqlite(
+    database_url: &str,
+    backend: DatabaseBackend,
+) -> Result<SqliteRepository> {
+    match backend {
+        DatabaseBackend::Sqlite => SqliteRepository::new(database_url).await,
+        DatabaseBackend::Postgres => {
+            anyhow::bail!("Backup and restore are only built in for SQLite; use pg_dump")
+        }
+    }
+}
+
+/// Writes a consistent snapshot of the configured database to `path`.
+pub async fn backup_database(
+    database_url: &str,
+    backend: DatabaseBackend,
+    path: &Path,
+) -> Result<()> {
+    let repo = open_sqlite(database_url, backend).await?;
+    let result = repo
+        .backup_to(path)
+        .await
+        .with_context(|| format!("Backup of {database_url} failed"));
+    repo.pool().close().await;
+    result
+}
+
+/// Replaces the contents of the configured database with the backup at
+/// `path`. Connections held by the shared [`TaxRepo`] see the restored data.
+pub async fn restore_database(
+    database_url: &str,
+    backend: DatabaseBackend,
+    path: &Path,
+) -> Result<()> {
+    let repo = open_sqlite(database_url, backend).await?;
+    let result = repo
+        .restore_from(path)
+        .await
+        .with_context(|| format!("Restore of {database_url} failed"));
+    repo.pool().close().await;
+    result
+}
+
+// ---------------------------------------------------------------------------
+// Project files
+// ---------------------------------------------------------------------------
+
+/// Extension of a project file. A project is a SQLite database of its own,
+/// holding one household's estimates and any reference data changes made
+/// while it was open.
+pub const PROJECT_FILE_EXTENSION: &str = "taxdb";
+
+/// File dialog filters for picking a project. Opening also accepts plain
+/// `.db` files, so backups and older databases can be opened as projects.
+pub fn project_file_filters(include_databases: bool) -> Vec<(String, Vec<String>)> {
+    let mut filters = vec![(
+        "Tax project".to_string(),
+        vec![PROJECT_FILE_EXTENSION.to_string()],
+    )];
+    if include_databases {
+        filters.push(("SQLite database".to_string(), vec!["db".to_string()]));
+    }
+    filters
+}
+
+/// Adds the project extension to a path picked in a save dialog, which some
+/// platforms return exactly as typed.
+pub fn with_project_extension(path: PathBuf) -> PathBuf {
+    if path.extension().is_some() {
+        path
+    } else {
+        path.with_extension(PROJECT_FILE_EXTENSION)
+    }
+}
+
+/// Name shown for the open project: the database file name without its
+/// extension.
+pub fn project_name(database_url: &str) -> String {
+    Path::new(database_url)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| database_url.to_string())
+}
+
+/// Deletes the file at `path`, along with any SQLite journal files next to
+/// it, so a new project can be written there. Refuses to touch the database
+/// that is currently open.
+pub fn remove_project_file(
+    path: &Path,
+    database_url: &str,
+) -> Result<()> {
+    if is_same_file(path, Path::new(database_url)) {
+        anyhow::bail!("{} is the project that is currently open", path.display());
+    }
+    for suffix in ["", "-wal", "-shm", "-journal"] {
+        let mut file = path.as_os_str().to_owned();
+        file.push(suffix);
+        match std::fs::remove_file(&file) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => {
+                return Err(e).with_context(|| format!("Failed to replace {}", path.display()));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn is_same_file(
+    a: &Path,
+    b: &Path,
+) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+/// Makes the SQLite database at `path` the open project: points
+/// [`AppConfig::database_url`] at it, rebuilds the shared [`TaxRepo`] with
+/// [`init_repository`] and saves the config so the project reopens on the
+/// next start. A missing file is created with the default reference data.
+///
+/// If the file cannot be opened, the previous database stays in use.
+pub async fn open_project(
+    path: &Path,
+    cx: &mut AsyncApp,
+) -> Result<()> {
+    let database_url = path.to_string_lossy().into_owned();
+    let previous = cx.update(|cx| {
+        let previous = AppConfig::get(cx).clone();
+        AppConfig::update(cx, |cfg| {
+            cfg.database_url = database_url;
+            cfg.database_backend = DatabaseBackend::Sqlite;
+        });
+        previous
+    })?;
+
+    if let Err(e) = init_repository(cx).await {
+        cx.update(|cx| AppConfig::update(cx, |cfg| *cfg = previous))?;
+        return Err(e.context(format!("Failed to open project {}", path.display())));
+    }
+
+    cx.update(|cx| {
+        ActiveTaxYear::reload(cx);
+        if let Err(e) = AppConfig::save(cx) {
+            tracing::warn!("Failed to save config: {e:#}");
+        }
+    })?;
+    tracing::info!("Opened project {}", path.display());
+    Ok(())
+}
+
+/// Closes the open project by switching back to the default database from
+/// [`AppConfig::default`].
+pub async fn close_project(cx: &mut AsyncApp) -> Result<()> {
+    let default_url = AppConfig::default().database_url;
+    open_project(Path::new(&default_url), cx).await
+}
+
 // ---------------------------------------------------------------------------
 // Active tax year (config loaded on demand)
 // ---------------------------------------------------------------------------
@@ -110,6 +320,16 @@ impl ActiveTaxYear {
             .map(|tyd: &TaxYearData| tyd.config.ss_wage_max)
     }
 
+    /// Drop the cached data for the active year and fetch it again, e.g.
+    /// after the database contents were replaced by a restore.
+    pub fn reload(cx: &mut App) {
+        let Some(year) = cx.try_global::<Self>().and_then(|a| a.year) else {
+            return;
+        };
+        cx.set_global(Self::default());
+        Self::load(year, cx);
+    }
+
     /// Kick off a fetch for `year`. No-op if already loaded.
     pub fn load(
         year: i32,
@@ -162,14 +382,16 @@ mod tests {
     use std::path::Path;
     use std::sync::Arc;
 
+    use chrono::TimeZone;
     use pretty_assertions::assert_eq;
     use rust_decimal_macros::dec;
     use tax_core::{
-        FilingStatusCode, RepositoryError, TaxEstimateComputed, TaxEstimateInput, TaxRepository,
+        DEFAULT_SCENARIO_NAME, FilingStatusCode, RepositoryError, TaxEstimateComputed,
+        TaxEstimateInput, TaxRepository,
     };
     use tax_db_sqlite::SqliteRepository;
 
-    use super::TaxRepo;
+    use super::*;
 
     async fn setup_test_repo() -> (Arc<dyn TaxRepository>, TaxRepo) {
         let seeds_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../tax-db-sqlite/seeds");
@@ -195,9 +417,13 @@ mod tests {
         TaxEstimateInput {
             tax_year: 2025,
             filing_status: FilingStatusCode::Single,
+            scenario_name: DEFAULT_SCENARIO_NAME.to_string(),
             se_income: Some(dec!(50000.00)),
             expected_crp_payments: Some(dec!(5000.00)),
             expected_wages: Some(dec!(60000.00)),
+            net_investment_income: Some(dec!(3000.00)),
+            qualified_dividends: Some(dec!(1500.00)),
+            net_capital_gain: Some(dec!(2500.00)),
             expected_agi: dec!(100000.00),
             expected_deduction: dec!(15000.00),
             expected_qbi_deduction: Some(dec!(5000.00)),
@@ -206,6 +432,7 @@ mod tests {
             expected_other_taxes: Some(dec!(500.00)),
             expected_withholding: Some(dec!(8000.00)),
             prior_year_tax: Some(dec!(12000.00)),
+            prior_year_agi: Some(dec!(140000.00)),
         }
     }
 
@@ -213,9 +440,13 @@ mod tests {
         TaxEstimateInput {
             tax_year: 2025,
             filing_status: FilingStatusCode::Single,
+            scenario_name: DEFAULT_SCENARIO_NAME.to_string(),
             se_income: None,
             expected_crp_payments: None,
             expected_wages: None,
+            net_investment_income: None,
+            qualified_dividends: None,
+            net_capital_gain: None,
             expected_agi: dec!(75000.00),
             expected_deduction: dec!(15000.00),
             expected_qbi_deduction: None,
@@ -224,6 +455,7 @@ mod tests {
             expected_other_taxes: None,
             expected_withholding: None,
             prior_year_tax: None,
+            prior_year_agi: None,
         }
     }
 
@@ -308,9 +540,13 @@ mod tests {
         let single_input = TaxEstimateInput {
             tax_year: 2025,
             filing_status: FilingStatusCode::Single,
+            scenario_name: DEFAULT_SCENARIO_NAME.to_string(),
             se_income: None,
             expected_crp_payments: None,
             expected_wages: None,
+            net_investment_income: None,
+            qualified_dividends: None,
+            net_capital_gain: None,
             expected_agi: dec!(80000.00),
             expected_deduction: dec!(15000.00),
             expected_qbi_deduction: None,
@@ -319,14 +555,19 @@ mod tests {
             expected_other_taxes: None,
             expected_withholding: None,
             prior_year_tax: None,
+            prior_year_agi: None,
         };
 
         let mfj_input = TaxEstimateInput {
             tax_year: 2025,
             filing_status: FilingStatusCode::MarriedFilingJointly,
+            scenario_name: DEFAULT_SCENARIO_NAME.to_string(),
             se_income: Some(dec!(40000.00)),
             expected_crp_payments: None,
             expected_wages: None,
+            net_investment_income: None,
+            qualified_dividends: None,
+            net_capital_gain: None,
             expected_agi: dec!(120000.00),
             expected_deduction: dec!(30000.00),
             expected_qbi_deduction: None,
@@ -335,6 +576,7 @@ mod tests {
             expected_other_taxes: None,
             expected_withholding: None,
             prior_year_tax: None,
+            prior_year_agi: None,
         };
 
         repo.create_estimate(single_input)
@@ -365,4 +607,168 @@ mod tests {
             .expect("list estimates for 2024");
         assert_eq!(for_2024.len(), 0);
     }
+
+    #[tokio::test]
+    async fn list_estimates_keeps_named_scenarios_side_by_side() {
+        let (repo, tax_repo) = setup_test_repo().await;
+
+        for name in ["conservative", "expected", "optimistic"] {
+            let mut input = minimal_input();
+            input.scenario_name = name.to_string();
+            repo.create_estimate(input)
+                .await
+                .expect("create_estimate should succeed");
+        }
+
+        let estimates = tax_repo
+            .list_estimates(Some(2025))
+            .await
+            .expect("list estimates for 2025");
+        let mut names: Vec<_> = estimates
+            .iter()
+            .map(|e| e.input.scenario_name.as_str())
+            .collect();
+        names.sort_unstable();
+        assert_eq!(names, vec!["conservative", "expected", "optimistic"]);
+    }
+
+    #[tokio::test]
+    async fn restore_estimate_revision_brings_back_earlier_values() {
+        let (repo, tax_repo) = setup_test_repo().await;
+
+        let mut estimate = repo
+            .create_estimate(minimal_input())
+            .await
+            .expect("create_estimate should succeed");
+        let original_agi = estimate.input.expected_agi;
+        estimate.input.expected_agi = dec!(250000.00);
+        repo.update_estimate(&estimate)
+            .await
+            .expect("update_estimate should succeed");
+
+        let revisions = tax_repo
+            .list_estimate_revisions(estimate.id)
+            .await
+            .expect("list revisions");
+        assert_eq!(revisions.len(), 2);
+
+        let restored = tax_repo
+            .restore_estimate_revision(revisions[0].id)
+            .await
+            .expect("restore first revision");
+        assert_eq!(restored.input.expected_agi, original_agi);
+        assert!(
+            revisions[0].changes_to(&restored).is_empty(),
+            "restored estimate should match the first revision"
+        );
+    }
+
+    #[test]
+    fn default_backup_file_name_uses_local_timestamp() {
+        let now = Local.with_ymd_and_hms(2025, 10, 15, 9, 30, 0).unwrap();
+
+        assert_eq!(
+            default_backup_file_name(now),
+            "taxes-backup-20251015-0930.db"
+        );
+    }
+
+    #[test]
+    fn backup_directory_is_database_parent() {
+        assert_eq!(
+            backup_directory("/home/me/taxes/taxes.db"),
+            PathBuf::from("/home/me/taxes")
+        );
+        assert_eq!(backup_directory("taxes.db"), PathBuf::from("."));
+    }
+
+    #[test]
+    fn with_project_extension_only_fills_in_a_missing_extension() {
+        assert_eq!(
+            with_project_extension(PathBuf::from("/home/me/smith")),
+            PathBuf::from("/home/me/smith.taxdb")
+        );
+        assert_eq!(
+            with_project_extension(PathBuf::from("/home/me/smith.db")),
+            PathBuf::from("/home/me/smith.db")
+        );
+    }
+
+    #[test]
+    fn project_name_is_file_stem() {
+        assert_eq!(
+            project_name("/home/me/Smith household.taxdb"),
+            "Smith household"
+        );
+        assert_eq!(project_name("taxes.db"), "taxes");
+    }
+
+    #[test]
+    fn remove_project_file_clears_journal_files_but_not_the_open_project() {
+        let dir = std::env::temp_dir().join(format!(
+            "tax-ui-project-{}-{}",
+            std::process::id(),
+            Local::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        std::fs::create_dir_all(&dir).expect("create scratch dir");
+        let project = dir.join("smith.taxdb");
+        let wal = dir.join("smith.taxdb-wal");
+        std::fs::write(&project, b"old").expect("write project");
+        std::fs::write(&wal, b"old").expect("write wal");
+        let open = project.to_string_lossy().into_owned();
+
+        assert!(remove_project_file(&project, &open).is_err());
+        assert!(project.exists());
+
+        remove_project_file(&project, "taxes.db").expect("remove project");
+        assert!(!project.exists());
+        assert!(!wal.exists());
+        remove_project_file(&project, "taxes.db").expect("missing file is fine");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn backup_and_restore_database_round_trip() {
+        let dir = std::env::temp_dir().join(format!(
+            "tax-ui-backup-{}-{}",
+            std::process::id(),
+            Local::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        std::fs::create_dir_all(&dir).expect("create scratch dir");
+        let database = dir.join("taxes.db");
+        let backup = dir.join("backup.db");
+        let database_url = database.to_string_lossy().into_owned();
+
+        let sqlite_repo = SqliteRepository::new(&database_url)
+            .await
+            .expect("create database");
+        sqlite_repo.run_migrations().await.expect("migrations");
+        sqlite_repo
+            .run_seeds(&Path::new(env!("CARGO_MANIFEST_DIR")).join("../tax-db-sqlite/seeds"))
+            .await
+            .expect("seeds");
+        let estimate = sqlite_repo
+            .create_estimate(minimal_input())
+            .await
+            .expect("create_estimate should succeed");
+
+        backup_database(&database_url, DatabaseBackend::Sqlite, &backup)
+            .await
+            .expect("backup");
+        sqlite_repo
+            .delete_estimate(estimate.id)
+            .await
+            .expect("delete_estimate should succeed");
+        restore_database(&database_url, DatabaseBackend::Sqlite, &backup)
+            .await
+            .expect("restore");
+
+        let restored = sqlite_repo
+            .get_estimate(estimate.id)
+            .await
+            .expect("estimate should be back after restore");
+        assert_eq!(restored.input, estimate.input);
+        sqlite_repo.pool().close().await;
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 }
@@ -1,3 +1,4 @@
+use chrono::NaiveDate;
 use rust_decimal::Decimal;
 use thiserror::Error;
 
@@ -10,6 +11,15 @@ pub struct ParseDecimalError {
     source: rust_decimal::Error,
 }
 
+/// Error returned when a string cannot be parsed as a `YYYY-MM-DD` date.
+#[derive(Debug, Error)]
+#[error("invalid date '{input}' (expected YYYY-MM-DD): {source}")]
+pub struct ParseDateError {
+    input: String,
+    #[source]
+    source: chrono::ParseError,
+}
+
 /// Normalizes input for decimal parsing: trims whitespace and removes commas (thousands separator).
 fn normalize_decimal_input(s: &str) -> String {
     s.trim().replace(',', "")
@@ -53,6 +63,22 @@ pub fn parse_optional_decimal(s: &str) -> Option<Decimal> {
     }
 }
 
+/// Parses a `YYYY-MM-DD` string into an optional [`NaiveDate`].
+///
+/// Returns `Ok(None)` for empty or whitespace-only input.
+pub fn parse_optional_date(s: &str) -> Result<Option<NaiveDate>, ParseDateError> {
+    let trimmed = s.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+    NaiveDate::parse_from_str(trimmed, "%Y-%m-%d")
+        .map(Some)
+        .map_err(|e| ParseDateError {
+            input: s.to_string(),
+            source: e,
+        })
+}
+
 /// Formats an optional [`Decimal`] for display, using "—" when `None`.
 pub fn opt_decimal_display(d: &Option<Decimal>) -> String {
     d.as_ref()
@@ -125,6 +151,22 @@ mod tests {
         assert_eq!(parse_optional_decimal("   "), None);
     }
 
+    #[test]
+    fn parse_optional_date_accepts_iso_date_and_empty() {
+        assert_eq!(
+            parse_optional_date(" 2025-06-16 ").unwrap(),
+            NaiveDate::from_ymd_opt(2025, 6, 16)
+        );
+        assert_eq!(parse_optional_date("").unwrap(), None);
+    }
+
+    #[test]
+    fn parse_optional_date_invalid_returns_error() {
+        let err = parse_optional_date("06/16/2025").unwrap_err();
+
+        assert!(err.to_string().starts_with("invalid date '06/16/2025'"));
+    }
+
     // ── currency / percent ──────────────────────────────────────────────
 
     #[test]
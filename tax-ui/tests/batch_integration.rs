@@ -0,0 +1,135 @@
+//! Integration test: a CSV of estimates recalculated in one batch against
+//! the seeded reference data.
+
+use std::path::PathBuf;
+
+use pretty_assertions::assert_eq;
+use tax_core::TaxRepository;
+use tax_core::db::DbConfig;
+use tax_ui::app::{build_registry, calculate_estimate, load_tax_year_data};
+use tax_ui::batch::{self, COLUMNS};
+use tax_ui::csv_loader;
+
+fn fixture_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("sample_estimates.csv")
+}
+
+async fn seeded_repo() -> Box<dyn TaxRepository> {
+    build_registry()
+        .create(&DbConfig {
+            backend: "sqlite".to_string(),
+            connection_string: ":memory:".to_string(),
+        })
+        .await
+        .expect("repository creation should succeed")
+}
+
+#[tokio::test]
+async fn batch_matches_calculating_each_row_alone() {
+    let repo = seeded_repo().await;
+    let rows = csv_loader::preview_from_file(&fixture_path()).unwrap();
+
+    let results = batch::recalculate(rows, &*repo).await;
+
+    assert_eq!(results.len(), 3);
+    let year_data = load_tax_year_data(&*repo, 2025).await.unwrap();
+    for (idx, row) in results.iter().enumerate() {
+        assert_eq!(row.row, idx + 1);
+        let input = row.input.as_ref().expect("fixture rows are valid");
+        let alone = calculate_estimate(&year_data, input).unwrap();
+        assert_eq!(
+            row.result.as_ref().map(|calc| calc.computed()),
+            Ok(alone.computed())
+        );
+    }
+}
+
+#[tokio::test]
+async fn batch_output_has_a_line_per_row_and_reports_errors() {
+    let repo = seeded_repo().await;
+    let csv = "\
+tax_year,filing_status,expected_agi,expected_deduction,se_income
+2025,S,175000,15000,100000
+2025,S,abc,15000,
+2020,MFJ,90000,30000,
+";
+    let results = batch::recalculate(csv_loader::preview_from_str(csv), &*repo).await;
+
+    let mut bytes = Vec::new();
+    batch::write_csv(&results, &mut bytes).unwrap();
+    let mut reader = csv::Reader::from_reader(bytes.as_slice());
+    assert_eq!(reader.headers().unwrap(), COLUMNS.as_slice());
+    let records: Vec<csv::StringRecord> = reader.records().map(Result::unwrap).collect();
+    let column = |name: &str| COLUMNS.iter().position(|c| *c == name).unwrap();
+
+    assert_eq!(records.len(), 3);
+    assert_eq!(&records[0][column("qbi_deduction")], "18587.04");
+    assert_eq!(&records[0][column("error")], "");
+    assert!(!records[1][column("error")].is_empty());
+    assert!(
+        records[2][column("error")].starts_with("Failed to load 2020 reference data"),
+        "unexpected error: {}",
+        &records[2][column("error")]
+    );
+    assert_eq!(&records[2][column("tax_year")], "2020");
+    assert_eq!(&records[2][column("line_11c_total_estimated_tax")], "");
+}
+
+/// Runs `tax-estimate batch` on `input` against a fresh seeded database,
+/// writing the results to `output`.
+fn run_batch_command(
+    input: &std::path::Path,
+    output: &std::path::Path,
+) -> std::process::Output {
+    // The binary reads the application config; give it an empty home so a
+    // developer's own config is never picked up.
+    let home = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("batch_home");
+    std::fs::create_dir_all(&home).unwrap();
+    std::process::Command::new(env!("CARGO_BIN_EXE_tax-estimate"))
+        .env("HOME", &home)
+        .env("XDG_CONFIG_HOME", &home)
+        .env("APPDATA", &home)
+        .args(["--backend", "sqlite", "--database", ":memory:", "batch"])
+        .arg(input)
+        .arg("--output")
+        .arg(output)
+        .output()
+        .expect("tax-estimate should run")
+}
+
+#[test]
+fn batch_command_fails_when_any_row_fails() {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("batch_command");
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let output = dir.join("all_valid.csv");
+    let run = run_batch_command(&fixture_path(), &output);
+    assert!(
+        run.status.success(),
+        "{}",
+        String::from_utf8_lossy(&run.stderr)
+    );
+
+    let input = dir.join("one_bad_row.csv");
+    std::fs::write(
+        &input,
+        "tax_year,filing_status,expected_agi,expected_deduction\n\
+         2025,S,75000,15000\n\
+         2025,S,abc,15000\n",
+    )
+    .unwrap();
+    let output = dir.join("one_bad_row_results.csv");
+    let run = run_batch_command(&input, &output);
+
+    assert!(!run.status.success());
+    assert!(
+        String::from_utf8_lossy(&run.stderr).contains("Calculated 1 of 2 rows; 1 failed."),
+        "{}",
+        String::from_utf8_lossy(&run.stderr)
+    );
+    let results = std::fs::read_to_string(&output).unwrap();
+    assert_eq!(results.lines().count(), 3, "every row is still written");
+}
@@ -86,3 +86,13 @@ fn test_load_nonexistent_file_returns_err() {
         csv_loader::load_from_file(&bad_path);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_preview_fixture_file_accepts_every_row() {
+    let rows = csv_loader::preview_from_file(&fixture_path()).unwrap();
+
+    assert_eq!(rows.len(), 3);
+    for row in &rows {
+        assert!(row.result.is_ok(), "row {}: {:?}", row.row, row.result);
+    }
+}
@@ -5,14 +5,20 @@
 
 use rust_decimal::Decimal;
 use tax_core::calculations::{
-    EstimatedTaxWorksheet, EstimatedTaxWorksheetContext, EstimatedTaxWorksheetResult, SeWorksheet,
-    SeWorksheetConfig, SeWorksheetResult,
+    AdditionalMedicareTaxConfig, AdditionalMedicareTaxWorksheet, EstimatedTaxWorksheet,
+    EstimatedTaxWorksheetContext, EstimatedTaxWorksheetResult, NiitConfig, NiitWorksheet,
+    QbiConfig, QbiWorksheet, QdcgConfig, SeWorksheet, SeWorksheetConfig, SeWorksheetResult,
 };
 use tax_core::db::{DbConfig, RepositoryRegistry};
 use tax_core::{
-    FilingStatusCode, TaxEstimate, TaxEstimateComputed, TaxEstimateInput, TaxRepository,
+    DEFAULT_SCENARIO_NAME, FilingStatusCode, TaxEstimate, TaxEstimateComputed, TaxEstimateInput,
+    TaxRepository,
 };
-use tax_ui::app::{FilingStatusData, TaxYearData, build_registry, load_tax_year_data};
+use tax_ui::app::{
+    FilingStatusData, TaxYearData, build_registry, calculate_estimate, load_tax_year_data,
+    se_tax_estimate,
+};
+use tax_ui::models::SeWorksheetModel;
 
 use pretty_assertions::assert_eq;
 use rust_decimal_macros::dec;
@@ -21,9 +27,13 @@ fn make_input() -> TaxEstimateInput {
     TaxEstimateInput {
         tax_year: 2025,
         filing_status: FilingStatusCode::Single,
+        scenario_name: DEFAULT_SCENARIO_NAME.to_string(),
         se_income: Some(dec!(100_000.00)),
         expected_crp_payments: None,
         expected_wages: Some(dec!(50_000.00)),
+        net_investment_income: Some(dec!(10_000.00)),
+        qualified_dividends: Some(dec!(4_000.00)),
+        net_capital_gain: Some(dec!(6_000.00)),
         expected_agi: dec!(175_000.00),
         expected_deduction: dec!(15_000.00),
         expected_qbi_deduction: None,
@@ -32,6 +42,7 @@ fn make_input() -> TaxEstimateInput {
         expected_other_taxes: None,
         expected_withholding: Some(dec!(20_000.00)),
         prior_year_tax: Some(dec!(25_000.00)),
+        prior_year_agi: Some(dec!(160_000.00)),
     }
 }
 
@@ -57,19 +68,71 @@ fn run_se_worksheet(
         .expect("SE worksheet calculation should succeed")
 }
 
+fn run_additional_medicare_tax(
+    status_data: &FilingStatusData,
+    config: &tax_core::TaxYearConfig,
+    wages: Decimal,
+    se_net_earnings: Decimal,
+) -> Decimal {
+    let amt_config = AdditionalMedicareTaxConfig::from_reference(
+        config,
+        &status_data.additional_medicare_threshold,
+    );
+    AdditionalMedicareTaxWorksheet::new(amt_config)
+        .calculate(wages, se_net_earnings)
+        .expect("Additional Medicare Tax calculation should succeed")
+        .total_tax
+}
+
+fn run_niit(
+    status_data: &FilingStatusData,
+    config: &tax_core::TaxYearConfig,
+    net_investment_income: Decimal,
+    modified_agi: Decimal,
+) -> Decimal {
+    let niit_config = NiitConfig::from_reference(config, &status_data.niit_threshold);
+    NiitWorksheet::new(niit_config)
+        .calculate(net_investment_income, modified_agi)
+        .expect("NIIT calculation should succeed")
+        .net_investment_income_tax
+}
+
+fn run_qbi_deduction(
+    status_data: &FilingStatusData,
+    qualified_business_income: Decimal,
+    taxable_income: Decimal,
+    net_capital_gain: Decimal,
+) -> Decimal {
+    QbiWorksheet::new(QbiConfig::from_reference(&status_data.qbi_threshold))
+        .calculate(qualified_business_income, taxable_income, net_capital_gain)
+        .expect("QBI deduction calculation should succeed")
+        .deduction
+}
+
 fn run_estimated_tax_worksheet(
     status_data: &FilingStatusData,
     input: &TaxEstimateInput,
+    qbi_deduction: Decimal,
     se_self_employment_tax: Decimal,
+    additional_medicare_tax: Decimal,
+    net_investment_income_tax: Decimal,
     config: &tax_core::TaxYearConfig,
 ) -> tax_core::calculations::EstimatedTaxWorksheetResult {
     let worksheet_input = input.to_estimated_tax_worksheet_input(&EstimatedTaxWorksheetContext {
+        qbi_deduction,
         self_employment_tax: se_self_employment_tax,
+        additional_medicare_tax,
+        net_investment_income_tax,
         refundable_credits: Decimal::ZERO,
         is_farmer_or_fisher: false,
         required_payment_threshold: config.req_pmnt_threshold,
+        prior_year_agi_threshold: config.prior_agi_threshold_for(input.filing_status),
+        high_income_prior_year_multiplier: config.prior_tax_multiplier,
     });
-    let worksheet = EstimatedTaxWorksheet::new(&status_data.tax_brackets);
+    let worksheet = EstimatedTaxWorksheet::new(&status_data.tax_brackets)
+        .with_capital_gain_breakpoints(QdcgConfig::from_reference(
+            &status_data.capital_gain_breakpoints,
+        ));
     worksheet
         .calculate(&worksheet_input)
         .expect("Estimated tax worksheet calculation should succeed")
@@ -92,6 +155,10 @@ async fn estimate_input_through_db_and_calculations_to_tax_estimate() {
     let year_data: TaxYearData = load_tax_year_data(&*repo, input.tax_year)
         .await
         .expect("load_tax_year_data should succeed");
+    assert!(
+        !year_data.interest_rates.is_empty(),
+        "seeded DB should have underpayment interest rates"
+    );
 
     let status_data: &FilingStatusData =
         status_data_for_filing_status(&year_data, input.filing_status)
@@ -102,12 +169,56 @@ async fn estimate_input_through_db_and_calculations_to_tax_estimate() {
     let wages: Decimal = input.expected_wages.unwrap_or(Decimal::ZERO);
     let se_result: SeWorksheetResult = run_se_worksheet(&year_data.config, se_income, crp, wages);
 
+    // $50,000 wages + $92,350 net SE earnings stays under the $200,000 threshold.
+    let additional_medicare_tax: Decimal = run_additional_medicare_tax(
+        status_data,
+        &year_data.config,
+        wages,
+        se_result.net_earnings,
+    );
+    assert_eq!(additional_medicare_tax, Decimal::ZERO);
+
+    // $175,000 MAGI is also under the $200,000 NIIT threshold.
+    let net_investment_income_tax: Decimal = run_niit(
+        status_data,
+        &year_data.config,
+        input.net_investment_income.unwrap_or(Decimal::ZERO),
+        input.expected_agi,
+    );
+    assert_eq!(net_investment_income_tax, Decimal::ZERO);
+
+    // $160,000 taxable income before the deduction is under the $197,300
+    // Form 8995 threshold, so 20% of ($100,000 - $7,064.78) is deductible.
+    let qbi_deduction: Decimal = run_qbi_deduction(
+        status_data,
+        QbiWorksheet::qualified_business_income(se_income, se_result.se_tax_deduction),
+        input.expected_agi - input.expected_deduction,
+        input.qualified_dividends.unwrap_or(Decimal::ZERO)
+            + input.net_capital_gain.unwrap_or(Decimal::ZERO),
+    );
+    assert_eq!(qbi_deduction, dec!(18_587.04));
+
     let est_result: EstimatedTaxWorksheetResult = run_estimated_tax_worksheet(
         status_data,
         &input,
+        qbi_deduction,
         se_result.self_employment_tax,
+        additional_medicare_tax,
+        net_investment_income_tax,
         &year_data.config,
     );
+    assert_eq!(
+        est_result.prior_year_tax_multiplier,
+        dec!(1.10),
+        "prior-year AGI above $150,000 should select the 110% safe harbor"
+    );
+
+    // The $10,000 of qualified income falls in the 24% bracket and the 15%
+    // capital gain band, saving 9% of it on line 4.
+    let regular_tax = EstimatedTaxWorksheet::new(&status_data.tax_brackets)
+        .calculate_tax(est_result.taxable_income)
+        .expect("regular tax should calculate");
+    assert_eq!(regular_tax - est_result.calculated_tax, dec!(900.00));
 
     let created: TaxEstimate = repo
         .create_estimate(input.clone())
@@ -143,4 +254,158 @@ async fn estimate_input_through_db_and_calculations_to_tax_estimate() {
     assert_eq!(fetched.input.filing_status, input.filing_status);
     assert_eq!(fetched.input.expected_agi, input.expected_agi);
     assert_eq!(fetched.input.expected_deduction, input.expected_deduction);
+    assert_eq!(
+        fetched.input.net_investment_income,
+        input.net_investment_income
+    );
+    assert_eq!(fetched.input.qualified_dividends, input.qualified_dividends);
+    assert_eq!(fetched.input.net_capital_gain, input.net_capital_gain);
+}
+
+/// The headless pipeline produces the same results as running each
+/// worksheet by hand.
+#[tokio::test]
+async fn calculate_estimate_matches_the_worksheets_run_by_hand() {
+    let input = make_input();
+    let registry: RepositoryRegistry = build_registry();
+    let repo: Box<dyn TaxRepository> = registry
+        .create(&DbConfig {
+            backend: "sqlite".to_string(),
+            connection_string: ":memory:".to_string(),
+        })
+        .await
+        .expect("repository creation should succeed");
+    let year_data: TaxYearData = load_tax_year_data(&*repo, input.tax_year)
+        .await
+        .expect("load_tax_year_data should succeed");
+    let status_data: &FilingStatusData = year_data
+        .status(input.filing_status)
+        .expect("seeded DB should have filing status for estimate");
+
+    let calculation =
+        calculate_estimate(&year_data, &input).expect("calculate_estimate should succeed");
+
+    let se_result = run_se_worksheet(
+        &year_data.config,
+        dec!(100_000.00),
+        Decimal::ZERO,
+        dec!(50_000.00),
+    );
+    assert_eq!(calculation.self_employment, se_result);
+    assert_eq!(
+        calculation.qbi.as_ref().map(|qbi| qbi.deduction),
+        Some(dec!(18_587.04))
+    );
+    let est_result = run_estimated_tax_worksheet(
+        status_data,
+        &input,
+        dec!(18_587.04),
+        se_result.self_employment_tax,
+        Decimal::ZERO,
+        Decimal::ZERO,
+        &year_data.config,
+    );
+    assert_eq!(calculation.result, est_result);
+    assert_eq!(
+        calculation.computed(),
+        TaxEstimateComputed {
+            se_tax: se_result.self_employment_tax,
+            total_tax: est_result.total_estimated_tax,
+            required_payment: est_result.required_annual_payment,
+        }
+    );
+}
+
+/// The estimate form and `tax-estimate calculate` give the same results for
+/// the same input.
+///
+/// The form side is rebuilt the way `EstimatedIncomeForm` does it: the SE
+/// worksheet dialog fills its model, the form takes the SE inputs from that
+/// model, and the whole input goes through [`calculate_estimate`]. The CLI
+/// side runs the real binary against its own seeded in-memory database.
+#[tokio::test]
+async fn estimate_form_and_cli_agree() {
+    let repo: Box<dyn TaxRepository> = build_registry()
+        .create(&DbConfig {
+            backend: "sqlite".to_string(),
+            connection_string: ":memory:".to_string(),
+        })
+        .await
+        .expect("repository creation should succeed");
+    let year_data: TaxYearData = load_tax_year_data(&*repo, 2025)
+        .await
+        .expect("load_tax_year_data should succeed");
+
+    let mut se_model = SeWorksheetModel {
+        line_1a_expected_se_income: Some(dec!(100_000.00)),
+        line_6_expected_wages: Some(dec!(50_000.00)),
+        ..SeWorksheetModel::default()
+    };
+    let se_result = se_tax_estimate(
+        &year_data.config,
+        se_model.line_1a_expected_se_income.unwrap_or_default(),
+        se_model.line_1b_expected_crp_payments.unwrap_or_default(),
+        se_model.line_6_expected_wages.unwrap_or_default(),
+    )
+    .expect("SE worksheet calculation should succeed");
+    se_model.from_worksheet_result(&se_result);
+    let form_input = TaxEstimateInput {
+        se_income: se_model.line_1a_expected_se_income,
+        expected_crp_payments: se_model.line_1b_expected_crp_payments,
+        expected_wages: se_model.line_6_expected_wages,
+        ..make_input()
+    };
+    let form = calculate_estimate(&year_data, &form_input).expect("form calculation");
+    assert_eq!(
+        Some(form.self_employment.self_employment_tax),
+        se_model.line_10_total_se_tax
+    );
+
+    // The binary reads the application config; give it an empty home so a
+    // developer's own config is never picked up.
+    let home =
+        std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("estimate_form_and_cli_agree");
+    std::fs::create_dir_all(&home).unwrap();
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_tax-estimate"))
+        .env("HOME", &home)
+        .env("XDG_CONFIG_HOME", &home)
+        .env("APPDATA", &home)
+        .args([
+            "--backend",
+            "sqlite",
+            "--database",
+            ":memory:",
+            "--format",
+            "json",
+            "calculate",
+        ])
+        .args(["--tax-year", "2025", "--filing-status", "S"])
+        .args(["--agi", "175000.00", "--deduction", "15000.00"])
+        .args(["--se-income", "100000.00", "--wages", "50000.00"])
+        .args(["--net-investment-income", "10000.00"])
+        .args([
+            "--qualified-dividends",
+            "4000.00",
+            "--net-capital-gain",
+            "6000.00",
+        ])
+        .args(["--withholding", "20000.00"])
+        .args([
+            "--prior-year-tax",
+            "25000.00",
+            "--prior-year-agi",
+            "160000.00",
+        ])
+        .output()
+        .expect("tax-estimate should run");
+    assert!(
+        output.status.success(),
+        "tax-estimate failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let cli: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("tax-estimate should print JSON");
+
+    assert_eq!(cli[0]["input"], serde_json::to_value(&form_input).unwrap());
+    assert_eq!(cli[0]["calculation"], serde_json::to_value(&form).unwrap());
 }